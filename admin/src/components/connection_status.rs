@@ -0,0 +1,68 @@
+use ::dioxus::prelude::*;
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::components::reconnect_button::ReconnectButton;
+use crate::config::AdminConfig;
+use crate::module_bindings::dioxus::{use_connection_error, use_connection_state, ConnectionState};
+
+/// Shown instead of the page content while there is no SpacetimeDB connection. The last
+/// connection error stays visible while a retry runs; it is cleared once a connect succeeds.
+/// After `AdminConfig.max_connect_attempts` reconnects the card stops showing progress and
+/// reports the connection as failed; retries continue, and a later success still gets through.
+#[component]
+pub fn ConnectionStatusCard() -> Element {
+    let config = use_context::<AdminConfig>();
+    let state = use_connection_state();
+    let conn_error = use_connection_error();
+    let max_attempts = config.max_connect_attempts;
+    let attempt = match state() {
+        ConnectionState::Reconnecting { attempt, .. } => Some(attempt),
+        _ => None,
+    };
+    let given_up = max_attempts > 0 && attempt.is_some_and(|a| a > max_attempts);
+    let retrying = !given_up
+        && matches!(
+            state(),
+            ConnectionState::Connecting | ConnectionState::Reconnecting { .. }
+        );
+
+    rsx! {
+        Container { class: "mt-5",
+            if retrying {
+                div { class: "d-flex justify-content-center align-items-center mb-4",
+                    div { class: "text-center",
+                        Spinner { color: Color::Primary, class: "mb-3", "Laden…" }
+                        p { class: "text-muted", "Verbindung zu SpacetimeDB wird hergestellt…" }
+                        if let Some(attempt) = attempt {
+                            if max_attempts > 0 {
+                                p { class: "text-muted small", "Versuch {attempt} von {max_attempts}" }
+                            } else {
+                                p { class: "text-muted small", "Versuch {attempt}" }
+                            }
+                        }
+                    }
+                }
+            }
+            if !retrying || conn_error().is_some() {
+                Alert { color: Color::Danger, class: "d-flex align-items-start", role: "alert",
+                    Icon { name: "exclamation-circle", class: "me-2 mt-1 flex-shrink-0" }
+                    div { style: "overflow-x: auto; width: 100%;",
+                        if given_up {
+                            div { class: "fw-semibold", "Keine Verbindung zu SpacetimeDB nach {max_attempts} Versuchen." }
+                        } else if retrying {
+                            div { class: "fw-semibold", "Letzter Verbindungsfehler" }
+                        } else {
+                            div { class: "fw-semibold", "Verbindung zu SpacetimeDB getrennt oder fehlgeschlagen." }
+                        }
+                        if let Some(err) = conn_error() {
+                            div { class: "mt-1 small font-monospace", "{err}" }
+                        }
+                    }
+                    div { class: "ms-2 flex-shrink-0",
+                        ReconnectButton {}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use ::dioxus::{logger::tracing::warn, prelude::*};
+use dioxus_bootstrap_css::prelude::*;
+use spacetimedb_sdk::{DbContext, SubscriptionHandle as _, Table};
+
+use crate::module_bindings::dioxus::{use_connection, SharedConnection};
+use crate::module_bindings::{
+    MtaConnectionLog, SubscriptionHandle, VisibleMtaConnectionLogInsertCallbackId,
+    VisibleMtaConnectionLogTableAccess,
+};
+
+/// How many decisions the live view keeps; older ones fall off the end.
+const LIVE_DECISIONS_MAX: usize = 200;
+
+/// Subscription and insert callback the live view holds on one connection.
+struct LiveFeed {
+    connection: SharedConnection,
+    subscription: SubscriptionHandle,
+    on_insert: VisibleMtaConnectionLogInsertCallbackId,
+}
+
+impl LiveFeed {
+    /// Subscribe to `visible_mta_connection_log` and keep the newest rows in `buffer`, newest first.
+    fn start(connection: SharedConnection, buffer: SyncSignal<Vec<MtaConnectionLog>>) -> Self {
+        let on_insert = connection
+            .db
+            .visible_mta_connection_log()
+            .on_insert(move |_ctx, row| {
+                let mut buffer = buffer;
+                let mut rows = buffer.write();
+                // Rows of the initial subscription arrive in no particular order, and come
+                // again after a reconnect.
+                let pos = rows.partition_point(|r| r.id > row.id);
+                let known = rows.get(pos).is_some_and(|r| r.id == row.id);
+                if !known && pos < LIVE_DECISIONS_MAX {
+                    rows.insert(pos, row.clone());
+                    rows.truncate(LIVE_DECISIONS_MAX);
+                }
+            });
+        let subscription = connection
+            .subscription_builder()
+            .on_error(|_ctx, err| warn!("Live decision subscription failed: {err}"))
+            .subscribe(["SELECT * FROM visible_mta_connection_log"]);
+        Self {
+            connection,
+            subscription,
+            on_insert,
+        }
+    }
+
+    /// Remove the callback and end the subscription, so nothing outlives the component.
+    fn stop(self) {
+        self.connection
+            .db
+            .visible_mta_connection_log()
+            .remove_on_insert(self.on_insert);
+        if let Err(e) = self.subscription.unsubscribe() {
+            warn!("Live decision unsubscribe failed: {e}");
+        }
+    }
+}
+
+/// `tail -f` for MTA decisions: newest `mta_connection_log` entries as they are written.
+/// Pausing freezes the list; new entries are still collected and shown on resume.
+#[component]
+pub fn LiveDecisionStream() -> Element {
+    let connection = use_connection();
+    let buffer: SyncSignal<Vec<MtaConnectionLog>> = use_signal_sync(Vec::new);
+    // Snapshot shown while paused.
+    let mut frozen: Signal<Option<Vec<MtaConnectionLog>>> = use_signal(|| None);
+    let feed: Rc<RefCell<Option<LiveFeed>>> = use_hook(|| Rc::new(RefCell::new(None)));
+
+    {
+        let feed = feed.clone();
+        use_effect(move || {
+            // Re-register whenever the connection instance changes (reconnect).
+            let current = connection();
+            if let Some(old) = feed.borrow_mut().take() {
+                old.stop();
+            }
+            if let Some(conn) = current {
+                feed.replace(Some(LiveFeed::start(conn, buffer)));
+            }
+        });
+    }
+    {
+        let feed = feed.clone();
+        use_drop(move || {
+            if let Some(old) = feed.borrow_mut().take() {
+                old.stop();
+            }
+        });
+    }
+
+    let paused = frozen().is_some();
+    let rows = frozen().unwrap_or_else(|| buffer());
+    let newest_shown = rows.first().map_or(0, |r| r.id);
+    let waiting = if paused {
+        buffer().iter().take_while(|r| r.id > newest_shown).count()
+    } else {
+        0
+    };
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white d-flex align-items-center",
+                    body_class: "p-0",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "broadcast", class: "me-2" }
+                            "Live-Entscheidungen"
+                        }
+                        div { class: "ms-auto d-flex align-items-center gap-2",
+                            if waiting > 0 {
+                                Badge { color: Color::Warning, "{waiting} neu" }
+                            }
+                            button {
+                                class: "btn btn-sm btn-light",
+                                onclick: move |_| {
+                                    if paused {
+                                        frozen.set(None);
+                                    } else {
+                                        frozen.set(Some(buffer.peek().clone()));
+                                    }
+                                },
+                                if paused {
+                                    Icon { name: "play-fill", class: "me-1" }
+                                    "Fortsetzen"
+                                } else {
+                                    Icon { name: "pause-fill", class: "me-1" }
+                                    "Pausieren"
+                                }
+                            }
+                        }
+                    },
+                    body: rsx! {
+                        if rows.is_empty() {
+                            div { class: "p-4 text-muted",
+                                Icon { name: "hourglass-split", class: "me-2" }
+                                "Noch keine Entscheidungen empfangen."
+                            }
+                        } else {
+                            div { class: "table-responsive", style: "max-height: 24rem; overflow-y: auto;",
+                                table { class: "table table-sm table-hover mb-0 small",
+                                    thead { class: "table-light",
+                                        tr {
+                                            th { "Zeit" }
+                                            th { "IP" }
+                                            th { "Stufe" }
+                                            th { "Aktion" }
+                                            th { "Details" }
+                                        }
+                                    }
+                                    tbody {
+                                        for row in rows {
+                                            tr { key: "{row.id}",
+                                                td { class: "text-nowrap", "{row.timestamp}" }
+                                                td { code { "{row.client_ip}" } }
+                                                td { "{row.stage}" }
+                                                td {
+                                                    match row.action.as_str() {
+                                                        "accept" => rsx! { Badge { color: Color::Success, "{row.action}" } },
+                                                        "reject" => rsx! { Badge { color: Color::Danger, "{row.action}" } },
+                                                        "defer" => rsx! { Badge { color: Color::Warning, "{row.action}" } },
+                                                        "dry-run" => rsx! { Badge { color: Color::Info, "{row.action}" } },
+                                                        _ => rsx! { Badge { color: Color::Secondary, "{row.action}" } },
+                                                    }
+                                                }
+                                                td { class: "text-muted", "{row.details}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
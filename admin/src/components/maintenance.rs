@@ -0,0 +1,244 @@
+use ::dioxus::{
+    logger::tracing::{error, info},
+    prelude::*,
+};
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::module_bindings::dioxus::{
+    use_reducer_flush_pending_syncs, use_reducer_set_dry_run, use_reducer_set_envelope_sample_rate,
+    use_reducer_set_maintenance_mode, use_reducer_set_reject_unknown_recipients_strict,
+    use_reducer_set_sync_paused, use_table_module_settings,
+};
+use crate::router::use_is_admin;
+
+/// Whether the module currently defers all mail (`module_settings.maintenance_mode`).
+fn use_maintenance_mode() -> bool {
+    let settings = use_table_module_settings();
+    settings().iter().any(|s| s.maintenance_mode)
+}
+
+/// Whether rejections are only logged (`module_settings.dry_run`).
+fn use_dry_run() -> bool {
+    let settings = use_table_module_settings();
+    settings().iter().any(|s| s.dry_run)
+}
+
+/// Whether Django syncs are queued instead of applied (`module_settings.sync_paused`).
+fn use_sync_paused() -> bool {
+    let settings = use_table_module_settings();
+    settings().iter().any(|s| s.sync_paused)
+}
+
+/// Whether RCPT ignores catch-all domain routes
+/// (`module_settings.reject_unknown_recipients_strict`).
+fn use_strict_recipients() -> bool {
+    let settings = use_table_module_settings();
+    settings()
+        .iter()
+        .any(|s| s.reject_unknown_recipients_strict)
+}
+
+/// Warning banner below the navbar while maintenance, dry-run mode, strict recipient checking or
+/// a sync pause is on. Admins can end each from here.
+#[component]
+pub fn MaintenanceBanner() -> Element {
+    let maintenance = use_maintenance_mode();
+    let dry_run = use_dry_run();
+    let strict_recipients = use_strict_recipients();
+    let sync_paused = use_sync_paused();
+    let is_admin = use_is_admin();
+
+    rsx! {
+        if maintenance {
+            Alert { color: Color::Warning, class: "d-flex align-items-center rounded-0 mb-0",
+                Icon { name: "cone-striped", class: "me-2" }
+                "Wartungsmodus aktiv: Der Mailserver stellt alle Nachrichten mit 451 zurück."
+                if is_admin {
+                    div { class: "ms-auto",
+                        MaintenanceToggle {}
+                    }
+                }
+            }
+        }
+        if dry_run {
+            Alert { color: Color::Info, class: "d-flex align-items-center rounded-0 mb-0",
+                Icon { name: "eyeglasses", class: "me-2" }
+                "Testmodus aktiv: Ablehnungen werden nur protokolliert, alle Nachrichten werden angenommen."
+                if is_admin {
+                    div { class: "ms-auto",
+                        DryRunToggle {}
+                    }
+                }
+            }
+        }
+        if strict_recipients {
+            Alert { color: Color::Danger, class: "d-flex align-items-center rounded-0 mb-0",
+                Icon { name: "lock-fill", class: "me-2" }
+                "Strikte Empfängerprüfung aktiv: Nur Themenadressen nehmen Mail an, Domain-Weiterleitungen werden abgelehnt."
+                if is_admin {
+                    div { class: "ms-auto",
+                        StrictRecipientsToggle {}
+                    }
+                }
+            }
+        }
+        if sync_paused {
+            Alert { color: Color::Secondary, class: "d-flex align-items-center rounded-0 mb-0",
+                Icon { name: "pause-circle", class: "me-2" }
+                "Django-Sync pausiert: Änderungen werden gesammelt und erst nach dem Fortsetzen übernommen."
+                if is_admin {
+                    div { class: "ms-auto",
+                        SyncPauseToggle {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Button that switches maintenance mode on or off.
+#[component]
+pub fn MaintenanceToggle() -> Element {
+    let maintenance = use_maintenance_mode();
+    let set_maintenance_mode = use_reducer_set_maintenance_mode();
+
+    rsx! {
+        Button {
+            color: if maintenance { Color::Success } else { Color::Warning },
+            size: Size::Sm,
+            onclick: move |_| {
+                info!("Setting maintenance mode = {}", !maintenance);
+                if let Err(e) = set_maintenance_mode(!maintenance) {
+                    error!("set_maintenance_mode failed: {e:?}");
+                }
+            },
+            if maintenance {
+                Icon { name: "play-fill", class: "me-1" }
+                "Wartungsmodus beenden"
+            } else {
+                Icon { name: "cone-striped", class: "me-1" }
+                "Wartungsmodus aktivieren"
+            }
+        }
+    }
+}
+
+/// Button that switches dry-run mode on or off.
+#[component]
+pub fn DryRunToggle() -> Element {
+    let dry_run = use_dry_run();
+    let set_dry_run = use_reducer_set_dry_run();
+
+    rsx! {
+        Button {
+            color: if dry_run { Color::Success } else { Color::Info },
+            size: Size::Sm,
+            onclick: move |_| {
+                info!("Setting dry-run mode = {}", !dry_run);
+                if let Err(e) = set_dry_run(!dry_run) {
+                    error!("set_dry_run failed: {e:?}");
+                }
+            },
+            if dry_run {
+                Icon { name: "play-fill", class: "me-1" }
+                "Testmodus beenden"
+            } else {
+                Icon { name: "eyeglasses", class: "me-1" }
+                "Testmodus aktivieren"
+            }
+        }
+    }
+}
+
+/// Button that switches strict recipient checking on or off.
+#[component]
+pub fn StrictRecipientsToggle() -> Element {
+    let strict = use_strict_recipients();
+    let set_strict = use_reducer_set_reject_unknown_recipients_strict();
+
+    rsx! {
+        Button {
+            color: if strict { Color::Success } else { Color::Danger },
+            size: Size::Sm,
+            onclick: move |_| {
+                info!("Setting strict recipient checking = {}", !strict);
+                if let Err(e) = set_strict(!strict) {
+                    error!("set_reject_unknown_recipients_strict failed: {e:?}");
+                }
+            },
+            if strict {
+                Icon { name: "unlock-fill", class: "me-1" }
+                "Strikte Prüfung beenden"
+            } else {
+                Icon { name: "lock-fill", class: "me-1" }
+                "Nur bekannte Empfänger"
+            }
+        }
+    }
+}
+
+/// Button that pauses the Django sync, or resumes it and applies the queued syncs.
+#[component]
+pub fn SyncPauseToggle() -> Element {
+    let sync_paused = use_sync_paused();
+    let set_sync_paused = use_reducer_set_sync_paused();
+    let flush_pending_syncs = use_reducer_flush_pending_syncs();
+
+    rsx! {
+        Button {
+            color: if sync_paused { Color::Success } else { Color::Secondary },
+            size: Size::Sm,
+            onclick: move |_| {
+                info!("Setting sync paused = {}", !sync_paused);
+                if let Err(e) = set_sync_paused(!sync_paused) {
+                    error!("set_sync_paused failed: {e:?}");
+                    return;
+                }
+                if sync_paused {
+                    if let Err(e) = flush_pending_syncs() {
+                        error!("flush_pending_syncs failed: {e:?}");
+                    }
+                }
+            },
+            if sync_paused {
+                Icon { name: "play-fill", class: "me-1" }
+                "Sync fortsetzen"
+            } else {
+                Icon { name: "pause-circle", class: "me-1" }
+                "Sync pausieren"
+            }
+        }
+    }
+}
+
+/// Input for the share of DATA messages (in per mille) stored in `envelope_sample`.
+#[component]
+pub fn EnvelopeSampleRateInput() -> Element {
+    let settings = use_table_module_settings();
+    let set_sample_rate = use_reducer_set_envelope_sample_rate();
+    let permille = settings().first().map_or(0, |s| s.envelope_sample_permille);
+
+    rsx! {
+        div { class: "input-group input-group-sm", style: "width: 14rem;",
+            span { class: "input-group-text", "Stichprobe" }
+            input {
+                class: "form-control",
+                r#type: "number",
+                min: "0",
+                max: "1000",
+                title: "Anteil der Nachrichten, deren vollständiger Umschlag gespeichert wird (0 = aus)",
+                value: "{permille}",
+                onchange: move |e| {
+                    let Ok(permille) = e.value().trim().parse::<u32>() else {
+                        return;
+                    };
+                    info!("Setting envelope sample rate = {permille}‰");
+                    if let Err(e) = set_sample_rate(permille) {
+                        error!("set_envelope_sample_rate failed: {e:?}");
+                    }
+                },
+            }
+            span { class: "input-group-text", "‰" }
+        }
+    }
+}
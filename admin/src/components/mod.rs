@@ -1 +1,8 @@
+pub mod connection_status;
+pub mod live_decisions;
+pub mod maintenance;
 pub mod navbar;
+pub mod offending_prefixes;
+pub mod reconnect_button;
+pub mod reducer_outcome;
+pub mod stage_timing;
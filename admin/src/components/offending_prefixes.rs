@@ -0,0 +1,152 @@
+use ::dioxus::{
+    logger::tracing::{error, info},
+    prelude::*,
+};
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::module_bindings::dioxus::{
+    use_reducer_block_ip, use_reducer_top_offending_prefixes, use_table_my_offending_prefix_results,
+};
+
+/// `request_id` of the panel's `top_offending_prefixes` answer.
+const NETBLOCKS_REQUEST_ID: &str = "worst-netblocks";
+/// How many prefixes the panel lists.
+const NETBLOCKS_LIMIT: u32 = 10;
+/// Selectable windows: seconds and label.
+const NETBLOCK_WINDOWS: [(u64, &str); 3] = [
+    (3_600, "1 Stunde"),
+    (86_400, "24 Stunden"),
+    (7 * 86_400, "7 Tage"),
+];
+
+/// "Schlimmste Netzblöcke": network prefixes with the most rejected connections in the chosen
+/// window, from `top_offending_prefixes`, each with a button to block the whole prefix.
+#[component]
+pub fn WorstNetblocks() -> Element {
+    let results = use_table_my_offending_prefix_results();
+    let top_offending_prefixes = use_reducer_top_offending_prefixes();
+    let block_ip = use_reducer_block_ip();
+    let mut window_secs = use_signal(|| NETBLOCK_WINDOWS[1].0);
+
+    let refresh = move || {
+        if let Err(e) = top_offending_prefixes(
+            NETBLOCKS_REQUEST_ID.to_string(),
+            window_secs(),
+            NETBLOCKS_LIMIT,
+        ) {
+            error!("top_offending_prefixes failed: {e:?}");
+        }
+    };
+    {
+        let refresh = refresh.clone();
+        // Runs again when the window or the connection changes.
+        use_effect(move || refresh());
+    }
+
+    let result = results()
+        .into_iter()
+        .find(|r| r.request_id == NETBLOCKS_REQUEST_ID);
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white d-flex align-items-center",
+                    body_class: "p-0",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "shield-exclamation", class: "me-2" }
+                            "Schlimmste Netzblöcke"
+                        }
+                        div { class: "ms-auto d-flex align-items-center gap-2",
+                            select {
+                                class: "form-select form-select-sm",
+                                style: "width: auto;",
+                                onchange: move |e| {
+                                    if let Ok(secs) = e.value().parse::<u64>() {
+                                        window_secs.set(secs);
+                                    }
+                                },
+                                for (secs, label) in NETBLOCK_WINDOWS {
+                                    option {
+                                        key: "{secs}",
+                                        value: "{secs}",
+                                        selected: secs == window_secs(),
+                                        "{label}"
+                                    }
+                                }
+                            }
+                            button {
+                                class: "btn btn-sm btn-light",
+                                onclick: {
+                                    let refresh = refresh.clone();
+                                    move |_| refresh()
+                                },
+                                Icon { name: "arrow-clockwise", class: "me-1" }
+                                "Aktualisieren"
+                            }
+                        }
+                    },
+                    body: rsx! {
+                        match result {
+                            None => rsx! {
+                                div { class: "p-4 text-muted",
+                                    Icon { name: "hourglass-split", class: "me-2" }
+                                    "Wird berechnet…"
+                                }
+                            },
+                            Some(result) if result.prefixes.is_empty() => rsx! {
+                                p { class: "text-muted p-3 mb-0",
+                                    "Keine abgelehnten Verbindungen aus ungesperrten Netzblöcken im Zeitraum."
+                                }
+                            },
+                            Some(result) => rsx! {
+                                table { class: "table table-sm table-hover mb-0 small",
+                                    thead { class: "table-light",
+                                        tr {
+                                            th { "Netzblock" }
+                                            th { class: "text-end", "Ablehnungen" }
+                                            th {}
+                                        }
+                                    }
+                                    tbody {
+                                        for (prefix, rejects) in result.prefixes.iter().cloned().zip(result.rejects.iter().copied()) {
+                                            tr { key: "{prefix}",
+                                                td { code { "{prefix}" } }
+                                                td { class: "text-end", "{rejects}" }
+                                                td { class: "text-end",
+                                                    Button {
+                                                        color: Color::Danger,
+                                                        outline: true,
+                                                        size: Size::Sm,
+                                                        onclick: {
+                                                            let block_ip = block_ip.clone();
+                                                            let refresh = refresh.clone();
+                                                            let prefix = prefix.clone();
+                                                            move |_| {
+                                                                info!("Blocking prefix {prefix}");
+                                                                let reason = format!("{rejects} Ablehnungen im Zeitraum");
+                                                                if let Err(e) = block_ip(prefix.clone(), reason) {
+                                                                    error!("block_ip failed: {e:?}");
+                                                                    return;
+                                                                }
+                                                                refresh();
+                                                            }
+                                                        },
+                                                        Icon { name: "slash-circle", class: "me-1" }
+                                                        "Netzblock sperren"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use ::dioxus::{logger::tracing::info, prelude::*};
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::hooks::connection::use_admin_connection;
+use crate::module_bindings::dioxus::{use_connection_state, ConnectionState};
+
+/// Drops the current SpacetimeDB connection and starts a fresh one.
+/// Disabled while a connection attempt is in progress.
+#[component]
+pub fn ReconnectButton() -> Element {
+    let connection = use_admin_connection();
+    let state = use_connection_state();
+    let connecting = matches!(state(), ConnectionState::Connecting);
+
+    rsx! {
+        Button {
+            color: Color::Primary,
+            size: Size::Sm,
+            disabled: connecting,
+            onclick: move |_| {
+                info!("Manual reconnect requested");
+                connection.disconnect();
+                connection.connect();
+            },
+            Icon { name: "arrow-clockwise", class: "me-1" }
+            "Neu verbinden"
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use ::dioxus::prelude::*;
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::hooks::reducer_outcome::use_reducer_outcome;
+
+/// Error banner below the navbar for the latest reducer call made through a
+/// `use_reducer_*_then` hook that the server refused, so the error is seen even if the calling
+/// form is no longer shown. Succeeded calls show nothing here. Closing it clears the outcome.
+#[component]
+pub fn ReducerErrorBanner() -> Element {
+    let mut outcome = use_reducer_outcome();
+    let failed = outcome().and_then(|o| o.result.err().map(|e| (o.reducer, e)));
+
+    rsx! {
+        if let Some((reducer, error)) = failed {
+            Alert { color: Color::Danger, class: "d-flex align-items-center rounded-0 mb-0",
+                Icon { name: "exclamation-octagon", class: "me-2" }
+                "Aktion {reducer} fehlgeschlagen: {error}"
+                button {
+                    class: "btn-close ms-auto",
+                    "aria-label": "Schließen",
+                    onclick: move |_| outcome.set(None),
+                }
+            }
+        }
+    }
+}
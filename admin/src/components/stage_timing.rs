@@ -0,0 +1,101 @@
+use ::dioxus::{logger::tracing::error, prelude::*};
+use dioxus_bootstrap_css::prelude::*;
+
+use crate::module_bindings::dioxus::{
+    use_reducer_stage_timing_percentiles, use_table_my_stage_timing_results,
+};
+
+/// `request_id` of the panel's `stage_timing_percentiles` answer.
+const STAGE_TIMING_REQUEST_ID: &str = "stage-timing";
+
+/// Microseconds as milliseconds with one decimal.
+fn format_ms(micros: u64) -> String {
+    format!("{:.1} ms", micros as f64 / 1000.0)
+}
+
+/// "Verarbeitungszeiten": p50 and p95 of the MTA hook processing time per stage, from
+/// `stage_timing_percentiles`. Only the HTTP route is measured.
+#[component]
+pub fn StageTimingCard() -> Element {
+    let results = use_table_my_stage_timing_results();
+    let stage_timing_percentiles = use_reducer_stage_timing_percentiles();
+
+    let refresh = move || {
+        if let Err(e) = stage_timing_percentiles(STAGE_TIMING_REQUEST_ID.to_string()) {
+            error!("stage_timing_percentiles failed: {e:?}");
+        }
+    };
+    {
+        let refresh = refresh.clone();
+        // Runs again when the connection changes.
+        use_effect(move || refresh());
+    }
+
+    let result = results()
+        .into_iter()
+        .find(|r| r.request_id == STAGE_TIMING_REQUEST_ID);
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white d-flex align-items-center",
+                    body_class: "p-0",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "stopwatch", class: "me-2" }
+                            "Verarbeitungszeiten"
+                        }
+                        button {
+                            class: "btn btn-sm btn-light ms-auto",
+                            onclick: {
+                                let refresh = refresh.clone();
+                                move |_| refresh()
+                            },
+                            Icon { name: "arrow-clockwise", class: "me-1" }
+                            "Aktualisieren"
+                        }
+                    },
+                    body: rsx! {
+                        match result {
+                            None => rsx! {
+                                div { class: "p-4 text-muted",
+                                    Icon { name: "hourglass-split", class: "me-2" }
+                                    "Wird berechnet…"
+                                }
+                            },
+                            Some(result) if result.stages.is_empty() => rsx! {
+                                p { class: "text-muted p-3 mb-0",
+                                    "Noch keine gemessenen Anfragen im Zeitraum."
+                                }
+                            },
+                            Some(result) => rsx! {
+                                table { class: "table table-sm table-hover mb-0 small",
+                                    thead { class: "table-light",
+                                        tr {
+                                            th { "Phase" }
+                                            th { class: "text-end", "Anfragen" }
+                                            th { class: "text-end", "p50" }
+                                            th { class: "text-end", "p95" }
+                                        }
+                                    }
+                                    tbody {
+                                        for (i, stage) in result.stages.iter().enumerate() {
+                                            tr { key: "{stage}",
+                                                td { code { "{stage}" } }
+                                                td { class: "text-end", "{result.samples[i]}" }
+                                                td { class: "text-end", {format_ms(result.p50_micros[i])} }
+                                                td { class: "text-end", {format_ms(result.p95_micros[i])} }
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
@@ -1,18 +1,63 @@
 use std::env;
 
+use serde::Deserialize;
+use tracing::{info, warn};
+
+/// sessionStorage key under which URL query overrides are kept, so they survive the OAuth redirect
+/// but not the browser tab.
+const OVERRIDES_STORAGE_KEY: &str = "admin_config_overrides";
+
+/// Keys a URL query may override. `extra_auth_params` is only read from `/config.json`, since it
+/// could add a second `redirect_uri` to the authorize request.
+const QUERY_OVERRIDE_KEYS: &[&str] = &[
+    "spacetimedb_uri",
+    "module_name",
+    "issuer_url",
+    "client_id",
+    "redirect_uri",
+    "scope",
+    "prompt",
+    "admin_group",
+    "django_base_url",
+];
+
+/// Query keys naming a host that tokens are sent or redirected to. They only apply with an
+/// origin from [`allowed_override_origins`].
+const URL_OVERRIDE_KEYS: &[&str] = &[
+    "spacetimedb_uri",
+    "issuer_url",
+    "redirect_uri",
+    "django_base_url",
+];
+
+/// Default for `AdminConfig.max_connect_attempts`.
+const DEFAULT_MAX_CONNECT_ATTEMPTS: u32 = 20;
+/// Default for `AdminConfig.heartbeat_interval_secs`.
+const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 30;
+/// Default for `AdminConfig.heartbeat_tolerance_secs`.
+const DEFAULT_HEARTBEAT_TOLERANCE_SECS: u64 = 90;
+
 /// Configuration for the admin web application
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AdminConfig {
     /// SpacetimeDB server URI
     pub spacetimedb_uri: String,
     /// SpacetimeDB module name
     pub spacetimedb_module_name: String,
+    /// Reconnect attempts after which the UI reports the connection as failed (0 = never)
+    pub max_connect_attempts: u32,
+    /// Seconds between two heartbeat pings on an open connection (0 = no heartbeat)
+    pub heartbeat_interval_secs: u64,
+    /// Seconds a heartbeat may stay unanswered before the connection is rebuilt
+    pub heartbeat_tolerance_secs: u64,
     /// OAuth configuration
     pub oauth: OAuthConfig,
+    /// Keys overridden by the URL query in this session; the login page offers a reset for them
+    pub query_override_keys: Vec<String>,
 }
 
 /// OAuth/OIDC configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OAuthConfig {
     /// OIDC issuer URL (discovery endpoint base)
     pub issuer_url: String,
@@ -22,16 +67,43 @@ pub struct OAuthConfig {
     pub redirect_uri: String,
     /// OAuth scopes (space-separated)
     pub scope: String,
+    /// `prompt` parameter of the authorize request (e.g. `consent`); not sent when `None`
+    pub prompt: Option<String>,
+    /// Further parameters appended to the authorize request, e.g. `audience`
+    pub extra_auth_params: Vec<(String, String)>,
+    /// Django group whose members see the admin UI; `None` leaves it to `admin_identities`
+    pub admin_group: Option<String>,
     /// Django base URL (for backward compatibility)
     pub django_base_url: String,
 }
 
+/// Per-deployment overrides from `/config.json` or URL query parameters.
+/// Every field is optional; missing fields keep the compiled default.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigOverrides {
+    pub spacetimedb_uri: Option<String>,
+    pub module_name: Option<String>,
+    pub issuer_url: Option<String>,
+    pub client_id: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub scope: Option<String>,
+    pub prompt: Option<String>,
+    /// Same syntax as `OAUTH_EXTRA_AUTH_PARAMS`: `key=value&key2=value2`.
+    pub extra_auth_params: Option<String>,
+    pub admin_group: Option<String>,
+    pub django_base_url: Option<String>,
+}
+
 impl Default for AdminConfig {
     fn default() -> Self {
         Self {
             spacetimedb_uri: "http://localhost:3000".to_string(),
             spacetimedb_module_name: "kommunikation".to_string(),
+            max_connect_attempts: DEFAULT_MAX_CONNECT_ATTEMPTS,
+            heartbeat_interval_secs: DEFAULT_HEARTBEAT_INTERVAL_SECS,
+            heartbeat_tolerance_secs: DEFAULT_HEARTBEAT_TOLERANCE_SECS,
             oauth: OAuthConfig::default(),
+            query_override_keys: Vec::new(),
         }
     }
 }
@@ -44,36 +116,118 @@ impl Default for OAuthConfig {
             client_id: "admin-app".to_string(),
             redirect_uri: "http://127.0.0.1:8080/callback".to_string(),
             scope: "openid profile email".to_string(),
+            prompt: None,
+            extra_auth_params: Vec::new(),
+            admin_group: None,
             django_base_url: django,
         }
     }
 }
 
+/// Runtime env var, then the value baked in at build time, then the default.
+/// In the browser `env::var` always fails, so the build-time value is what counts there.
+fn env_or(name: &str, build_time: Option<&'static str>, default: &str) -> String {
+    env::var(name)
+        .ok()
+        .or_else(|| build_time.map(str::to_string))
+        .unwrap_or_else(|| default.to_string())
+}
+
+/// `None` for an empty or blank value.
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Parse authorize request parameters written like a query string (`key=value&key2=value2`,
+/// percent-encoding allowed). Pairs without a key are dropped.
+fn parse_auth_params(raw: &str) -> Vec<(String, String)> {
+    url::form_urlencoded::parse(raw.trim().as_bytes())
+        .filter(|(key, _)| !key.is_empty())
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect()
+}
+
 impl AdminConfig {
     /// Load configuration from environment variables with defaults
     pub fn from_env() -> Self {
-        let django_base_url =
-            env::var("DJANGO_BASE_URL").unwrap_or_else(|_| "http://127.0.0.1:8000".to_string());
+        let django_base_url = env_or(
+            "DJANGO_BASE_URL",
+            option_env!("DJANGO_BASE_URL"),
+            "http://127.0.0.1:8000",
+        );
 
         Self {
-            spacetimedb_uri: env::var("SPACETIMEDB_URI")
-                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
-            spacetimedb_module_name: env::var("SPACETIMEDB_MODULE_NAME")
-                .unwrap_or_else(|_| "kommunikation".to_string()),
+            spacetimedb_uri: env_or(
+                "SPACETIMEDB_URI",
+                option_env!("SPACETIMEDB_URI"),
+                "http://localhost:3000",
+            ),
+            spacetimedb_module_name: env_or(
+                "SPACETIMEDB_MODULE_NAME",
+                option_env!("SPACETIMEDB_MODULE_NAME"),
+                "kommunikation",
+            ),
+            max_connect_attempts: env_or(
+                "SPACETIMEDB_MAX_CONNECT_ATTEMPTS",
+                option_env!("SPACETIMEDB_MAX_CONNECT_ATTEMPTS"),
+                "",
+            )
+            .trim()
+            .parse()
+            .unwrap_or(DEFAULT_MAX_CONNECT_ATTEMPTS),
+            heartbeat_interval_secs: env_or(
+                "SPACETIMEDB_HEARTBEAT_INTERVAL_SECS",
+                option_env!("SPACETIMEDB_HEARTBEAT_INTERVAL_SECS"),
+                "",
+            )
+            .trim()
+            .parse()
+            .unwrap_or(DEFAULT_HEARTBEAT_INTERVAL_SECS),
+            heartbeat_tolerance_secs: env_or(
+                "SPACETIMEDB_HEARTBEAT_TOLERANCE_SECS",
+                option_env!("SPACETIMEDB_HEARTBEAT_TOLERANCE_SECS"),
+                "",
+            )
+            .trim()
+            .parse()
+            .unwrap_or(DEFAULT_HEARTBEAT_TOLERANCE_SECS),
             oauth: OAuthConfig {
-                issuer_url: env::var("OIDC_ISSUER_URL")
-                    .unwrap_or_else(|_| format!("{django_base_url}/o")),
-                client_id: env::var("OIDC_CLIENT_ID").unwrap_or_else(|_| "admin-app".to_string()),
-                redirect_uri: env::var("ADMIN_REDIRECT_URI")
-                    .unwrap_or_else(|_| "http://127.0.0.1:8080/callback".to_string()),
-                scope: env::var("OAUTH_SCOPES")
-                    .unwrap_or_else(|_| "openid profile email".to_string()),
+                issuer_url: env_or(
+                    "OIDC_ISSUER_URL",
+                    option_env!("OIDC_ISSUER_URL"),
+                    &format!("{django_base_url}/o"),
+                ),
+                client_id: env_or("OIDC_CLIENT_ID", option_env!("OIDC_CLIENT_ID"), "admin-app"),
+                redirect_uri: env_or(
+                    "ADMIN_REDIRECT_URI",
+                    option_env!("ADMIN_REDIRECT_URI"),
+                    "http://127.0.0.1:8080/callback",
+                ),
+                scope: env_or(
+                    "OAUTH_SCOPES",
+                    option_env!("OAUTH_SCOPES"),
+                    "openid profile email",
+                ),
+                prompt: non_empty(env_or("OAUTH_PROMPT", option_env!("OAUTH_PROMPT"), "")),
+                extra_auth_params: parse_auth_params(&env_or(
+                    "OAUTH_EXTRA_AUTH_PARAMS",
+                    option_env!("OAUTH_EXTRA_AUTH_PARAMS"),
+                    "",
+                )),
+                admin_group: non_empty(env_or(
+                    "OAUTH_ADMIN_GROUP",
+                    option_env!("OAUTH_ADMIN_GROUP"),
+                    "",
+                )),
                 django_base_url,
             },
+            query_override_keys: Vec::new(),
         }
     }
 
-    /// Load configuration from environment, attempting to load .env file first
+    /// Load configuration from environment, attempting to load .env file first.
+    /// URL query overrides (current or remembered from before the OAuth redirect) are applied on top.
     pub fn load() -> Self {
         // Try to load .env file, but don't fail if it doesn't exist
         // For WASM targets, this will be a no-op
@@ -82,6 +236,265 @@ impl AdminConfig {
             let _ = dotenvy::dotenv();
         }
 
-        Self::from_env()
+        let mut config = Self::from_env();
+        let query = query_overrides();
+        config.apply_overrides(&ConfigOverrides::from_pairs(&query));
+        config.query_override_keys = query.into_iter().map(|(key, _)| key).collect();
+        config
+    }
+
+    /// Like [`AdminConfig::load`], but also fetches `/config.json` from the serving origin.
+    /// Precedence: URL query parameters > `/config.json` > build-time env > defaults.
+    pub async fn load_runtime() -> Self {
+        let mut config = Self::load();
+        if let Some(remote) = fetch_config_json().await {
+            config.apply_overrides(&remote);
+            // Query parameters win over the deployment file.
+            config.apply_overrides(&ConfigOverrides::from_pairs(&query_overrides()));
+        }
+        config
+    }
+
+    /// Apply all fields set in `overrides`. Only the names of overridden keys are logged,
+    /// never their values.
+    pub fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        let mut applied: Vec<&str> = Vec::new();
+        let mut set = |target: &mut String, value: &Option<String>, key: &'static str| {
+            if let Some(v) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                *target = v.to_string();
+                applied.push(key);
+            }
+        };
+        set(
+            &mut self.spacetimedb_uri,
+            &overrides.spacetimedb_uri,
+            "spacetimedb_uri",
+        );
+        set(
+            &mut self.spacetimedb_module_name,
+            &overrides.module_name,
+            "module_name",
+        );
+        set(
+            &mut self.oauth.django_base_url,
+            &overrides.django_base_url,
+            "django_base_url",
+        );
+        set(
+            &mut self.oauth.issuer_url,
+            &overrides.issuer_url,
+            "issuer_url",
+        );
+        set(&mut self.oauth.client_id, &overrides.client_id, "client_id");
+        set(
+            &mut self.oauth.redirect_uri,
+            &overrides.redirect_uri,
+            "redirect_uri",
+        );
+        set(&mut self.oauth.scope, &overrides.scope, "scope");
+        if let Some(prompt) = overrides.prompt.clone().and_then(non_empty) {
+            self.oauth.prompt = Some(prompt);
+            applied.push("prompt");
+        }
+        if let Some(group) = overrides.admin_group.clone().and_then(non_empty) {
+            self.oauth.admin_group = Some(group);
+            applied.push("admin_group");
+        }
+        if let Some(raw) = overrides.extra_auth_params.as_deref() {
+            let params = parse_auth_params(raw);
+            if !params.is_empty() {
+                self.oauth.extra_auth_params = params;
+                applied.push("extra_auth_params");
+            }
+        }
+        if !applied.is_empty() {
+            info!("Config overrides applied for: {}", applied.join(", "));
+        }
+    }
+}
+
+impl ConfigOverrides {
+    /// Overrides from `key=value` pairs; unknown keys are ignored.
+    fn from_pairs(pairs: &[(String, String)]) -> Self {
+        let mut o = Self::default();
+        for (key, value) in pairs {
+            let slot = match key.as_str() {
+                "spacetimedb_uri" => &mut o.spacetimedb_uri,
+                "module_name" => &mut o.module_name,
+                "issuer_url" => &mut o.issuer_url,
+                "client_id" => &mut o.client_id,
+                "redirect_uri" => &mut o.redirect_uri,
+                "scope" => &mut o.scope,
+                "prompt" => &mut o.prompt,
+                "extra_auth_params" => &mut o.extra_auth_params,
+                "admin_group" => &mut o.admin_group,
+                "django_base_url" => &mut o.django_base_url,
+                _ => continue,
+            };
+            *slot = Some(value.clone());
+        }
+        o
+    }
+}
+
+/// Serialized origin (`scheme://host[:port]`) of a URL, `None` if it has none.
+fn origin_of(value: &str) -> Option<String> {
+    let origin = url::Url::parse(value.trim()).ok()?.origin();
+    origin.is_tuple().then(|| origin.ascii_serialization())
+}
+
+/// Origins URL-valued query overrides may point to: the app's own and those listed at build
+/// time in `ADMIN_OVERRIDE_ORIGINS` (comma-separated).
+fn allowed_override_origins() -> Vec<String> {
+    let mut origins: Vec<String> = env_or(
+        "ADMIN_OVERRIDE_ORIGINS",
+        option_env!("ADMIN_OVERRIDE_ORIGINS"),
+        "",
+    )
+    .split(',')
+    .filter_map(origin_of)
+    .collect();
+    if let Some(own) = web_sys::window().and_then(|w| w.location().origin().ok()) {
+        origins.push(own);
+    }
+    origins
+}
+
+/// The query pairs that may override the config: keys from [`QUERY_OVERRIDE_KEYS`], and for the
+/// URL-valued ones only values whose origin is in `allowed`. The first pair per key wins.
+fn accepted_query_overrides(
+    pairs: impl IntoIterator<Item = (String, String)>,
+    allowed: &[String],
+) -> Vec<(String, String)> {
+    let mut accepted: Vec<(String, String)> = Vec::new();
+    for (key, value) in pairs {
+        if !QUERY_OVERRIDE_KEYS.contains(&key.as_str()) || accepted.iter().any(|(k, _)| *k == key) {
+            continue;
+        }
+        if URL_OVERRIDE_KEYS.contains(&key.as_str())
+            && !origin_of(&value).is_some_and(|origin| allowed.contains(&origin))
+        {
+            warn!("Ignoring URL override for {key}: origin not allowed");
+            continue;
+        }
+        accepted.push((key, value));
+    }
+    accepted
+}
+
+/// Accepted overrides from the current URL's query string, then those remembered earlier in this
+/// tab. They are kept in sessionStorage because the OAuth callback URL no longer carries them.
+fn query_overrides() -> Vec<(String, String)> {
+    let Some(window) = web_sys::window() else {
+        return Vec::new();
+    };
+    // Earlier builds kept query overrides in localStorage for good; drop what they left.
+    if let Some(local) = window.local_storage().ok().flatten() {
+        for key in QUERY_OVERRIDE_KEYS.iter().chain(&["extra_auth_params"]) {
+            let _ = local.remove_item(&format!("{OVERRIDES_STORAGE_KEY}.{key}"));
+        }
+    }
+    let storage = window.session_storage().ok().flatten();
+
+    let from_url: Vec<(String, String)> = window
+        .location()
+        .href()
+        .ok()
+        .and_then(|href| url::Url::parse(&href).ok())
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default();
+    // Stored values are checked again, the allowlist may have changed with a new build.
+    let stored = QUERY_OVERRIDE_KEYS.iter().filter_map(|key| {
+        let value = storage
+            .as_ref()?
+            .get_item(&format!("{OVERRIDES_STORAGE_KEY}.{key}"))
+            .ok()??;
+        Some((key.to_string(), value))
+    });
+    let accepted = accepted_query_overrides(
+        from_url.into_iter().chain(stored),
+        &allowed_override_origins(),
+    );
+
+    if let Some(s) = &storage {
+        for (key, value) in &accepted {
+            let _ = s.set_item(&format!("{OVERRIDES_STORAGE_KEY}.{key}"), value);
+        }
+    }
+    accepted
+}
+
+/// Forget this session's URL query overrides and reload the app without a query string.
+pub fn reset_query_overrides() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Some(storage) = window.session_storage().ok().flatten() {
+        for key in QUERY_OVERRIDE_KEYS {
+            let _ = storage.remove_item(&format!("{OVERRIDES_STORAGE_KEY}.{key}"));
+        }
+    }
+    let location = window.location();
+    if let (Ok(origin), Ok(path)) = (location.origin(), location.pathname()) {
+        let _ = location.replace(&format!("{origin}{path}"));
+    }
+}
+
+/// Fetch `/config.json` from the origin serving the SPA. A missing or invalid file is not an error.
+async fn fetch_config_json() -> Option<ConfigOverrides> {
+    let origin = web_sys::window()?.location().origin().ok()?;
+    let response = reqwest::get(format!("{origin}/config.json")).await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    match response.json::<ConfigOverrides>().await {
+        Ok(overrides) => Some(overrides),
+        Err(e) => {
+            warn!("Ignoring invalid /config.json: {e}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(raw: &[(&str, &str)]) -> Vec<(String, String)> {
+        raw.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn url_overrides_need_an_allowed_origin() {
+        let allowed = vec!["https://db.example.org".to_string()];
+        let accepted = accepted_query_overrides(
+            pairs(&[
+                ("spacetimedb_uri", "https://db.example.org/"),
+                ("issuer_url", "https://evil.example.com/o"),
+                ("redirect_uri", "javascript:alert(1)"),
+                ("client_id", "staging"),
+                ("extra_auth_params", "redirect_uri=https://evil.example.com"),
+                ("unknown", "x"),
+            ]),
+            &allowed,
+        );
+        assert_eq!(
+            accepted,
+            pairs(&[
+                ("spacetimedb_uri", "https://db.example.org/"),
+                ("client_id", "staging"),
+            ])
+        );
+    }
+
+    #[test]
+    fn url_query_wins_over_stored_override() {
+        let accepted = accepted_query_overrides(
+            pairs(&[("module_name", "from-url"), ("module_name", "stored")]),
+            &[],
+        );
+        assert_eq!(accepted, pairs(&[("module_name", "from-url")]));
     }
 }
@@ -0,0 +1,434 @@
+use ::dioxus::logger::tracing::{info, warn};
+use ::dioxus::prelude::*;
+use ::dioxus::signals::SyncSignal;
+use std::sync::{Arc, Mutex};
+
+use super::heartbeat::{run_heartbeat, HeartbeatConfig};
+use super::reducer_outcome::{LatestReducerOutcome, ReducerOutcome};
+use super::tables::watch_tables;
+use crate::module_bindings::dioxus::{
+    ConnectionState, SharedConnection, SpacetimeDbContext, TableSignals,
+};
+use crate::module_bindings::DbConnection;
+
+/// Admin additions to the generated [`SpacetimeDbContext`], provided by
+/// [`use_admin_connection_provider`].
+#[derive(Clone, Copy)]
+pub struct AdminConnection {
+    connection: SyncSignal<Option<SharedConnection>>,
+    /// Bumped by [`AdminConnection::connect`] to restart the connection loop.
+    restart: SyncSignal<u64>,
+}
+
+impl AdminConnection {
+    /// Close the current connection, if any.
+    pub fn disconnect(&self) {
+        if let Some(conn) = self.connection.peek().as_ref() {
+            let _ = conn.disconnect();
+        }
+    }
+
+    /// Start a fresh connection loop (resetting the reconnect attempts), replacing the running one.
+    pub fn connect(&self) {
+        let mut restart = self.restart;
+        *restart.write() += 1;
+    }
+}
+
+/// Get the [`AdminConnection`] from a parent component.
+#[must_use]
+pub fn use_admin_connection() -> AdminConnection {
+    use_context::<AdminConnection>()
+}
+
+// Reconnect policy and sleep, as in the generated provider.
+const RECONNECT_BASE_DELAY_MS: u64 = 400;
+const RECONNECT_MAX_DELAY_MS: u64 = 10_000;
+const RECONNECT_JITTER_MS: u64 = 300;
+const MAX_RECONNECT_ATTEMPTS: u32 = 0;
+
+#[must_use]
+fn should_retry_reconnect(attempt: u32) -> bool {
+    MAX_RECONNECT_ATTEMPTS == 0 || attempt <= MAX_RECONNECT_ATTEMPTS
+}
+
+#[must_use]
+fn reconnect_delay_ms(attempt: u32) -> u64 {
+    let shift = attempt.min(8);
+    let factor = 1u64.checked_shl(shift).unwrap_or(u64::MAX);
+    let base_ms = RECONNECT_BASE_DELAY_MS
+        .saturating_mul(factor)
+        .min(RECONNECT_MAX_DELAY_MS);
+    let jitter = (u64::from(attempt).saturating_mul(137)) % RECONNECT_JITTER_MS.max(1);
+    base_ms.saturating_add(jitter)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) struct ThreadSleep {
+    done: Arc<std::sync::atomic::AtomicBool>,
+    started: bool,
+    delay_ms: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ThreadSleep {
+    pub(super) fn new(delay_ms: u64) -> Self {
+        Self {
+            done: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            started: false,
+            delay_ms,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl std::future::Future for ThreadSleep {
+    type Output = ();
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if self.done.load(std::sync::atomic::Ordering::Acquire) {
+            return std::task::Poll::Ready(());
+        }
+
+        if !self.started {
+            self.started = true;
+            let done = Arc::clone(&self.done);
+            let delay_ms = self.delay_ms;
+            let waker = cx.waker().clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+                done.store(true, std::sync::atomic::Ordering::Release);
+                waker.wake();
+            });
+        }
+
+        std::task::Poll::Pending
+    }
+}
+
+async fn reconnect_sleep(delay_ms: u64) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        ThreadSleep::new(delay_ms).await;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = delay_ms;
+    }
+}
+
+#[must_use]
+fn is_fatal_connection_error(err: &spacetimedb_sdk::Error) -> bool {
+    let msg = err.to_string().to_ascii_lowercase();
+    msg.contains("unauthorized")
+        || msg.contains("forbidden")
+        || msg.contains("invalid credentials")
+        || msg.contains("invalid token")
+        || msg.contains("token expired")
+}
+
+/// After an authentication error, replace the active token with the fallback token if it has not
+/// been tried yet. Returns whether there is a new token to connect with.
+fn switch_to_fallback_token(
+    active_token: &Arc<Mutex<Option<String>>>,
+    fallback_token: &Arc<Mutex<Option<String>>>,
+    token_source: &Arc<Mutex<&'static str>>,
+) -> bool {
+    let Some(fallback) = fallback_token.lock().ok().and_then(|mut f| f.take()) else {
+        return false;
+    };
+    warn!("SpacetimeDB rejected the primary token, retrying once with the fallback token");
+    if let Ok(mut token_store) = active_token.lock() {
+        *token_store = Some(fallback);
+    }
+    if let Ok(mut source) = token_source.lock() {
+        *source = "fallback token";
+    }
+    true
+}
+
+/// Initialize the SpacetimeDB context at the root of the authenticated app, in place of the
+/// generated `use_spacetimedb_context_provider`.
+///
+/// Provides the generated [`SpacetimeDbContext`], so the generated table, reducer and connection
+/// state hooks work as usual, plus [`AdminConnection`] and the latest reducer outcome.
+/// It creates all table signals at root level to ensure they outlive child components.
+///
+/// # Arguments
+/// * `uri` - The SpacetimeDB server URI
+/// * `module_name` - The module name to connect to
+/// * `token` - An optional OpenID Connect compliant JSON Web Token (JWT) for authentication.
+///   If `None` is passed or this method is not called, SpacetimeDB will generate a new Identity
+///   and sign a new private access token for the connection.
+/// * `fallback_token` - Tried once instead of `token` if SpacetimeDB rejects `token` with an
+///   authentication error before the first successful connection.
+/// * `heartbeat` - How often an open connection is pinged and how long it may stay silent
+///   before it is rebuilt; see [`HeartbeatConfig`].
+#[must_use]
+pub fn use_admin_connection_provider(
+    uri: &str,
+    module_name: &str,
+    token: Option<impl ToString>,
+    fallback_token: Option<impl ToString>,
+    heartbeat: HeartbeatConfig,
+) -> SpacetimeDbContext {
+    let uri = uri.to_string();
+    let module_name = module_name.to_string();
+    let token = token.map(|t| t.to_string());
+    let active_token: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(token.clone()));
+    // Taken on first use, and dropped once any token got us connected.
+    let fallback_token: Arc<Mutex<Option<String>>> =
+        Arc::new(Mutex::new(fallback_token.map(|t| t.to_string())));
+    // Which token the next connection attempt uses, for the log.
+    let token_source: Arc<Mutex<&'static str>> = Arc::new(Mutex::new("primary token"));
+
+    let connection: SyncSignal<Option<SharedConnection>> = use_signal_sync(|| None);
+    let state: SyncSignal<ConnectionState> = use_signal_sync(|| ConnectionState::Disconnected);
+    let error: SyncSignal<Option<String>> = use_signal_sync(|| None);
+    let restart: SyncSignal<u64> = use_signal_sync(|| 0);
+    let reducer_outcome: SyncSignal<Option<ReducerOutcome>> = use_signal_sync(|| None);
+    let mut running: Signal<Option<Task>> = use_signal(|| None);
+
+    let table_signals = TableSignals {
+        account: use_signal_sync(Vec::new),
+        active_subscriptions: use_signal_sync(Vec::new),
+        active_unsubscribe_tokens: use_signal_sync(Vec::new),
+        category_address: use_signal_sync(Vec::new),
+        domain_route: use_signal_sync(Vec::new),
+        mail_deliveries: use_signal_sync(Vec::new),
+        mail_delivery_events: use_signal_sync(Vec::new),
+        mail_ingress: use_signal_sync(Vec::new),
+        message_categories: use_signal_sync(Vec::new),
+        module_settings: use_signal_sync(Vec::new),
+        mta_daily_stats: use_signal_sync(Vec::new),
+        my_account_lookup_results: use_signal_sync(Vec::new),
+        my_category_impact_results: use_signal_sync(Vec::new),
+        my_expected_identity_results: use_signal_sync(Vec::new),
+        my_export_blobs: use_signal_sync(Vec::new),
+        my_mta_decisions: use_signal_sync(Vec::new),
+        my_offending_prefix_results: use_signal_sync(Vec::new),
+        my_ping_results: use_signal_sync(Vec::new),
+        my_resolve_results: use_signal_sync(Vec::new),
+        my_stage_timing_results: use_signal_sync(Vec::new),
+        reject_reason_stats: use_signal_sync(Vec::new),
+        sender_mail_deliveries: use_signal_sync(Vec::new),
+        sender_mail_ingress: use_signal_sync(Vec::new),
+        subscription_unsubscribe_tokens: use_signal_sync(Vec::new),
+        subscriptions: use_signal_sync(Vec::new),
+        table_size_history: use_signal_sync(Vec::new),
+        visible_account_aliases: use_signal_sync(Vec::new),
+        visible_account_tags: use_signal_sync(Vec::new),
+        visible_accounts: use_signal_sync(Vec::new),
+        visible_admin_identities: use_signal_sync(Vec::new),
+        visible_category_sender_rules: use_signal_sync(Vec::new),
+        visible_complaints: use_signal_sync(Vec::new),
+        visible_data_issues: use_signal_sync(Vec::new),
+        visible_delivery_outcomes: use_signal_sync(Vec::new),
+        visible_digest_out: use_signal_sync(Vec::new),
+        visible_envelope_samples: use_signal_sync(Vec::new),
+        visible_forward_rules: use_signal_sync(Vec::new),
+        visible_group_category_map: use_signal_sync(Vec::new),
+        visible_login_events: use_signal_sync(Vec::new),
+        visible_messages: use_signal_sync(Vec::new),
+        visible_mta_connection_log: use_signal_sync(Vec::new),
+        visible_notifications: use_signal_sync(Vec::new),
+        visible_orphaned_identities: use_signal_sync(Vec::new),
+        visible_relay_out: use_signal_sync(Vec::new),
+        visible_sender_whitelist: use_signal_sync(Vec::new),
+        visible_subscriptions: use_signal_sync(Vec::new),
+        visible_webhook_tokens: use_signal_sync(Vec::new),
+    };
+
+    let ctx = SpacetimeDbContext {
+        connection,
+        state,
+        error,
+        tables: table_signals.clone(),
+    };
+
+    use_context_provider(|| ctx.clone());
+    use_context_provider(|| AdminConnection {
+        connection,
+        restart,
+    });
+    use_context_provider(|| LatestReducerOutcome(reducer_outcome));
+
+    use_effect(move || {
+        let mut connection = connection;
+        let mut state = state;
+        let mut error = error;
+        let uri = uri.clone();
+        let module_name = module_name.clone();
+        let active_token = active_token.clone();
+        let fallback_token = fallback_token.clone();
+        let token_source = token_source.clone();
+        let table_signals = table_signals.clone();
+
+        // Reading `restart` makes `connect()` re-run this effect; stop the previous loop first.
+        let _ = restart();
+        if let Some(previous) = running.peek().as_ref() {
+            previous.cancel();
+        }
+
+        let task = spawn(async move {
+            let mut reconnect_attempt: u32 = 0;
+
+            loop {
+                if reconnect_attempt == 0 {
+                    state.set(ConnectionState::Connecting);
+                } else {
+                    let delay_ms = reconnect_delay_ms(reconnect_attempt);
+                    state.set(ConnectionState::Reconnecting {
+                        attempt: reconnect_attempt,
+                        delay_ms,
+                    });
+                }
+
+                let token_for_build = active_token.lock().ok().and_then(|token| token.clone());
+
+                let disconnect_fatal: Arc<Mutex<bool>> = Arc::new(Mutex::new(false));
+                let disconnect_fatal_on_disconnect = disconnect_fatal.clone();
+
+                let mut connection_on_disconnect = connection.clone();
+                let mut state_on_disconnect = state.clone();
+                let mut error_on_disconnect = error.clone();
+                let mut state_on_connect = state.clone();
+                let mut error_on_connect = error.clone();
+                let table_signals_on_connect = table_signals.clone();
+                let connection_for_refresh = connection;
+                let active_token_on_connect = active_token.clone();
+                let fallback_token_on_connect = fallback_token.clone();
+                let token_source_on_connect = token_source.clone();
+
+                let conn = match DbConnection::builder()
+                    .with_uri(&uri)
+                    .with_database_name(&module_name)
+                    .with_token(token_for_build)
+                    .on_connect(move |conn, identity, token| {
+                        watch_tables(conn, connection_for_refresh, &table_signals_on_connect);
+                        if let Ok(mut token_store) = active_token_on_connect.lock() {
+                            *token_store = Some(token.to_string());
+                        }
+                        if let Ok(mut source) = token_source_on_connect.lock() {
+                            info!("Connected to SpacetimeDB with the {}", *source);
+                            *source = "token issued by SpacetimeDB";
+                        }
+                        if let Ok(mut fallback) = fallback_token_on_connect.lock() {
+                            *fallback = None;
+                        }
+
+                        // Store the assigned Identity and private access token in state so the app
+                        // can persist the token (JWT) and reuse it for future reconnections via `with_token`.
+                        error_on_connect.set(None);
+                        state_on_connect
+                            .set(ConnectionState::Connected(identity, token.to_string()));
+                    })
+                    .on_disconnect(move |_ctx, err: Option<spacetimedb_sdk::Error>| {
+                        connection_on_disconnect.set(None);
+                        if let Some(e) = err {
+                            error_on_disconnect.set(Some::<String>(e.to_string()));
+                            if is_fatal_connection_error(&e) {
+                                if let Ok(mut fatal) = disconnect_fatal_on_disconnect.lock() {
+                                    *fatal = true;
+                                }
+                            }
+                        }
+                        state_on_disconnect.set(ConnectionState::Disconnected);
+                    })
+                    .build()
+                    .await
+                {
+                    Ok(conn) => conn,
+                    Err(e) => {
+                        connection.set(None);
+                        error.set(Some::<String>(e.to_string()));
+                        if is_fatal_connection_error(&e) {
+                            if switch_to_fallback_token(
+                                &active_token,
+                                &fallback_token,
+                                &token_source,
+                            ) {
+                                continue;
+                            }
+                            state.set(ConnectionState::Error);
+                            break;
+                        }
+                        reconnect_attempt = reconnect_attempt.saturating_add(1);
+                        if !should_retry_reconnect(reconnect_attempt) {
+                            state.set(ConnectionState::Error);
+                            break;
+                        }
+                        let delay_ms = reconnect_delay_ms(reconnect_attempt);
+                        state.set(ConnectionState::Reconnecting {
+                            attempt: reconnect_attempt,
+                            delay_ms,
+                        });
+                        reconnect_sleep(delay_ms).await;
+                        continue;
+                    }
+                };
+
+                let shared_conn: Arc<DbConnection> = Arc::new(conn);
+                connection.set(Some(shared_conn.clone()));
+                reconnect_attempt = 0;
+
+                let run_result = if heartbeat.interval_ms == 0 {
+                    shared_conn.run_async().await
+                } else {
+                    let run = std::pin::pin!(shared_conn.run_async());
+                    let beat = std::pin::pin!(run_heartbeat(shared_conn.clone(), heartbeat));
+                    match futures_util::future::select(run, beat).await {
+                        futures_util::future::Either::Left((result, _)) => result,
+                        futures_util::future::Either::Right(((), _)) => {
+                            // The stalled socket may never report its end; drop it and rebuild.
+                            let _ = shared_conn.disconnect();
+                            error.set(Some(format!(
+                                "Keine Antwort von SpacetimeDB seit über {} s",
+                                heartbeat.tolerance_ms / 1000
+                            )));
+                            state.set(ConnectionState::Error);
+                            Ok(())
+                        }
+                    }
+                };
+                connection.set(None);
+
+                let disconnected_with_fatal_error = disconnect_fatal
+                    .lock()
+                    .ok()
+                    .map(|fatal| *fatal)
+                    .unwrap_or(false);
+                let run_fatal = matches!(&run_result, Err(e) if is_fatal_connection_error(e));
+                if let Err(e) = run_result {
+                    error.set(Some::<String>(e.to_string()));
+                }
+                if disconnected_with_fatal_error || run_fatal {
+                    if switch_to_fallback_token(&active_token, &fallback_token, &token_source) {
+                        continue;
+                    }
+                    state.set(ConnectionState::Error);
+                    break;
+                }
+
+                reconnect_attempt = reconnect_attempt.saturating_add(1);
+                if !should_retry_reconnect(reconnect_attempt) {
+                    state.set(ConnectionState::Error);
+                    break;
+                }
+
+                let delay_ms = reconnect_delay_ms(reconnect_attempt);
+                state.set(ConnectionState::Reconnecting {
+                    attempt: reconnect_attempt,
+                    delay_ms,
+                });
+                reconnect_sleep(delay_ms).await;
+            }
+        });
+        running.set(Some(task));
+    });
+
+    ctx
+}
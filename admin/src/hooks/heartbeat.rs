@@ -0,0 +1,97 @@
+use ::dioxus::logger::tracing::warn;
+use std::sync::{Arc, Mutex};
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::connection::ThreadSleep;
+use crate::module_bindings::dioxus::SharedConnection;
+use crate::module_bindings::ping;
+
+/// How the connection loop checks that an open connection still answers.
+///
+/// Every `interval_ms` the client calls the `ping` reducer. If a ping stays unanswered for
+/// longer than `tolerance_ms`, the socket counts as stalled (e.g. after a laptop resumed from
+/// sleep): the state goes to `ConnectionState::Error` and the loop reconnects.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HeartbeatConfig {
+    /// Time between two pings; `0` turns the heartbeat off.
+    pub interval_ms: u64,
+    /// Silence after which the connection is dropped and rebuilt.
+    pub tolerance_ms: u64,
+}
+
+impl Default for HeartbeatConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 30_000,
+            tolerance_ms: 90_000,
+        }
+    }
+}
+
+/// Milliseconds since the Unix epoch, for measuring heartbeat silence.
+fn heartbeat_now_ms() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as u64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis() as u64)
+    }
+}
+
+async fn heartbeat_sleep(delay_ms: u64) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        ThreadSleep::new(delay_ms).await;
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        gloo_timers::future::TimeoutFuture::new(delay_ms.min(u64::from(u32::MAX)) as u32).await;
+    }
+}
+
+/// Ping `conn` every `config.interval_ms` and return once a ping stayed unanswered for longer
+/// than `config.tolerance_ms`. Never returns while the connection keeps answering.
+pub(super) async fn run_heartbeat(conn: SharedConnection, config: HeartbeatConfig) {
+    // When the oldest unanswered ping was sent; `None` while nothing is outstanding.
+    let unanswered_since: Arc<Mutex<Option<u64>>> = Arc::new(Mutex::new(None));
+    // One row per connection identity: `ping` updates it in place.
+    let request_id = match conn.try_identity() {
+        Some(identity) => format!("admin-heartbeat:{}", identity.to_hex()),
+        None => "admin-heartbeat".to_string(),
+    };
+    loop {
+        heartbeat_sleep(config.interval_ms).await;
+        let now = heartbeat_now_ms();
+        let Ok(mut pending) = unanswered_since.lock() else {
+            return;
+        };
+        match *pending {
+            Some(sent_at) if now.saturating_sub(sent_at) > config.tolerance_ms => {
+                warn!(
+                    "SpacetimeDB left a heartbeat unanswered for {} ms; reconnecting",
+                    now.saturating_sub(sent_at)
+                );
+                return;
+            }
+            Some(_) => continue,
+            None => *pending = Some(now),
+        }
+        drop(pending);
+
+        let unanswered_since = unanswered_since.clone();
+        let sent = conn
+            .reducers
+            .ping_then(request_id.clone(), move |_ctx, _result| {
+                if let Ok(mut pending) = unanswered_since.lock() {
+                    *pending = None;
+                }
+            });
+        if let Err(e) = sent {
+            warn!("Heartbeat ping could not be sent: {e}");
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Hooks around the generated SpacetimeDB bindings. `module_bindings` is regenerated by
+//! `spacetime generate`, so everything the admin adds on top of it lives here.
+
+pub mod connection;
+pub mod heartbeat;
+pub mod reducer_outcome;
+pub mod subscription;
+mod tables;
@@ -0,0 +1,115 @@
+use ::dioxus::logger::tracing::warn;
+use ::dioxus::prelude::*;
+use ::dioxus::signals::SyncSignal;
+
+use crate::module_bindings::dioxus::use_connection;
+use crate::module_bindings::{add_account_alias, add_message_category, ReducerEventContext};
+
+/// Committed outcome of a reducer call, as reported by the server once the transaction ran.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReducerOutcome {
+    /// Name of the reducer, e.g. `add_message_category`.
+    pub reducer: &'static str,
+    /// `Ok` if the transaction committed; otherwise the reducer's error, or why the call
+    /// could not be sent or completed.
+    pub result: Result<(), String>,
+}
+
+/// Context holding the outcome of the latest reducer call made through a `use_reducer_*_then`
+/// hook. Provided by [`super::connection::use_admin_connection_provider`].
+#[derive(Clone, Copy)]
+pub struct LatestReducerOutcome(pub SyncSignal<Option<ReducerOutcome>>);
+
+/// Get a reactive signal with the outcome of the latest reducer call made through a
+/// `use_reducer_*_then` hook, e.g. to show a notification for any failed call.
+#[must_use]
+pub fn use_reducer_outcome() -> SyncSignal<Option<ReducerOutcome>> {
+    use_context::<LatestReducerOutcome>().0
+}
+
+/// Store `result` in the caller's `result` signal and the context-wide `outcome` signal.
+fn record_reducer_outcome(
+    reducer: &'static str,
+    result: Result<(), String>,
+    mut result_signal: SyncSignal<Option<Result<(), String>>>,
+    mut outcome: SyncSignal<Option<ReducerOutcome>>,
+) {
+    if let Err(e) = &result {
+        warn!("Reducer {reducer} failed: {e}");
+    }
+    result_signal.set(Some(result.clone()));
+    outcome.set(Some(ReducerOutcome { reducer, result }));
+}
+
+/// Callback for a `<reducer>_then` call that records the committed outcome via
+/// [`record_reducer_outcome`].
+fn reducer_outcome_callback<E: std::fmt::Display>(
+    reducer: &'static str,
+    result_signal: SyncSignal<Option<Result<(), String>>>,
+    outcome: SyncSignal<Option<ReducerOutcome>>,
+) -> impl FnOnce(&ReducerEventContext, Result<Result<(), String>, E>) + Send + 'static {
+    move |_ctx, res| {
+        let result = res.unwrap_or_else(|e| Err(e.to_string()));
+        record_reducer_outcome(reducer, result, result_signal, outcome);
+    }
+}
+
+/// Get a callback to invoke the `add_message_category` reducer, and a signal that receives its
+/// committed outcome. Unlike `use_reducer_add_message_category`, whose `Ok(())` only means the
+/// request was sent, `Some(Ok(()))` here means the category exists. Clear the signal after
+/// handling it, so the next call can be observed.
+#[must_use]
+pub fn use_reducer_add_message_category_then() -> (
+    impl Fn(String, String, String) + Clone + 'static,
+    SyncSignal<Option<Result<(), String>>>,
+) {
+    let conn_signal = use_connection();
+    let outcome = use_reducer_outcome();
+    let result: SyncSignal<Option<Result<(), String>>> = use_signal_sync(|| None);
+
+    let invoke = move |name: String, email_address: String, description: String| {
+        let sent = match conn_signal().as_ref() {
+            Some(conn) => conn.reducers.add_message_category_then(
+                name,
+                email_address,
+                description,
+                reducer_outcome_callback("add_message_category", result, outcome),
+            ),
+            None => Err(spacetimedb_sdk::Error::Disconnected),
+        };
+        if let Err(e) = sent {
+            record_reducer_outcome("add_message_category", Err(e.to_string()), result, outcome);
+        }
+    };
+
+    (invoke, result)
+}
+
+/// Get a callback to invoke the `add_account_alias` reducer, and a signal that receives its
+/// committed outcome, like [`use_reducer_add_message_category_then`]. `Some(Ok(()))` means the
+/// alias was added. Clear the signal after handling it, so the next call can be observed.
+#[must_use]
+pub fn use_reducer_add_account_alias_then() -> (
+    impl Fn(u64, String) + Clone + 'static,
+    SyncSignal<Option<Result<(), String>>>,
+) {
+    let conn_signal = use_connection();
+    let outcome = use_reducer_outcome();
+    let result: SyncSignal<Option<Result<(), String>>> = use_signal_sync(|| None);
+
+    let invoke = move |account_id: u64, email: String| {
+        let sent = match conn_signal().as_ref() {
+            Some(conn) => conn.reducers.add_account_alias_then(
+                account_id,
+                email,
+                reducer_outcome_callback("add_account_alias", result, outcome),
+            ),
+            None => Err(spacetimedb_sdk::Error::Disconnected),
+        };
+        if let Err(e) = sent {
+            record_reducer_outcome("add_account_alias", Err(e.to_string()), result, outcome);
+        }
+    };
+
+    (invoke, result)
+}
@@ -0,0 +1,73 @@
+use ::dioxus::prelude::*;
+use ::dioxus::signals::SyncSignal;
+use std::sync::Arc;
+
+use crate::module_bindings::dioxus::{use_connection, SharedConnection};
+
+/// Subscribe to a set of SQL queries.
+///
+/// Re-subscribes automatically whenever the connection instance changes
+/// (initial connect, reconnect after a network interruption, or [`AdminConnection::connect`]).
+/// Subscribes once per connection instance, so a stable connection, or one whose subscribe is
+/// still pending, is not subscribed twice. Callbacks of a subscription made on an older
+/// connection are ignored, so a late `on_error` cannot clobber the state of the current one.
+/// A failed subscription is retried on the next connection change.
+/// Subscription errors are printed to stderr for diagnosis.
+///
+/// [`AdminConnection::connect`]: super::connection::AdminConnection::connect
+pub fn use_subscription(queries: &[&str]) {
+    let queries: Vec<String> = queries.iter().map(|s| s.to_string()).collect();
+    let conn_signal = use_connection();
+
+    // Stores the Arc pointer of the connection the queries were last subscribed on.
+    // Using peek() inside the effect keeps this read non-reactive (no infinite loop).
+    let last_conn: SyncSignal<Option<SharedConnection>> = use_signal_sync(|| None);
+    // Bumped on every subscribe; callbacks only act if it still matches.
+    let generation: SyncSignal<u64> = use_signal_sync(|| 0);
+
+    use_effect(move || {
+        // transition (None->Some on connect, Some->None on disconnect, Some(a)->Some(b) on reconnect).
+        let current = conn_signal();
+        let mut last = last_conn;
+        let mut generation = generation;
+
+        match current.as_ref() {
+            None => {
+                // Connection lost: clear the tracker so the next connection
+                // instance will trigger a fresh subscribe.
+                // peek() avoids creating a reactive dependency that would loop.
+                if last.peek().is_some() {
+                    last.set(None);
+                }
+            }
+            Some(conn) => {
+                // peek() reads last without subscribing to it reactively.
+                if last
+                    .peek()
+                    .as_ref()
+                    .map(|prev| Arc::ptr_eq(prev, conn))
+                    .unwrap_or(false)
+                {
+                    // Same connection instance – already subscribed or subscribing.
+                    return;
+                }
+                // New or reconnected instance – store it and subscribe.
+                last.set(Some(conn.clone()));
+                let this_generation = *generation.peek() + 1;
+                generation.set(this_generation);
+
+                let queries = queries.clone();
+                conn.subscription_builder()
+                    .on_applied(|_ctx| {})
+                    .on_error(move |_ctx, err| {
+                        eprintln!("[spacetimedb] subscription error: {err}");
+                        if *generation.peek() == this_generation {
+                            // Forget the connection so the next change re-subscribes.
+                            last.set(None);
+                        }
+                    })
+                    .subscribe(queries);
+            }
+        }
+    });
+}
@@ -0,0 +1,185 @@
+use ::dioxus::prelude::*;
+use ::dioxus::signals::SyncSignal;
+use spacetimedb_sdk::{Table, TableWithPrimaryKey};
+use std::sync::{Arc, Mutex};
+
+use crate::module_bindings::dioxus::{SharedConnection, TableSignals};
+use crate::module_bindings::*;
+
+/// Window in which row callbacks of one table are coalesced into a single signal update.
+const TABLE_REFRESH_DEBOUNCE_MS: u64 = 100;
+
+/// Run `f` once the current debounce window has passed.
+#[cfg(target_arch = "wasm32")]
+fn after_debounce_window(f: impl FnOnce() + 'static) {
+    wasm_bindgen_futures::spawn_local(async move {
+        gloo_timers::future::TimeoutFuture::new(TABLE_REFRESH_DEBOUNCE_MS as u32).await;
+        f();
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn after_debounce_window(f: impl FnOnce() + Send + 'static) {
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(TABLE_REFRESH_DEBOUNCE_MS));
+        f();
+    });
+}
+
+#[derive(Default)]
+struct RefreshWindow {
+    open: bool,
+    dirty: bool,
+}
+
+/// Refreshes one table signal from the client cache, coalescing bursts of row callbacks
+/// (e.g. the initial rows of a subscription or a bulk sync).
+///
+/// The first callback after a quiet period refreshes immediately, so single updates show up
+/// without delay. Further callbacks within [`TABLE_REFRESH_DEBOUNCE_MS`] only mark the table
+/// dirty; when the window ends, one refresh picks them all up and a new window starts.
+#[derive(Clone)]
+struct TableRefresh {
+    connection: SyncSignal<Option<SharedConnection>>,
+    refresh: Arc<Mutex<dyn FnMut(&RemoteTables) + Send>>,
+    window: Arc<Mutex<RefreshWindow>>,
+}
+
+impl TableRefresh {
+    fn new(
+        connection: SyncSignal<Option<SharedConnection>>,
+        refresh: impl FnMut(&RemoteTables) + Send + 'static,
+    ) -> Self {
+        Self {
+            connection,
+            refresh: Arc::new(Mutex::new(refresh)),
+            window: Arc::new(Mutex::new(RefreshWindow::default())),
+        }
+    }
+
+    fn run(&self, db: &RemoteTables) {
+        if let Ok(mut refresh) = self.refresh.lock() {
+            refresh(db);
+        }
+    }
+
+    /// Called from the row callbacks with the cache of the triggering event.
+    fn trigger(&self, db: &RemoteTables) {
+        if let Ok(mut window) = self.window.lock() {
+            if window.open {
+                window.dirty = true;
+                return;
+            }
+            window.open = true;
+        }
+        self.run(db);
+        self.close_window_later();
+    }
+
+    fn close_window_later(&self) {
+        let this = self.clone();
+        after_debounce_window(move || {
+            let flush = match this.window.lock() {
+                Ok(mut window) => {
+                    let dirty = std::mem::take(&mut window.dirty);
+                    window.open = dirty;
+                    dirty
+                }
+                Err(_) => false,
+            };
+            if flush {
+                if let Some(conn) = this.connection.peek().as_ref() {
+                    this.run(&conn.db);
+                }
+                this.close_window_later();
+            }
+        });
+    }
+}
+
+/// Fill the signal of `$table` from the client cache and keep it in sync with the row
+/// callbacks; bursts are coalesced by [`TableRefresh`]. Tables with a primary key also pass
+/// `on_update`; views only get inserts and deletes.
+macro_rules! watch_table {
+    ($conn:ident, $connection:ident, $tables:ident, $table:ident $(, $on_update:ident)?) => {{
+        let mut signal = $tables.$table;
+        signal.set($conn.db.$table().iter().collect());
+        let refresh = TableRefresh::new($connection, move |db: &RemoteTables| {
+            signal.set(db.$table().iter().collect())
+        });
+        $conn.db.$table().on_insert({
+            let refresh = refresh.clone();
+            move |ctx, _row| refresh.trigger(&ctx.db)
+        });
+        $($conn.db.$table().$on_update({
+            let refresh = refresh.clone();
+            move |ctx, _old, _new| refresh.trigger(&ctx.db)
+        });)?
+        $conn
+            .db
+            .$table()
+            .on_delete(move |ctx, _row| refresh.trigger(&ctx.db));
+    }};
+}
+
+/// Keep every table signal in sync with the client cache of `conn`. Called from `on_connect`;
+/// `connection` is the context's connection signal, read when a debounce window ends.
+pub(super) fn watch_tables(
+    conn: &DbConnection,
+    connection: SyncSignal<Option<SharedConnection>>,
+    tables: &TableSignals,
+) {
+    watch_table!(conn, connection, tables, account, on_update);
+    watch_table!(conn, connection, tables, active_subscriptions);
+    watch_table!(conn, connection, tables, active_unsubscribe_tokens);
+    watch_table!(conn, connection, tables, category_address, on_update);
+    watch_table!(conn, connection, tables, domain_route, on_update);
+    watch_table!(conn, connection, tables, mail_deliveries, on_update);
+    watch_table!(conn, connection, tables, mail_delivery_events, on_update);
+    watch_table!(conn, connection, tables, mail_ingress, on_update);
+    watch_table!(conn, connection, tables, message_categories, on_update);
+    watch_table!(conn, connection, tables, module_settings, on_update);
+    watch_table!(conn, connection, tables, mta_daily_stats, on_update);
+    watch_table!(conn, connection, tables, my_account_lookup_results);
+    watch_table!(conn, connection, tables, my_category_impact_results);
+    watch_table!(conn, connection, tables, my_expected_identity_results);
+    watch_table!(conn, connection, tables, my_export_blobs);
+    watch_table!(conn, connection, tables, my_mta_decisions);
+    watch_table!(conn, connection, tables, my_offending_prefix_results);
+    watch_table!(conn, connection, tables, my_ping_results);
+    watch_table!(conn, connection, tables, my_resolve_results);
+    watch_table!(conn, connection, tables, my_stage_timing_results);
+    watch_table!(conn, connection, tables, reject_reason_stats, on_update);
+    watch_table!(conn, connection, tables, sender_mail_deliveries);
+    watch_table!(conn, connection, tables, sender_mail_ingress);
+    watch_table!(
+        conn,
+        connection,
+        tables,
+        subscription_unsubscribe_tokens,
+        on_update
+    );
+    watch_table!(conn, connection, tables, subscriptions, on_update);
+    watch_table!(conn, connection, tables, table_size_history, on_update);
+    watch_table!(conn, connection, tables, visible_account_aliases);
+    watch_table!(conn, connection, tables, visible_account_tags);
+    watch_table!(conn, connection, tables, visible_accounts);
+    watch_table!(conn, connection, tables, visible_admin_identities);
+    watch_table!(conn, connection, tables, visible_category_sender_rules);
+    watch_table!(conn, connection, tables, visible_complaints);
+    watch_table!(conn, connection, tables, visible_data_issues);
+    watch_table!(conn, connection, tables, visible_delivery_outcomes);
+    watch_table!(conn, connection, tables, visible_digest_out);
+    watch_table!(conn, connection, tables, visible_envelope_samples);
+    watch_table!(conn, connection, tables, visible_forward_rules);
+    watch_table!(conn, connection, tables, visible_group_category_map);
+    watch_table!(conn, connection, tables, visible_login_events);
+    watch_table!(conn, connection, tables, visible_messages);
+    watch_table!(conn, connection, tables, visible_mta_connection_log);
+    watch_table!(conn, connection, tables, visible_notifications);
+    watch_table!(conn, connection, tables, visible_orphaned_identities);
+    watch_table!(conn, connection, tables, visible_relay_out);
+    watch_table!(conn, connection, tables, visible_sender_whitelist);
+    watch_table!(conn, connection, tables, visible_subscriptions);
+    watch_table!(conn, connection, tables, visible_webhook_tokens);
+}
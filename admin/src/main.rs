@@ -1,18 +1,23 @@
 mod components;
 mod config;
+mod hooks;
 mod module_bindings;
 mod oauth;
 mod pages;
 mod router;
 
-use ::dioxus::{logger::tracing::info, prelude::*};
+use ::dioxus::{
+    logger::tracing::{error, info},
+    prelude::*,
+};
 use config::AdminConfig;
 use dioxus_bootstrap_css::prelude::*;
-use module_bindings::dioxus::{
-    use_connection_state, use_spacetimedb_context_provider, use_subscription, ConnectionState,
-};
+use hooks::connection::use_admin_connection_provider;
+use hooks::heartbeat::HeartbeatConfig;
+use hooks::subscription::use_subscription;
+use module_bindings::dioxus::{use_connection_state, use_reducer_record_login, ConnectionState};
 use oauth::{use_oauth, AuthState, UserInfo};
-use router::ActiveView;
+use router::{ActiveView, AdminGroupMember};
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
 
@@ -72,8 +77,8 @@ fn solawi_theme() -> BootstrapTheme {
 
 #[component]
 fn App() -> Element {
-    let config = use_signal(AdminConfig::load);
-    let (auth_state, login, logout) = use_oauth(config.read().oauth.clone());
+    // Compiled defaults, overridden by /config.json and URL query parameters.
+    let config = use_resource(AdminConfig::load_runtime);
 
     // Theme signal for ThemeProvider + ThemeToggle
     let theme = use_signal(|| Theme::Light);
@@ -83,10 +88,30 @@ fn App() -> Element {
         ThemeProvider { theme }
         BootstrapHead {}
         BootstrapThemeProvider { theme: solawi_theme() }
+        match &*config.read() {
+            Some(config) => rsx! {
+                ConfiguredApp { config: config.clone(), theme }
+            },
+            None => rsx! {
+                AuthenticatingPage {}
+            },
+        }
+    }
+}
+
+#[component]
+fn ConfiguredApp(config: AdminConfig, theme: Signal<Theme>) -> Element {
+    let (auth_state, login, logout) = use_oauth(config.oauth.clone());
+    use_context_provider(|| config);
+
+    rsx! {
         match &*auth_state.read() {
             AuthState::Unauthenticated => rsx! {
                 LoginPage { on_login: login }
             },
+            AuthState::ProviderUnreachable(error) => rsx! {
+                LoginPage { on_login: login, unreachable: error.clone() }
+            },
             AuthState::Authenticating => rsx! {
                 AuthenticatingPage {}
             },
@@ -104,22 +129,54 @@ fn App() -> Element {
 // Auth pages — shown before / instead of the main app
 // ---------------------------------------------------------------------------
 
+/// Login button. With `unreachable` set, discovery of the OAuth provider failed: the page
+/// explains that and the button retries discovery instead of logging in. Settings taken from
+/// the link are listed with a reset, so nobody logs in against a host they didn't choose.
 #[component]
-fn LoginPage(on_login: Callback<()>) -> Element {
+fn LoginPage(on_login: Callback<()>, unreachable: Option<String>) -> Element {
+    let overridden = use_context::<AdminConfig>().query_override_keys.join(", ");
+
     rsx! {
         div { class: "d-flex justify-content-center align-items-center vh-100 bg-light",
-            Card { class: "shadow p-4", style: "min-width: 320px;",
+            Card { class: "shadow p-4", style: "min-width: 320px; max-width: 480px;",
                 div { class: "text-center mb-4",
                     Icon { name: "envelope-fill", class: "text-primary" }
                     h4 { class: "mt-2 mb-0", "Kommunikationszentrum" }
                     p { class: "text-muted small", "SoLaWi Nachrichten verwaltung" }
                 }
-                Button {
-                    color: Color::Primary,
-                    class: "w-100",
-                    onclick: move |_| on_login.call(()),
-                    Icon { name: "box-arrow-in-right", class: "me-2" }
-                    "Mit SoLaWi-Account anmelden"
+                if !overridden.is_empty() {
+                    Alert { color: Color::Info, class: "small",
+                        p { class: "mb-2", "Diese Sitzung nutzt Einstellungen aus dem Link: {overridden}" }
+                        Button {
+                            color: Color::Secondary,
+                            size: Size::Sm,
+                            onclick: move |_| config::reset_query_overrides(),
+                            Icon { name: "x-circle", class: "me-2" }
+                            "Zurücksetzen"
+                        }
+                    }
+                }
+                if let Some(error) = unreachable {
+                    Alert { color: Color::Warning, class: "small",
+                        strong { "Der Anmeldedienst ist gerade nicht erreichbar." }
+                        p { class: "mb-1", "Meist ist er nach kurzer Zeit wieder da. Bitte später erneut versuchen." }
+                        p { class: "mb-0 text-muted", "{error}" }
+                    }
+                    Button {
+                        color: Color::Primary,
+                        class: "w-100",
+                        onclick: move |_| on_login.call(()),
+                        Icon { name: "arrow-clockwise", class: "me-2" }
+                        "Erneut verbinden"
+                    }
+                } else {
+                    Button {
+                        color: Color::Primary,
+                        class: "w-100",
+                        onclick: move |_| on_login.call(()),
+                        Icon { name: "box-arrow-in-right", class: "me-2" }
+                        "Mit SoLaWi-Account anmelden"
+                    }
                 }
             }
         }
@@ -167,24 +224,73 @@ fn AuthenticatedApp(
     on_logout: EventHandler<()>,
     theme: Signal<Theme>,
 ) -> Element {
-    let config = use_signal(AdminConfig::load);
-    let uri = config.read().spacetimedb_uri.clone();
-    let module_name = config.read().spacetimedb_module_name.clone();
+    let config = use_context::<AdminConfig>();
+    let uri = config.spacetimedb_uri.clone();
+    let module_name = config.spacetimedb_module_name.clone();
 
     info!("Authenticated as: {}", user_info.mitgliedsnr);
 
-    let _ctx = use_spacetimedb_context_provider(&uri, &module_name, user_info.id_token.clone());
+    // SpacetimeDB gets the ID token; deployments whose SpacetimeDB rejects it fall back to the
+    // OAuth access token once.
+    let _ctx = use_admin_connection_provider(
+        &uri,
+        &module_name,
+        user_info.id_token.clone(),
+        Some(user_info.access_token.clone()),
+        HeartbeatConfig {
+            interval_ms: config.heartbeat_interval_secs.saturating_mul(1000),
+            tolerance_ms: config.heartbeat_tolerance_secs.saturating_mul(1000),
+        },
+    );
+
+    let in_admin_group = user_info.in_group(config.oauth.admin_group.as_deref());
+    let mut admin_group_member =
+        use_context_provider(|| AdminGroupMember(Signal::new(in_admin_group))).0;
+    // Refreshed tokens bring fresh groups.
+    use_effect(use_reactive((&in_admin_group,), move |(in_admin_group,)| {
+        admin_group_member.set(in_admin_group)
+    }));
 
     use_subscription(&[
         "SELECT * FROM visible_accounts",
         "SELECT * FROM visible_admin_identities",
         "SELECT * FROM message_categories",
+        "SELECT * FROM category_address",
         "SELECT * FROM visible_subscriptions",
         "SELECT * FROM visible_messages",
         "SELECT * FROM visible_webhook_tokens",
+        "SELECT * FROM mta_daily_stats",
+        "SELECT * FROM reject_reason_stats",
+        "SELECT * FROM table_size_history",
+        "SELECT * FROM module_settings",
+        "SELECT * FROM my_expected_identity_results",
+        "SELECT * FROM visible_delivery_outcomes",
+        "SELECT * FROM visible_account_aliases",
+        "SELECT * FROM visible_account_tags",
+        "SELECT * FROM my_category_impact_results",
+        "SELECT * FROM my_export_blobs",
+        "SELECT * FROM my_offending_prefix_results",
+        "SELECT * FROM visible_login_events",
     ]);
 
     let state = use_connection_state();
+
+    // Audit trail: report the login once per page load, as soon as the connection is up. The
+    // module drops repeats within its rate-limit window, e.g. after a reload.
+    let record_login = use_reducer_record_login();
+    let mut login_recorded = use_signal(|| false);
+    use_effect(move || {
+        if login_recorded() || !matches!(state(), ConnectionState::Connected(_, _)) {
+            return;
+        }
+        login_recorded.set(true);
+        let user_agent = web_sys::window()
+            .and_then(|w| w.navigator().user_agent().ok())
+            .unwrap_or_default();
+        if let Err(e) = record_login(user_agent) {
+            error!("record_login failed: {e:?}");
+        }
+    });
     let active_view = use_signal(|| ActiveView::MySubscriptions);
 
     rsx! {
@@ -194,6 +300,8 @@ fn AuthenticatedApp(
             on_logout,
             theme: theme.clone(),
         }
+        components::maintenance::MaintenanceBanner {}
+        components::reducer_outcome::ReducerErrorBanner {}
         {
             match state() {
                 ConnectionState::Connected(_, _) => {
@@ -215,23 +323,8 @@ fn AuthenticatedApp(
                         },
                     }
                 }
-                ConnectionState::Connecting | ConnectionState::Reconnecting { .. } => {
-                    rsx! {
-                        div { class: "d-flex justify-content-center align-items-center mt-5",
-                            div { class: "text-center",
-                                Spinner { color: Color::Primary, class: "mb-3", "Laden…" }
-                                p { class: "text-muted", "Verbindung zu SpacetimeDB wird hergestellt…" }
-                            }
-                        }
-                    }
-                }
                 _ => rsx! {
-                    Container { class: "mt-5",
-                        Alert { color: Color::Danger, class: "d-flex align-items-center",
-                            Icon { name: "exclamation-circle", class: "me-2" }
-                            "Verbindung zu SpacetimeDB getrennt oder fehlgeschlagen."
-                        }
-                    }
+                    components::connection_status::ConnectionStatusCard {}
                 },
             }
         }
@@ -22,17 +22,48 @@ pub struct TableSignals {
     pub account: SyncSignal<Vec<Account>>,
     pub active_subscriptions: SyncSignal<Vec<Subscription>>,
     pub active_unsubscribe_tokens: SyncSignal<Vec<SubscriptionUnsubscribeToken>>,
+    pub category_address: SyncSignal<Vec<CategoryAddress>>,
+    pub domain_route: SyncSignal<Vec<DomainRoute>>,
     pub mail_deliveries: SyncSignal<Vec<MailDelivery>>,
     pub mail_delivery_events: SyncSignal<Vec<MailDeliveryEvent>>,
     pub mail_ingress: SyncSignal<Vec<MailIngress>>,
     pub message_categories: SyncSignal<Vec<MessageCategory>>,
+    pub module_settings: SyncSignal<Vec<ModuleSettings>>,
+    pub mta_daily_stats: SyncSignal<Vec<MtaDailyStats>>,
+    pub my_account_lookup_results: SyncSignal<Vec<AccountLookupResult>>,
+    pub my_category_impact_results: SyncSignal<Vec<CategoryImpactResult>>,
+    pub my_expected_identity_results: SyncSignal<Vec<ExpectedIdentityResult>>,
+    pub my_export_blobs: SyncSignal<Vec<ExportBlob>>,
+    pub my_mta_decisions: SyncSignal<Vec<MtaDecision>>,
+    pub my_offending_prefix_results: SyncSignal<Vec<OffendingPrefixResult>>,
+    pub my_ping_results: SyncSignal<Vec<PingResult>>,
+    pub my_resolve_results: SyncSignal<Vec<ResolveResult>>,
+    pub my_stage_timing_results: SyncSignal<Vec<StageTimingResult>>,
+    pub reject_reason_stats: SyncSignal<Vec<RejectReasonStat>>,
     pub sender_mail_deliveries: SyncSignal<Vec<MailDelivery>>,
     pub sender_mail_ingress: SyncSignal<Vec<MailIngress>>,
     pub subscription_unsubscribe_tokens: SyncSignal<Vec<SubscriptionUnsubscribeToken>>,
     pub subscriptions: SyncSignal<Vec<Subscription>>,
+    pub table_size_history: SyncSignal<Vec<TableSizeHistory>>,
+    pub visible_account_aliases: SyncSignal<Vec<AccountAlias>>,
+    pub visible_account_tags: SyncSignal<Vec<AccountTag>>,
     pub visible_accounts: SyncSignal<Vec<Account>>,
     pub visible_admin_identities: SyncSignal<Vec<AdminIdentity>>,
+    pub visible_category_sender_rules: SyncSignal<Vec<CategorySenderRule>>,
+    pub visible_complaints: SyncSignal<Vec<Complaint>>,
+    pub visible_data_issues: SyncSignal<Vec<DataIssue>>,
+    pub visible_delivery_outcomes: SyncSignal<Vec<DeliveryOutcome>>,
+    pub visible_digest_out: SyncSignal<Vec<DigestOut>>,
+    pub visible_envelope_samples: SyncSignal<Vec<EnvelopeSample>>,
+    pub visible_forward_rules: SyncSignal<Vec<ForwardRule>>,
+    pub visible_group_category_map: SyncSignal<Vec<GroupCategoryMap>>,
+    pub visible_login_events: SyncSignal<Vec<LoginEvent>>,
     pub visible_messages: SyncSignal<Vec<ReceivedMessage>>,
+    pub visible_mta_connection_log: SyncSignal<Vec<MtaConnectionLog>>,
+    pub visible_notifications: SyncSignal<Vec<NotificationOut>>,
+    pub visible_orphaned_identities: SyncSignal<Vec<OrphanedIdentity>>,
+    pub visible_relay_out: SyncSignal<Vec<RelayOut>>,
+    pub visible_sender_whitelist: SyncSignal<Vec<SenderWhitelist>>,
     pub visible_subscriptions: SyncSignal<Vec<Subscription>>,
     pub visible_webhook_tokens: SyncSignal<Vec<WebhookToken>>,
 }
@@ -184,17 +215,48 @@ pub fn use_spacetimedb_context_provider(
         account: use_signal_sync(Vec::new),
         active_subscriptions: use_signal_sync(Vec::new),
         active_unsubscribe_tokens: use_signal_sync(Vec::new),
+        category_address: use_signal_sync(Vec::new),
+        domain_route: use_signal_sync(Vec::new),
         mail_deliveries: use_signal_sync(Vec::new),
         mail_delivery_events: use_signal_sync(Vec::new),
         mail_ingress: use_signal_sync(Vec::new),
         message_categories: use_signal_sync(Vec::new),
+        module_settings: use_signal_sync(Vec::new),
+        mta_daily_stats: use_signal_sync(Vec::new),
+        my_account_lookup_results: use_signal_sync(Vec::new),
+        my_category_impact_results: use_signal_sync(Vec::new),
+        my_expected_identity_results: use_signal_sync(Vec::new),
+        my_export_blobs: use_signal_sync(Vec::new),
+        my_mta_decisions: use_signal_sync(Vec::new),
+        my_offending_prefix_results: use_signal_sync(Vec::new),
+        my_ping_results: use_signal_sync(Vec::new),
+        my_resolve_results: use_signal_sync(Vec::new),
+        my_stage_timing_results: use_signal_sync(Vec::new),
+        reject_reason_stats: use_signal_sync(Vec::new),
         sender_mail_deliveries: use_signal_sync(Vec::new),
         sender_mail_ingress: use_signal_sync(Vec::new),
         subscription_unsubscribe_tokens: use_signal_sync(Vec::new),
         subscriptions: use_signal_sync(Vec::new),
+        table_size_history: use_signal_sync(Vec::new),
+        visible_account_aliases: use_signal_sync(Vec::new),
+        visible_account_tags: use_signal_sync(Vec::new),
         visible_accounts: use_signal_sync(Vec::new),
         visible_admin_identities: use_signal_sync(Vec::new),
+        visible_category_sender_rules: use_signal_sync(Vec::new),
+        visible_complaints: use_signal_sync(Vec::new),
+        visible_data_issues: use_signal_sync(Vec::new),
+        visible_delivery_outcomes: use_signal_sync(Vec::new),
+        visible_digest_out: use_signal_sync(Vec::new),
+        visible_envelope_samples: use_signal_sync(Vec::new),
+        visible_forward_rules: use_signal_sync(Vec::new),
+        visible_group_category_map: use_signal_sync(Vec::new),
+        visible_login_events: use_signal_sync(Vec::new),
         visible_messages: use_signal_sync(Vec::new),
+        visible_mta_connection_log: use_signal_sync(Vec::new),
+        visible_notifications: use_signal_sync(Vec::new),
+        visible_orphaned_identities: use_signal_sync(Vec::new),
+        visible_relay_out: use_signal_sync(Vec::new),
+        visible_sender_whitelist: use_signal_sync(Vec::new),
         visible_subscriptions: use_signal_sync(Vec::new),
         visible_webhook_tokens: use_signal_sync(Vec::new),
     };
@@ -308,6 +370,46 @@ pub fn use_spacetimedb_context_provider(
                                     .active_unsubscribe_tokens
                                     .set(updated);
                             });
+                        // Populate initial rows for category_address
+                        let current: Vec<CategoryAddress> =
+                            conn.db.category_address().iter().collect();
+                        table_signals_on_connect.category_address.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.category_address().on_insert(move |ctx, _row| {
+                            let updated: Vec<CategoryAddress> =
+                                ctx.db.category_address().iter().collect();
+                            table_signals_on_connect.category_address.set(updated);
+                        });
+                        conn.db
+                            .category_address()
+                            .on_update(move |ctx, _old, _new| {
+                                let updated: Vec<CategoryAddress> =
+                                    ctx.db.category_address().iter().collect();
+                                table_signals_on_connect.category_address.set(updated);
+                            });
+                        conn.db.category_address().on_delete(move |ctx, _row| {
+                            let updated: Vec<CategoryAddress> =
+                                ctx.db.category_address().iter().collect();
+                            table_signals_on_connect.category_address.set(updated);
+                        });
+                        // Populate initial rows for domain_route
+                        let current: Vec<DomainRoute> = conn.db.domain_route().iter().collect();
+                        table_signals_on_connect.domain_route.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.domain_route().on_insert(move |ctx, _row| {
+                            let updated: Vec<DomainRoute> = ctx.db.domain_route().iter().collect();
+                            table_signals_on_connect.domain_route.set(updated);
+                        });
+                        conn.db.domain_route().on_update(move |ctx, _old, _new| {
+                            let updated: Vec<DomainRoute> = ctx.db.domain_route().iter().collect();
+                            table_signals_on_connect.domain_route.set(updated);
+                        });
+                        conn.db.domain_route().on_delete(move |ctx, _row| {
+                            let updated: Vec<DomainRoute> = ctx.db.domain_route().iter().collect();
+                            table_signals_on_connect.domain_route.set(updated);
+                        });
                         // Populate initial rows for mail_deliveries
                         let current: Vec<MailDelivery> = conn.db.mail_deliveries().iter().collect();
                         table_signals_on_connect.mail_deliveries.set(current);
@@ -391,6 +493,262 @@ pub fn use_spacetimedb_context_provider(
                                 ctx.db.message_categories().iter().collect();
                             table_signals_on_connect.message_categories.set(updated);
                         });
+                        // Populate initial rows for module_settings
+                        let current: Vec<ModuleSettings> =
+                            conn.db.module_settings().iter().collect();
+                        table_signals_on_connect.module_settings.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.module_settings().on_insert(move |ctx, _row| {
+                            let updated: Vec<ModuleSettings> =
+                                ctx.db.module_settings().iter().collect();
+                            table_signals_on_connect.module_settings.set(updated);
+                        });
+                        conn.db.module_settings().on_update(move |ctx, _old, _new| {
+                            let updated: Vec<ModuleSettings> =
+                                ctx.db.module_settings().iter().collect();
+                            table_signals_on_connect.module_settings.set(updated);
+                        });
+                        conn.db.module_settings().on_delete(move |ctx, _row| {
+                            let updated: Vec<ModuleSettings> =
+                                ctx.db.module_settings().iter().collect();
+                            table_signals_on_connect.module_settings.set(updated);
+                        });
+                        // Populate initial rows for mta_daily_stats
+                        let current: Vec<MtaDailyStats> =
+                            conn.db.mta_daily_stats().iter().collect();
+                        table_signals_on_connect.mta_daily_stats.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.mta_daily_stats().on_insert(move |ctx, _row| {
+                            let updated: Vec<MtaDailyStats> =
+                                ctx.db.mta_daily_stats().iter().collect();
+                            table_signals_on_connect.mta_daily_stats.set(updated);
+                        });
+                        conn.db.mta_daily_stats().on_update(move |ctx, _old, _new| {
+                            let updated: Vec<MtaDailyStats> =
+                                ctx.db.mta_daily_stats().iter().collect();
+                            table_signals_on_connect.mta_daily_stats.set(updated);
+                        });
+                        conn.db.mta_daily_stats().on_delete(move |ctx, _row| {
+                            let updated: Vec<MtaDailyStats> =
+                                ctx.db.mta_daily_stats().iter().collect();
+                            table_signals_on_connect.mta_daily_stats.set(updated);
+                        });
+                        // Populate initial rows for my_account_lookup_results
+                        let current: Vec<AccountLookupResult> =
+                            conn.db.my_account_lookup_results().iter().collect();
+                        table_signals_on_connect
+                            .my_account_lookup_results
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .my_account_lookup_results()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<AccountLookupResult> =
+                                    ctx.db.my_account_lookup_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_account_lookup_results
+                                    .set(updated);
+                            });
+                        conn.db
+                            .my_account_lookup_results()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<AccountLookupResult> =
+                                    ctx.db.my_account_lookup_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_account_lookup_results
+                                    .set(updated);
+                            });
+                        // Populate initial rows for my_category_impact_results
+                        let current: Vec<CategoryImpactResult> =
+                            conn.db.my_category_impact_results().iter().collect();
+                        table_signals_on_connect
+                            .my_category_impact_results
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .my_category_impact_results()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<CategoryImpactResult> =
+                                    ctx.db.my_category_impact_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_category_impact_results
+                                    .set(updated);
+                            });
+                        conn.db
+                            .my_category_impact_results()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<CategoryImpactResult> =
+                                    ctx.db.my_category_impact_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_category_impact_results
+                                    .set(updated);
+                            });
+                        // Populate initial rows for my_expected_identity_results
+                        let current: Vec<ExpectedIdentityResult> =
+                            conn.db.my_expected_identity_results().iter().collect();
+                        table_signals_on_connect
+                            .my_expected_identity_results
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .my_expected_identity_results()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<ExpectedIdentityResult> =
+                                    ctx.db.my_expected_identity_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_expected_identity_results
+                                    .set(updated);
+                            });
+                        conn.db
+                            .my_expected_identity_results()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<ExpectedIdentityResult> =
+                                    ctx.db.my_expected_identity_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_expected_identity_results
+                                    .set(updated);
+                            });
+                        // Populate initial rows for my_export_blobs
+                        let current: Vec<ExportBlob> = conn.db.my_export_blobs().iter().collect();
+                        table_signals_on_connect.my_export_blobs.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.my_export_blobs().on_insert(move |ctx, _row| {
+                            let updated: Vec<ExportBlob> =
+                                ctx.db.my_export_blobs().iter().collect();
+                            table_signals_on_connect.my_export_blobs.set(updated);
+                        });
+                        conn.db.my_export_blobs().on_delete(move |ctx, _row| {
+                            let updated: Vec<ExportBlob> =
+                                ctx.db.my_export_blobs().iter().collect();
+                            table_signals_on_connect.my_export_blobs.set(updated);
+                        });
+                        // Populate initial rows for my_mta_decisions
+                        let current: Vec<MtaDecision> = conn.db.my_mta_decisions().iter().collect();
+                        table_signals_on_connect.my_mta_decisions.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.my_mta_decisions().on_insert(move |ctx, _row| {
+                            let updated: Vec<MtaDecision> =
+                                ctx.db.my_mta_decisions().iter().collect();
+                            table_signals_on_connect.my_mta_decisions.set(updated);
+                        });
+                        conn.db.my_mta_decisions().on_delete(move |ctx, _row| {
+                            let updated: Vec<MtaDecision> =
+                                ctx.db.my_mta_decisions().iter().collect();
+                            table_signals_on_connect.my_mta_decisions.set(updated);
+                        });
+                        // Populate initial rows for my_offending_prefix_results
+                        let current: Vec<OffendingPrefixResult> =
+                            conn.db.my_offending_prefix_results().iter().collect();
+                        table_signals_on_connect
+                            .my_offending_prefix_results
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .my_offending_prefix_results()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<OffendingPrefixResult> =
+                                    ctx.db.my_offending_prefix_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_offending_prefix_results
+                                    .set(updated);
+                            });
+                        conn.db
+                            .my_offending_prefix_results()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<OffendingPrefixResult> =
+                                    ctx.db.my_offending_prefix_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_offending_prefix_results
+                                    .set(updated);
+                            });
+                        // Populate initial rows for my_ping_results
+                        let current: Vec<PingResult> = conn.db.my_ping_results().iter().collect();
+                        table_signals_on_connect.my_ping_results.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.my_ping_results().on_insert(move |ctx, _row| {
+                            let updated: Vec<PingResult> =
+                                ctx.db.my_ping_results().iter().collect();
+                            table_signals_on_connect.my_ping_results.set(updated);
+                        });
+                        conn.db.my_ping_results().on_delete(move |ctx, _row| {
+                            let updated: Vec<PingResult> =
+                                ctx.db.my_ping_results().iter().collect();
+                            table_signals_on_connect.my_ping_results.set(updated);
+                        });
+                        // Populate initial rows for my_resolve_results
+                        let current: Vec<ResolveResult> =
+                            conn.db.my_resolve_results().iter().collect();
+                        table_signals_on_connect.my_resolve_results.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.my_resolve_results().on_insert(move |ctx, _row| {
+                            let updated: Vec<ResolveResult> =
+                                ctx.db.my_resolve_results().iter().collect();
+                            table_signals_on_connect.my_resolve_results.set(updated);
+                        });
+                        conn.db.my_resolve_results().on_delete(move |ctx, _row| {
+                            let updated: Vec<ResolveResult> =
+                                ctx.db.my_resolve_results().iter().collect();
+                            table_signals_on_connect.my_resolve_results.set(updated);
+                        });
+                        // Populate initial rows for my_stage_timing_results
+                        let current: Vec<StageTimingResult> =
+                            conn.db.my_stage_timing_results().iter().collect();
+                        table_signals_on_connect
+                            .my_stage_timing_results
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .my_stage_timing_results()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<StageTimingResult> =
+                                    ctx.db.my_stage_timing_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_stage_timing_results
+                                    .set(updated);
+                            });
+                        conn.db
+                            .my_stage_timing_results()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<StageTimingResult> =
+                                    ctx.db.my_stage_timing_results().iter().collect();
+                                table_signals_on_connect
+                                    .my_stage_timing_results
+                                    .set(updated);
+                            });
+                        // Populate initial rows for reject_reason_stats
+                        let current: Vec<RejectReasonStat> =
+                            conn.db.reject_reason_stats().iter().collect();
+                        table_signals_on_connect.reject_reason_stats.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.reject_reason_stats().on_insert(move |ctx, _row| {
+                            let updated: Vec<RejectReasonStat> =
+                                ctx.db.reject_reason_stats().iter().collect();
+                            table_signals_on_connect.reject_reason_stats.set(updated);
+                        });
+                        conn.db
+                            .reject_reason_stats()
+                            .on_update(move |ctx, _old, _new| {
+                                let updated: Vec<RejectReasonStat> =
+                                    ctx.db.reject_reason_stats().iter().collect();
+                                table_signals_on_connect.reject_reason_stats.set(updated);
+                            });
+                        conn.db.reject_reason_stats().on_delete(move |ctx, _row| {
+                            let updated: Vec<RejectReasonStat> =
+                                ctx.db.reject_reason_stats().iter().collect();
+                            table_signals_on_connect.reject_reason_stats.set(updated);
+                        });
                         // Populate initial rows for sender_mail_deliveries
                         let current: Vec<MailDelivery> =
                             conn.db.sender_mail_deliveries().iter().collect();
@@ -482,6 +840,71 @@ pub fn use_spacetimedb_context_provider(
                                 ctx.db.subscriptions().iter().collect();
                             table_signals_on_connect.subscriptions.set(updated);
                         });
+                        // Populate initial rows for table_size_history
+                        let current: Vec<TableSizeHistory> =
+                            conn.db.table_size_history().iter().collect();
+                        table_signals_on_connect.table_size_history.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.table_size_history().on_insert(move |ctx, _row| {
+                            let updated: Vec<TableSizeHistory> =
+                                ctx.db.table_size_history().iter().collect();
+                            table_signals_on_connect.table_size_history.set(updated);
+                        });
+                        conn.db
+                            .table_size_history()
+                            .on_update(move |ctx, _old, _new| {
+                                let updated: Vec<TableSizeHistory> =
+                                    ctx.db.table_size_history().iter().collect();
+                                table_signals_on_connect.table_size_history.set(updated);
+                            });
+                        conn.db.table_size_history().on_delete(move |ctx, _row| {
+                            let updated: Vec<TableSizeHistory> =
+                                ctx.db.table_size_history().iter().collect();
+                            table_signals_on_connect.table_size_history.set(updated);
+                        });
+                        // Populate initial rows for visible_account_aliases
+                        let current: Vec<AccountAlias> =
+                            conn.db.visible_account_aliases().iter().collect();
+                        table_signals_on_connect
+                            .visible_account_aliases
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_account_aliases()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<AccountAlias> =
+                                    ctx.db.visible_account_aliases().iter().collect();
+                                table_signals_on_connect
+                                    .visible_account_aliases
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_account_aliases()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<AccountAlias> =
+                                    ctx.db.visible_account_aliases().iter().collect();
+                                table_signals_on_connect
+                                    .visible_account_aliases
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_account_tags
+                        let current: Vec<AccountTag> =
+                            conn.db.visible_account_tags().iter().collect();
+                        table_signals_on_connect.visible_account_tags.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_account_tags().on_insert(move |ctx, _row| {
+                            let updated: Vec<AccountTag> =
+                                ctx.db.visible_account_tags().iter().collect();
+                            table_signals_on_connect.visible_account_tags.set(updated);
+                        });
+                        conn.db.visible_account_tags().on_delete(move |ctx, _row| {
+                            let updated: Vec<AccountTag> =
+                                ctx.db.visible_account_tags().iter().collect();
+                            table_signals_on_connect.visible_account_tags.set(updated);
+                        });
                         // Populate initial rows for visible_accounts
                         let current: Vec<Account> = conn.db.visible_accounts().iter().collect();
                         table_signals_on_connect.visible_accounts.set(current);
@@ -521,6 +944,188 @@ pub fn use_spacetimedb_context_provider(
                                     .visible_admin_identities
                                     .set(updated);
                             });
+                        // Populate initial rows for visible_category_sender_rules
+                        let current: Vec<CategorySenderRule> =
+                            conn.db.visible_category_sender_rules().iter().collect();
+                        table_signals_on_connect
+                            .visible_category_sender_rules
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_category_sender_rules()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<CategorySenderRule> =
+                                    ctx.db.visible_category_sender_rules().iter().collect();
+                                table_signals_on_connect
+                                    .visible_category_sender_rules
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_category_sender_rules()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<CategorySenderRule> =
+                                    ctx.db.visible_category_sender_rules().iter().collect();
+                                table_signals_on_connect
+                                    .visible_category_sender_rules
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_complaints
+                        let current: Vec<Complaint> = conn.db.visible_complaints().iter().collect();
+                        table_signals_on_connect.visible_complaints.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_complaints().on_insert(move |ctx, _row| {
+                            let updated: Vec<Complaint> =
+                                ctx.db.visible_complaints().iter().collect();
+                            table_signals_on_connect.visible_complaints.set(updated);
+                        });
+                        conn.db.visible_complaints().on_delete(move |ctx, _row| {
+                            let updated: Vec<Complaint> =
+                                ctx.db.visible_complaints().iter().collect();
+                            table_signals_on_connect.visible_complaints.set(updated);
+                        });
+                        // Populate initial rows for visible_data_issues
+                        let current: Vec<DataIssue> =
+                            conn.db.visible_data_issues().iter().collect();
+                        table_signals_on_connect.visible_data_issues.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_data_issues().on_insert(move |ctx, _row| {
+                            let updated: Vec<DataIssue> =
+                                ctx.db.visible_data_issues().iter().collect();
+                            table_signals_on_connect.visible_data_issues.set(updated);
+                        });
+                        conn.db.visible_data_issues().on_delete(move |ctx, _row| {
+                            let updated: Vec<DataIssue> =
+                                ctx.db.visible_data_issues().iter().collect();
+                            table_signals_on_connect.visible_data_issues.set(updated);
+                        });
+                        // Populate initial rows for visible_delivery_outcomes
+                        let current: Vec<DeliveryOutcome> =
+                            conn.db.visible_delivery_outcomes().iter().collect();
+                        table_signals_on_connect
+                            .visible_delivery_outcomes
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_delivery_outcomes()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<DeliveryOutcome> =
+                                    ctx.db.visible_delivery_outcomes().iter().collect();
+                                table_signals_on_connect
+                                    .visible_delivery_outcomes
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_delivery_outcomes()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<DeliveryOutcome> =
+                                    ctx.db.visible_delivery_outcomes().iter().collect();
+                                table_signals_on_connect
+                                    .visible_delivery_outcomes
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_digest_out
+                        let current: Vec<DigestOut> = conn.db.visible_digest_out().iter().collect();
+                        table_signals_on_connect.visible_digest_out.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_digest_out().on_insert(move |ctx, _row| {
+                            let updated: Vec<DigestOut> =
+                                ctx.db.visible_digest_out().iter().collect();
+                            table_signals_on_connect.visible_digest_out.set(updated);
+                        });
+                        conn.db.visible_digest_out().on_delete(move |ctx, _row| {
+                            let updated: Vec<DigestOut> =
+                                ctx.db.visible_digest_out().iter().collect();
+                            table_signals_on_connect.visible_digest_out.set(updated);
+                        });
+                        // Populate initial rows for visible_envelope_samples
+                        let current: Vec<EnvelopeSample> =
+                            conn.db.visible_envelope_samples().iter().collect();
+                        table_signals_on_connect
+                            .visible_envelope_samples
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_envelope_samples()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<EnvelopeSample> =
+                                    ctx.db.visible_envelope_samples().iter().collect();
+                                table_signals_on_connect
+                                    .visible_envelope_samples
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_envelope_samples()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<EnvelopeSample> =
+                                    ctx.db.visible_envelope_samples().iter().collect();
+                                table_signals_on_connect
+                                    .visible_envelope_samples
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_forward_rules
+                        let current: Vec<ForwardRule> =
+                            conn.db.visible_forward_rules().iter().collect();
+                        table_signals_on_connect.visible_forward_rules.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_forward_rules().on_insert(move |ctx, _row| {
+                            let updated: Vec<ForwardRule> =
+                                ctx.db.visible_forward_rules().iter().collect();
+                            table_signals_on_connect.visible_forward_rules.set(updated);
+                        });
+                        conn.db.visible_forward_rules().on_delete(move |ctx, _row| {
+                            let updated: Vec<ForwardRule> =
+                                ctx.db.visible_forward_rules().iter().collect();
+                            table_signals_on_connect.visible_forward_rules.set(updated);
+                        });
+                        // Populate initial rows for visible_group_category_map
+                        let current: Vec<GroupCategoryMap> =
+                            conn.db.visible_group_category_map().iter().collect();
+                        table_signals_on_connect
+                            .visible_group_category_map
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_group_category_map()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<GroupCategoryMap> =
+                                    ctx.db.visible_group_category_map().iter().collect();
+                                table_signals_on_connect
+                                    .visible_group_category_map
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_group_category_map()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<GroupCategoryMap> =
+                                    ctx.db.visible_group_category_map().iter().collect();
+                                table_signals_on_connect
+                                    .visible_group_category_map
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_login_events
+                        let current: Vec<LoginEvent> =
+                            conn.db.visible_login_events().iter().collect();
+                        table_signals_on_connect.visible_login_events.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_login_events().on_insert(move |ctx, _row| {
+                            let updated: Vec<LoginEvent> =
+                                ctx.db.visible_login_events().iter().collect();
+                            table_signals_on_connect.visible_login_events.set(updated);
+                        });
+                        conn.db.visible_login_events().on_delete(move |ctx, _row| {
+                            let updated: Vec<LoginEvent> =
+                                ctx.db.visible_login_events().iter().collect();
+                            table_signals_on_connect.visible_login_events.set(updated);
+                        });
                         // Populate initial rows for visible_messages
                         let current: Vec<ReceivedMessage> =
                             conn.db.visible_messages().iter().collect();
@@ -537,39 +1142,148 @@ pub fn use_spacetimedb_context_provider(
                                 ctx.db.visible_messages().iter().collect();
                             table_signals_on_connect.visible_messages.set(updated);
                         });
-                        // Populate initial rows for visible_subscriptions
-                        let current: Vec<Subscription> =
-                            conn.db.visible_subscriptions().iter().collect();
-                        table_signals_on_connect.visible_subscriptions.set(current);
+                        // Populate initial rows for visible_mta_connection_log
+                        let current: Vec<MtaConnectionLog> =
+                            conn.db.visible_mta_connection_log().iter().collect();
+                        table_signals_on_connect
+                            .visible_mta_connection_log
+                            .set(current);
 
                         // Keep signal in sync on changes
-                        conn.db.visible_subscriptions().on_insert(move |ctx, _row| {
-                            let updated: Vec<Subscription> =
-                                ctx.db.visible_subscriptions().iter().collect();
-                            table_signals_on_connect.visible_subscriptions.set(updated);
+                        conn.db
+                            .visible_mta_connection_log()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<MtaConnectionLog> =
+                                    ctx.db.visible_mta_connection_log().iter().collect();
+                                table_signals_on_connect
+                                    .visible_mta_connection_log
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_mta_connection_log()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<MtaConnectionLog> =
+                                    ctx.db.visible_mta_connection_log().iter().collect();
+                                table_signals_on_connect
+                                    .visible_mta_connection_log
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_notifications
+                        let current: Vec<NotificationOut> =
+                            conn.db.visible_notifications().iter().collect();
+                        table_signals_on_connect.visible_notifications.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_notifications().on_insert(move |ctx, _row| {
+                            let updated: Vec<NotificationOut> =
+                                ctx.db.visible_notifications().iter().collect();
+                            table_signals_on_connect.visible_notifications.set(updated);
                         });
-                        conn.db.visible_subscriptions().on_delete(move |ctx, _row| {
-                            let updated: Vec<Subscription> =
-                                ctx.db.visible_subscriptions().iter().collect();
-                            table_signals_on_connect.visible_subscriptions.set(updated);
+                        conn.db.visible_notifications().on_delete(move |ctx, _row| {
+                            let updated: Vec<NotificationOut> =
+                                ctx.db.visible_notifications().iter().collect();
+                            table_signals_on_connect.visible_notifications.set(updated);
                         });
-                        // Populate initial rows for visible_webhook_tokens
-                        let current: Vec<WebhookToken> =
-                            conn.db.visible_webhook_tokens().iter().collect();
-                        table_signals_on_connect.visible_webhook_tokens.set(current);
+                        // Populate initial rows for visible_orphaned_identities
+                        let current: Vec<OrphanedIdentity> =
+                            conn.db.visible_orphaned_identities().iter().collect();
+                        table_signals_on_connect
+                            .visible_orphaned_identities
+                            .set(current);
 
                         // Keep signal in sync on changes
                         conn.db
-                            .visible_webhook_tokens()
+                            .visible_orphaned_identities()
                             .on_insert(move |ctx, _row| {
-                                let updated: Vec<WebhookToken> =
-                                    ctx.db.visible_webhook_tokens().iter().collect();
-                                table_signals_on_connect.visible_webhook_tokens.set(updated);
+                                let updated: Vec<OrphanedIdentity> =
+                                    ctx.db.visible_orphaned_identities().iter().collect();
+                                table_signals_on_connect
+                                    .visible_orphaned_identities
+                                    .set(updated);
                             });
                         conn.db
-                            .visible_webhook_tokens()
+                            .visible_orphaned_identities()
                             .on_delete(move |ctx, _row| {
-                                let updated: Vec<WebhookToken> =
+                                let updated: Vec<OrphanedIdentity> =
+                                    ctx.db.visible_orphaned_identities().iter().collect();
+                                table_signals_on_connect
+                                    .visible_orphaned_identities
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_relay_out
+                        let current: Vec<RelayOut> = conn.db.visible_relay_out().iter().collect();
+                        table_signals_on_connect.visible_relay_out.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_relay_out().on_insert(move |ctx, _row| {
+                            let updated: Vec<RelayOut> =
+                                ctx.db.visible_relay_out().iter().collect();
+                            table_signals_on_connect.visible_relay_out.set(updated);
+                        });
+                        conn.db.visible_relay_out().on_delete(move |ctx, _row| {
+                            let updated: Vec<RelayOut> =
+                                ctx.db.visible_relay_out().iter().collect();
+                            table_signals_on_connect.visible_relay_out.set(updated);
+                        });
+                        // Populate initial rows for visible_sender_whitelist
+                        let current: Vec<SenderWhitelist> =
+                            conn.db.visible_sender_whitelist().iter().collect();
+                        table_signals_on_connect
+                            .visible_sender_whitelist
+                            .set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_sender_whitelist()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<SenderWhitelist> =
+                                    ctx.db.visible_sender_whitelist().iter().collect();
+                                table_signals_on_connect
+                                    .visible_sender_whitelist
+                                    .set(updated);
+                            });
+                        conn.db
+                            .visible_sender_whitelist()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<SenderWhitelist> =
+                                    ctx.db.visible_sender_whitelist().iter().collect();
+                                table_signals_on_connect
+                                    .visible_sender_whitelist
+                                    .set(updated);
+                            });
+                        // Populate initial rows for visible_subscriptions
+                        let current: Vec<Subscription> =
+                            conn.db.visible_subscriptions().iter().collect();
+                        table_signals_on_connect.visible_subscriptions.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db.visible_subscriptions().on_insert(move |ctx, _row| {
+                            let updated: Vec<Subscription> =
+                                ctx.db.visible_subscriptions().iter().collect();
+                            table_signals_on_connect.visible_subscriptions.set(updated);
+                        });
+                        conn.db.visible_subscriptions().on_delete(move |ctx, _row| {
+                            let updated: Vec<Subscription> =
+                                ctx.db.visible_subscriptions().iter().collect();
+                            table_signals_on_connect.visible_subscriptions.set(updated);
+                        });
+                        // Populate initial rows for visible_webhook_tokens
+                        let current: Vec<WebhookToken> =
+                            conn.db.visible_webhook_tokens().iter().collect();
+                        table_signals_on_connect.visible_webhook_tokens.set(current);
+
+                        // Keep signal in sync on changes
+                        conn.db
+                            .visible_webhook_tokens()
+                            .on_insert(move |ctx, _row| {
+                                let updated: Vec<WebhookToken> =
+                                    ctx.db.visible_webhook_tokens().iter().collect();
+                                table_signals_on_connect.visible_webhook_tokens.set(updated);
+                            });
+                        conn.db
+                            .visible_webhook_tokens()
+                            .on_delete(move |ctx, _row| {
+                                let updated: Vec<WebhookToken> =
                                     ctx.db.visible_webhook_tokens().iter().collect();
                                 table_signals_on_connect.visible_webhook_tokens.set(updated);
                             });
@@ -754,6 +1468,20 @@ pub fn use_table_active_unsubscribe_tokens() -> SyncSignal<Vec<SubscriptionUnsub
     ctx.tables.active_unsubscribe_tokens
 }
 
+/// Get a reactive signal containing all rows of the `category_address` table.
+#[must_use]
+pub fn use_table_category_address() -> SyncSignal<Vec<CategoryAddress>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.category_address
+}
+
+/// Get a reactive signal containing all rows of the `domain_route` table.
+#[must_use]
+pub fn use_table_domain_route() -> SyncSignal<Vec<DomainRoute>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.domain_route
+}
+
 /// Get a reactive signal containing all rows of the `mail_deliveries` table.
 #[must_use]
 pub fn use_table_mail_deliveries() -> SyncSignal<Vec<MailDelivery>> {
@@ -782,6 +1510,90 @@ pub fn use_table_message_categories() -> SyncSignal<Vec<MessageCategory>> {
     ctx.tables.message_categories
 }
 
+/// Get a reactive signal containing all rows of the `module_settings` table.
+#[must_use]
+pub fn use_table_module_settings() -> SyncSignal<Vec<ModuleSettings>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.module_settings
+}
+
+/// Get a reactive signal containing all rows of the `mta_daily_stats` table.
+#[must_use]
+pub fn use_table_mta_daily_stats() -> SyncSignal<Vec<MtaDailyStats>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.mta_daily_stats
+}
+
+/// Get a reactive signal containing all rows of the `my_account_lookup_results` table.
+#[must_use]
+pub fn use_table_my_account_lookup_results() -> SyncSignal<Vec<AccountLookupResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_account_lookup_results
+}
+
+/// Get a reactive signal containing all rows of the `my_category_impact_results` table.
+#[must_use]
+pub fn use_table_my_category_impact_results() -> SyncSignal<Vec<CategoryImpactResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_category_impact_results
+}
+
+/// Get a reactive signal containing all rows of the `my_expected_identity_results` table.
+#[must_use]
+pub fn use_table_my_expected_identity_results() -> SyncSignal<Vec<ExpectedIdentityResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_expected_identity_results
+}
+
+/// Get a reactive signal containing all rows of the `my_export_blobs` table.
+#[must_use]
+pub fn use_table_my_export_blobs() -> SyncSignal<Vec<ExportBlob>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_export_blobs
+}
+
+/// Get a reactive signal containing all rows of the `my_mta_decisions` table.
+#[must_use]
+pub fn use_table_my_mta_decisions() -> SyncSignal<Vec<MtaDecision>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_mta_decisions
+}
+
+/// Get a reactive signal containing all rows of the `my_offending_prefix_results` table.
+#[must_use]
+pub fn use_table_my_offending_prefix_results() -> SyncSignal<Vec<OffendingPrefixResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_offending_prefix_results
+}
+
+/// Get a reactive signal containing all rows of the `my_ping_results` table.
+#[must_use]
+pub fn use_table_my_ping_results() -> SyncSignal<Vec<PingResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_ping_results
+}
+
+/// Get a reactive signal containing all rows of the `my_resolve_results` table.
+#[must_use]
+pub fn use_table_my_resolve_results() -> SyncSignal<Vec<ResolveResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_resolve_results
+}
+
+/// Get a reactive signal containing all rows of the `my_stage_timing_results` table.
+#[must_use]
+pub fn use_table_my_stage_timing_results() -> SyncSignal<Vec<StageTimingResult>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.my_stage_timing_results
+}
+
+/// Get a reactive signal containing all rows of the `reject_reason_stats` table.
+#[must_use]
+pub fn use_table_reject_reason_stats() -> SyncSignal<Vec<RejectReasonStat>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.reject_reason_stats
+}
+
 /// Get a reactive signal containing all rows of the `sender_mail_deliveries` table.
 #[must_use]
 pub fn use_table_sender_mail_deliveries() -> SyncSignal<Vec<MailDelivery>> {
@@ -811,6 +1623,27 @@ pub fn use_table_subscriptions() -> SyncSignal<Vec<Subscription>> {
     ctx.tables.subscriptions
 }
 
+/// Get a reactive signal containing all rows of the `table_size_history` table.
+#[must_use]
+pub fn use_table_table_size_history() -> SyncSignal<Vec<TableSizeHistory>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.table_size_history
+}
+
+/// Get a reactive signal containing all rows of the `visible_account_aliases` table.
+#[must_use]
+pub fn use_table_visible_account_aliases() -> SyncSignal<Vec<AccountAlias>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_account_aliases
+}
+
+/// Get a reactive signal containing all rows of the `visible_account_tags` table.
+#[must_use]
+pub fn use_table_visible_account_tags() -> SyncSignal<Vec<AccountTag>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_account_tags
+}
+
 /// Get a reactive signal containing all rows of the `visible_accounts` table.
 #[must_use]
 pub fn use_table_visible_accounts() -> SyncSignal<Vec<Account>> {
@@ -825,6 +1658,69 @@ pub fn use_table_visible_admin_identities() -> SyncSignal<Vec<AdminIdentity>> {
     ctx.tables.visible_admin_identities
 }
 
+/// Get a reactive signal containing all rows of the `visible_category_sender_rules` table.
+#[must_use]
+pub fn use_table_visible_category_sender_rules() -> SyncSignal<Vec<CategorySenderRule>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_category_sender_rules
+}
+
+/// Get a reactive signal containing all rows of the `visible_complaints` table.
+#[must_use]
+pub fn use_table_visible_complaints() -> SyncSignal<Vec<Complaint>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_complaints
+}
+
+/// Get a reactive signal containing all rows of the `visible_data_issues` table.
+#[must_use]
+pub fn use_table_visible_data_issues() -> SyncSignal<Vec<DataIssue>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_data_issues
+}
+
+/// Get a reactive signal containing all rows of the `visible_delivery_outcomes` table.
+#[must_use]
+pub fn use_table_visible_delivery_outcomes() -> SyncSignal<Vec<DeliveryOutcome>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_delivery_outcomes
+}
+
+/// Get a reactive signal containing all rows of the `visible_digest_out` table.
+#[must_use]
+pub fn use_table_visible_digest_out() -> SyncSignal<Vec<DigestOut>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_digest_out
+}
+
+/// Get a reactive signal containing all rows of the `visible_envelope_samples` table.
+#[must_use]
+pub fn use_table_visible_envelope_samples() -> SyncSignal<Vec<EnvelopeSample>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_envelope_samples
+}
+
+/// Get a reactive signal containing all rows of the `visible_forward_rules` table.
+#[must_use]
+pub fn use_table_visible_forward_rules() -> SyncSignal<Vec<ForwardRule>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_forward_rules
+}
+
+/// Get a reactive signal containing all rows of the `visible_group_category_map` table.
+#[must_use]
+pub fn use_table_visible_group_category_map() -> SyncSignal<Vec<GroupCategoryMap>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_group_category_map
+}
+
+/// Get a reactive signal containing all rows of the `visible_login_events` table.
+#[must_use]
+pub fn use_table_visible_login_events() -> SyncSignal<Vec<LoginEvent>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_login_events
+}
+
 /// Get a reactive signal containing all rows of the `visible_messages` table.
 #[must_use]
 pub fn use_table_visible_messages() -> SyncSignal<Vec<ReceivedMessage>> {
@@ -832,6 +1728,41 @@ pub fn use_table_visible_messages() -> SyncSignal<Vec<ReceivedMessage>> {
     ctx.tables.visible_messages
 }
 
+/// Get a reactive signal containing all rows of the `visible_mta_connection_log` table.
+#[must_use]
+pub fn use_table_visible_mta_connection_log() -> SyncSignal<Vec<MtaConnectionLog>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_mta_connection_log
+}
+
+/// Get a reactive signal containing all rows of the `visible_notifications` table.
+#[must_use]
+pub fn use_table_visible_notifications() -> SyncSignal<Vec<NotificationOut>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_notifications
+}
+
+/// Get a reactive signal containing all rows of the `visible_orphaned_identities` table.
+#[must_use]
+pub fn use_table_visible_orphaned_identities() -> SyncSignal<Vec<OrphanedIdentity>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_orphaned_identities
+}
+
+/// Get a reactive signal containing all rows of the `visible_relay_out` table.
+#[must_use]
+pub fn use_table_visible_relay_out() -> SyncSignal<Vec<RelayOut>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_relay_out
+}
+
+/// Get a reactive signal containing all rows of the `visible_sender_whitelist` table.
+#[must_use]
+pub fn use_table_visible_sender_whitelist() -> SyncSignal<Vec<SenderWhitelist>> {
+    let ctx = use_spacetimedb_context();
+    ctx.tables.visible_sender_whitelist
+}
+
 /// Get a reactive signal containing all rows of the `visible_subscriptions` table.
 #[must_use]
 pub fn use_table_visible_subscriptions() -> SyncSignal<Vec<Subscription>> {
@@ -848,151 +1779,344 @@ pub fn use_table_visible_webhook_tokens() -> SyncSignal<Vec<WebhookToken>> {
 
 // --- Reducer hooks ---
 
-/// Get a callback to invoke the `add_message_category` reducer.
+/// Get a callback to invoke the `add_account_alias` reducer.
 #[must_use]
-pub fn use_reducer_add_message_category(
-) -> impl Fn(String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_account_alias(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |name: String, email_address: String, description: String| {
+    move |account_id: u64, email: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers
-                .add_message_category(name, email_address, description)
+            conn.reducers.add_account_alias(account_id, email)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `add_subscription` reducer.
+/// Get a callback to invoke the `add_account_tag` reducer.
 #[must_use]
-pub fn use_reducer_add_subscription(
-) -> impl Fn(u64, String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_account_tag(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |subscriber_account_id: u64, subscriber_email: String, category_id: u64| {
+    move |account_id: u64, tag: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers
-                .add_subscription(subscriber_account_id, subscriber_email, category_id)
+            conn.reducers.add_account_tag(account_id, tag)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `claim_next_mail_delivery` reducer.
+/// Get a callback to invoke the `add_category_address` reducer.
 #[must_use]
-pub fn use_reducer_claim_next_mail_delivery(
-) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_category_address(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move || {
+    move |category_id: u64, email_address: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.claim_next_mail_delivery()
+            conn.reducers
+                .add_category_address(category_id, email_address)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `claim_next_mail_ingress` reducer.
+/// Get a callback to invoke the `add_category_sender_rule` reducer.
 #[must_use]
-pub fn use_reducer_claim_next_mail_ingress(
-) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_category_sender_rule(
+) -> impl Fn(u64, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move || {
+    move |category_id: u64, pattern: String, kind: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.claim_next_mail_ingress()
+            conn.reducers
+                .add_category_sender_rule(category_id, pattern, kind)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `complete_mail_ingress` reducer.
+/// Get a callback to invoke the `add_forward_rule` reducer.
 #[must_use]
-pub fn use_reducer_complete_mail_ingress(
-) -> impl Fn(String, u32, u32) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_forward_rule(
+) -> impl Fn(u64, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |ingress_id: String, delivery_count: u32, failed_delivery_count: u32| {
+    move |category_id: u64, destination_email: String, mode: String| {
         if let Some(conn) = conn_signal().as_ref() {
             conn.reducers
-                .complete_mail_ingress(ingress_id, delivery_count, failed_delivery_count)
+                .add_forward_rule(category_id, destination_email, mode)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `create_webhook_token` reducer.
+/// Get a callback to invoke the `add_group_category_mapping` reducer.
 #[must_use]
-pub fn use_reducer_create_webhook_token(
-) -> impl Fn(String, String, Vec<String>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_group_category_mapping(
+) -> impl Fn(String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |token_hash: String, label: String, permissions: Vec<String>| {
+    move |group_name: String, category_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
             conn.reducers
-                .create_webhook_token(token_hash, label, permissions)
+                .add_group_category_mapping(group_name, category_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `dump_mta_logs_to_server_logs` reducer.
+/// Get a callback to invoke the `add_message_category` reducer.
 #[must_use]
-pub fn use_reducer_dump_mta_logs_to_server_logs(
-) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_add_message_category(
+) -> impl Fn(String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move || {
+    move |name: String, email_address: String, description: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.dump_mta_logs_to_server_logs()
+            conn.reducers
+                .add_message_category(name, email_address, description)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `enqueue_mail_delivery` reducer.
+/// Get a callback to invoke the `add_sender_whitelist` reducer.
 #[must_use]
-pub fn use_reducer_enqueue_mail_delivery() -> impl Fn(
-    String,
-    u64,
-    String,
-    Option<u64>,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-    String,
-) -> spacetimedb_sdk::Result<()>
-       + Clone
-       + 'static {
+pub fn use_reducer_add_sender_whitelist(
+) -> impl Fn(String, Option<__sdk::Timestamp>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |ingress_id: String,
-          subscription_id: u64,
-          recipient_email: String,
-          recipient_account_id: Option<u64>,
-          list_email: String,
-          list_name: String,
-          original_sender_email: String,
-          from_header: String,
-          reply_to: String,
-          subject: String,
-          body_raw: String,
-          headers_raw: String,
-          raw_message: String,
-          unsubscribe_token: String| {
+    move |email: String, expires_at: Option<__sdk::Timestamp>| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.add_sender_whitelist(email, expires_at)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `add_subscription` reducer.
+#[must_use]
+pub fn use_reducer_add_subscription(
+) -> impl Fn(u64, String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |subscriber_account_id: u64, subscriber_email: String, category_id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .add_subscription(subscriber_account_id, subscriber_email, category_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `add_subscription_with_expiry` reducer.
+#[must_use]
+pub fn use_reducer_add_subscription_with_expiry(
+) -> impl Fn(u64, String, u64, __sdk::Timestamp) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |subscriber_account_id: u64,
+          subscriber_email: String,
+          category_id: u64,
+          expires_at: __sdk::Timestamp| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.add_subscription_with_expiry(
+                subscriber_account_id,
+                subscriber_email,
+                category_id,
+                expires_at,
+            )
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `block_ip` reducer.
+#[must_use]
+pub fn use_reducer_block_ip(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |ip: String, reason: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.block_ip(ip, reason)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `claim_next_mail_delivery` reducer.
+#[must_use]
+pub fn use_reducer_claim_next_mail_delivery(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.claim_next_mail_delivery()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `claim_next_mail_ingress` reducer.
+#[must_use]
+pub fn use_reducer_claim_next_mail_ingress(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.claim_next_mail_ingress()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `clean_orphaned_identities` reducer.
+#[must_use]
+pub fn use_reducer_clean_orphaned_identities(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.clean_orphaned_identities()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `complete_mail_ingress` reducer.
+#[must_use]
+pub fn use_reducer_complete_mail_ingress(
+) -> impl Fn(String, u32, u32) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |ingress_id: String, delivery_count: u32, failed_delivery_count: u32| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .complete_mail_ingress(ingress_id, delivery_count, failed_delivery_count)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `compute_expected_identity` reducer.
+#[must_use]
+pub fn use_reducer_compute_expected_identity(
+) -> impl Fn(String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String, mitgliedsnr: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .compute_expected_identity(request_id, mitgliedsnr)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `create_webhook_token` reducer.
+#[must_use]
+pub fn use_reducer_create_webhook_token(
+) -> impl Fn(String, String, Vec<String>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |token_hash: String, label: String, permissions: Vec<String>| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .create_webhook_token(token_hash, label, permissions)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `dedupe_subscriptions` reducer.
+#[must_use]
+pub fn use_reducer_dedupe_subscriptions(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.dedupe_subscriptions()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `dump_mta_logs_to_server_logs` reducer.
+#[must_use]
+pub fn use_reducer_dump_mta_logs_to_server_logs(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.dump_mta_logs_to_server_logs()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `enqueue_mail_delivery` reducer.
+#[must_use]
+pub fn use_reducer_enqueue_mail_delivery() -> impl Fn(
+    String,
+    u64,
+    String,
+    Option<u64>,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+) -> spacetimedb_sdk::Result<()>
+       + Clone
+       + 'static {
+    let conn_signal = use_connection();
+
+    move |ingress_id: String,
+          subscription_id: u64,
+          recipient_email: String,
+          recipient_account_id: Option<u64>,
+          list_email: String,
+          list_name: String,
+          original_sender_email: String,
+          from_header: String,
+          reply_to: String,
+          subject: String,
+          body_raw: String,
+          headers_raw: String,
+          raw_message: String,
+          unsubscribe_token: String| {
         if let Some(conn) = conn_signal().as_ref() {
             conn.reducers.enqueue_mail_delivery(
                 ingress_id,
@@ -1016,146 +2140,691 @@ pub fn use_reducer_enqueue_mail_delivery() -> impl Fn(
     }
 }
 
-/// Get a callback to invoke the `ensure_subscription_unsubscribe_token` reducer.
+/// Get a callback to invoke the `ensure_subscription_unsubscribe_token` reducer.
+#[must_use]
+pub fn use_reducer_ensure_subscription_unsubscribe_token(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |subscription_id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .ensure_subscription_unsubscribe_token(subscription_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `expire_subscriptions` reducer.
+#[must_use]
+pub fn use_reducer_expire_subscriptions(
+) -> impl Fn(SubscriptionExpirySchedule) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |_schedule: SubscriptionExpirySchedule| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.expire_subscriptions(_schedule)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `export_admins` reducer.
+#[must_use]
+pub fn use_reducer_export_admins() -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.export_admins()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `export_message_logs` reducer.
+#[must_use]
+pub fn use_reducer_export_message_logs(
+) -> impl Fn(__sdk::Timestamp, __sdk::Timestamp) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |from_ts: __sdk::Timestamp, to_ts: __sdk::Timestamp| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.export_message_logs(from_ts, to_ts)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `fail_mail_delivery` reducer.
+#[must_use]
+pub fn use_reducer_fail_mail_delivery(
+) -> impl Fn(String, Option<u16>, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |delivery_id: String,
+          smtp_status_code: Option<u16>,
+          smtp_response: String,
+          error_kind: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.fail_mail_delivery(
+                delivery_id,
+                smtp_status_code,
+                smtp_response,
+                error_kind,
+            )
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `fail_mail_ingress` reducer.
+#[must_use]
+pub fn use_reducer_fail_mail_ingress(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |ingress_id: String, error: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.fail_mail_ingress(ingress_id, error)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `find_orphaned_identities` reducer.
+#[must_use]
+pub fn use_reducer_find_orphaned_identities(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.find_orphaned_identities()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `flush_digests` reducer.
+#[must_use]
+pub fn use_reducer_flush_digests(
+) -> impl Fn(DigestFlushSchedule) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |_schedule: DigestFlushSchedule| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.flush_digests(_schedule)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `flush_pending_syncs` reducer.
+#[must_use]
+pub fn use_reducer_flush_pending_syncs(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.flush_pending_syncs()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `handle_mta_hook` reducer.
+#[must_use]
+pub fn use_reducer_handle_mta_hook(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String, hook_data: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.handle_mta_hook(request_id, hook_data)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `hard_delete_category` reducer.
+#[must_use]
+pub fn use_reducer_hard_delete_category(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.hard_delete_category(id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `import_admins` reducer.
+#[must_use]
+pub fn use_reducer_import_admins(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |json: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.import_admins(json)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `import_blocked_ips` reducer.
+#[must_use]
+pub fn use_reducer_import_blocked_ips(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |json: String, source: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.import_blocked_ips(json, source)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `import_subscriptions` reducer.
+#[must_use]
+pub fn use_reducer_import_subscriptions(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |json: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.import_subscriptions(json)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `inject_test_mta_event` reducer.
+#[must_use]
+pub fn use_reducer_inject_test_mta_event(
+) -> impl Fn(String, String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |stage: String, from: String, to: String, subject: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .inject_test_mta_event(stage, from, to, subject)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `lookup_account_by_identity` reducer.
+#[must_use]
+pub fn use_reducer_lookup_account_by_identity(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String, identity_hex: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .lookup_account_by_identity(request_id, identity_hex)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `mark_mail_delivery_bounced` reducer.
+#[must_use]
+pub fn use_reducer_mark_mail_delivery_bounced(
+) -> impl Fn(String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |delivery_id: String, smtp_response: String, error_kind: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .mark_mail_delivery_bounced(delivery_id, smtp_response, error_kind)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `mark_mail_delivery_sent` reducer.
+#[must_use]
+pub fn use_reducer_mark_mail_delivery_sent(
+) -> impl Fn(String, Option<u16>, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |delivery_id: String, smtp_status_code: Option<u16>, smtp_response: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .mark_mail_delivery_sent(delivery_id, smtp_status_code, smtp_response)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `mark_notification_delivered` reducer.
+#[must_use]
+pub fn use_reducer_mark_notification_delivered(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.mark_notification_delivered(id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `ping` reducer.
+#[must_use]
+pub fn use_reducer_ping() -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.ping(request_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `preview_category_impact` reducer.
+#[must_use]
+pub fn use_reducer_preview_category_impact(
+) -> impl Fn(String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String, category_id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .preview_category_impact(request_id, category_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `prune_blocks_by_source` reducer.
+#[must_use]
+pub fn use_reducer_prune_blocks_by_source(
+) -> impl Fn(String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |source: String, older_than_secs: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .prune_blocks_by_source(source, older_than_secs)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `prune_stale_admin_identities` reducer.
+#[must_use]
+pub fn use_reducer_prune_stale_admin_identities(
+) -> impl Fn(u32) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |max_idle_days: u32| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.prune_stale_admin_identities(max_idle_days)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `reassign_subscribers` reducer.
+#[must_use]
+pub fn use_reducer_reassign_subscribers(
+) -> impl Fn(u64, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |from_category_id: u64, to_category_id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .reassign_subscribers(from_category_id, to_category_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `recompute_mta_stats` reducer.
+#[must_use]
+pub fn use_reducer_recompute_mta_stats(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.recompute_mta_stats()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `recompute_reject_stats` reducer.
+#[must_use]
+pub fn use_reducer_recompute_reject_stats(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.recompute_reject_stats()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `record_auth_failure` reducer.
+#[must_use]
+pub fn use_reducer_record_auth_failure(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |client_ip: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.record_auth_failure(client_ip)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `record_complaint` reducer.
+#[must_use]
+pub fn use_reducer_record_complaint(
+) -> impl Fn(String, String, __sdk::Timestamp) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |from_address: String, kind: String, received_at: __sdk::Timestamp| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .record_complaint(from_address, kind, received_at)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `record_delivery_outcome` reducer.
+#[must_use]
+pub fn use_reducer_record_delivery_outcome(
+) -> impl Fn(String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |queue_id: String, outcome: String, detail: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .record_delivery_outcome(queue_id, outcome, detail)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `record_login` reducer.
+#[must_use]
+pub fn use_reducer_record_login() -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static
+{
+    let conn_signal = use_connection();
+
+    move |user_agent: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.record_login(user_agent)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `record_table_sizes` reducer.
+#[must_use]
+pub fn use_reducer_record_table_sizes(
+) -> impl Fn(TableSizeSchedule) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |_schedule: TableSizeSchedule| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.record_table_sizes(_schedule)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `register_admin_identity` reducer.
+#[must_use]
+pub fn use_reducer_register_admin_identity(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |identity_hex: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.register_admin_identity(identity_hex)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `relink_subscriptions` reducer.
+#[must_use]
+pub fn use_reducer_relink_subscriptions(
+) -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.relink_subscriptions()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `remove_account_alias` reducer.
+#[must_use]
+pub fn use_reducer_remove_account_alias(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.remove_account_alias(id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `remove_account_tag` reducer.
+#[must_use]
+pub fn use_reducer_remove_account_tag(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |account_id: u64, tag: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.remove_account_tag(account_id, tag)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `remove_category_address` reducer.
+#[must_use]
+pub fn use_reducer_remove_category_address(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.remove_category_address(id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `remove_category_sender_rule` reducer.
 #[must_use]
-pub fn use_reducer_ensure_subscription_unsubscribe_token(
+pub fn use_reducer_remove_category_sender_rule(
 ) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |subscription_id: u64| {
+    move |rule_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers
-                .ensure_subscription_unsubscribe_token(subscription_id)
+            conn.reducers.remove_category_sender_rule(rule_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `fail_mail_delivery` reducer.
+/// Get a callback to invoke the `remove_domain_route` reducer.
 #[must_use]
-pub fn use_reducer_fail_mail_delivery(
-) -> impl Fn(String, Option<u16>, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_domain_route(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |delivery_id: String,
-          smtp_status_code: Option<u16>,
-          smtp_response: String,
-          error_kind: String| {
+    move |domain: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.fail_mail_delivery(
-                delivery_id,
-                smtp_status_code,
-                smtp_response,
-                error_kind,
-            )
+            conn.reducers.remove_domain_route(domain)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `fail_mail_ingress` reducer.
+/// Get a callback to invoke the `remove_forward_rule` reducer.
 #[must_use]
-pub fn use_reducer_fail_mail_ingress(
-) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_forward_rule(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |ingress_id: String, error: String| {
+    move |rule_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.fail_mail_ingress(ingress_id, error)
+            conn.reducers.remove_forward_rule(rule_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `handle_mta_hook` reducer.
+/// Get a callback to invoke the `remove_group_category_mapping` reducer.
 #[must_use]
-pub fn use_reducer_handle_mta_hook(
-) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_group_category_mapping(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |hook_data: String| {
+    move |mapping_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.handle_mta_hook(hook_data)
+            conn.reducers.remove_group_category_mapping(mapping_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `mark_mail_delivery_bounced` reducer.
+/// Get a callback to invoke the `remove_message_category` reducer.
 #[must_use]
-pub fn use_reducer_mark_mail_delivery_bounced(
-) -> impl Fn(String, String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_message_category(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |delivery_id: String, smtp_response: String, error_kind: String| {
+    move |category_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers
-                .mark_mail_delivery_bounced(delivery_id, smtp_response, error_kind)
+            conn.reducers.remove_message_category(category_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `mark_mail_delivery_sent` reducer.
+/// Get a callback to invoke the `remove_sender_whitelist` reducer.
 #[must_use]
-pub fn use_reducer_mark_mail_delivery_sent(
-) -> impl Fn(String, Option<u16>, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_sender_whitelist(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |delivery_id: String, smtp_status_code: Option<u16>, smtp_response: String| {
+    move |email: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers
-                .mark_mail_delivery_sent(delivery_id, smtp_status_code, smtp_response)
+            conn.reducers.remove_sender_whitelist(email)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `register_admin_identity` reducer.
+/// Get a callback to invoke the `remove_subscription` reducer.
 #[must_use]
-pub fn use_reducer_register_admin_identity(
-) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_remove_subscription(
+) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |identity_hex: String| {
+    move |subscription_id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.register_admin_identity(identity_hex)
+            conn.reducers.remove_subscription(subscription_id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `remove_message_category` reducer.
+/// Get a callback to invoke the `resolve_recipient` reducer.
 #[must_use]
-pub fn use_reducer_remove_message_category(
-) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+pub fn use_reducer_resolve_recipient(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |category_id: u64| {
+    move |request_id: String, address: String| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.remove_message_category(category_id)
+            conn.reducers.resolve_recipient(request_id, address)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
     }
 }
 
-/// Get a callback to invoke the `remove_subscription` reducer.
+/// Get a callback to invoke the `restore_category` reducer.
 #[must_use]
-pub fn use_reducer_remove_subscription(
+pub fn use_reducer_restore_category(
 ) -> impl Fn(u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
     let conn_signal = use_connection();
 
-    move |subscription_id: u64| {
+    move |id: u64| {
         if let Some(conn) = conn_signal().as_ref() {
-            conn.reducers.remove_subscription(subscription_id)
+            conn.reducers.restore_category(id)
         } else {
             Err(spacetimedb_sdk::Error::Disconnected)
         }
@@ -1215,6 +2884,234 @@ pub fn use_reducer_schedule_mail_delivery_retry(
     }
 }
 
+/// Get a callback to invoke the `set_account_name` reducer.
+#[must_use]
+pub fn use_reducer_set_account_name(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, name: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_account_name(id, name)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_admin_identity_label` reducer.
+#[must_use]
+pub fn use_reducer_set_admin_identity_label(
+) -> impl Fn(String, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |identity_hex: String, label: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_admin_identity_label(identity_hex, label)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_accept_response` reducer.
+#[must_use]
+pub fn use_reducer_set_category_accept_response(
+) -> impl Fn(u64, Option<u16>, Option<String>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, accept_code: Option<u16>, accept_message: Option<String>| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .set_category_accept_response(id, accept_code, accept_message)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_active` reducer.
+#[must_use]
+pub fn use_reducer_set_category_active(
+) -> impl Fn(u64, bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, active: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_category_active(id, active)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_digest` reducer.
+#[must_use]
+pub fn use_reducer_set_category_digest(
+) -> impl Fn(u64, Option<u64>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |category_id: u64, interval_secs: Option<u64>| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .set_category_digest(category_id, interval_secs)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_log_level` reducer.
+#[must_use]
+pub fn use_reducer_set_category_log_level(
+) -> impl Fn(u64, String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, log_level: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_category_log_level(id, log_level)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_rate_limit` reducer.
+#[must_use]
+pub fn use_reducer_set_category_rate_limit(
+) -> impl Fn(u64, Option<u32>) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, rate_limit_per_hour: Option<u32>| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .set_category_rate_limit(id, rate_limit_per_hour)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_category_subscription_required` reducer.
+#[must_use]
+pub fn use_reducer_set_category_subscription_required(
+) -> impl Fn(u64, bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, subscription_required: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .set_category_subscription_required(id, subscription_required)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_domain_route` reducer.
+#[must_use]
+pub fn use_reducer_set_domain_route(
+) -> impl Fn(String, u64) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |domain: String, category_id: u64| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_domain_route(domain, category_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_dry_run` reducer.
+#[must_use]
+pub fn use_reducer_set_dry_run() -> impl Fn(bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |on: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_dry_run(on)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_envelope_sample_rate` reducer.
+#[must_use]
+pub fn use_reducer_set_envelope_sample_rate(
+) -> impl Fn(u32) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |permille: u32| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_envelope_sample_rate(permille)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_maintenance_mode` reducer.
+#[must_use]
+pub fn use_reducer_set_maintenance_mode(
+) -> impl Fn(bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |on: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_maintenance_mode(on)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_reject_unknown_recipients_strict` reducer.
+#[must_use]
+pub fn use_reducer_set_reject_unknown_recipients_strict(
+) -> impl Fn(bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |on: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_reject_unknown_recipients_strict(on)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `set_sync_paused` reducer.
+#[must_use]
+pub fn use_reducer_set_sync_paused(
+) -> impl Fn(bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |paused: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.set_sync_paused(paused)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `stage_timing_percentiles` reducer.
+#[must_use]
+pub fn use_reducer_stage_timing_percentiles(
+) -> impl Fn(String) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.stage_timing_percentiles(request_id)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
 /// Get a callback to invoke the `sync_user` reducer.
 #[must_use]
 pub fn use_reducer_sync_user(
@@ -1230,6 +3127,22 @@ pub fn use_reducer_sync_user(
     }
 }
 
+/// Get a callback to invoke the `top_offending_prefixes` reducer.
+#[must_use]
+pub fn use_reducer_top_offending_prefixes(
+) -> impl Fn(String, u64, u32) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |request_id: String, window_secs: u64, limit: u32| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers
+                .top_offending_prefixes(request_id, window_secs, limit)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
 /// Get a callback to invoke the `unregister_admin_identity` reducer.
 #[must_use]
 pub fn use_reducer_unregister_admin_identity(
@@ -1245,6 +3158,35 @@ pub fn use_reducer_unregister_admin_identity(
     }
 }
 
+/// Get a callback to invoke the `update_account` reducer.
+#[must_use]
+pub fn use_reducer_update_account(
+) -> impl Fn(u64, String, String, bool) -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move |id: u64, name: String, email: String, is_active: bool| {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.update_account(id, name, email, is_active)
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
+/// Get a callback to invoke the `validate_data` reducer.
+#[must_use]
+pub fn use_reducer_validate_data() -> impl Fn() -> spacetimedb_sdk::Result<()> + Clone + 'static {
+    let conn_signal = use_connection();
+
+    move || {
+        if let Some(conn) = conn_signal().as_ref() {
+            conn.reducers.validate_data()
+        } else {
+            Err(spacetimedb_sdk::Error::Disconnected)
+        }
+    }
+}
+
 // --- Procedure hooks ---
 
 /// Invoke the `provision_message_category` procedure and get a reactive signal for its result.
@@ -8,24 +8,69 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
 pub mod dioxus;
 
+pub mod account_alias_type;
+pub mod account_lookup_result_type;
 pub mod account_table;
+pub mod account_tag_type;
 pub mod account_type;
 pub mod active_subscriptions_table;
 pub mod active_unsubscribe_tokens_table;
+pub mod add_account_alias_reducer;
+pub mod add_account_tag_reducer;
+pub mod add_category_address_reducer;
+pub mod add_category_sender_rule_reducer;
+pub mod add_forward_rule_reducer;
+pub mod add_group_category_mapping_reducer;
 pub mod add_message_category_reducer;
+pub mod add_sender_whitelist_reducer;
 pub mod add_subscription_reducer;
+pub mod add_subscription_with_expiry_reducer;
 pub mod admin_identity_type;
+pub mod auth_failure_type;
+pub mod block_ip_reducer;
 pub mod blocked_ip_type;
+pub mod category_address_table;
+pub mod category_address_type;
+pub mod category_impact_result_type;
+pub mod category_sender_rule_type;
 pub mod claim_next_mail_delivery_reducer;
 pub mod claim_next_mail_ingress_reducer;
+pub mod clean_orphaned_identities_reducer;
+pub mod complaint_type;
 pub mod complete_mail_ingress_reducer;
+pub mod compute_expected_identity_reducer;
 pub mod create_webhook_token_reducer;
+pub mod data_issue_type;
+pub mod dedupe_subscriptions_reducer;
+pub mod delivery_outcome_type;
+pub mod digest_flush_schedule_type;
+pub mod digest_out_type;
+pub mod domain_route_table;
+pub mod domain_route_type;
 pub mod dump_mta_logs_to_server_logs_reducer;
 pub mod enqueue_mail_delivery_reducer;
 pub mod ensure_subscription_unsubscribe_token_reducer;
+pub mod envelope_sample_type;
+pub mod expected_identity_result_type;
+pub mod expire_subscriptions_reducer;
+pub mod export_admins_reducer;
+pub mod export_blob_type;
+pub mod export_message_logs_reducer;
 pub mod fail_mail_delivery_reducer;
 pub mod fail_mail_ingress_reducer;
+pub mod find_orphaned_identities_reducer;
+pub mod flush_digests_reducer;
+pub mod flush_pending_syncs_reducer;
+pub mod forward_rule_type;
+pub mod group_category_map_type;
 pub mod handle_mta_hook_reducer;
+pub mod hard_delete_category_reducer;
+pub mod import_admins_reducer;
+pub mod import_blocked_ips_reducer;
+pub mod import_subscriptions_reducer;
+pub mod inject_test_mta_event_reducer;
+pub mod login_event_type;
+pub mod lookup_account_by_identity_reducer;
 pub mod mail_deliveries_table;
 pub mod mail_delivery_event_type;
 pub mod mail_delivery_events_table;
@@ -34,51 +79,183 @@ pub mod mail_ingress_table;
 pub mod mail_ingress_type;
 pub mod mark_mail_delivery_bounced_reducer;
 pub mod mark_mail_delivery_sent_reducer;
+pub mod mark_notification_delivered_reducer;
 pub mod message_categories_table;
 pub mod message_category_type;
+pub mod module_settings_table;
+pub mod module_settings_type;
 pub mod mta_connection_log_type;
+pub mod mta_daily_stats_table;
+pub mod mta_daily_stats_type;
+pub mod mta_decision_type;
 pub mod mta_message_log_type;
+pub mod my_account_lookup_results_table;
+pub mod my_category_impact_results_table;
+pub mod my_expected_identity_results_table;
+pub mod my_export_blobs_table;
+pub mod my_mta_decisions_table;
+pub mod my_offending_prefix_results_table;
+pub mod my_ping_results_table;
+pub mod my_resolve_results_table;
+pub mod my_stage_timing_results_table;
+pub mod notification_out_type;
+pub mod offending_prefix_result_type;
+pub mod orphaned_identity_type;
+pub mod pending_digest_type;
+pub mod ping_reducer;
+pub mod ping_result_type;
+pub mod preview_category_impact_reducer;
 pub mod provision_message_category_procedure;
+pub mod prune_blocks_by_source_reducer;
+pub mod prune_stale_admin_identities_reducer;
+pub mod reassign_subscribers_reducer;
 pub mod received_message_type;
+pub mod recompute_mta_stats_reducer;
+pub mod recompute_reject_stats_reducer;
+pub mod record_auth_failure_reducer;
+pub mod record_complaint_reducer;
+pub mod record_delivery_outcome_reducer;
+pub mod record_login_reducer;
+pub mod record_table_sizes_reducer;
 pub mod register_admin_identity_reducer;
+pub mod reject_reason_stat_type;
+pub mod reject_reason_stats_table;
+pub mod relay_out_type;
+pub mod relink_subscriptions_reducer;
+pub mod remove_account_alias_reducer;
+pub mod remove_account_tag_reducer;
+pub mod remove_category_address_reducer;
+pub mod remove_category_sender_rule_reducer;
+pub mod remove_domain_route_reducer;
+pub mod remove_forward_rule_reducer;
+pub mod remove_group_category_mapping_reducer;
 pub mod remove_message_category_reducer;
+pub mod remove_sender_whitelist_reducer;
 pub mod remove_subscription_reducer;
+pub mod resolve_recipient_reducer;
+pub mod resolve_result_type;
+pub mod restore_category_reducer;
 pub mod retry_mail_ingress_reducer;
 pub mod revoke_webhook_token_reducer;
 pub mod schedule_mail_delivery_retry_reducer;
 pub mod sender_mail_deliveries_table;
 pub mod sender_mail_ingress_table;
+pub mod sender_whitelist_type;
+pub mod set_account_name_reducer;
+pub mod set_admin_identity_label_reducer;
+pub mod set_category_accept_response_reducer;
+pub mod set_category_active_reducer;
+pub mod set_category_digest_reducer;
+pub mod set_category_log_level_reducer;
+pub mod set_category_rate_limit_reducer;
+pub mod set_category_subscription_required_reducer;
+pub mod set_domain_route_reducer;
+pub mod set_dry_run_reducer;
+pub mod set_envelope_sample_rate_reducer;
+pub mod set_maintenance_mode_reducer;
+pub mod set_reject_unknown_recipients_strict_reducer;
+pub mod set_sync_paused_reducer;
+pub mod stage_timing_percentiles_reducer;
+pub mod stage_timing_result_type;
+pub mod subscription_expiry_schedule_type;
 pub mod subscription_type;
 pub mod subscription_unsubscribe_token_type;
 pub mod subscription_unsubscribe_tokens_table;
 pub mod subscriptions_table;
 pub mod sync_user_reducer;
+pub mod table_size_history_table;
+pub mod table_size_history_type;
+pub mod table_size_schedule_type;
+pub mod top_offending_prefixes_reducer;
 pub mod unregister_admin_identity_reducer;
+pub mod update_account_reducer;
+pub mod validate_data_reducer;
+pub mod visible_account_aliases_table;
+pub mod visible_account_tags_table;
 pub mod visible_accounts_table;
 pub mod visible_admin_identities_table;
+pub mod visible_category_sender_rules_table;
+pub mod visible_complaints_table;
+pub mod visible_data_issues_table;
+pub mod visible_delivery_outcomes_table;
+pub mod visible_digest_out_table;
+pub mod visible_envelope_samples_table;
+pub mod visible_forward_rules_table;
+pub mod visible_group_category_map_table;
+pub mod visible_login_events_table;
 pub mod visible_messages_table;
+pub mod visible_mta_connection_log_table;
+pub mod visible_notifications_table;
+pub mod visible_orphaned_identities_table;
+pub mod visible_relay_out_table;
+pub mod visible_sender_whitelist_table;
 pub mod visible_subscriptions_table;
 pub mod visible_webhook_tokens_table;
 pub mod webhook_token_type;
 
+pub use account_alias_type::AccountAlias;
+pub use account_lookup_result_type::AccountLookupResult;
 pub use account_table::*;
+pub use account_tag_type::AccountTag;
 pub use account_type::Account;
 pub use active_subscriptions_table::*;
 pub use active_unsubscribe_tokens_table::*;
+pub use add_account_alias_reducer::add_account_alias;
+pub use add_account_tag_reducer::add_account_tag;
+pub use add_category_address_reducer::add_category_address;
+pub use add_category_sender_rule_reducer::add_category_sender_rule;
+pub use add_forward_rule_reducer::add_forward_rule;
+pub use add_group_category_mapping_reducer::add_group_category_mapping;
 pub use add_message_category_reducer::add_message_category;
+pub use add_sender_whitelist_reducer::add_sender_whitelist;
 pub use add_subscription_reducer::add_subscription;
+pub use add_subscription_with_expiry_reducer::add_subscription_with_expiry;
 pub use admin_identity_type::AdminIdentity;
+pub use auth_failure_type::AuthFailure;
+pub use block_ip_reducer::block_ip;
 pub use blocked_ip_type::BlockedIp;
+pub use category_address_table::*;
+pub use category_address_type::CategoryAddress;
+pub use category_impact_result_type::CategoryImpactResult;
+pub use category_sender_rule_type::CategorySenderRule;
 pub use claim_next_mail_delivery_reducer::claim_next_mail_delivery;
 pub use claim_next_mail_ingress_reducer::claim_next_mail_ingress;
+pub use clean_orphaned_identities_reducer::clean_orphaned_identities;
+pub use complaint_type::Complaint;
 pub use complete_mail_ingress_reducer::complete_mail_ingress;
+pub use compute_expected_identity_reducer::compute_expected_identity;
 pub use create_webhook_token_reducer::create_webhook_token;
+pub use data_issue_type::DataIssue;
+pub use dedupe_subscriptions_reducer::dedupe_subscriptions;
+pub use delivery_outcome_type::DeliveryOutcome;
+pub use digest_flush_schedule_type::DigestFlushSchedule;
+pub use digest_out_type::DigestOut;
+pub use domain_route_table::*;
+pub use domain_route_type::DomainRoute;
 pub use dump_mta_logs_to_server_logs_reducer::dump_mta_logs_to_server_logs;
 pub use enqueue_mail_delivery_reducer::enqueue_mail_delivery;
 pub use ensure_subscription_unsubscribe_token_reducer::ensure_subscription_unsubscribe_token;
+pub use envelope_sample_type::EnvelopeSample;
+pub use expected_identity_result_type::ExpectedIdentityResult;
+pub use expire_subscriptions_reducer::expire_subscriptions;
+pub use export_admins_reducer::export_admins;
+pub use export_blob_type::ExportBlob;
+pub use export_message_logs_reducer::export_message_logs;
 pub use fail_mail_delivery_reducer::fail_mail_delivery;
 pub use fail_mail_ingress_reducer::fail_mail_ingress;
+pub use find_orphaned_identities_reducer::find_orphaned_identities;
+pub use flush_digests_reducer::flush_digests;
+pub use flush_pending_syncs_reducer::flush_pending_syncs;
+pub use forward_rule_type::ForwardRule;
+pub use group_category_map_type::GroupCategoryMap;
 pub use handle_mta_hook_reducer::handle_mta_hook;
+pub use hard_delete_category_reducer::hard_delete_category;
+pub use import_admins_reducer::import_admins;
+pub use import_blocked_ips_reducer::import_blocked_ips;
+pub use import_subscriptions_reducer::import_subscriptions;
+pub use inject_test_mta_event_reducer::inject_test_mta_event;
+pub use login_event_type::LoginEvent;
+pub use lookup_account_by_identity_reducer::lookup_account_by_identity;
 pub use mail_deliveries_table::*;
 pub use mail_delivery_event_type::MailDeliveryEvent;
 pub use mail_delivery_events_table::*;
@@ -87,29 +264,116 @@ pub use mail_ingress_table::*;
 pub use mail_ingress_type::MailIngress;
 pub use mark_mail_delivery_bounced_reducer::mark_mail_delivery_bounced;
 pub use mark_mail_delivery_sent_reducer::mark_mail_delivery_sent;
+pub use mark_notification_delivered_reducer::mark_notification_delivered;
 pub use message_categories_table::*;
 pub use message_category_type::MessageCategory;
+pub use module_settings_table::*;
+pub use module_settings_type::ModuleSettings;
 pub use mta_connection_log_type::MtaConnectionLog;
+pub use mta_daily_stats_table::*;
+pub use mta_daily_stats_type::MtaDailyStats;
+pub use mta_decision_type::MtaDecision;
 pub use mta_message_log_type::MtaMessageLog;
+pub use my_account_lookup_results_table::*;
+pub use my_category_impact_results_table::*;
+pub use my_expected_identity_results_table::*;
+pub use my_export_blobs_table::*;
+pub use my_mta_decisions_table::*;
+pub use my_offending_prefix_results_table::*;
+pub use my_ping_results_table::*;
+pub use my_resolve_results_table::*;
+pub use my_stage_timing_results_table::*;
+pub use notification_out_type::NotificationOut;
+pub use offending_prefix_result_type::OffendingPrefixResult;
+pub use orphaned_identity_type::OrphanedIdentity;
+pub use pending_digest_type::PendingDigest;
+pub use ping_reducer::ping;
+pub use ping_result_type::PingResult;
+pub use preview_category_impact_reducer::preview_category_impact;
 pub use provision_message_category_procedure::provision_message_category;
+pub use prune_blocks_by_source_reducer::prune_blocks_by_source;
+pub use prune_stale_admin_identities_reducer::prune_stale_admin_identities;
+pub use reassign_subscribers_reducer::reassign_subscribers;
 pub use received_message_type::ReceivedMessage;
+pub use recompute_mta_stats_reducer::recompute_mta_stats;
+pub use recompute_reject_stats_reducer::recompute_reject_stats;
+pub use record_auth_failure_reducer::record_auth_failure;
+pub use record_complaint_reducer::record_complaint;
+pub use record_delivery_outcome_reducer::record_delivery_outcome;
+pub use record_login_reducer::record_login;
+pub use record_table_sizes_reducer::record_table_sizes;
 pub use register_admin_identity_reducer::register_admin_identity;
+pub use reject_reason_stat_type::RejectReasonStat;
+pub use reject_reason_stats_table::*;
+pub use relay_out_type::RelayOut;
+pub use relink_subscriptions_reducer::relink_subscriptions;
+pub use remove_account_alias_reducer::remove_account_alias;
+pub use remove_account_tag_reducer::remove_account_tag;
+pub use remove_category_address_reducer::remove_category_address;
+pub use remove_category_sender_rule_reducer::remove_category_sender_rule;
+pub use remove_domain_route_reducer::remove_domain_route;
+pub use remove_forward_rule_reducer::remove_forward_rule;
+pub use remove_group_category_mapping_reducer::remove_group_category_mapping;
 pub use remove_message_category_reducer::remove_message_category;
+pub use remove_sender_whitelist_reducer::remove_sender_whitelist;
 pub use remove_subscription_reducer::remove_subscription;
+pub use resolve_recipient_reducer::resolve_recipient;
+pub use resolve_result_type::ResolveResult;
+pub use restore_category_reducer::restore_category;
 pub use retry_mail_ingress_reducer::retry_mail_ingress;
 pub use revoke_webhook_token_reducer::revoke_webhook_token;
 pub use schedule_mail_delivery_retry_reducer::schedule_mail_delivery_retry;
 pub use sender_mail_deliveries_table::*;
 pub use sender_mail_ingress_table::*;
+pub use sender_whitelist_type::SenderWhitelist;
+pub use set_account_name_reducer::set_account_name;
+pub use set_admin_identity_label_reducer::set_admin_identity_label;
+pub use set_category_accept_response_reducer::set_category_accept_response;
+pub use set_category_active_reducer::set_category_active;
+pub use set_category_digest_reducer::set_category_digest;
+pub use set_category_log_level_reducer::set_category_log_level;
+pub use set_category_rate_limit_reducer::set_category_rate_limit;
+pub use set_category_subscription_required_reducer::set_category_subscription_required;
+pub use set_domain_route_reducer::set_domain_route;
+pub use set_dry_run_reducer::set_dry_run;
+pub use set_envelope_sample_rate_reducer::set_envelope_sample_rate;
+pub use set_maintenance_mode_reducer::set_maintenance_mode;
+pub use set_reject_unknown_recipients_strict_reducer::set_reject_unknown_recipients_strict;
+pub use set_sync_paused_reducer::set_sync_paused;
+pub use stage_timing_percentiles_reducer::stage_timing_percentiles;
+pub use stage_timing_result_type::StageTimingResult;
+pub use subscription_expiry_schedule_type::SubscriptionExpirySchedule;
 pub use subscription_type::Subscription;
 pub use subscription_unsubscribe_token_type::SubscriptionUnsubscribeToken;
 pub use subscription_unsubscribe_tokens_table::*;
 pub use subscriptions_table::*;
 pub use sync_user_reducer::sync_user;
+pub use table_size_history_table::*;
+pub use table_size_history_type::TableSizeHistory;
+pub use table_size_schedule_type::TableSizeSchedule;
+pub use top_offending_prefixes_reducer::top_offending_prefixes;
 pub use unregister_admin_identity_reducer::unregister_admin_identity;
+pub use update_account_reducer::update_account;
+pub use validate_data_reducer::validate_data;
+pub use visible_account_aliases_table::*;
+pub use visible_account_tags_table::*;
 pub use visible_accounts_table::*;
 pub use visible_admin_identities_table::*;
+pub use visible_category_sender_rules_table::*;
+pub use visible_complaints_table::*;
+pub use visible_data_issues_table::*;
+pub use visible_delivery_outcomes_table::*;
+pub use visible_digest_out_table::*;
+pub use visible_envelope_samples_table::*;
+pub use visible_forward_rules_table::*;
+pub use visible_group_category_map_table::*;
+pub use visible_login_events_table::*;
 pub use visible_messages_table::*;
+pub use visible_mta_connection_log_table::*;
+pub use visible_notifications_table::*;
+pub use visible_orphaned_identities_table::*;
+pub use visible_relay_out_table::*;
+pub use visible_sender_whitelist_table::*;
 pub use visible_subscriptions_table::*;
 pub use visible_webhook_tokens_table::*;
 pub use webhook_token_type::WebhookToken;
@@ -122,28 +386,74 @@ pub use webhook_token_type::WebhookToken;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    AddAccountAlias {
+        account_id: u64,
+        email: String,
+    },
+    AddAccountTag {
+        account_id: u64,
+        tag: String,
+    },
+    AddCategoryAddress {
+        category_id: u64,
+        email_address: String,
+    },
+    AddCategorySenderRule {
+        category_id: u64,
+        pattern: String,
+        kind: String,
+    },
+    AddForwardRule {
+        category_id: u64,
+        destination_email: String,
+        mode: String,
+    },
+    AddGroupCategoryMapping {
+        group_name: String,
+        category_id: u64,
+    },
     AddMessageCategory {
         name: String,
         email_address: String,
         description: String,
     },
+    AddSenderWhitelist {
+        email: String,
+        expires_at: Option<Timestamp>,
+    },
     AddSubscription {
         subscriber_account_id: u64,
         subscriber_email: String,
         category_id: u64,
     },
+    AddSubscriptionWithExpiry {
+        subscriber_account_id: u64,
+        subscriber_email: String,
+        category_id: u64,
+        expires_at: Timestamp,
+    },
+    BlockIp {
+        ip: String,
+        reason: String,
+    },
     ClaimNextMailDelivery,
     ClaimNextMailIngress,
+    CleanOrphanedIdentities,
     CompleteMailIngress {
         ingress_id: String,
         delivery_count: u32,
         failed_delivery_count: u32,
     },
+    ComputeExpectedIdentity {
+        request_id: String,
+        mitgliedsnr: u64,
+    },
     CreateWebhookToken {
         token_hash: String,
         label: String,
         permissions: Vec<String>,
     },
+    DedupeSubscriptions,
     DumpMtaLogsToServerLogs,
     EnqueueMailDelivery {
         ingress_id: String,
@@ -164,6 +474,14 @@ pub enum Reducer {
     EnsureSubscriptionUnsubscribeToken {
         subscription_id: u64,
     },
+    ExpireSubscriptions {
+        _schedule: SubscriptionExpirySchedule,
+    },
+    ExportAdmins,
+    ExportMessageLogs {
+        from_ts: Timestamp,
+        to_ts: Timestamp,
+    },
     FailMailDelivery {
         delivery_id: String,
         smtp_status_code: Option<u16>,
@@ -174,9 +492,38 @@ pub enum Reducer {
         ingress_id: String,
         error: String,
     },
+    FindOrphanedIdentities,
+    FlushDigests {
+        _schedule: DigestFlushSchedule,
+    },
+    FlushPendingSyncs,
     HandleMtaHook {
+        request_id: String,
         hook_data: String,
     },
+    HardDeleteCategory {
+        id: u64,
+    },
+    ImportAdmins {
+        json: String,
+    },
+    ImportBlockedIps {
+        json: String,
+        source: String,
+    },
+    ImportSubscriptions {
+        json: String,
+    },
+    InjectTestMtaEvent {
+        stage: String,
+        from: String,
+        to: String,
+        subject: String,
+    },
+    LookupAccountByIdentity {
+        request_id: String,
+        identity_hex: String,
+    },
     MarkMailDeliveryBounced {
         delivery_id: String,
         smtp_response: String,
@@ -187,15 +534,90 @@ pub enum Reducer {
         smtp_status_code: Option<u16>,
         smtp_response: String,
     },
+    MarkNotificationDelivered {
+        id: u64,
+    },
+    Ping {
+        request_id: String,
+    },
+    PreviewCategoryImpact {
+        request_id: String,
+        category_id: u64,
+    },
+    PruneBlocksBySource {
+        source: String,
+        older_than_secs: u64,
+    },
+    PruneStaleAdminIdentities {
+        max_idle_days: u32,
+    },
+    ReassignSubscribers {
+        from_category_id: u64,
+        to_category_id: u64,
+    },
+    RecomputeMtaStats,
+    RecomputeRejectStats,
+    RecordAuthFailure {
+        client_ip: String,
+    },
+    RecordComplaint {
+        from_address: String,
+        kind: String,
+        received_at: Timestamp,
+    },
+    RecordDeliveryOutcome {
+        queue_id: String,
+        outcome: String,
+        detail: String,
+    },
+    RecordLogin {
+        user_agent: String,
+    },
+    RecordTableSizes {
+        _schedule: TableSizeSchedule,
+    },
     RegisterAdminIdentity {
         identity_hex: String,
     },
+    RelinkSubscriptions,
+    RemoveAccountAlias {
+        id: u64,
+    },
+    RemoveAccountTag {
+        account_id: u64,
+        tag: String,
+    },
+    RemoveCategoryAddress {
+        id: u64,
+    },
+    RemoveCategorySenderRule {
+        rule_id: u64,
+    },
+    RemoveDomainRoute {
+        domain: String,
+    },
+    RemoveForwardRule {
+        rule_id: u64,
+    },
+    RemoveGroupCategoryMapping {
+        mapping_id: u64,
+    },
     RemoveMessageCategory {
         category_id: u64,
     },
+    RemoveSenderWhitelist {
+        email: String,
+    },
     RemoveSubscription {
         subscription_id: u64,
     },
+    ResolveRecipient {
+        request_id: String,
+        address: String,
+    },
+    RestoreCategory {
+        id: u64,
+    },
     RetryMailIngress {
         ingress_id: String,
         error: String,
@@ -209,13 +631,81 @@ pub enum Reducer {
         smtp_response: String,
         error_kind: String,
     },
+    SetAccountName {
+        id: u64,
+        name: String,
+    },
+    SetAdminIdentityLabel {
+        identity_hex: String,
+        label: String,
+    },
+    SetCategoryAcceptResponse {
+        id: u64,
+        accept_code: Option<u16>,
+        accept_message: Option<String>,
+    },
+    SetCategoryActive {
+        id: u64,
+        active: bool,
+    },
+    SetCategoryDigest {
+        category_id: u64,
+        interval_secs: Option<u64>,
+    },
+    SetCategoryLogLevel {
+        id: u64,
+        log_level: String,
+    },
+    SetCategoryRateLimit {
+        id: u64,
+        rate_limit_per_hour: Option<u32>,
+    },
+    SetCategorySubscriptionRequired {
+        id: u64,
+        subscription_required: bool,
+    },
+    SetDomainRoute {
+        domain: String,
+        category_id: u64,
+    },
+    SetDryRun {
+        on: bool,
+    },
+    SetEnvelopeSampleRate {
+        permille: u32,
+    },
+    SetMaintenanceMode {
+        on: bool,
+    },
+    SetRejectUnknownRecipientsStrict {
+        on: bool,
+    },
+    SetSyncPaused {
+        paused: bool,
+    },
+    StageTimingPercentiles {
+        request_id: String,
+    },
     SyncUser {
         action: String,
         user_data: String,
+        correlation_id: String,
+    },
+    TopOffendingPrefixes {
+        request_id: String,
+        window_secs: u64,
+        limit: u32,
     },
     UnregisterAdminIdentity {
         identity_hex: String,
     },
+    UpdateAccount {
+        id: u64,
+        name: String,
+        email: String,
+        is_active: bool,
+    },
+    ValidateData,
 }
 
 impl __sdk::InModule for Reducer {
@@ -225,37 +715,151 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AddAccountAlias { .. } => "add_account_alias",
+            Reducer::AddAccountTag { .. } => "add_account_tag",
+            Reducer::AddCategoryAddress { .. } => "add_category_address",
+            Reducer::AddCategorySenderRule { .. } => "add_category_sender_rule",
+            Reducer::AddForwardRule { .. } => "add_forward_rule",
+            Reducer::AddGroupCategoryMapping { .. } => "add_group_category_mapping",
             Reducer::AddMessageCategory { .. } => "add_message_category",
+            Reducer::AddSenderWhitelist { .. } => "add_sender_whitelist",
             Reducer::AddSubscription { .. } => "add_subscription",
+            Reducer::AddSubscriptionWithExpiry { .. } => "add_subscription_with_expiry",
+            Reducer::BlockIp { .. } => "block_ip",
             Reducer::ClaimNextMailDelivery => "claim_next_mail_delivery",
             Reducer::ClaimNextMailIngress => "claim_next_mail_ingress",
+            Reducer::CleanOrphanedIdentities => "clean_orphaned_identities",
             Reducer::CompleteMailIngress { .. } => "complete_mail_ingress",
+            Reducer::ComputeExpectedIdentity { .. } => "compute_expected_identity",
             Reducer::CreateWebhookToken { .. } => "create_webhook_token",
+            Reducer::DedupeSubscriptions => "dedupe_subscriptions",
             Reducer::DumpMtaLogsToServerLogs => "dump_mta_logs_to_server_logs",
             Reducer::EnqueueMailDelivery { .. } => "enqueue_mail_delivery",
             Reducer::EnsureSubscriptionUnsubscribeToken { .. } => {
                 "ensure_subscription_unsubscribe_token"
             }
+            Reducer::ExpireSubscriptions { .. } => "expire_subscriptions",
+            Reducer::ExportAdmins => "export_admins",
+            Reducer::ExportMessageLogs { .. } => "export_message_logs",
             Reducer::FailMailDelivery { .. } => "fail_mail_delivery",
             Reducer::FailMailIngress { .. } => "fail_mail_ingress",
+            Reducer::FindOrphanedIdentities => "find_orphaned_identities",
+            Reducer::FlushDigests { .. } => "flush_digests",
+            Reducer::FlushPendingSyncs => "flush_pending_syncs",
             Reducer::HandleMtaHook { .. } => "handle_mta_hook",
+            Reducer::HardDeleteCategory { .. } => "hard_delete_category",
+            Reducer::ImportAdmins { .. } => "import_admins",
+            Reducer::ImportBlockedIps { .. } => "import_blocked_ips",
+            Reducer::ImportSubscriptions { .. } => "import_subscriptions",
+            Reducer::InjectTestMtaEvent { .. } => "inject_test_mta_event",
+            Reducer::LookupAccountByIdentity { .. } => "lookup_account_by_identity",
             Reducer::MarkMailDeliveryBounced { .. } => "mark_mail_delivery_bounced",
             Reducer::MarkMailDeliverySent { .. } => "mark_mail_delivery_sent",
+            Reducer::MarkNotificationDelivered { .. } => "mark_notification_delivered",
+            Reducer::Ping { .. } => "ping",
+            Reducer::PreviewCategoryImpact { .. } => "preview_category_impact",
+            Reducer::PruneBlocksBySource { .. } => "prune_blocks_by_source",
+            Reducer::PruneStaleAdminIdentities { .. } => "prune_stale_admin_identities",
+            Reducer::ReassignSubscribers { .. } => "reassign_subscribers",
+            Reducer::RecomputeMtaStats => "recompute_mta_stats",
+            Reducer::RecomputeRejectStats => "recompute_reject_stats",
+            Reducer::RecordAuthFailure { .. } => "record_auth_failure",
+            Reducer::RecordComplaint { .. } => "record_complaint",
+            Reducer::RecordDeliveryOutcome { .. } => "record_delivery_outcome",
+            Reducer::RecordLogin { .. } => "record_login",
+            Reducer::RecordTableSizes { .. } => "record_table_sizes",
             Reducer::RegisterAdminIdentity { .. } => "register_admin_identity",
+            Reducer::RelinkSubscriptions => "relink_subscriptions",
+            Reducer::RemoveAccountAlias { .. } => "remove_account_alias",
+            Reducer::RemoveAccountTag { .. } => "remove_account_tag",
+            Reducer::RemoveCategoryAddress { .. } => "remove_category_address",
+            Reducer::RemoveCategorySenderRule { .. } => "remove_category_sender_rule",
+            Reducer::RemoveDomainRoute { .. } => "remove_domain_route",
+            Reducer::RemoveForwardRule { .. } => "remove_forward_rule",
+            Reducer::RemoveGroupCategoryMapping { .. } => "remove_group_category_mapping",
             Reducer::RemoveMessageCategory { .. } => "remove_message_category",
+            Reducer::RemoveSenderWhitelist { .. } => "remove_sender_whitelist",
             Reducer::RemoveSubscription { .. } => "remove_subscription",
+            Reducer::ResolveRecipient { .. } => "resolve_recipient",
+            Reducer::RestoreCategory { .. } => "restore_category",
             Reducer::RetryMailIngress { .. } => "retry_mail_ingress",
             Reducer::RevokeWebhookToken { .. } => "revoke_webhook_token",
             Reducer::ScheduleMailDeliveryRetry { .. } => "schedule_mail_delivery_retry",
+            Reducer::SetAccountName { .. } => "set_account_name",
+            Reducer::SetAdminIdentityLabel { .. } => "set_admin_identity_label",
+            Reducer::SetCategoryAcceptResponse { .. } => "set_category_accept_response",
+            Reducer::SetCategoryActive { .. } => "set_category_active",
+            Reducer::SetCategoryDigest { .. } => "set_category_digest",
+            Reducer::SetCategoryLogLevel { .. } => "set_category_log_level",
+            Reducer::SetCategoryRateLimit { .. } => "set_category_rate_limit",
+            Reducer::SetCategorySubscriptionRequired { .. } => "set_category_subscription_required",
+            Reducer::SetDomainRoute { .. } => "set_domain_route",
+            Reducer::SetDryRun { .. } => "set_dry_run",
+            Reducer::SetEnvelopeSampleRate { .. } => "set_envelope_sample_rate",
+            Reducer::SetMaintenanceMode { .. } => "set_maintenance_mode",
+            Reducer::SetRejectUnknownRecipientsStrict { .. } => {
+                "set_reject_unknown_recipients_strict"
+            }
+            Reducer::SetSyncPaused { .. } => "set_sync_paused",
+            Reducer::StageTimingPercentiles { .. } => "stage_timing_percentiles",
             Reducer::SyncUser { .. } => "sync_user",
+            Reducer::TopOffendingPrefixes { .. } => "top_offending_prefixes",
             Reducer::UnregisterAdminIdentity { .. } => "unregister_admin_identity",
+            Reducer::UpdateAccount { .. } => "update_account",
+            Reducer::ValidateData => "validate_data",
             _ => unreachable!(),
         }
     }
     #[allow(clippy::clone_on_copy)]
     fn args_bsatn(&self) -> Result<Vec<u8>, __sats::bsatn::EncodeError> {
         match self {
-                        Reducer::AddMessageCategory{
+                        Reducer::AddAccountAlias{
+                account_id,
+                email,
+}             => __sats::bsatn::to_vec(&add_account_alias_reducer::AddAccountAliasArgs {
+                account_id: account_id.clone(),
+                email: email.clone(),
+}),
+            Reducer::AddAccountTag{
+                account_id,
+                tag,
+}             => __sats::bsatn::to_vec(&add_account_tag_reducer::AddAccountTagArgs {
+                account_id: account_id.clone(),
+                tag: tag.clone(),
+}),
+            Reducer::AddCategoryAddress{
+                category_id,
+                email_address,
+}             => __sats::bsatn::to_vec(&add_category_address_reducer::AddCategoryAddressArgs {
+                category_id: category_id.clone(),
+                email_address: email_address.clone(),
+}),
+            Reducer::AddCategorySenderRule{
+                category_id,
+                pattern,
+                kind,
+}             => __sats::bsatn::to_vec(&add_category_sender_rule_reducer::AddCategorySenderRuleArgs {
+                category_id: category_id.clone(),
+                pattern: pattern.clone(),
+                kind: kind.clone(),
+}),
+            Reducer::AddForwardRule{
+                category_id,
+                destination_email,
+                mode,
+}             => __sats::bsatn::to_vec(&add_forward_rule_reducer::AddForwardRuleArgs {
+                category_id: category_id.clone(),
+                destination_email: destination_email.clone(),
+                mode: mode.clone(),
+}),
+            Reducer::AddGroupCategoryMapping{
+                group_name,
+                category_id,
+}             => __sats::bsatn::to_vec(&add_group_category_mapping_reducer::AddGroupCategoryMappingArgs {
+                group_name: group_name.clone(),
+                category_id: category_id.clone(),
+}),
+            Reducer::AddMessageCategory{
                 name,
                 email_address,
                 description,
@@ -263,6 +867,13 @@ impl __sdk::Reducer for Reducer {
                 name: name.clone(),
                 email_address: email_address.clone(),
                 description: description.clone(),
+}),
+            Reducer::AddSenderWhitelist{
+                email,
+                expires_at,
+}             => __sats::bsatn::to_vec(&add_sender_whitelist_reducer::AddSenderWhitelistArgs {
+                email: email.clone(),
+                expires_at: expires_at.clone(),
 }),
             Reducer::AddSubscription{
                 subscriber_account_id,
@@ -272,11 +883,31 @@ impl __sdk::Reducer for Reducer {
                 subscriber_account_id: subscriber_account_id.clone(),
                 subscriber_email: subscriber_email.clone(),
                 category_id: category_id.clone(),
+}),
+            Reducer::AddSubscriptionWithExpiry{
+                subscriber_account_id,
+                subscriber_email,
+                category_id,
+                expires_at,
+}             => __sats::bsatn::to_vec(&add_subscription_with_expiry_reducer::AddSubscriptionWithExpiryArgs {
+                subscriber_account_id: subscriber_account_id.clone(),
+                subscriber_email: subscriber_email.clone(),
+                category_id: category_id.clone(),
+                expires_at: expires_at.clone(),
+}),
+            Reducer::BlockIp{
+                ip,
+                reason,
+}             => __sats::bsatn::to_vec(&block_ip_reducer::BlockIpArgs {
+                ip: ip.clone(),
+                reason: reason.clone(),
 }),
             Reducer::ClaimNextMailDelivery => __sats::bsatn::to_vec(&claim_next_mail_delivery_reducer::ClaimNextMailDeliveryArgs {
                 }),
 Reducer::ClaimNextMailIngress => __sats::bsatn::to_vec(&claim_next_mail_ingress_reducer::ClaimNextMailIngressArgs {
                 }),
+Reducer::CleanOrphanedIdentities => __sats::bsatn::to_vec(&clean_orphaned_identities_reducer::CleanOrphanedIdentitiesArgs {
+                }),
 Reducer::CompleteMailIngress{
                 ingress_id,
                 delivery_count,
@@ -285,6 +916,13 @@ Reducer::CompleteMailIngress{
                 ingress_id: ingress_id.clone(),
                 delivery_count: delivery_count.clone(),
                 failed_delivery_count: failed_delivery_count.clone(),
+}),
+            Reducer::ComputeExpectedIdentity{
+                request_id,
+                mitgliedsnr,
+}             => __sats::bsatn::to_vec(&compute_expected_identity_reducer::ComputeExpectedIdentityArgs {
+                request_id: request_id.clone(),
+                mitgliedsnr: mitgliedsnr.clone(),
 }),
             Reducer::CreateWebhookToken{
                 token_hash,
@@ -295,7 +933,9 @@ Reducer::CompleteMailIngress{
                 label: label.clone(),
                 permissions: permissions.clone(),
 }),
-            Reducer::DumpMtaLogsToServerLogs => __sats::bsatn::to_vec(&dump_mta_logs_to_server_logs_reducer::DumpMtaLogsToServerLogsArgs {
+            Reducer::DedupeSubscriptions => __sats::bsatn::to_vec(&dedupe_subscriptions_reducer::DedupeSubscriptionsArgs {
+                }),
+Reducer::DumpMtaLogsToServerLogs => __sats::bsatn::to_vec(&dump_mta_logs_to_server_logs_reducer::DumpMtaLogsToServerLogsArgs {
                 }),
 Reducer::EnqueueMailDelivery{
                 ingress_id,
@@ -332,6 +972,20 @@ Reducer::EnqueueMailDelivery{
                 subscription_id,
 }             => __sats::bsatn::to_vec(&ensure_subscription_unsubscribe_token_reducer::EnsureSubscriptionUnsubscribeTokenArgs {
                 subscription_id: subscription_id.clone(),
+}),
+            Reducer::ExpireSubscriptions{
+                _schedule,
+}             => __sats::bsatn::to_vec(&expire_subscriptions_reducer::ExpireSubscriptionsArgs {
+                _schedule: _schedule.clone(),
+}),
+            Reducer::ExportAdmins => __sats::bsatn::to_vec(&export_admins_reducer::ExportAdminsArgs {
+                }),
+Reducer::ExportMessageLogs{
+                from_ts,
+                to_ts,
+}             => __sats::bsatn::to_vec(&export_message_logs_reducer::ExportMessageLogsArgs {
+                from_ts: from_ts.clone(),
+                to_ts: to_ts.clone(),
 }),
             Reducer::FailMailDelivery{
                 delivery_id,
@@ -351,10 +1005,61 @@ Reducer::EnqueueMailDelivery{
                 ingress_id: ingress_id.clone(),
                 error: error.clone(),
 }),
-            Reducer::HandleMtaHook{
+            Reducer::FindOrphanedIdentities => __sats::bsatn::to_vec(&find_orphaned_identities_reducer::FindOrphanedIdentitiesArgs {
+                }),
+Reducer::FlushDigests{
+                _schedule,
+}             => __sats::bsatn::to_vec(&flush_digests_reducer::FlushDigestsArgs {
+                _schedule: _schedule.clone(),
+}),
+            Reducer::FlushPendingSyncs => __sats::bsatn::to_vec(&flush_pending_syncs_reducer::FlushPendingSyncsArgs {
+                }),
+Reducer::HandleMtaHook{
+                request_id,
                 hook_data,
 }             => __sats::bsatn::to_vec(&handle_mta_hook_reducer::HandleMtaHookArgs {
+                request_id: request_id.clone(),
                 hook_data: hook_data.clone(),
+}),
+            Reducer::HardDeleteCategory{
+                id,
+}             => __sats::bsatn::to_vec(&hard_delete_category_reducer::HardDeleteCategoryArgs {
+                id: id.clone(),
+}),
+            Reducer::ImportAdmins{
+                json,
+}             => __sats::bsatn::to_vec(&import_admins_reducer::ImportAdminsArgs {
+                json: json.clone(),
+}),
+            Reducer::ImportBlockedIps{
+                json,
+                source,
+}             => __sats::bsatn::to_vec(&import_blocked_ips_reducer::ImportBlockedIpsArgs {
+                json: json.clone(),
+                source: source.clone(),
+}),
+            Reducer::ImportSubscriptions{
+                json,
+}             => __sats::bsatn::to_vec(&import_subscriptions_reducer::ImportSubscriptionsArgs {
+                json: json.clone(),
+}),
+            Reducer::InjectTestMtaEvent{
+                stage,
+                from,
+                to,
+                subject,
+}             => __sats::bsatn::to_vec(&inject_test_mta_event_reducer::InjectTestMtaEventArgs {
+                stage: stage.clone(),
+                from: from.clone(),
+                to: to.clone(),
+                subject: subject.clone(),
+}),
+            Reducer::LookupAccountByIdentity{
+                request_id,
+                identity_hex,
+}             => __sats::bsatn::to_vec(&lookup_account_by_identity_reducer::LookupAccountByIdentityArgs {
+                request_id: request_id.clone(),
+                identity_hex: identity_hex.clone(),
 }),
             Reducer::MarkMailDeliveryBounced{
                 delivery_id,
@@ -373,21 +1078,150 @@ Reducer::EnqueueMailDelivery{
                 delivery_id: delivery_id.clone(),
                 smtp_status_code: smtp_status_code.clone(),
                 smtp_response: smtp_response.clone(),
+}),
+            Reducer::MarkNotificationDelivered{
+                id,
+}             => __sats::bsatn::to_vec(&mark_notification_delivered_reducer::MarkNotificationDeliveredArgs {
+                id: id.clone(),
+}),
+            Reducer::Ping{
+                request_id,
+}             => __sats::bsatn::to_vec(&ping_reducer::PingArgs {
+                request_id: request_id.clone(),
+}),
+            Reducer::PreviewCategoryImpact{
+                request_id,
+                category_id,
+}             => __sats::bsatn::to_vec(&preview_category_impact_reducer::PreviewCategoryImpactArgs {
+                request_id: request_id.clone(),
+                category_id: category_id.clone(),
+}),
+            Reducer::PruneBlocksBySource{
+                source,
+                older_than_secs,
+}             => __sats::bsatn::to_vec(&prune_blocks_by_source_reducer::PruneBlocksBySourceArgs {
+                source: source.clone(),
+                older_than_secs: older_than_secs.clone(),
+}),
+            Reducer::PruneStaleAdminIdentities{
+                max_idle_days,
+}             => __sats::bsatn::to_vec(&prune_stale_admin_identities_reducer::PruneStaleAdminIdentitiesArgs {
+                max_idle_days: max_idle_days.clone(),
+}),
+            Reducer::ReassignSubscribers{
+                from_category_id,
+                to_category_id,
+}             => __sats::bsatn::to_vec(&reassign_subscribers_reducer::ReassignSubscribersArgs {
+                from_category_id: from_category_id.clone(),
+                to_category_id: to_category_id.clone(),
+}),
+            Reducer::RecomputeMtaStats => __sats::bsatn::to_vec(&recompute_mta_stats_reducer::RecomputeMtaStatsArgs {
+                }),
+Reducer::RecomputeRejectStats => __sats::bsatn::to_vec(&recompute_reject_stats_reducer::RecomputeRejectStatsArgs {
+                }),
+Reducer::RecordAuthFailure{
+                client_ip,
+}             => __sats::bsatn::to_vec(&record_auth_failure_reducer::RecordAuthFailureArgs {
+                client_ip: client_ip.clone(),
+}),
+            Reducer::RecordComplaint{
+                from_address,
+                kind,
+                received_at,
+}             => __sats::bsatn::to_vec(&record_complaint_reducer::RecordComplaintArgs {
+                from_address: from_address.clone(),
+                kind: kind.clone(),
+                received_at: received_at.clone(),
+}),
+            Reducer::RecordDeliveryOutcome{
+                queue_id,
+                outcome,
+                detail,
+}             => __sats::bsatn::to_vec(&record_delivery_outcome_reducer::RecordDeliveryOutcomeArgs {
+                queue_id: queue_id.clone(),
+                outcome: outcome.clone(),
+                detail: detail.clone(),
+}),
+            Reducer::RecordLogin{
+                user_agent,
+}             => __sats::bsatn::to_vec(&record_login_reducer::RecordLoginArgs {
+                user_agent: user_agent.clone(),
+}),
+            Reducer::RecordTableSizes{
+                _schedule,
+}             => __sats::bsatn::to_vec(&record_table_sizes_reducer::RecordTableSizesArgs {
+                _schedule: _schedule.clone(),
 }),
             Reducer::RegisterAdminIdentity{
                 identity_hex,
 }             => __sats::bsatn::to_vec(&register_admin_identity_reducer::RegisterAdminIdentityArgs {
                 identity_hex: identity_hex.clone(),
+}),
+            Reducer::RelinkSubscriptions => __sats::bsatn::to_vec(&relink_subscriptions_reducer::RelinkSubscriptionsArgs {
+                }),
+Reducer::RemoveAccountAlias{
+                id,
+}             => __sats::bsatn::to_vec(&remove_account_alias_reducer::RemoveAccountAliasArgs {
+                id: id.clone(),
+}),
+            Reducer::RemoveAccountTag{
+                account_id,
+                tag,
+}             => __sats::bsatn::to_vec(&remove_account_tag_reducer::RemoveAccountTagArgs {
+                account_id: account_id.clone(),
+                tag: tag.clone(),
+}),
+            Reducer::RemoveCategoryAddress{
+                id,
+}             => __sats::bsatn::to_vec(&remove_category_address_reducer::RemoveCategoryAddressArgs {
+                id: id.clone(),
+}),
+            Reducer::RemoveCategorySenderRule{
+                rule_id,
+}             => __sats::bsatn::to_vec(&remove_category_sender_rule_reducer::RemoveCategorySenderRuleArgs {
+                rule_id: rule_id.clone(),
+}),
+            Reducer::RemoveDomainRoute{
+                domain,
+}             => __sats::bsatn::to_vec(&remove_domain_route_reducer::RemoveDomainRouteArgs {
+                domain: domain.clone(),
+}),
+            Reducer::RemoveForwardRule{
+                rule_id,
+}             => __sats::bsatn::to_vec(&remove_forward_rule_reducer::RemoveForwardRuleArgs {
+                rule_id: rule_id.clone(),
+}),
+            Reducer::RemoveGroupCategoryMapping{
+                mapping_id,
+}             => __sats::bsatn::to_vec(&remove_group_category_mapping_reducer::RemoveGroupCategoryMappingArgs {
+                mapping_id: mapping_id.clone(),
 }),
             Reducer::RemoveMessageCategory{
                 category_id,
 }             => __sats::bsatn::to_vec(&remove_message_category_reducer::RemoveMessageCategoryArgs {
                 category_id: category_id.clone(),
+}),
+            Reducer::RemoveSenderWhitelist{
+                email,
+}             => __sats::bsatn::to_vec(&remove_sender_whitelist_reducer::RemoveSenderWhitelistArgs {
+                email: email.clone(),
 }),
             Reducer::RemoveSubscription{
                 subscription_id,
 }             => __sats::bsatn::to_vec(&remove_subscription_reducer::RemoveSubscriptionArgs {
                 subscription_id: subscription_id.clone(),
+}),
+            Reducer::ResolveRecipient{
+                request_id,
+                address,
+}             => __sats::bsatn::to_vec(&resolve_recipient_reducer::ResolveRecipientArgs {
+                request_id: request_id.clone(),
+                address: address.clone(),
+}),
+            Reducer::RestoreCategory{
+                id,
+}             => __sats::bsatn::to_vec(&restore_category_reducer::RestoreCategoryArgs {
+                id: id.clone(),
 }),
             Reducer::RetryMailIngress{
                 ingress_id,
@@ -411,20 +1245,139 @@ Reducer::EnqueueMailDelivery{
                 smtp_status_code: smtp_status_code.clone(),
                 smtp_response: smtp_response.clone(),
                 error_kind: error_kind.clone(),
+}),
+            Reducer::SetAccountName{
+                id,
+                name,
+}             => __sats::bsatn::to_vec(&set_account_name_reducer::SetAccountNameArgs {
+                id: id.clone(),
+                name: name.clone(),
+}),
+            Reducer::SetAdminIdentityLabel{
+                identity_hex,
+                label,
+}             => __sats::bsatn::to_vec(&set_admin_identity_label_reducer::SetAdminIdentityLabelArgs {
+                identity_hex: identity_hex.clone(),
+                label: label.clone(),
+}),
+            Reducer::SetCategoryAcceptResponse{
+                id,
+                accept_code,
+                accept_message,
+}             => __sats::bsatn::to_vec(&set_category_accept_response_reducer::SetCategoryAcceptResponseArgs {
+                id: id.clone(),
+                accept_code: accept_code.clone(),
+                accept_message: accept_message.clone(),
+}),
+            Reducer::SetCategoryActive{
+                id,
+                active,
+}             => __sats::bsatn::to_vec(&set_category_active_reducer::SetCategoryActiveArgs {
+                id: id.clone(),
+                active: active.clone(),
+}),
+            Reducer::SetCategoryDigest{
+                category_id,
+                interval_secs,
+}             => __sats::bsatn::to_vec(&set_category_digest_reducer::SetCategoryDigestArgs {
+                category_id: category_id.clone(),
+                interval_secs: interval_secs.clone(),
+}),
+            Reducer::SetCategoryLogLevel{
+                id,
+                log_level,
+}             => __sats::bsatn::to_vec(&set_category_log_level_reducer::SetCategoryLogLevelArgs {
+                id: id.clone(),
+                log_level: log_level.clone(),
+}),
+            Reducer::SetCategoryRateLimit{
+                id,
+                rate_limit_per_hour,
+}             => __sats::bsatn::to_vec(&set_category_rate_limit_reducer::SetCategoryRateLimitArgs {
+                id: id.clone(),
+                rate_limit_per_hour: rate_limit_per_hour.clone(),
+}),
+            Reducer::SetCategorySubscriptionRequired{
+                id,
+                subscription_required,
+}             => __sats::bsatn::to_vec(&set_category_subscription_required_reducer::SetCategorySubscriptionRequiredArgs {
+                id: id.clone(),
+                subscription_required: subscription_required.clone(),
+}),
+            Reducer::SetDomainRoute{
+                domain,
+                category_id,
+}             => __sats::bsatn::to_vec(&set_domain_route_reducer::SetDomainRouteArgs {
+                domain: domain.clone(),
+                category_id: category_id.clone(),
+}),
+            Reducer::SetDryRun{
+                on,
+}             => __sats::bsatn::to_vec(&set_dry_run_reducer::SetDryRunArgs {
+                on: on.clone(),
+}),
+            Reducer::SetEnvelopeSampleRate{
+                permille,
+}             => __sats::bsatn::to_vec(&set_envelope_sample_rate_reducer::SetEnvelopeSampleRateArgs {
+                permille: permille.clone(),
+}),
+            Reducer::SetMaintenanceMode{
+                on,
+}             => __sats::bsatn::to_vec(&set_maintenance_mode_reducer::SetMaintenanceModeArgs {
+                on: on.clone(),
+}),
+            Reducer::SetRejectUnknownRecipientsStrict{
+                on,
+}             => __sats::bsatn::to_vec(&set_reject_unknown_recipients_strict_reducer::SetRejectUnknownRecipientsStrictArgs {
+                on: on.clone(),
+}),
+            Reducer::SetSyncPaused{
+                paused,
+}             => __sats::bsatn::to_vec(&set_sync_paused_reducer::SetSyncPausedArgs {
+                paused: paused.clone(),
+}),
+            Reducer::StageTimingPercentiles{
+                request_id,
+}             => __sats::bsatn::to_vec(&stage_timing_percentiles_reducer::StageTimingPercentilesArgs {
+                request_id: request_id.clone(),
 }),
             Reducer::SyncUser{
                 action,
                 user_data,
+                correlation_id,
 }             => __sats::bsatn::to_vec(&sync_user_reducer::SyncUserArgs {
                 action: action.clone(),
                 user_data: user_data.clone(),
+                correlation_id: correlation_id.clone(),
+}),
+            Reducer::TopOffendingPrefixes{
+                request_id,
+                window_secs,
+                limit,
+}             => __sats::bsatn::to_vec(&top_offending_prefixes_reducer::TopOffendingPrefixesArgs {
+                request_id: request_id.clone(),
+                window_secs: window_secs.clone(),
+                limit: limit.clone(),
 }),
             Reducer::UnregisterAdminIdentity{
                 identity_hex,
 }             => __sats::bsatn::to_vec(&unregister_admin_identity_reducer::UnregisterAdminIdentityArgs {
                 identity_hex: identity_hex.clone(),
 }),
-            _ => unreachable!(),
+            Reducer::UpdateAccount{
+                id,
+                name,
+                email,
+                is_active,
+}             => __sats::bsatn::to_vec(&update_account_reducer::UpdateAccountArgs {
+                id: id.clone(),
+                name: name.clone(),
+                email: email.clone(),
+                is_active: is_active.clone(),
+}),
+            Reducer::ValidateData => __sats::bsatn::to_vec(&validate_data_reducer::ValidateDataArgs {
+                }),
+_ => unreachable!(),
 }
     }
 }
@@ -436,17 +1389,48 @@ pub struct DbUpdate {
     account: __sdk::TableUpdate<Account>,
     active_subscriptions: __sdk::TableUpdate<Subscription>,
     active_unsubscribe_tokens: __sdk::TableUpdate<SubscriptionUnsubscribeToken>,
+    category_address: __sdk::TableUpdate<CategoryAddress>,
+    domain_route: __sdk::TableUpdate<DomainRoute>,
     mail_deliveries: __sdk::TableUpdate<MailDelivery>,
     mail_delivery_events: __sdk::TableUpdate<MailDeliveryEvent>,
     mail_ingress: __sdk::TableUpdate<MailIngress>,
     message_categories: __sdk::TableUpdate<MessageCategory>,
+    module_settings: __sdk::TableUpdate<ModuleSettings>,
+    mta_daily_stats: __sdk::TableUpdate<MtaDailyStats>,
+    my_account_lookup_results: __sdk::TableUpdate<AccountLookupResult>,
+    my_category_impact_results: __sdk::TableUpdate<CategoryImpactResult>,
+    my_expected_identity_results: __sdk::TableUpdate<ExpectedIdentityResult>,
+    my_export_blobs: __sdk::TableUpdate<ExportBlob>,
+    my_mta_decisions: __sdk::TableUpdate<MtaDecision>,
+    my_offending_prefix_results: __sdk::TableUpdate<OffendingPrefixResult>,
+    my_ping_results: __sdk::TableUpdate<PingResult>,
+    my_resolve_results: __sdk::TableUpdate<ResolveResult>,
+    my_stage_timing_results: __sdk::TableUpdate<StageTimingResult>,
+    reject_reason_stats: __sdk::TableUpdate<RejectReasonStat>,
     sender_mail_deliveries: __sdk::TableUpdate<MailDelivery>,
     sender_mail_ingress: __sdk::TableUpdate<MailIngress>,
     subscription_unsubscribe_tokens: __sdk::TableUpdate<SubscriptionUnsubscribeToken>,
     subscriptions: __sdk::TableUpdate<Subscription>,
+    table_size_history: __sdk::TableUpdate<TableSizeHistory>,
+    visible_account_aliases: __sdk::TableUpdate<AccountAlias>,
+    visible_account_tags: __sdk::TableUpdate<AccountTag>,
     visible_accounts: __sdk::TableUpdate<Account>,
     visible_admin_identities: __sdk::TableUpdate<AdminIdentity>,
+    visible_category_sender_rules: __sdk::TableUpdate<CategorySenderRule>,
+    visible_complaints: __sdk::TableUpdate<Complaint>,
+    visible_data_issues: __sdk::TableUpdate<DataIssue>,
+    visible_delivery_outcomes: __sdk::TableUpdate<DeliveryOutcome>,
+    visible_digest_out: __sdk::TableUpdate<DigestOut>,
+    visible_envelope_samples: __sdk::TableUpdate<EnvelopeSample>,
+    visible_forward_rules: __sdk::TableUpdate<ForwardRule>,
+    visible_group_category_map: __sdk::TableUpdate<GroupCategoryMap>,
+    visible_login_events: __sdk::TableUpdate<LoginEvent>,
     visible_messages: __sdk::TableUpdate<ReceivedMessage>,
+    visible_mta_connection_log: __sdk::TableUpdate<MtaConnectionLog>,
+    visible_notifications: __sdk::TableUpdate<NotificationOut>,
+    visible_orphaned_identities: __sdk::TableUpdate<OrphanedIdentity>,
+    visible_relay_out: __sdk::TableUpdate<RelayOut>,
+    visible_sender_whitelist: __sdk::TableUpdate<SenderWhitelist>,
     visible_subscriptions: __sdk::TableUpdate<Subscription>,
     visible_webhook_tokens: __sdk::TableUpdate<WebhookToken>,
 }
@@ -466,6 +1450,12 @@ impl TryFrom<__ws::v2::TransactionUpdate> for DbUpdate {
                 "active_unsubscribe_tokens" => db_update.active_unsubscribe_tokens.append(
                     active_unsubscribe_tokens_table::parse_table_update(table_update)?,
                 ),
+                "category_address" => db_update
+                    .category_address
+                    .append(category_address_table::parse_table_update(table_update)?),
+                "domain_route" => db_update
+                    .domain_route
+                    .append(domain_route_table::parse_table_update(table_update)?),
                 "mail_deliveries" => db_update
                     .mail_deliveries
                     .append(mail_deliveries_table::parse_table_update(table_update)?),
@@ -478,6 +1468,42 @@ impl TryFrom<__ws::v2::TransactionUpdate> for DbUpdate {
                 "message_categories" => db_update
                     .message_categories
                     .append(message_categories_table::parse_table_update(table_update)?),
+                "module_settings" => db_update
+                    .module_settings
+                    .append(module_settings_table::parse_table_update(table_update)?),
+                "mta_daily_stats" => db_update
+                    .mta_daily_stats
+                    .append(mta_daily_stats_table::parse_table_update(table_update)?),
+                "my_account_lookup_results" => db_update.my_account_lookup_results.append(
+                    my_account_lookup_results_table::parse_table_update(table_update)?,
+                ),
+                "my_category_impact_results" => db_update.my_category_impact_results.append(
+                    my_category_impact_results_table::parse_table_update(table_update)?,
+                ),
+                "my_expected_identity_results" => db_update.my_expected_identity_results.append(
+                    my_expected_identity_results_table::parse_table_update(table_update)?,
+                ),
+                "my_export_blobs" => db_update
+                    .my_export_blobs
+                    .append(my_export_blobs_table::parse_table_update(table_update)?),
+                "my_mta_decisions" => db_update
+                    .my_mta_decisions
+                    .append(my_mta_decisions_table::parse_table_update(table_update)?),
+                "my_offending_prefix_results" => db_update.my_offending_prefix_results.append(
+                    my_offending_prefix_results_table::parse_table_update(table_update)?,
+                ),
+                "my_ping_results" => db_update
+                    .my_ping_results
+                    .append(my_ping_results_table::parse_table_update(table_update)?),
+                "my_resolve_results" => db_update
+                    .my_resolve_results
+                    .append(my_resolve_results_table::parse_table_update(table_update)?),
+                "my_stage_timing_results" => db_update.my_stage_timing_results.append(
+                    my_stage_timing_results_table::parse_table_update(table_update)?,
+                ),
+                "reject_reason_stats" => db_update
+                    .reject_reason_stats
+                    .append(reject_reason_stats_table::parse_table_update(table_update)?),
                 "sender_mail_deliveries" => db_update.sender_mail_deliveries.append(
                     sender_mail_deliveries_table::parse_table_update(table_update)?,
                 ),
@@ -492,15 +1518,66 @@ impl TryFrom<__ws::v2::TransactionUpdate> for DbUpdate {
                 "subscriptions" => db_update
                     .subscriptions
                     .append(subscriptions_table::parse_table_update(table_update)?),
+                "table_size_history" => db_update
+                    .table_size_history
+                    .append(table_size_history_table::parse_table_update(table_update)?),
+                "visible_account_aliases" => db_update.visible_account_aliases.append(
+                    visible_account_aliases_table::parse_table_update(table_update)?,
+                ),
+                "visible_account_tags" => db_update.visible_account_tags.append(
+                    visible_account_tags_table::parse_table_update(table_update)?,
+                ),
                 "visible_accounts" => db_update
                     .visible_accounts
                     .append(visible_accounts_table::parse_table_update(table_update)?),
                 "visible_admin_identities" => db_update.visible_admin_identities.append(
                     visible_admin_identities_table::parse_table_update(table_update)?,
                 ),
+                "visible_category_sender_rules" => db_update.visible_category_sender_rules.append(
+                    visible_category_sender_rules_table::parse_table_update(table_update)?,
+                ),
+                "visible_complaints" => db_update
+                    .visible_complaints
+                    .append(visible_complaints_table::parse_table_update(table_update)?),
+                "visible_data_issues" => db_update
+                    .visible_data_issues
+                    .append(visible_data_issues_table::parse_table_update(table_update)?),
+                "visible_delivery_outcomes" => db_update.visible_delivery_outcomes.append(
+                    visible_delivery_outcomes_table::parse_table_update(table_update)?,
+                ),
+                "visible_digest_out" => db_update
+                    .visible_digest_out
+                    .append(visible_digest_out_table::parse_table_update(table_update)?),
+                "visible_envelope_samples" => db_update.visible_envelope_samples.append(
+                    visible_envelope_samples_table::parse_table_update(table_update)?,
+                ),
+                "visible_forward_rules" => db_update.visible_forward_rules.append(
+                    visible_forward_rules_table::parse_table_update(table_update)?,
+                ),
+                "visible_group_category_map" => db_update.visible_group_category_map.append(
+                    visible_group_category_map_table::parse_table_update(table_update)?,
+                ),
+                "visible_login_events" => db_update.visible_login_events.append(
+                    visible_login_events_table::parse_table_update(table_update)?,
+                ),
                 "visible_messages" => db_update
                     .visible_messages
                     .append(visible_messages_table::parse_table_update(table_update)?),
+                "visible_mta_connection_log" => db_update.visible_mta_connection_log.append(
+                    visible_mta_connection_log_table::parse_table_update(table_update)?,
+                ),
+                "visible_notifications" => db_update.visible_notifications.append(
+                    visible_notifications_table::parse_table_update(table_update)?,
+                ),
+                "visible_orphaned_identities" => db_update.visible_orphaned_identities.append(
+                    visible_orphaned_identities_table::parse_table_update(table_update)?,
+                ),
+                "visible_relay_out" => db_update
+                    .visible_relay_out
+                    .append(visible_relay_out_table::parse_table_update(table_update)?),
+                "visible_sender_whitelist" => db_update.visible_sender_whitelist.append(
+                    visible_sender_whitelist_table::parse_table_update(table_update)?,
+                ),
                 "visible_subscriptions" => db_update.visible_subscriptions.append(
                     visible_subscriptions_table::parse_table_update(table_update)?,
                 ),
@@ -536,6 +1613,12 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.account = cache
             .apply_diff_to_table::<Account>("account", &self.account)
             .with_updates_by_pk(|row| &row.id);
+        diff.category_address = cache
+            .apply_diff_to_table::<CategoryAddress>("category_address", &self.category_address)
+            .with_updates_by_pk(|row| &row.id);
+        diff.domain_route = cache
+            .apply_diff_to_table::<DomainRoute>("domain_route", &self.domain_route)
+            .with_updates_by_pk(|row| &row.domain);
         diff.mail_deliveries = cache
             .apply_diff_to_table::<MailDelivery>("mail_deliveries", &self.mail_deliveries)
             .with_updates_by_pk(|row| &row.id);
@@ -551,6 +1634,18 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.message_categories = cache
             .apply_diff_to_table::<MessageCategory>("message_categories", &self.message_categories)
             .with_updates_by_pk(|row| &row.id);
+        diff.module_settings = cache
+            .apply_diff_to_table::<ModuleSettings>("module_settings", &self.module_settings)
+            .with_updates_by_pk(|row| &row.id);
+        diff.mta_daily_stats = cache
+            .apply_diff_to_table::<MtaDailyStats>("mta_daily_stats", &self.mta_daily_stats)
+            .with_updates_by_pk(|row| &row.day);
+        diff.reject_reason_stats = cache
+            .apply_diff_to_table::<RejectReasonStat>(
+                "reject_reason_stats",
+                &self.reject_reason_stats,
+            )
+            .with_updates_by_pk(|row| &row.id);
         diff.subscription_unsubscribe_tokens = cache
             .apply_diff_to_table::<SubscriptionUnsubscribeToken>(
                 "subscription_unsubscribe_tokens",
@@ -560,6 +1655,9 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.subscriptions = cache
             .apply_diff_to_table::<Subscription>("subscriptions", &self.subscriptions)
             .with_updates_by_pk(|row| &row.id);
+        diff.table_size_history = cache
+            .apply_diff_to_table::<TableSizeHistory>("table_size_history", &self.table_size_history)
+            .with_updates_by_pk(|row| &row.id);
         diff.active_subscriptions = cache
             .apply_diff_to_table::<Subscription>("active_subscriptions", &self.active_subscriptions)
             .with_updates_by_pk(|row| &row.id);
@@ -569,6 +1667,34 @@ impl __sdk::DbUpdate for DbUpdate {
                 &self.active_unsubscribe_tokens,
             )
             .with_updates_by_pk(|row| &row.token);
+        diff.my_account_lookup_results = cache.apply_diff_to_table::<AccountLookupResult>(
+            "my_account_lookup_results",
+            &self.my_account_lookup_results,
+        );
+        diff.my_category_impact_results = cache.apply_diff_to_table::<CategoryImpactResult>(
+            "my_category_impact_results",
+            &self.my_category_impact_results,
+        );
+        diff.my_expected_identity_results = cache.apply_diff_to_table::<ExpectedIdentityResult>(
+            "my_expected_identity_results",
+            &self.my_expected_identity_results,
+        );
+        diff.my_export_blobs =
+            cache.apply_diff_to_table::<ExportBlob>("my_export_blobs", &self.my_export_blobs);
+        diff.my_mta_decisions =
+            cache.apply_diff_to_table::<MtaDecision>("my_mta_decisions", &self.my_mta_decisions);
+        diff.my_offending_prefix_results = cache.apply_diff_to_table::<OffendingPrefixResult>(
+            "my_offending_prefix_results",
+            &self.my_offending_prefix_results,
+        );
+        diff.my_ping_results =
+            cache.apply_diff_to_table::<PingResult>("my_ping_results", &self.my_ping_results);
+        diff.my_resolve_results = cache
+            .apply_diff_to_table::<ResolveResult>("my_resolve_results", &self.my_resolve_results);
+        diff.my_stage_timing_results = cache.apply_diff_to_table::<StageTimingResult>(
+            "my_stage_timing_results",
+            &self.my_stage_timing_results,
+        );
         diff.sender_mail_deliveries = cache
             .apply_diff_to_table::<MailDelivery>(
                 "sender_mail_deliveries",
@@ -578,6 +1704,12 @@ impl __sdk::DbUpdate for DbUpdate {
         diff.sender_mail_ingress = cache
             .apply_diff_to_table::<MailIngress>("sender_mail_ingress", &self.sender_mail_ingress)
             .with_updates_by_pk(|row| &row.id);
+        diff.visible_account_aliases = cache.apply_diff_to_table::<AccountAlias>(
+            "visible_account_aliases",
+            &self.visible_account_aliases,
+        );
+        diff.visible_account_tags = cache
+            .apply_diff_to_table::<AccountTag>("visible_account_tags", &self.visible_account_tags);
         diff.visible_accounts =
             cache.apply_diff_to_table::<Account>("visible_accounts", &self.visible_accounts);
         diff.visible_admin_identities = cache
@@ -586,8 +1718,63 @@ impl __sdk::DbUpdate for DbUpdate {
                 &self.visible_admin_identities,
             )
             .with_updates_by_pk(|row| &row.identity);
+        diff.visible_category_sender_rules = cache
+            .apply_diff_to_table::<CategorySenderRule>(
+                "visible_category_sender_rules",
+                &self.visible_category_sender_rules,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.visible_complaints =
+            cache.apply_diff_to_table::<Complaint>("visible_complaints", &self.visible_complaints);
+        diff.visible_data_issues = cache
+            .apply_diff_to_table::<DataIssue>("visible_data_issues", &self.visible_data_issues)
+            .with_updates_by_pk(|row| &row.id);
+        diff.visible_delivery_outcomes = cache.apply_diff_to_table::<DeliveryOutcome>(
+            "visible_delivery_outcomes",
+            &self.visible_delivery_outcomes,
+        );
+        diff.visible_digest_out =
+            cache.apply_diff_to_table::<DigestOut>("visible_digest_out", &self.visible_digest_out);
+        diff.visible_envelope_samples = cache.apply_diff_to_table::<EnvelopeSample>(
+            "visible_envelope_samples",
+            &self.visible_envelope_samples,
+        );
+        diff.visible_forward_rules = cache
+            .apply_diff_to_table::<ForwardRule>(
+                "visible_forward_rules",
+                &self.visible_forward_rules,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.visible_group_category_map = cache.apply_diff_to_table::<GroupCategoryMap>(
+            "visible_group_category_map",
+            &self.visible_group_category_map,
+        );
+        diff.visible_login_events = cache
+            .apply_diff_to_table::<LoginEvent>("visible_login_events", &self.visible_login_events);
         diff.visible_messages = cache
             .apply_diff_to_table::<ReceivedMessage>("visible_messages", &self.visible_messages);
+        diff.visible_mta_connection_log = cache
+            .apply_diff_to_table::<MtaConnectionLog>(
+                "visible_mta_connection_log",
+                &self.visible_mta_connection_log,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.visible_notifications = cache
+            .apply_diff_to_table::<NotificationOut>(
+                "visible_notifications",
+                &self.visible_notifications,
+            )
+            .with_updates_by_pk(|row| &row.id);
+        diff.visible_orphaned_identities = cache.apply_diff_to_table::<OrphanedIdentity>(
+            "visible_orphaned_identities",
+            &self.visible_orphaned_identities,
+        );
+        diff.visible_relay_out =
+            cache.apply_diff_to_table::<RelayOut>("visible_relay_out", &self.visible_relay_out);
+        diff.visible_sender_whitelist = cache.apply_diff_to_table::<SenderWhitelist>(
+            "visible_sender_whitelist",
+            &self.visible_sender_whitelist,
+        );
         diff.visible_subscriptions = cache.apply_diff_to_table::<Subscription>(
             "visible_subscriptions",
             &self.visible_subscriptions,
@@ -614,6 +1801,12 @@ impl __sdk::DbUpdate for DbUpdate {
                 "active_unsubscribe_tokens" => db_update
                     .active_unsubscribe_tokens
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "category_address" => db_update
+                    .category_address
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "domain_route" => db_update
+                    .domain_route
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "mail_deliveries" => db_update
                     .mail_deliveries
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
@@ -626,6 +1819,42 @@ impl __sdk::DbUpdate for DbUpdate {
                 "message_categories" => db_update
                     .message_categories
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "module_settings" => db_update
+                    .module_settings
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "mta_daily_stats" => db_update
+                    .mta_daily_stats
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_account_lookup_results" => db_update
+                    .my_account_lookup_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_category_impact_results" => db_update
+                    .my_category_impact_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_expected_identity_results" => db_update
+                    .my_expected_identity_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_export_blobs" => db_update
+                    .my_export_blobs
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_mta_decisions" => db_update
+                    .my_mta_decisions
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_offending_prefix_results" => db_update
+                    .my_offending_prefix_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_ping_results" => db_update
+                    .my_ping_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_resolve_results" => db_update
+                    .my_resolve_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "my_stage_timing_results" => db_update
+                    .my_stage_timing_results
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "reject_reason_stats" => db_update
+                    .reject_reason_stats
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "sender_mail_deliveries" => db_update
                     .sender_mail_deliveries
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
@@ -638,15 +1867,66 @@ impl __sdk::DbUpdate for DbUpdate {
                 "subscriptions" => db_update
                     .subscriptions
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "table_size_history" => db_update
+                    .table_size_history
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_account_aliases" => db_update
+                    .visible_account_aliases
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_account_tags" => db_update
+                    .visible_account_tags
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "visible_accounts" => db_update
                     .visible_accounts
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "visible_admin_identities" => db_update
                     .visible_admin_identities
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_category_sender_rules" => db_update
+                    .visible_category_sender_rules
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_complaints" => db_update
+                    .visible_complaints
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_data_issues" => db_update
+                    .visible_data_issues
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_delivery_outcomes" => db_update
+                    .visible_delivery_outcomes
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_digest_out" => db_update
+                    .visible_digest_out
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_envelope_samples" => db_update
+                    .visible_envelope_samples
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_forward_rules" => db_update
+                    .visible_forward_rules
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_group_category_map" => db_update
+                    .visible_group_category_map
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_login_events" => db_update
+                    .visible_login_events
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "visible_messages" => db_update
                     .visible_messages
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_mta_connection_log" => db_update
+                    .visible_mta_connection_log
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_notifications" => db_update
+                    .visible_notifications
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_orphaned_identities" => db_update
+                    .visible_orphaned_identities
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_relay_out" => db_update
+                    .visible_relay_out
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
+                "visible_sender_whitelist" => db_update
+                    .visible_sender_whitelist
+                    .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
                 "visible_subscriptions" => db_update
                     .visible_subscriptions
                     .append(__sdk::parse_row_list_as_inserts(table_rows.rows)?),
@@ -675,6 +1955,12 @@ impl __sdk::DbUpdate for DbUpdate {
                 "active_unsubscribe_tokens" => db_update
                     .active_unsubscribe_tokens
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "category_address" => db_update
+                    .category_address
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "domain_route" => db_update
+                    .domain_route
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "mail_deliveries" => db_update
                     .mail_deliveries
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
@@ -687,6 +1973,42 @@ impl __sdk::DbUpdate for DbUpdate {
                 "message_categories" => db_update
                     .message_categories
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "module_settings" => db_update
+                    .module_settings
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "mta_daily_stats" => db_update
+                    .mta_daily_stats
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_account_lookup_results" => db_update
+                    .my_account_lookup_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_category_impact_results" => db_update
+                    .my_category_impact_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_expected_identity_results" => db_update
+                    .my_expected_identity_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_export_blobs" => db_update
+                    .my_export_blobs
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_mta_decisions" => db_update
+                    .my_mta_decisions
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_offending_prefix_results" => db_update
+                    .my_offending_prefix_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_ping_results" => db_update
+                    .my_ping_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_resolve_results" => db_update
+                    .my_resolve_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "my_stage_timing_results" => db_update
+                    .my_stage_timing_results
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "reject_reason_stats" => db_update
+                    .reject_reason_stats
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "sender_mail_deliveries" => db_update
                     .sender_mail_deliveries
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
@@ -699,15 +2021,66 @@ impl __sdk::DbUpdate for DbUpdate {
                 "subscriptions" => db_update
                     .subscriptions
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "table_size_history" => db_update
+                    .table_size_history
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_account_aliases" => db_update
+                    .visible_account_aliases
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_account_tags" => db_update
+                    .visible_account_tags
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "visible_accounts" => db_update
                     .visible_accounts
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "visible_admin_identities" => db_update
                     .visible_admin_identities
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_category_sender_rules" => db_update
+                    .visible_category_sender_rules
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_complaints" => db_update
+                    .visible_complaints
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_data_issues" => db_update
+                    .visible_data_issues
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_delivery_outcomes" => db_update
+                    .visible_delivery_outcomes
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_digest_out" => db_update
+                    .visible_digest_out
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_envelope_samples" => db_update
+                    .visible_envelope_samples
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_forward_rules" => db_update
+                    .visible_forward_rules
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_group_category_map" => db_update
+                    .visible_group_category_map
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_login_events" => db_update
+                    .visible_login_events
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "visible_messages" => db_update
                     .visible_messages
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_mta_connection_log" => db_update
+                    .visible_mta_connection_log
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_notifications" => db_update
+                    .visible_notifications
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_orphaned_identities" => db_update
+                    .visible_orphaned_identities
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_relay_out" => db_update
+                    .visible_relay_out
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
+                "visible_sender_whitelist" => db_update
+                    .visible_sender_whitelist
+                    .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
                 "visible_subscriptions" => db_update
                     .visible_subscriptions
                     .append(__sdk::parse_row_list_as_deletes(table_rows.rows)?),
@@ -732,17 +2105,48 @@ pub struct AppliedDiff<'r> {
     account: __sdk::TableAppliedDiff<'r, Account>,
     active_subscriptions: __sdk::TableAppliedDiff<'r, Subscription>,
     active_unsubscribe_tokens: __sdk::TableAppliedDiff<'r, SubscriptionUnsubscribeToken>,
+    category_address: __sdk::TableAppliedDiff<'r, CategoryAddress>,
+    domain_route: __sdk::TableAppliedDiff<'r, DomainRoute>,
     mail_deliveries: __sdk::TableAppliedDiff<'r, MailDelivery>,
     mail_delivery_events: __sdk::TableAppliedDiff<'r, MailDeliveryEvent>,
     mail_ingress: __sdk::TableAppliedDiff<'r, MailIngress>,
     message_categories: __sdk::TableAppliedDiff<'r, MessageCategory>,
+    module_settings: __sdk::TableAppliedDiff<'r, ModuleSettings>,
+    mta_daily_stats: __sdk::TableAppliedDiff<'r, MtaDailyStats>,
+    my_account_lookup_results: __sdk::TableAppliedDiff<'r, AccountLookupResult>,
+    my_category_impact_results: __sdk::TableAppliedDiff<'r, CategoryImpactResult>,
+    my_expected_identity_results: __sdk::TableAppliedDiff<'r, ExpectedIdentityResult>,
+    my_export_blobs: __sdk::TableAppliedDiff<'r, ExportBlob>,
+    my_mta_decisions: __sdk::TableAppliedDiff<'r, MtaDecision>,
+    my_offending_prefix_results: __sdk::TableAppliedDiff<'r, OffendingPrefixResult>,
+    my_ping_results: __sdk::TableAppliedDiff<'r, PingResult>,
+    my_resolve_results: __sdk::TableAppliedDiff<'r, ResolveResult>,
+    my_stage_timing_results: __sdk::TableAppliedDiff<'r, StageTimingResult>,
+    reject_reason_stats: __sdk::TableAppliedDiff<'r, RejectReasonStat>,
     sender_mail_deliveries: __sdk::TableAppliedDiff<'r, MailDelivery>,
     sender_mail_ingress: __sdk::TableAppliedDiff<'r, MailIngress>,
     subscription_unsubscribe_tokens: __sdk::TableAppliedDiff<'r, SubscriptionUnsubscribeToken>,
     subscriptions: __sdk::TableAppliedDiff<'r, Subscription>,
+    table_size_history: __sdk::TableAppliedDiff<'r, TableSizeHistory>,
+    visible_account_aliases: __sdk::TableAppliedDiff<'r, AccountAlias>,
+    visible_account_tags: __sdk::TableAppliedDiff<'r, AccountTag>,
     visible_accounts: __sdk::TableAppliedDiff<'r, Account>,
     visible_admin_identities: __sdk::TableAppliedDiff<'r, AdminIdentity>,
+    visible_category_sender_rules: __sdk::TableAppliedDiff<'r, CategorySenderRule>,
+    visible_complaints: __sdk::TableAppliedDiff<'r, Complaint>,
+    visible_data_issues: __sdk::TableAppliedDiff<'r, DataIssue>,
+    visible_delivery_outcomes: __sdk::TableAppliedDiff<'r, DeliveryOutcome>,
+    visible_digest_out: __sdk::TableAppliedDiff<'r, DigestOut>,
+    visible_envelope_samples: __sdk::TableAppliedDiff<'r, EnvelopeSample>,
+    visible_forward_rules: __sdk::TableAppliedDiff<'r, ForwardRule>,
+    visible_group_category_map: __sdk::TableAppliedDiff<'r, GroupCategoryMap>,
+    visible_login_events: __sdk::TableAppliedDiff<'r, LoginEvent>,
     visible_messages: __sdk::TableAppliedDiff<'r, ReceivedMessage>,
+    visible_mta_connection_log: __sdk::TableAppliedDiff<'r, MtaConnectionLog>,
+    visible_notifications: __sdk::TableAppliedDiff<'r, NotificationOut>,
+    visible_orphaned_identities: __sdk::TableAppliedDiff<'r, OrphanedIdentity>,
+    visible_relay_out: __sdk::TableAppliedDiff<'r, RelayOut>,
+    visible_sender_whitelist: __sdk::TableAppliedDiff<'r, SenderWhitelist>,
     visible_subscriptions: __sdk::TableAppliedDiff<'r, Subscription>,
     visible_webhook_tokens: __sdk::TableAppliedDiff<'r, WebhookToken>,
     __unused: std::marker::PhantomData<&'r ()>,
@@ -769,6 +2173,16 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.active_unsubscribe_tokens,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<CategoryAddress>(
+            "category_address",
+            &self.category_address,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DomainRoute>(
+            "domain_route",
+            &self.domain_route,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<MailDelivery>(
             "mail_deliveries",
             &self.mail_deliveries,
@@ -789,6 +2203,66 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.message_categories,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<ModuleSettings>(
+            "module_settings",
+            &self.module_settings,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MtaDailyStats>(
+            "mta_daily_stats",
+            &self.mta_daily_stats,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AccountLookupResult>(
+            "my_account_lookup_results",
+            &self.my_account_lookup_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<CategoryImpactResult>(
+            "my_category_impact_results",
+            &self.my_category_impact_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ExpectedIdentityResult>(
+            "my_expected_identity_results",
+            &self.my_expected_identity_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ExportBlob>(
+            "my_export_blobs",
+            &self.my_export_blobs,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MtaDecision>(
+            "my_mta_decisions",
+            &self.my_mta_decisions,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<OffendingPrefixResult>(
+            "my_offending_prefix_results",
+            &self.my_offending_prefix_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PingResult>(
+            "my_ping_results",
+            &self.my_ping_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ResolveResult>(
+            "my_resolve_results",
+            &self.my_resolve_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<StageTimingResult>(
+            "my_stage_timing_results",
+            &self.my_stage_timing_results,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RejectReasonStat>(
+            "reject_reason_stats",
+            &self.reject_reason_stats,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<MailDelivery>(
             "sender_mail_deliveries",
             &self.sender_mail_deliveries,
@@ -809,6 +2283,21 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.subscriptions,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<TableSizeHistory>(
+            "table_size_history",
+            &self.table_size_history,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AccountAlias>(
+            "visible_account_aliases",
+            &self.visible_account_aliases,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<AccountTag>(
+            "visible_account_tags",
+            &self.visible_account_tags,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Account>(
             "visible_accounts",
             &self.visible_accounts,
@@ -819,11 +2308,81 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
             &self.visible_admin_identities,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<CategorySenderRule>(
+            "visible_category_sender_rules",
+            &self.visible_category_sender_rules,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Complaint>(
+            "visible_complaints",
+            &self.visible_complaints,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DataIssue>(
+            "visible_data_issues",
+            &self.visible_data_issues,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DeliveryOutcome>(
+            "visible_delivery_outcomes",
+            &self.visible_delivery_outcomes,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<DigestOut>(
+            "visible_digest_out",
+            &self.visible_digest_out,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<EnvelopeSample>(
+            "visible_envelope_samples",
+            &self.visible_envelope_samples,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ForwardRule>(
+            "visible_forward_rules",
+            &self.visible_forward_rules,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<GroupCategoryMap>(
+            "visible_group_category_map",
+            &self.visible_group_category_map,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LoginEvent>(
+            "visible_login_events",
+            &self.visible_login_events,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<ReceivedMessage>(
             "visible_messages",
             &self.visible_messages,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<MtaConnectionLog>(
+            "visible_mta_connection_log",
+            &self.visible_mta_connection_log,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NotificationOut>(
+            "visible_notifications",
+            &self.visible_notifications,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<OrphanedIdentity>(
+            "visible_orphaned_identities",
+            &self.visible_orphaned_identities,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RelayOut>(
+            "visible_relay_out",
+            &self.visible_relay_out,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<SenderWhitelist>(
+            "visible_sender_whitelist",
+            &self.visible_sender_whitelist,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Subscription>(
             "visible_subscriptions",
             &self.visible_subscriptions,
@@ -1497,17 +3056,48 @@ impl __sdk::SpacetimeModule for RemoteModule {
         account_table::register_table(client_cache);
         active_subscriptions_table::register_table(client_cache);
         active_unsubscribe_tokens_table::register_table(client_cache);
+        category_address_table::register_table(client_cache);
+        domain_route_table::register_table(client_cache);
         mail_deliveries_table::register_table(client_cache);
         mail_delivery_events_table::register_table(client_cache);
         mail_ingress_table::register_table(client_cache);
         message_categories_table::register_table(client_cache);
+        module_settings_table::register_table(client_cache);
+        mta_daily_stats_table::register_table(client_cache);
+        my_account_lookup_results_table::register_table(client_cache);
+        my_category_impact_results_table::register_table(client_cache);
+        my_expected_identity_results_table::register_table(client_cache);
+        my_export_blobs_table::register_table(client_cache);
+        my_mta_decisions_table::register_table(client_cache);
+        my_offending_prefix_results_table::register_table(client_cache);
+        my_ping_results_table::register_table(client_cache);
+        my_resolve_results_table::register_table(client_cache);
+        my_stage_timing_results_table::register_table(client_cache);
+        reject_reason_stats_table::register_table(client_cache);
         sender_mail_deliveries_table::register_table(client_cache);
         sender_mail_ingress_table::register_table(client_cache);
         subscription_unsubscribe_tokens_table::register_table(client_cache);
         subscriptions_table::register_table(client_cache);
+        table_size_history_table::register_table(client_cache);
+        visible_account_aliases_table::register_table(client_cache);
+        visible_account_tags_table::register_table(client_cache);
         visible_accounts_table::register_table(client_cache);
         visible_admin_identities_table::register_table(client_cache);
+        visible_category_sender_rules_table::register_table(client_cache);
+        visible_complaints_table::register_table(client_cache);
+        visible_data_issues_table::register_table(client_cache);
+        visible_delivery_outcomes_table::register_table(client_cache);
+        visible_digest_out_table::register_table(client_cache);
+        visible_envelope_samples_table::register_table(client_cache);
+        visible_forward_rules_table::register_table(client_cache);
+        visible_group_category_map_table::register_table(client_cache);
+        visible_login_events_table::register_table(client_cache);
         visible_messages_table::register_table(client_cache);
+        visible_mta_connection_log_table::register_table(client_cache);
+        visible_notifications_table::register_table(client_cache);
+        visible_orphaned_identities_table::register_table(client_cache);
+        visible_relay_out_table::register_table(client_cache);
+        visible_sender_whitelist_table::register_table(client_cache);
         visible_subscriptions_table::register_table(client_cache);
         visible_webhook_tokens_table::register_table(client_cache);
     }
@@ -1515,17 +3105,48 @@ impl __sdk::SpacetimeModule for RemoteModule {
         "account",
         "active_subscriptions",
         "active_unsubscribe_tokens",
+        "category_address",
+        "domain_route",
         "mail_deliveries",
         "mail_delivery_events",
         "mail_ingress",
         "message_categories",
+        "module_settings",
+        "mta_daily_stats",
+        "my_account_lookup_results",
+        "my_category_impact_results",
+        "my_expected_identity_results",
+        "my_export_blobs",
+        "my_mta_decisions",
+        "my_offending_prefix_results",
+        "my_ping_results",
+        "my_resolve_results",
+        "my_stage_timing_results",
+        "reject_reason_stats",
         "sender_mail_deliveries",
         "sender_mail_ingress",
         "subscription_unsubscribe_tokens",
         "subscriptions",
+        "table_size_history",
+        "visible_account_aliases",
+        "visible_account_tags",
         "visible_accounts",
         "visible_admin_identities",
+        "visible_category_sender_rules",
+        "visible_complaints",
+        "visible_data_issues",
+        "visible_delivery_outcomes",
+        "visible_digest_out",
+        "visible_envelope_samples",
+        "visible_forward_rules",
+        "visible_group_category_map",
+        "visible_login_events",
         "visible_messages",
+        "visible_mta_connection_log",
+        "visible_notifications",
+        "visible_orphaned_identities",
+        "visible_relay_out",
+        "visible_sender_whitelist",
         "visible_subscriptions",
         "visible_webhook_tokens",
     ];
@@ -13,6 +13,9 @@ pub struct MtaConnectionLog {
     pub action: String,
     pub timestamp: __sdk::Timestamp,
     pub details: String,
+    pub correlation_id: String,
+    pub processing_ms: u64,
+    pub reject_reason: String,
 }
 
 impl __sdk::InModule for MtaConnectionLog {
@@ -29,6 +32,9 @@ pub struct MtaConnectionLogCols {
     pub action: __sdk::__query_builder::Col<MtaConnectionLog, String>,
     pub timestamp: __sdk::__query_builder::Col<MtaConnectionLog, __sdk::Timestamp>,
     pub details: __sdk::__query_builder::Col<MtaConnectionLog, String>,
+    pub correlation_id: __sdk::__query_builder::Col<MtaConnectionLog, String>,
+    pub processing_ms: __sdk::__query_builder::Col<MtaConnectionLog, u64>,
+    pub reject_reason: __sdk::__query_builder::Col<MtaConnectionLog, String>,
 }
 
 impl __sdk::__query_builder::HasCols for MtaConnectionLog {
@@ -41,6 +47,9 @@ impl __sdk::__query_builder::HasCols for MtaConnectionLog {
             action: __sdk::__query_builder::Col::new(table_name, "action"),
             timestamp: __sdk::__query_builder::Col::new(table_name, "timestamp"),
             details: __sdk::__query_builder::Col::new(table_name, "details"),
+            correlation_id: __sdk::__query_builder::Col::new(table_name, "correlation_id"),
+            processing_ms: __sdk::__query_builder::Col::new(table_name, "processing_ms"),
+            reject_reason: __sdk::__query_builder::Col::new(table_name, "reject_reason"),
         }
     }
 }
@@ -49,6 +58,7 @@ impl __sdk::__query_builder::HasCols for MtaConnectionLog {
 ///
 /// Provides typed access to indexed columns for query building.
 pub struct MtaConnectionLogIxCols {
+    pub correlation_id: __sdk::__query_builder::IxCol<MtaConnectionLog, String>,
     pub id: __sdk::__query_builder::IxCol<MtaConnectionLog, u64>,
 }
 
@@ -56,6 +66,7 @@ impl __sdk::__query_builder::HasIxCols for MtaConnectionLog {
     type IxCols = MtaConnectionLogIxCols;
     fn ix_cols(table_name: &'static str) -> Self::IxCols {
         MtaConnectionLogIxCols {
+            correlation_id: __sdk::__query_builder::IxCol::new(table_name, "correlation_id"),
             id: __sdk::__query_builder::IxCol::new(table_name, "id"),
         }
     }
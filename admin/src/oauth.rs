@@ -9,7 +9,7 @@ use openidconnect::{
     core::{
         CoreClient, CoreProviderMetadata, CoreResponseType, CoreTokenResponse, CoreUserInfoClaims,
     },
-    AuthenticationFlow, AuthorizationCode, ClientId, CsrfToken, IssuerUrl, Nonce,
+    AuthenticationFlow, AuthorizationCode, ClientId, CsrfToken, DiscoveryError, IssuerUrl, Nonce,
     OAuth2TokenResponse, PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, RefreshToken, Scope,
 };
 use openidconnect::{EndpointMaybeSet, EndpointNotSet, EndpointSet};
@@ -20,6 +20,82 @@ use tracing::warn;
 
 // Wir verlassen uns auf openid_client für PKCE (es generiert Verifier & Challenge intern wenn nicht vorgegeben).
 
+/// OIDC client after discovery, with authorization and token endpoints set.
+type OidcClient = CoreClient<
+    EndpointSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointNotSet,
+    EndpointSet,
+    EndpointMaybeSet,
+>;
+
+/// Discovery attempts before the provider counts as unreachable.
+const DISCOVERY_ATTEMPTS: u32 = 4;
+/// Wait before the second discovery attempt; doubled for each further one (1 s, 2 s, 4 s).
+const DISCOVERY_RETRY_BASE_MS: u32 = 1_000;
+
+/// Issuer URL, client ID and redirect URI the cached client was built for.
+type OidcCacheKey = (String, String, String);
+
+thread_local! {
+    /// Discovered client, kept across re-renders and remounts so navigation does not re-run
+    /// discovery. A different key (e.g. a changed issuer) discards it.
+    static OIDC_CLIENT_CACHE: RefCell<Option<(OidcCacheKey, OidcClient)>> = const { RefCell::new(None) };
+    /// One HTTP client for discovery, token exchange, user info and refresh.
+    static HTTP_CLIENT: Rc<HttpClient> = Rc::new(build_http_client());
+}
+
+// Redirects (SSRF Schutz) nur deaktivieren außerhalb WASM.
+fn build_http_client() -> HttpClient {
+    #[allow(unused_mut)]
+    let mut builder = HttpClient::builder();
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        builder = builder.redirect(reqwest::redirect::Policy::none());
+    }
+    builder
+        .build()
+        .expect("failed to build reqwest HTTP client for OIDC")
+}
+
+fn oidc_cache_key(cfg: &OAuthConfig) -> OidcCacheKey {
+    (
+        cfg.issuer_url.clone(),
+        cfg.client_id.clone(),
+        cfg.redirect_uri.clone(),
+    )
+}
+
+fn cached_oidc_client(cfg: &OAuthConfig) -> Option<OidcClient> {
+    let key = oidc_cache_key(cfg);
+    OIDC_CLIENT_CACHE.with(|cache| {
+        cache
+            .borrow()
+            .as_ref()
+            .filter(|(cached_key, _)| *cached_key == key)
+            .map(|(_, client)| client.clone())
+    })
+}
+
+/// Drop the cached OIDC client so the next auth effect runs discovery again.
+pub fn invalidate_oidc_discovery() {
+    OIDC_CLIENT_CACHE.with(|cache| cache.borrow_mut().take());
+}
+
+/// Whether a failed discovery is worth retrying: the provider could not be reached, or answered
+/// with a server error or 429. Anything else (404, no valid discovery document) points at a
+/// wrong issuer URL, which retrying does not fix.
+fn is_transient_discovery_error<RE: std::error::Error + 'static>(err: &DiscoveryError<RE>) -> bool {
+    match err {
+        DiscoveryError::Request(_) => true,
+        DiscoveryError::Response(status, _, _) => {
+            status.is_server_error() || status.as_u16() == 429
+        }
+        _ => false,
+    }
+}
+
 // URL parameter parsing
 fn parse_url_params() -> std::collections::HashMap<String, String> {
     let mut params = std::collections::HashMap::new();
@@ -48,6 +124,9 @@ pub enum AuthState {
     Unauthenticated,
     Authenticating,
     Authenticated(UserInfo),
+    /// Discovery kept failing with network or server errors; the provider is probably down.
+    /// Logging in again retries discovery.
+    ProviderUnreachable(String),
     Error(String),
 }
 
@@ -77,7 +156,60 @@ pub struct DecodedJwt {
     pub claims_raw: String,
 }
 
+/// `iat` / `exp` claims of the ID token, in seconds since the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JwtTimes {
+    pub iat: Option<i64>,
+    pub exp: Option<i64>,
+}
+
+/// Tokens expiring within this many seconds are refreshed instead of used.
+const TOKEN_EXPIRY_LEEWAY_SECS: i64 = 60;
+
+/// Browser clock in seconds since the Unix epoch, the time base of `iat` / `exp`.
+pub fn unix_now() -> i64 {
+    (Date::now() / 1000.0) as i64
+}
+
+impl JwtTimes {
+    /// Seconds from `now` until `exp`, negative once expired; `None` without an `exp` claim.
+    pub fn expires_in(&self, now: i64) -> Option<i64> {
+        self.exp.map(|exp| exp - now)
+    }
+}
+
 impl UserInfo {
+    /// `iat` / `exp` of the ID token; `None` if there is no token or it does not decode.
+    pub fn decode_jwt_times(&self) -> Option<JwtTimes> {
+        let decoded = self.decode_id_token()?.ok()?;
+        Some(JwtTimes {
+            iat: decoded.claims.get("iat").and_then(|v| v.as_i64()),
+            exp: decoded.claims.get("exp").and_then(|v| v.as_i64()),
+        })
+    }
+
+    /// Django group names from the `groups` claim of the ID token.
+    fn groups_from_id_token(&self) -> Option<Vec<String>> {
+        let decoded = self.decode_id_token()?.ok()?;
+        let groups = decoded.claims.get("groups")?.as_array()?;
+        Some(
+            groups
+                .iter()
+                .filter_map(|g| g.as_str().map(str::to_string))
+                .collect(),
+        )
+    }
+
+    /// Whether `groups` contains `group`. Always false when no group is configured.
+    pub fn in_group(&self, group: Option<&str>) -> bool {
+        let Some(group) = group.map(str::trim).filter(|g| !g.is_empty()) else {
+            return false;
+        };
+        self.groups
+            .as_ref()
+            .is_some_and(|groups| groups.iter().any(|g| g == group))
+    }
+
     /// Decode the stored ID token (JWS) without verifying the signature.
     /// NOTE: This is a base64url decode + JSON parse only. Do not rely on this for security
     /// decisions; signature and claim validation must already have been done during login.
@@ -131,21 +263,13 @@ impl UserInfo {
 pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callback<()>) {
     let auth_state = use_signal(|| AuthState::Unauthenticated);
     let config_signal = use_signal(|| config);
+    // Bumped to run discovery again after it failed.
+    let discovery_run = use_signal(|| 0u32);
 
-    // OIDC Client (lazy). Kein expliziter Typ für die Endpoint-Typzustände.
-    let oidc_client: Rc<RefCell<Option<_>>> = Rc::new(RefCell::new(None));
-    // Persistenter HTTP Client (AsyncHttpClient Trait). Redirects (SSRF Schutz) nur deaktivieren außerhalb WASM.
-    let http_client = Rc::new({
-        #[allow(unused_mut)]
-        let mut builder = HttpClient::builder();
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            builder = builder.redirect(reqwest::redirect::Policy::none());
-        }
-        builder
-            .build()
-            .expect("failed to build reqwest HTTP client for OIDC")
-    });
+    // OIDC Client (lazy), stable across re-renders; filled from the discovery cache if possible.
+    let oidc_client: Rc<RefCell<Option<OidcClient>>> = use_hook(|| Rc::new(RefCell::new(None)));
+    // Persistenter HTTP Client (AsyncHttpClient Trait), shared by all components.
+    let http_client = HTTP_CLIENT.with(Rc::clone);
 
     // Initialisierung & Callback Handling
     {
@@ -154,6 +278,7 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
         let mut auth_state = auth_state;
         let config_sig = config_signal.clone();
         use_effect(move || {
+            let _ = discovery_run();
             let params = parse_url_params();
             // Fehler-Handling
             if let Some(error) = params.get("error") {
@@ -175,9 +300,11 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
             let http_client_discovery_for_refresh = http_client_discovery.clone();
             let http_client_discovery_inner = http_client_discovery.clone();
             spawn(async move {
-                // Discovery (wenn noch kein Client)
+                // Discovery nur, wenn der Cache keinen Client für diesen Issuer hat
+                // (erster Aufruf, geänderte Konfiguration oder invalidate_oidc_discovery).
+                let cfg = config_sig.read().clone();
+                oidc_client_outer.replace(cached_oidc_client(&cfg));
                 if oidc_client_outer.borrow().is_none() {
-                    let cfg = config_sig.read().clone();
                     let issuer = match IssuerUrl::new(cfg.issuer_url.clone()) {
                         Ok(i) => i,
                         Err(e) => {
@@ -185,17 +312,36 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
                             return;
                         }
                     };
-                    let provider_metadata = match CoreProviderMetadata::discover_async(
-                        issuer,
-                        &*http_client_discovery_clone,
-                    )
-                    .await
-                    {
-                        Ok(m) => m,
-                        Err(e) => {
-                            auth_state.set(AuthState::Error(format!("Discovery failed: {e}")));
+                    let mut attempt = 1;
+                    let provider_metadata = loop {
+                        let err = match CoreProviderMetadata::discover_async(
+                            issuer.clone(),
+                            &*http_client_discovery_clone,
+                        )
+                        .await
+                        {
+                            Ok(m) => break m,
+                            Err(e) => e,
+                        };
+                        if !is_transient_discovery_error(&err) {
+                            auth_state.set(AuthState::Error(format!(
+                                "Discovery failed: {err}. Check that OIDC_ISSUER_URL ({}) is the \
+                                 issuer of the OAuth provider.",
+                                cfg.issuer_url
+                            )));
                             return;
                         }
+                        if attempt >= DISCOVERY_ATTEMPTS {
+                            auth_state.set(AuthState::ProviderUnreachable(format!(
+                                "{} did not answer after {attempt} attempts: {err}",
+                                cfg.issuer_url
+                            )));
+                            return;
+                        }
+                        let delay_ms = DISCOVERY_RETRY_BASE_MS << (attempt - 1);
+                        warn!("OIDC discovery attempt {attempt} failed: {err}; retrying in {delay_ms} ms");
+                        gloo_timers::future::TimeoutFuture::new(delay_ms).await;
+                        attempt += 1;
                     };
                     let client_id = ClientId::new(cfg.client_id.clone());
                     let redirect_url = match RedirectUrl::new(cfg.redirect_uri.clone()) {
@@ -222,6 +368,9 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
                             .set_auth_uri(auth_ep)
                             .set_token_uri(token_ep);
                     // UserInfo Endpoint bleibt MaybeSet typzustand (wir setzen ihn nicht explizit, verwenden user_info_maybe fallibel)
+                    OIDC_CLIENT_CACHE.with(|cache| {
+                        cache.replace(Some((oidc_cache_key(&cfg), client.clone())));
+                    });
                     oidc_client_outer.replace(Some(client));
                 }
 
@@ -325,58 +474,48 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
                                 .set(AuthState::Error(format!("Token exchange failed: {e}"))),
                         }
                     }
-                } else if let Some(user_info) = get_stored_user_info() {
-                    // Validate expiry of restored id_token; if expired and we have a refresh_token, try refresh; else force re-login
-                    let ui = user_info;
-                    let now = (Date::new_0().get_time() / 1000.0) as u64;
-                    let mut exp_opt: Option<u64> = None;
-                    if let Some(Ok(decoded)) = ui.decode_id_token() {
-                        if let Some(exp) = decoded.claims.get("exp").and_then(|v| v.as_u64()) {
-                            exp_opt = Some(exp);
-                        }
-                    }
-
-                    // Consider tokens expiring within the next 60s as effectively expired
-                    let needs_refresh = match exp_opt {
-                        Some(exp) => exp <= now.saturating_add(60),
-                        None => false, // if no exp, proceed (provider might omit ID token)
-                    };
-
-                    if needs_refresh {
-                        if let (Some(client), Some(rt)) = (
-                            oidc_client_outer.borrow().as_ref(),
-                            ui.refresh_token.clone(),
-                        ) {
-                            // Attempt immediate refresh; keep UI responsive by updating state after success
-                            attempt_refresh(
-                                client.clone(),
-                                rt,
-                                auth_state.clone(),
-                                http_client_discovery_for_refresh.clone(),
-                            );
-                        } else {
-                            // No refresh_token -> require login
-                            auth_state.set(AuthState::Unauthenticated);
+                } else if let Some(ui) = get_stored_user_info() {
+                    // Only trust the stored session while its ID token is valid. No `exp`
+                    // (provider omitted the ID token) means restore as is.
+                    let expires_in = ui
+                        .decode_jwt_times()
+                        .and_then(|times| times.expires_in(unix_now()));
+                    let client = oidc_client_outer.borrow().clone();
+                    match (expires_in, client) {
+                        (Some(secs), Some(client)) if secs <= TOKEN_EXPIRY_LEEWAY_SECS => {
+                            if let Some(rt) = ui.refresh_token.clone() {
+                                // Silent refresh; login is only offered if it fails.
+                                auth_state.set(AuthState::Authenticating);
+                                attempt_refresh(
+                                    client,
+                                    ui,
+                                    rt,
+                                    true,
+                                    auth_state.clone(),
+                                    http_client_discovery_for_refresh.clone(),
+                                );
+                            } else {
+                                warn!(
+                                    "Stored ID token expired and no refresh token; login required"
+                                );
+                                remove_stored_user_info();
+                                auth_state.set(AuthState::Unauthenticated);
+                            }
                         }
-                    } else {
-                        // Token still valid; schedule a refresh using (exp - now)
-                        if let (Some(client), Some(exp)) =
-                            (oidc_client_outer.borrow().as_ref(), exp_opt)
-                        {
-                            let expires_in_secs = exp.saturating_sub(now);
+                        (Some(secs), Some(client)) => {
                             store_user_info(&ui);
                             auth_state.set(AuthState::Authenticated(ui.clone()));
                             if let Some(rt) = ui.refresh_token.clone() {
                                 schedule_refresh(
-                                    client.clone(),
+                                    client,
                                     rt,
-                                    expires_in_secs,
+                                    secs as u64,
                                     auth_state.clone(),
                                     http_client_discovery_for_refresh.clone(),
                                 );
                             }
-                        } else {
-                            // No exp -> just restore state
+                        }
+                        _ => {
                             store_user_info(&ui);
                             auth_state.set(AuthState::Authenticated(ui));
                         }
@@ -390,6 +529,8 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
     let login = {
         let config_sig = config_signal.clone();
         let oidc_client = oidc_client.clone();
+        let mut auth_state = auth_state;
+        let mut discovery_run = discovery_run;
         Callback::<()>::new(move |_| {
             if let Some(client) = oidc_client.borrow().as_ref() {
                 let cfg = config_sig.read();
@@ -406,6 +547,12 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
                         auth_req = auth_req.add_scope(Scope::new(sc.into()));
                     }
                 }
+                if let Some(prompt) = &cfg.prompt {
+                    auth_req = auth_req.add_extra_param("prompt", prompt.clone());
+                }
+                for (key, value) in &cfg.extra_auth_params {
+                    auth_req = auth_req.add_extra_param(key.clone(), value.clone());
+                }
                 // PKCE
                 let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
                 store_code_verifier(pkce_verifier.secret());
@@ -416,6 +563,14 @@ pub fn use_oauth(config: OAuthConfig) -> (Signal<AuthState>, Callback<()>, Callb
                 if let Some(window) = window() {
                     let _ = window.location().assign(auth_url.as_str());
                 }
+            } else if matches!(
+                *auth_state.peek(),
+                AuthState::ProviderUnreachable(_) | AuthState::Error(_)
+            ) {
+                // Discovery failed before; try it again instead of waiting for a reload.
+                invalidate_oidc_discovery();
+                auth_state.set(AuthState::Unauthenticated);
+                *discovery_run.write() += 1;
             } else {
                 warn!("OIDC client not ready yet");
             }
@@ -504,7 +659,6 @@ fn build_user_info_from_openid(
     let mut given_name = None;
     let mut family_name = None;
     let mut name = None;
-    let groups: Option<Vec<String>> = None;
     if let Some(c) = &claims {
         if let Some(s) = c.preferred_username() {
             username = s.to_string();
@@ -527,7 +681,7 @@ fn build_user_info_from_openid(
     if username.is_empty() {
         username = sub.clone();
     }
-    UserInfo {
+    let mut user_info = UserInfo {
         username,
         email,
         access_token,
@@ -539,102 +693,109 @@ fn build_user_info_from_openid(
         name,
         is_staff: None,
         is_superuser: None,
-        groups,
-    }
+        groups: None,
+    };
+    // The standard userinfo claims have no `groups`; Django puts them into the ID token.
+    user_info.groups = user_info.groups_from_id_token();
+    user_info
 }
 
 // Planung einer automatischen Token-Erneuerung ~60s vor Ablauf
 fn schedule_refresh(
-    client: CoreClient<
-        EndpointSet,
-        EndpointNotSet,
-        EndpointNotSet,
-        EndpointNotSet,
-        EndpointSet,
-        EndpointMaybeSet,
-    >,
+    client: OidcClient,
     refresh_token: String,
     expires_in_secs: u64,
     auth_state: Signal<AuthState>,
     http_client: Rc<HttpClient>,
 ) {
-    let wait_ms = expires_in_secs.saturating_sub(60) * 1000; // 60s Puffer
-    if wait_ms == 0 {
-        attempt_refresh(client, refresh_token, auth_state, http_client);
-        return;
-    }
+    let wait_ms = expires_in_secs.saturating_sub(TOKEN_EXPIRY_LEEWAY_SECS as u64) * 1000;
     spawn(async move {
-        gloo_timers::future::TimeoutFuture::new(wait_ms as u32).await;
-        attempt_refresh(client, refresh_token, auth_state, http_client);
+        if wait_ms > 0 {
+            gloo_timers::future::TimeoutFuture::new(wait_ms as u32).await;
+        }
+        // Snapshot des aktuellen Zustands; nach einem Logout wird nicht mehr erneuert.
+        let current_snapshot = auth_state.peek().clone();
+        if let AuthState::Authenticated(current) = current_snapshot {
+            attempt_refresh(
+                client,
+                current,
+                refresh_token,
+                false,
+                auth_state,
+                http_client,
+            );
+        }
     });
 }
 
+/// Exchange `refresh_token` for new tokens, keeping profile fields of `current` the response
+/// lacks. `restoring`: the session is being restored with an expired token, so a failure just
+/// asks for a new login instead of showing an error.
 fn attempt_refresh(
-    client: CoreClient<
-        EndpointSet,
-        EndpointNotSet,
-        EndpointNotSet,
-        EndpointNotSet,
-        EndpointSet,
-        EndpointMaybeSet,
-    >,
+    client: OidcClient,
+    current: UserInfo,
     refresh_token: String,
+    restoring: bool,
     auth_state: Signal<AuthState>,
     http_client: Rc<HttpClient>,
 ) {
     let mut auth_state_cloned = auth_state.clone();
     spawn(async move {
-        // Snapshot des aktuellen Zustands ohne Borrow während async weiterer Mutationen
-        let current_snapshot = auth_state_cloned.read().clone();
-        if let AuthState::Authenticated(current) = current_snapshot {
-            let rt = RefreshToken::new(refresh_token.clone());
-            match client
-                .exchange_refresh_token(&rt)
-                .request_async(&*http_client)
-                .await
-            {
-                Ok(token_response) => {
-                    let new_refresh = token_response
-                        .refresh_token()
-                        .map(|r| r.secret().to_string())
-                        .or_else(|| Some(refresh_token.clone()));
-                    let maybe_userinfo =
-                        match client.user_info(token_response.access_token().clone(), None) {
-                            Ok(req) => match req.request_async(&*http_client).await {
-                                Ok(claims) => Some(claims),
-                                Err(_) => None,
-                            },
+        let rt = RefreshToken::new(refresh_token.clone());
+        match client
+            .exchange_refresh_token(&rt)
+            .request_async(&*http_client)
+            .await
+        {
+            Ok(token_response) => {
+                let new_refresh = token_response
+                    .refresh_token()
+                    .map(|r| r.secret().to_string())
+                    .or_else(|| Some(refresh_token.clone()));
+                let maybe_userinfo =
+                    match client.user_info(token_response.access_token().clone(), None) {
+                        Ok(req) => match req.request_async(&*http_client).await {
+                            Ok(claims) => Some(claims),
                             Err(_) => None,
-                        };
-                    let mut updated =
-                        build_user_info_from_openid(&token_response, maybe_userinfo, new_refresh);
-                    // Fehlende Felder aus vorherigem Zustand übernehmen
-                    if updated.name.is_none() {
-                        updated.name = current.name.clone();
-                    }
-                    if updated.given_name.is_none() {
-                        updated.given_name = current.given_name.clone();
-                    }
-                    if updated.family_name.is_none() {
-                        updated.family_name = current.family_name.clone();
-                    }
-                    store_user_info(&updated);
-                    auth_state_cloned.set(AuthState::Authenticated(updated.clone()));
-                    if let (Some(rt), Some(exp)) =
-                        (updated.refresh_token.clone(), token_response.expires_in())
-                    {
-                        schedule_refresh(
-                            client.clone(),
-                            rt,
-                            exp.as_secs(),
-                            auth_state_cloned.clone(),
-                            http_client.clone(),
-                        );
-                    }
+                        },
+                        Err(_) => None,
+                    };
+                let mut updated =
+                    build_user_info_from_openid(&token_response, maybe_userinfo, new_refresh);
+                // Fehlende Felder aus vorherigem Zustand übernehmen
+                if updated.name.is_none() {
+                    updated.name = current.name.clone();
+                }
+                if updated.given_name.is_none() {
+                    updated.given_name = current.given_name.clone();
+                }
+                if updated.family_name.is_none() {
+                    updated.family_name = current.family_name.clone();
+                }
+                if updated.groups.is_none() {
+                    updated.groups = current.groups.clone();
+                }
+                store_user_info(&updated);
+                auth_state_cloned.set(AuthState::Authenticated(updated.clone()));
+                if let (Some(rt), Some(exp)) =
+                    (updated.refresh_token.clone(), token_response.expires_in())
+                {
+                    schedule_refresh(
+                        client.clone(),
+                        rt,
+                        exp.as_secs(),
+                        auth_state_cloned.clone(),
+                        http_client.clone(),
+                    );
                 }
-                Err(e) => {
+            }
+            Err(e) => {
+                remove_stored_user_info();
+                if restoring {
+                    warn!("Silent refresh of expired session failed: {e}");
+                    auth_state_cloned.set(AuthState::Unauthenticated);
+                } else {
                     auth_state_cloned.set(AuthState::Error(format!("Refresh failed: {e}")));
-                    remove_stored_user_info();
                 }
             }
         }
@@ -674,3 +835,54 @@ fn remove_stored_nonce() {
         let _ = s.remove_item("oauth_nonce");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// User whose ID token carries `claims`; the signature is not checked when decoding.
+    fn user_with_claims(claims: serde_json::Value) -> UserInfo {
+        let encode = |json: &serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json.to_string())
+        };
+        let header = serde_json::json!({ "alg": "RS256", "typ": "JWT" });
+        UserInfo {
+            username: "alice".to_string(),
+            email: None,
+            access_token: String::new(),
+            id_token: Some(format!("{}.{}.c2ln", encode(&header), encode(&claims))),
+            refresh_token: None,
+            mitgliedsnr: "42".to_string(),
+            given_name: None,
+            family_name: None,
+            name: None,
+            is_staff: None,
+            is_superuser: None,
+            groups: None,
+        }
+    }
+
+    #[test]
+    fn admin_group_matches_the_groups_claim() {
+        let mut user = user_with_claims(serde_json::json!({
+            "sub": "42",
+            "groups": ["gaertner", "vorstand"],
+        }));
+        user.groups = user.groups_from_id_token();
+        assert!(user.in_group(Some("vorstand")));
+        assert!(user.in_group(Some(" vorstand ")));
+        assert!(!user.in_group(Some("Vorstand")));
+        assert!(!user.in_group(Some("kasse")));
+        // No admin group configured
+        assert!(!user.in_group(None));
+        assert!(!user.in_group(Some("")));
+    }
+
+    #[test]
+    fn token_without_groups_claim_is_in_no_group() {
+        let mut user = user_with_claims(serde_json::json!({ "sub": "42" }));
+        user.groups = user.groups_from_id_token();
+        assert_eq!(user.groups, None);
+        assert!(!user.in_group(Some("vorstand")));
+    }
+}
@@ -4,18 +4,43 @@ use ::dioxus::{
 };
 use dioxus_bootstrap_css::prelude::*;
 
+use crate::hooks::reducer_outcome::use_reducer_add_message_category_then;
 use crate::module_bindings::dioxus::{
-    use_procedure_provision_message_category, use_reducer_remove_message_category,
-    use_table_message_categories,
+    use_procedure_provision_message_category, use_reducer_add_category_address,
+    use_reducer_hard_delete_category, use_reducer_preview_category_impact,
+    use_reducer_remove_category_address, use_reducer_remove_message_category,
+    use_reducer_restore_category, use_reducer_set_category_accept_response,
+    use_reducer_set_category_active, use_reducer_set_category_log_level,
+    use_reducer_set_category_rate_limit, use_reducer_set_category_subscription_required,
+    use_table_category_address, use_table_message_categories, use_table_my_category_impact_results,
 };
 
+/// `log_level` values with their labels, least verbose first.
+const LOG_LEVEL_OPTIONS: [(&str, &str); 3] = [
+    ("none", "Log: aus"),
+    ("summary", "Log: Zusammenfassung"),
+    ("full", "Log: vollständig"),
+];
+
 /// Admin-only view: lists all message categories with inline add and delete controls.
+/// Soft-deleted categories are hidden unless "Gelöschte anzeigen" is ticked.
 #[component]
 pub fn CategoriesPage() -> Element {
     let categories = use_table_message_categories();
     // New generated hook returns (invoke, result_signal).
     let (add_invoke, add_result) = use_procedure_provision_message_category();
     let remove_category = use_reducer_remove_message_category();
+    let set_active = use_reducer_set_category_active();
+    let set_subscription_required = use_reducer_set_category_subscription_required();
+    let set_log_level = use_reducer_set_category_log_level();
+    let set_rate_limit = use_reducer_set_category_rate_limit();
+    let set_accept_response = use_reducer_set_category_accept_response();
+    let restore_category = use_reducer_restore_category();
+    let hard_delete_category = use_reducer_hard_delete_category();
+    let mut show_deleted = use_signal(|| false);
+    let preview_impact = use_reducer_preview_category_impact();
+    // Category (id, name) waiting for confirmation in the pause dialog.
+    let mut pausing: Signal<Option<(u64, String)>> = use_signal(|| None);
 
     let mut name = use_signal(String::new);
     let mut email_address = use_signal(String::new);
@@ -64,6 +89,11 @@ pub fn CategoriesPage() -> Element {
         });
     }
 
+    let shown_categories: Vec<_> = categories()
+        .into_iter()
+        .filter(|c| show_deleted() || c.deleted_at.is_none())
+        .collect();
+
     rsx! {
         Container { fluid: true, class: "mt-4",
             Row { class: "mb-3",
@@ -165,11 +195,21 @@ pub fn CategoriesPage() -> Element {
                                 Icon { name: "list-ul", class: "me-2" }
                                 "Vorhandene Themen"
                                 // No Color::White in dioxus-bootstrap-css; keep as raw HTML.
-                                span { class: "badge bg-white text-primary ms-2", "{categories().len()}" }
+                                span { class: "badge bg-white text-primary ms-2", "{shown_categories.len()}" }
+                            }
+                            div { class: "form-check form-switch mb-0 mt-2",
+                                input {
+                                    class: "form-check-input",
+                                    r#type: "checkbox",
+                                    id: "categories-show-deleted",
+                                    checked: show_deleted(),
+                                    onchange: move |e| show_deleted.set(e.checked()),
+                                }
+                                label { class: "form-check-label", r#for: "categories-show-deleted", "Gelöschte anzeigen" }
                             }
                         },
                         body: rsx! {
-                            if categories().is_empty() {
+                            if shown_categories.is_empty() {
                                 div { class: "p-4 text-muted",
                                     Icon { name: "inbox", class: "me-2" }
                                     "Keine Themen vorhanden."
@@ -180,45 +220,192 @@ pub fn CategoriesPage() -> Element {
                                         thead { class: "table-light",
                                             tr {
                                                 th { "Name" }
-                                                th { "E-Mail-Adresse" }
+                                                th { "E-Mail-Adressen" }
                                                 th { "Beschreibung" }
                                                 th { "Status" }
                                                 th { class: "text-end", "Aktionen" }
                                             }
                                         }
                                         tbody {
-                                            for cat in categories() {
+                                            for cat in shown_categories {
                                                 {
                                                     let cat_id = cat.id;
+                                                    let cat_active = cat.active;
+                                                    let cat_deleted = cat.deleted_at.is_some();
                                                     let remove = remove_category.clone();
+                                                    let set_active = set_active.clone();
+                                                    let preview_impact = preview_impact.clone();
+                                                    let cat_name = cat.name.clone();
+                                                    let set_subscription_required = set_subscription_required.clone();
+                                                    let cat_subscription_required = cat.subscription_required;
+                                                    let set_log_level = set_log_level.clone();
+                                                    let cat_log_level = cat.log_level.clone();
+                                                    let set_rate_limit = set_rate_limit.clone();
+                                                    let cat_rate_limit = cat.rate_limit_per_hour.map(|l| l.to_string()).unwrap_or_default();
+                                                    let set_accept_response = set_accept_response.clone();
+                                                    let cat_accept_code = cat.accept_code;
+                                                    let cat_accept_message = cat.accept_message.clone().unwrap_or_default();
+                                                    let switch_id = format!("category-subscription-required-{cat_id}");
+                                                    let restore = restore_category.clone();
+                                                    let hard_delete = hard_delete_category.clone();
                                                     rsx! {
                                                         tr {
                                                             td {
-                                                                strong { "{cat.name}" }
+                                                                CategoryName {
+                                                                    email_address: cat.email_address.clone(),
+                                                                    name: cat.name.clone(),
+                                                                    description: cat.description.clone(),
+                                                                    editable: !cat_deleted,
+                                                                }
                                                             }
                                                             td {
-                                                                code { "{cat.email_address}" }
+                                                                CategoryAddresses {
+                                                                    category_id: cat_id,
+                                                                    primary: cat.email_address.clone(),
+                                                                    editable: !cat_deleted,
+                                                                }
                                                             }
                                                             td { class: "text-muted", "{cat.description}" }
                                                             td {
-                                                                if cat.active {
+                                                                if cat_deleted {
+                                                                    Badge { color: Color::Danger, "Gelöscht" }
+                                                                } else if cat.active {
                                                                     Badge { color: Color::Success, "Aktiv" }
                                                                 } else {
                                                                     Badge { color: Color::Secondary, "Inaktiv" }
                                                                 }
+                                                                if !cat_deleted {
+                                                                    div { class: "form-check form-switch mb-0 mt-1 small",
+                                                                        input {
+                                                                            class: "form-check-input",
+                                                                            r#type: "checkbox",
+                                                                            id: "{switch_id}",
+                                                                            checked: cat_subscription_required,
+                                                                            onchange: move |e| {
+                                                                                let required = e.checked();
+                                                                                info!("Setting category {cat_id} subscription_required = {required}");
+                                                                                if let Err(e) = set_subscription_required(cat_id, required) {
+                                                                                    error!("set_category_subscription_required failed: {e:?}");
+                                                                                }
+                                                                            },
+                                                                        }
+                                                                        label { class: "form-check-label", r#for: "{switch_id}", "Nur Abonnenten" }
+                                                                    }
+                                                                    select {
+                                                                        class: "form-select form-select-sm mt-1",
+                                                                        style: "width: auto;",
+                                                                        title: "Protokollierung im MTA-Nachrichtenlog",
+                                                                        onchange: move |e| {
+                                                                            let level = e.value();
+                                                                            info!("Setting category {cat_id} log_level = {level}");
+                                                                            if let Err(e) = set_log_level(cat_id, level) {
+                                                                                error!("set_category_log_level failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                        for (value, label) in LOG_LEVEL_OPTIONS {
+                                                                            option { value: "{value}", selected: cat_log_level == value, "{label}" }
+                                                                        }
+                                                                    }
+                                                                    input {
+                                                                        class: "form-control form-control-sm mt-1",
+                                                                        style: "width: 9rem;",
+                                                                        r#type: "number",
+                                                                        min: "0",
+                                                                        placeholder: "Max. pro Stunde",
+                                                                        title: "Höchstens so viele Nachrichten pro Stunde annehmen, weitere werden zurückgestellt (leer = unbegrenzt)",
+                                                                        value: "{cat_rate_limit}",
+                                                                        onchange: move |e| {
+                                                                            let limit = e.value().trim().parse::<u32>().ok();
+                                                                            info!("Setting category {cat_id} rate_limit_per_hour = {limit:?}");
+                                                                            if let Err(e) = set_rate_limit(cat_id, limit) {
+                                                                                error!("set_category_rate_limit failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                    }
+                                                                    input {
+                                                                        class: "form-control form-control-sm mt-1",
+                                                                        r#type: "text",
+                                                                        placeholder: "Annahme-Antwort",
+                                                                        title: "SMTP-Antwort bei Annahme, z. B. ein Hinweis zur Weiterleitung (leer = Standard)",
+                                                                        value: "{cat_accept_message}",
+                                                                        onchange: move |e| {
+                                                                            let message = Some(e.value().trim().to_string()).filter(|m| !m.is_empty());
+                                                                            info!("Setting category {cat_id} accept_message = {message:?}");
+                                                                            if let Err(e) = set_accept_response(cat_id, cat_accept_code, message) {
+                                                                                error!("set_category_accept_response failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                    }
+                                                                }
                                                             }
                                                             td { class: "text-end",
-                                                                Button {
-                                                                    color: Color::Danger,
-                                                                    size: Size::Sm,
-                                                                    onclick: move |_| {
-                                                                        info!("Removing category {cat_id}");
-                                                                        if let Err(e) = remove(cat_id) {
-                                                                            error!("remove_message_category failed: {e:?}");
+                                                                if cat_deleted {
+                                                                    Button {
+                                                                        color: Color::Secondary,
+                                                                        outline: true,
+                                                                        size: Size::Sm,
+                                                                        class: "me-2",
+                                                                        onclick: move |_| {
+                                                                            info!("Restoring category {cat_id}");
+                                                                            if let Err(e) = restore(cat_id) {
+                                                                                error!("restore_category failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                        Icon { name: "arrow-counterclockwise", class: "me-1" }
+                                                                        "Wiederherstellen"
+                                                                    }
+                                                                    Button {
+                                                                        color: Color::Danger,
+                                                                        size: Size::Sm,
+                                                                        onclick: move |_| {
+                                                                            info!("Permanently deleting category {cat_id}");
+                                                                            if let Err(e) = hard_delete(cat_id) {
+                                                                                error!("hard_delete_category failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                        Icon { name: "trash-fill", class: "me-1" }
+                                                                        "Endgültig löschen"
+                                                                    }
+                                                                } else {
+                                                                    Button {
+                                                                        color: Color::Secondary,
+                                                                        outline: true,
+                                                                        size: Size::Sm,
+                                                                        class: "me-2",
+                                                                        onclick: move |_| {
+                                                                            if cat_active {
+                                                                                // Show who is affected before pausing.
+                                                                                if let Err(e) = preview_impact(impact_request_id(cat_id), cat_id) {
+                                                                                    error!("preview_category_impact failed: {e:?}");
+                                                                                }
+                                                                                pausing.set(Some((cat_id, cat_name.clone())));
+                                                                            } else {
+                                                                                info!("Setting category {cat_id} active = true");
+                                                                                if let Err(e) = set_active(cat_id, true) {
+                                                                                    error!("set_category_active failed: {e:?}");
+                                                                                }
+                                                                            }
+                                                                        },
+                                                                        if cat_active {
+                                                                            Icon { name: "pause-fill", class: "me-1" }
+                                                                            "Pausieren"
+                                                                        } else {
+                                                                            Icon { name: "play-fill", class: "me-1" }
+                                                                            "Aktivieren"
                                                                         }
-                                                                    },
-                                                                    Icon { name: "trash", class: "me-1" }
-                                                                    "Löschen"
+                                                                    }
+                                                                    Button {
+                                                                        color: Color::Danger,
+                                                                        size: Size::Sm,
+                                                                        onclick: move |_| {
+                                                                            info!("Removing category {cat_id}");
+                                                                            if let Err(e) = remove(cat_id) {
+                                                                                error!("remove_message_category failed: {e:?}");
+                                                                            }
+                                                                        },
+                                                                        Icon { name: "trash", class: "me-1" }
+                                                                        "Löschen"
+                                                                    }
                                                                 }
                                                             }
                                                         }
@@ -233,6 +420,283 @@ pub fn CategoriesPage() -> Element {
                     }
                 }
             }
+
+            if let Some((id, name)) = pausing() {
+                DeactivateCategoryModal {
+                    category_id: id,
+                    name,
+                    on_confirm: move |id: u64| {
+                        info!("Setting category {id} active = false");
+                        if let Err(e) = set_active(id, false) {
+                            error!("set_category_active failed: {e:?}");
+                        }
+                        pausing.set(None);
+                    },
+                    on_close: move |_| pausing.set(None),
+                }
+            }
+        }
+    }
+}
+
+/// `request_id` under which the pause dialog asks for a category's impact preview.
+fn impact_request_id(category_id: u64) -> String {
+    format!("category-impact-{category_id}")
+}
+
+/// Confirmation before pausing a category: shows how many current subscribers would no longer
+/// be able to send to it, with a sample of their addresses from `preview_category_impact`.
+#[component]
+fn DeactivateCategoryModal(
+    category_id: u64,
+    name: String,
+    on_confirm: EventHandler<u64>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let results = use_table_my_category_impact_results();
+    let request_id = impact_request_id(category_id);
+    let impact = results()
+        .into_iter()
+        .find(|r| r.request_id == request_id && r.category_id == category_id);
+
+    rsx! {
+        div { class: "modal d-block", tabindex: "-1", role: "dialog",
+            div { class: "modal-dialog modal-dialog-centered",
+                div { class: "modal-content",
+                    div { class: "modal-header",
+                        h5 { class: "modal-title",
+                            Icon { name: "pause-fill", class: "me-2" }
+                            "Thema „{name}“ pausieren"
+                        }
+                        button {
+                            class: "btn-close",
+                            "aria-label": "Schließen",
+                            onclick: move |_| on_close.call(()),
+                        }
+                    }
+                    div { class: "modal-body",
+                        p { "Pausierte Themen nehmen keine Nachrichten mehr an." }
+                        match impact {
+                            None => rsx! {
+                                p { class: "text-muted mb-0",
+                                    Icon { name: "hourglass-split", class: "me-2" }
+                                    "Betroffene Abonnenten werden berechnet…"
+                                }
+                            },
+                            Some(impact) if impact.active_subscribers == 0 => rsx! {
+                                p { class: "text-muted mb-0", "Das Thema hat keine aktiven Abonnenten." }
+                            },
+                            Some(impact) => rsx! {
+                                p {
+                                    strong { "{impact.active_subscribers}" }
+                                    " aktive Abonnenten könnten nicht mehr an dieses Thema schreiben, darunter:"
+                                }
+                                ul { class: "small mb-0",
+                                    for email in impact.sample_emails.iter() {
+                                        li { key: "{email}", code { "{email}" } }
+                                    }
+                                    if impact.active_subscribers as usize > impact.sample_emails.len() {
+                                        li { class: "text-muted", "…" }
+                                    }
+                                }
+                            },
+                        }
+                    }
+                    div { class: "modal-footer",
+                        Button {
+                            color: Color::Secondary,
+                            onclick: move |_| on_close.call(()),
+                            "Abbrechen"
+                        }
+                        Button {
+                            color: Color::Warning,
+                            onclick: move |_| on_confirm.call(category_id),
+                            Icon { name: "pause-fill", class: "me-1" }
+                            "Pausieren"
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "modal-backdrop show" }
+    }
+}
+
+/// Name of one category, with an inline form to change name and description. Saving calls
+/// `add_message_category`, which updates the category that has `email_address`; the form only
+/// closes once the server committed the change, and shows its error otherwise.
+#[component]
+fn CategoryName(
+    email_address: String,
+    name: String,
+    description: String,
+    editable: bool,
+) -> Element {
+    let (save, save_result) = use_reducer_add_message_category_then();
+    let mut editing = use_signal(|| false);
+    let mut new_name = use_signal(String::new);
+    let mut new_description = use_signal(String::new);
+    let mut save_error: Signal<Option<String>> = use_signal(|| None);
+    let mut is_sending = use_signal(|| false);
+
+    {
+        let mut save_result = save_result;
+        use_effect(move || {
+            if let Some(res) = save_result() {
+                is_sending.set(false);
+                match res {
+                    Ok(()) => {
+                        editing.set(false);
+                        save_error.set(None);
+                    }
+                    Err(e) => save_error.set(Some(e)),
+                }
+                // clear the result so the next invocation can be observed
+                save_result.set(None);
+            }
+        });
+    }
+
+    if !editing() {
+        return rsx! {
+            strong { "{name}" }
+            if editable {
+                button {
+                    class: "btn btn-link btn-sm p-0 ms-2",
+                    title: "Name und Beschreibung bearbeiten",
+                    onclick: {
+                        let name = name.clone();
+                        let description = description.clone();
+                        move |_| {
+                            new_name.set(name.clone());
+                            new_description.set(description.clone());
+                            save_error.set(None);
+                            editing.set(true);
+                        }
+                    },
+                    Icon { name: "pencil" }
+                }
+            }
+        };
+    }
+
+    rsx! {
+        input {
+            class: "form-control form-control-sm mb-1",
+            r#type: "text",
+            placeholder: "Thema Name",
+            value: "{new_name}",
+            oninput: move |e| new_name.set(e.value()),
+        }
+        input {
+            class: "form-control form-control-sm mb-1",
+            r#type: "text",
+            placeholder: "Kurze Beschreibung",
+            value: "{new_description}",
+            oninput: move |e| new_description.set(e.value()),
+        }
+        if let Some(e) = save_error() {
+            small { class: "text-danger d-block mb-1", "{e}" }
+        }
+        div { class: "d-flex gap-1",
+            Button {
+                color: Color::Primary,
+                size: Size::Sm,
+                disabled: new_name.read().trim().is_empty() || is_sending(),
+                onclick: {
+                    let save = save.clone();
+                    let email_address = email_address.clone();
+                    move |_| {
+                        info!("Updating name and description of category {email_address}");
+                        is_sending.set(true);
+                        save(
+                            new_name.read().trim().to_string(),
+                            email_address.clone(),
+                            new_description.read().trim().to_string(),
+                        );
+                    }
+                },
+                "Speichern"
+            }
+            Button {
+                color: Color::Secondary,
+                outline: true,
+                size: Size::Sm,
+                disabled: is_sending(),
+                onclick: move |_| editing.set(false),
+                "Abbrechen"
+            }
+        }
+    }
+}
+
+/// Address list of one category: the primary address plus removable additional addresses,
+/// with an inline field to add another one.
+#[component]
+fn CategoryAddresses(category_id: u64, primary: String, editable: bool) -> Element {
+    let addresses = use_table_category_address();
+    let add_address = use_reducer_add_category_address();
+    let remove_address = use_reducer_remove_category_address();
+    let mut new_address = use_signal(String::new);
+
+    let extra: Vec<_> = addresses()
+        .into_iter()
+        .filter(|a| a.category_id == category_id && a.email_address != primary)
+        .collect();
+
+    rsx! {
+        div {
+            code { "{primary}" }
+        }
+        for address in extra {
+            {
+                let address_id = address.id;
+                let remove = remove_address.clone();
+                rsx! {
+                    div { class: "d-flex align-items-center",
+                        code { class: if !address.active { "text-muted" }, "{address.email_address}" }
+                        if editable {
+                            button {
+                                class: "btn btn-link btn-sm text-danger p-0 ms-2",
+                                title: "Adresse entfernen",
+                                onclick: move |_| {
+                                    info!("Removing category address {address_id}");
+                                    if let Err(e) = remove(address_id) {
+                                        error!("remove_category_address failed: {e:?}");
+                                    }
+                                },
+                                Icon { name: "x-circle" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if editable {
+            div { class: "input-group input-group-sm mt-1",
+                input {
+                    class: "form-control",
+                    r#type: "email",
+                    placeholder: "weitere@example.com",
+                    value: "{new_address}",
+                    oninput: move |e| new_address.set(e.value()),
+                }
+                Button {
+                    color: Color::Secondary,
+                    outline: true,
+                    size: Size::Sm,
+                    disabled: new_address.read().trim().is_empty(),
+                    onclick: move |_| {
+                        let address = new_address.read().trim().to_string();
+                        info!("Adding address {address} to category {category_id}");
+                        match add_address(category_id, address) {
+                            Ok(()) => new_address.set(String::new()),
+                            Err(e) => error!("add_category_address failed: {e:?}"),
+                        }
+                    },
+                    Icon { name: "plus-lg" }
+                }
+            }
         }
     }
 }
@@ -4,13 +4,27 @@ use ::dioxus::{
 };
 use dioxus_bootstrap_css::prelude::*;
 
+use crate::components::live_decisions::LiveDecisionStream;
+use crate::components::maintenance::{
+    DryRunToggle, EnvelopeSampleRateInput, MaintenanceToggle, StrictRecipientsToggle,
+    SyncPauseToggle,
+};
+use crate::components::offending_prefixes::WorstNetblocks;
+use crate::components::reconnect_button::ReconnectButton;
+use crate::components::stage_timing::StageTimingCard;
 use crate::module_bindings::dioxus::{
-    use_connection_error, use_connection_state, use_reducer_create_webhook_token,
-    use_reducer_dump_mta_logs_to_server_logs, use_reducer_register_admin_identity,
-    use_reducer_revoke_webhook_token, use_reducer_unregister_admin_identity,
-    use_table_visible_admin_identities, use_table_visible_webhook_tokens, ConnectionState,
+    use_connection_error, use_connection_state, use_reducer_compute_expected_identity,
+    use_reducer_create_webhook_token, use_reducer_dump_mta_logs_to_server_logs,
+    use_reducer_export_admins, use_reducer_import_admins,
+    use_reducer_prune_stale_admin_identities, use_reducer_recompute_mta_stats,
+    use_reducer_recompute_reject_stats, use_reducer_register_admin_identity, use_reducer_revoke_webhook_token,
+    use_reducer_set_admin_identity_label, use_reducer_unregister_admin_identity,
+    use_table_mta_daily_stats, use_table_my_expected_identity_results, use_table_my_export_blobs,
+    use_table_reject_reason_stats, use_table_table_size_history, use_table_visible_accounts,
+    use_table_visible_admin_identities, use_table_visible_login_events,
+    use_table_visible_webhook_tokens, ConnectionState,
 };
-use crate::oauth::UserInfo;
+use crate::oauth::{unix_now, JwtTimes, UserInfo};
 use wasm_bindgen_futures::{spawn_local, JsFuture};
 
 /// Admin-only view: SpacetimeDB connection details, identity info, and admin identity management.
@@ -21,6 +35,8 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
     let admin_identities = use_table_visible_admin_identities();
     let register_admin = use_reducer_register_admin_identity();
     let unregister_admin = use_reducer_unregister_admin_identity();
+    let set_admin_label = use_reducer_set_admin_identity_label();
+    let prune_admins = use_reducer_prune_stale_admin_identities();
     let dump_logs = use_reducer_dump_mta_logs_to_server_logs();
     let create_webhook_token = use_reducer_create_webhook_token();
     let revoke_webhook_token = use_reducer_revoke_webhook_token();
@@ -28,6 +44,7 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
     let admin_tokens = use_table_visible_webhook_tokens();
 
     let mut register_hex = use_signal(String::new);
+    let mut prune_days = use_signal(|| ADMIN_PRUNE_DEFAULT_DAYS.to_string());
 
     // Webhook token creation state (token plaintext is kept only in the browser)
     let mut token_plain = use_signal(String::new);
@@ -99,6 +116,11 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
                                         div { class: "text-danger mt-1 small", "Fehler: {err}" }
                                     }
                                 }
+                                if matches!(state(), ConnectionState::Error | ConnectionState::Disconnected) {
+                                    div { class: "ms-2 flex-shrink-0",
+                                        ReconnectButton {}
+                                    }
+                                }
                             }
                             Row { class: "text-center",
                                 Col { md: ColumnSize::Span(4),
@@ -133,6 +155,10 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
                                     }
                                 }
                             }
+                            IdentityCheckPanel { mitgliedsnr: user_info.mitgliedsnr.clone() }
+                            if jwt_debug_enabled() {
+                                JwtTimingPanel { times: user_info.decode_jwt_times() }
+                            }
                             div { class: "mt-3",
                                 Button {
                                     color: Color::Secondary,
@@ -147,12 +173,31 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
                                     Icon { name: "journal-text", class: "me-1" }
                                     "MTA Logs ausgeben"
                                 }
+                                span { class: "ms-2", MaintenanceToggle {} }
+                                span { class: "ms-2", DryRunToggle {} }
+                                span { class: "ms-2", StrictRecipientsToggle {} }
+                                span { class: "ms-2", SyncPauseToggle {} }
                             }
+                            div { class: "mt-2", EnvelopeSampleRateInput {} }
                         }
                     }
                 }
             }
 
+            MtaStatsCard {}
+
+            RejectReasonsCard {}
+
+            TableSizesCard {}
+
+            StageTimingCard {}
+
+            RecentLoginsCard {}
+
+            LiveDecisionStream {}
+
+            WorstNetblocks {}
+
             // Admin identity management
             Row {
                 Col { xs: ColumnSize::Span(12),
@@ -203,6 +248,45 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
                                     }
                                 }
                             }
+                            Row { class: "g-2 mb-3 align-items-center",
+                                Col { class: "col-auto small text-muted", "Nicht verbunden seit mehr als" }
+                                Col { class: "col-auto",
+                                    input {
+                                        class: "form-control form-control-sm",
+                                        style: "width: 6rem;",
+                                        r#type: "number",
+                                        min: "1",
+                                        value: "{prune_days}",
+                                        oninput: move |e| prune_days.set(e.value()),
+                                    }
+                                }
+                                Col { class: "col-auto small text-muted", "Tagen" }
+                                Col { class: "col-auto",
+                                    Button {
+                                        color: Color::Danger,
+                                        outline: true,
+                                        size: Size::Sm,
+                                        disabled: prune_days.read().parse::<u32>().map_or(true, |d| d == 0),
+                                        onclick: {
+                                            let prune = prune_admins.clone();
+                                            move |_| {
+                                                let Ok(days) = prune_days.read().parse::<u32>() else {
+                                                    return;
+                                                };
+                                                info!("Pruning admin identities idle for {days} days");
+                                                if let Err(e) = prune(days) {
+                                                    error!(
+                                                        "prune_stale_admin_identities failed: {e:?}"
+                                                    );
+                                                }
+                                            }
+                                        },
+                                        Icon { name: "trash", class: "me-1" }
+                                        "Inaktive entfernen"
+                                    }
+                                }
+                            }
+                            AdminBackupPanel {}
                             if admin_identities().is_empty() {
                                 p { class: "text-muted mb-0",
                                     "Keine Admin-Identitäten registriert."
@@ -213,10 +297,32 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
                                         {
                                             let hex = ident.identity.to_string();
                                             let hex_for_remove = hex.clone();
+                                            let hex_for_label = hex.clone();
                                             let unregister = unregister_admin.clone();
+                                            let set_label = set_admin_label.clone();
                                             rsx! {
                                                 div { class: "list-group-item d-flex justify-content-between align-items-center",
-                                                    code { class: "small text-break", "{hex}" }
+                                                    div { class: "flex-grow-1",
+                                                        input {
+                                                            class: "form-control form-control-sm mb-1",
+                                                            r#type: "text",
+                                                            placeholder: "Bezeichnung",
+                                                            value: "{ident.label}",
+                                                            onchange: move |e| {
+                                                                if let Err(e) =
+                                                                    set_label(hex_for_label.clone(), e.value())
+                                                                {
+                                                                    error!(
+                                                                        "set_admin_identity_label failed: {e:?}"
+                                                                    );
+                                                                }
+                                                            },
+                                                        }
+                                                        code { class: "small text-break", "{hex}" }
+                                                        div { class: "small text-muted",
+                                                            "Zuletzt verbunden: {ident.last_seen}"
+                                                        }
+                                                    }
                                                     Button {
                                                         color: Color::Danger,
                                                         outline: true,
@@ -407,3 +513,494 @@ pub fn DebugPage(user_info: UserInfo) -> Element {
         }
     }
 }
+
+/// Today's MTA decisions from `mta_daily_stats`, with a button to rebuild the counters from the logs.
+#[component]
+fn MtaStatsCard() -> Element {
+    let stats = use_table_mta_daily_stats();
+    let recompute = use_reducer_recompute_mta_stats();
+
+    let today = (js_sys::Date::now() / 1000.0 / 86_400.0) as u64;
+    let (accepted, rejected, quarantined) = stats()
+        .into_iter()
+        .find(|s| s.day == today)
+        .map(|s| (s.accept_count, s.reject_count, s.quarantine_count))
+        .unwrap_or_default();
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "bar-chart-fill", class: "me-2" }
+                            "MTA-Statistik heute"
+                        }
+                    },
+                    body: rsx! {
+                        Row { class: "text-center",
+                            Col { md: ColumnSize::Span(4),
+                                div { class: "border-end",
+                                    h6 { class: "text-muted mb-1", "Angenommen" }
+                                    p { class: "h4 mb-0 text-success", "{accepted}" }
+                                }
+                            }
+                            Col { md: ColumnSize::Span(4),
+                                div { class: "border-end",
+                                    h6 { class: "text-muted mb-1", "Abgelehnt" }
+                                    p { class: "h4 mb-0 text-danger", "{rejected}" }
+                                }
+                            }
+                            Col { md: ColumnSize::Span(4),
+                                h6 { class: "text-muted mb-1", "Quarantäne" }
+                                p { class: "h4 mb-0 text-warning", "{quarantined}" }
+                            }
+                        }
+                        div { class: "mt-3",
+                            Button {
+                                color: Color::Secondary,
+                                outline: true,
+                                size: Size::Sm,
+                                onclick: move |_| {
+                                    info!("Recomputing MTA stats");
+                                    if let Err(e) = recompute() {
+                                        error!("recompute_mta_stats failed: {e:?}");
+                                    }
+                                },
+                                Icon { name: "arrow-repeat", class: "me-1" }
+                                "Aus Logs neu berechnen"
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Hours summed up by the reject reason chart; matches the window the server keeps.
+const REJECT_REASONS_WINDOW_HOURS: u64 = 24;
+
+/// How many reasons the chart shows at most.
+const REJECT_REASONS_SHOWN: usize = 10;
+
+/// Most frequent rejection reasons of the last 24 hours from `reject_reason_stats`, as bars.
+#[component]
+fn RejectReasonsCard() -> Element {
+    let stats = use_table_reject_reason_stats();
+    let recompute = use_reducer_recompute_reject_stats();
+
+    let current_hour = (js_sys::Date::now() / 1000.0 / 3_600.0) as u64;
+    let first_hour = current_hour.saturating_sub(REJECT_REASONS_WINDOW_HOURS - 1);
+    let mut totals: Vec<(String, u64)> = Vec::new();
+    for row in stats().into_iter().filter(|r| r.hour >= first_hour) {
+        match totals.iter_mut().find(|(reason, _)| *reason == row.reason) {
+            Some((_, count)) => *count += row.count,
+            None => totals.push((row.reason, row.count)),
+        }
+    }
+    totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals.truncate(REJECT_REASONS_SHOWN);
+    let max = totals.first().map_or(1, |(_, count)| (*count).max(1));
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "slash-circle", class: "me-2" }
+                            "Ablehnungsgründe (24 h)"
+                        }
+                    },
+                    body: rsx! {
+                        if totals.is_empty() {
+                            p { class: "text-muted mb-0", "Keine Ablehnungen in den letzten 24 Stunden." }
+                        } else {
+                            for (reason, count) in totals {
+                                div { key: "{reason}", class: "mb-2",
+                                    div { class: "d-flex justify-content-between small",
+                                        span { "{reason}" }
+                                        strong { "{count}" }
+                                    }
+                                    div { class: "progress", style: "height: 0.5rem;",
+                                        div {
+                                            class: "progress-bar bg-danger",
+                                            role: "progressbar",
+                                            style: "width: {count * 100 / max}%;",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "mt-3",
+                            Button {
+                                color: Color::Secondary,
+                                outline: true,
+                                size: Size::Sm,
+                                onclick: move |_| {
+                                    info!("Recomputing reject reason stats");
+                                    if let Err(e) = recompute() {
+                                        error!("recompute_reject_stats failed: {e:?}");
+                                    }
+                                },
+                                Icon { name: "arrow-repeat", class: "me-1" }
+                                "Aus Logs neu berechnen"
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Growth in the table size card is measured against the snapshot at least this old.
+const TABLE_GROWTH_WINDOW_DAYS: i64 = 7;
+
+/// Latest row count per table from `table_size_history`, with the growth since the snapshot
+/// `TABLE_GROWTH_WINDOW_DAYS` days earlier (or the oldest one, if the history is shorter).
+#[component]
+fn TableSizesCard() -> Element {
+    let history = use_table_table_size_history();
+
+    let mut rows = history();
+    rows.sort_by_key(|r| r.timestamp.to_micros_since_unix_epoch());
+    let window_micros = TABLE_GROWTH_WINDOW_DAYS * 86_400 * 1_000_000;
+    let mut sizes: Vec<(String, u64, i64, String)> = Vec::new();
+    let mut names: Vec<String> = rows.iter().map(|r| r.table_name.clone()).collect();
+    names.sort();
+    names.dedup();
+    for name in names {
+        let snapshots: Vec<_> = rows.iter().filter(|r| r.table_name == name).collect();
+        let Some(latest) = snapshots.last() else {
+            continue;
+        };
+        let cutoff = latest.timestamp.to_micros_since_unix_epoch() - window_micros;
+        let base = snapshots
+            .iter()
+            .rev()
+            .find(|r| r.timestamp.to_micros_since_unix_epoch() <= cutoff)
+            .or(snapshots.first())
+            .map_or(latest.count, |r| r.count);
+        sizes.push((
+            name,
+            latest.count,
+            latest.count as i64 - base as i64,
+            latest.timestamp.to_string(),
+        ));
+    }
+    sizes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    let recorded_at = sizes.first().map(|s| s.3.clone()).unwrap_or_default();
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white",
+                    body_class: "p-0",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "database", class: "me-2" }
+                            "Tabellengrößen"
+                        }
+                    },
+                    body: rsx! {
+                        if sizes.is_empty() {
+                            p { class: "text-muted p-3 mb-0", "Noch keine Messung vorhanden (einmal täglich)." }
+                        } else {
+                            table { class: "table table-sm mb-0 small",
+                                thead { class: "table-light",
+                                    tr {
+                                        th { "Tabelle" }
+                                        th { class: "text-end", "Zeilen" }
+                                        th { class: "text-end", "+/- {TABLE_GROWTH_WINDOW_DAYS} Tage" }
+                                    }
+                                }
+                                tbody {
+                                    for (name, count, growth, _) in sizes {
+                                        tr { key: "{name}",
+                                            td { code { "{name}" } }
+                                            td { class: "text-end", "{count}" }
+                                            td { class: if growth > 0 { "text-end text-warning" } else { "text-end text-muted" },
+                                                if growth > 0 { "+{growth}" } else { "{growth}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            p { class: "text-muted small px-3 py-2 mb-0", "Stand: {recorded_at}" }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// How many logins the recent logins card lists.
+const RECENT_LOGINS_MAX: usize = 20;
+
+/// Newest admin panel logins from `visible_login_events`, with the member behind each identity.
+#[component]
+fn RecentLoginsCard() -> Element {
+    let events = use_table_visible_login_events();
+    let accounts = use_table_visible_accounts();
+
+    let mut rows = events();
+    rows.sort_by_key(|e| std::cmp::Reverse(e.logged_in_at.to_micros_since_unix_epoch()));
+    rows.truncate(RECENT_LOGINS_MAX);
+    let accounts = accounts();
+    let member = |account_id: Option<u64>| match account_id {
+        Some(id) => accounts
+            .iter()
+            .find(|a| a.id == id)
+            .map_or_else(|| format!("#{id}"), |a| format!("{} (#{id})", a.name)),
+        None => "Admin-Identität ohne Konto".to_string(),
+    };
+
+    rsx! {
+        Row { class: "mb-4",
+            Col { xs: ColumnSize::Span(12),
+                Card {
+                    class: "shadow-sm",
+                    header_class: "bg-primary text-white",
+                    body_class: "p-0",
+                    header: rsx! {
+                        h5 { class: "card-title mb-0",
+                            Icon { name: "box-arrow-in-right", class: "me-2" }
+                            "Letzte Anmeldungen"
+                        }
+                    },
+                    body: rsx! {
+                        if rows.is_empty() {
+                            p { class: "text-muted p-3 mb-0", "Noch keine Anmeldungen erfasst." }
+                        } else {
+                            table { class: "table table-sm mb-0 small",
+                                thead { class: "table-light",
+                                    tr {
+                                        th { "Zeit" }
+                                        th { "Mitglied" }
+                                        th { "Browser" }
+                                    }
+                                }
+                                tbody {
+                                    for event in rows {
+                                        tr { key: "{event.id}",
+                                            td { class: "text-nowrap", "{event.logged_in_at}" }
+                                            td { {member(event.account_id)} }
+                                            td { class: "text-muted text-truncate", style: "max-width: 24rem;", title: "{event.user_agent}",
+                                                "{event.user_agent}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Export of the admin identities into `my_export_blobs` and import of such an export,
+/// e.g. to restore admin access on a freshly published module.
+#[component]
+fn AdminBackupPanel() -> Element {
+    let exports = use_table_my_export_blobs();
+    let export_admins = use_reducer_export_admins();
+    let import_admins = use_reducer_import_admins();
+    let mut import_json = use_signal(String::new);
+
+    let exported = exports()
+        .into_iter()
+        .find(|blob| blob.kind == "admins")
+        .map(|blob| blob.content);
+
+    rsx! {
+        details { class: "mb-3",
+            summary { class: "small text-muted", "Sichern / Wiederherstellen" }
+            div { class: "mt-2",
+                Button {
+                    color: Color::Secondary,
+                    outline: true,
+                    size: Size::Sm,
+                    onclick: move |_| {
+                        info!("Exporting admin identities");
+                        if let Err(e) = export_admins() {
+                            error!("export_admins failed: {e:?}");
+                        }
+                    },
+                    Icon { name: "download", class: "me-1" }
+                    "Exportieren"
+                }
+                if let Some(content) = exported {
+                    textarea {
+                        class: "form-control form-control-sm font-monospace mt-2",
+                        rows: "4",
+                        readonly: true,
+                        value: "{content}",
+                    }
+                }
+                textarea {
+                    class: "form-control form-control-sm font-monospace mt-3",
+                    rows: "4",
+                    placeholder: "JSON aus einem Export",
+                    value: "{import_json}",
+                    oninput: move |e| import_json.set(e.value()),
+                }
+                Button {
+                    color: Color::Primary,
+                    size: Size::Sm,
+                    class: "mt-2",
+                    disabled: import_json.read().trim().is_empty(),
+                    onclick: move |_| {
+                        info!("Importing admin identities");
+                        match import_admins(import_json()) {
+                            Ok(()) => import_json.set(String::new()),
+                            Err(e) => error!("import_admins failed: {e:?}"),
+                        }
+                    },
+                    Icon { name: "upload", class: "me-1" }
+                    "Importieren"
+                }
+            }
+        }
+    }
+}
+
+/// Preset of the "remove inactive admin identities" field.
+const ADMIN_PRUNE_DEFAULT_DAYS: u32 = 180;
+
+/// `request_id` under which the debug page stores its own `compute_expected_identity` answer.
+const IDENTITY_CHECK_REQUEST_ID: &str = "debug-identity-check";
+
+/// Compares the live connection identity with the one the server derives for this
+/// Mitgliedsnummer, i.e. the identity a synced account gets.
+#[component]
+fn IdentityCheckPanel(mitgliedsnr: String) -> Element {
+    let state = use_connection_state();
+    let results = use_table_my_expected_identity_results();
+    let compute = use_reducer_compute_expected_identity();
+
+    let live = match state() {
+        ConnectionState::Connected(id, _) => Some(id),
+        _ => None,
+    };
+    let expected = results()
+        .into_iter()
+        .find(|r| r.request_id == IDENTITY_CHECK_REQUEST_ID);
+    let parsed = mitgliedsnr.parse::<u64>().ok();
+
+    rsx! {
+        div { class: "alert alert-light border small mt-3 mb-0",
+            div { class: "d-flex align-items-center",
+                strong { "Identity-Prüfung" }
+                div { class: "ms-auto",
+                    Button {
+                        color: Color::Secondary,
+                        outline: true,
+                        size: Size::Sm,
+                        disabled: parsed.is_none(),
+                        onclick: move |_| {
+                            let Some(nr) = parsed else { return };
+                            info!("Computing expected identity for {nr}");
+                            if let Err(e) = compute(IDENTITY_CHECK_REQUEST_ID.to_string(), nr) {
+                                error!("compute_expected_identity failed: {e:?}");
+                            }
+                        },
+                        Icon { name: "calculator", class: "me-1" }
+                        "Erwartete Identity berechnen"
+                    }
+                }
+            }
+            if parsed.is_none() {
+                div { class: "text-muted mt-1", "Mitgliedsnummer ist keine Zahl: {mitgliedsnr}" }
+            }
+            if let Some(expected) = expected {
+                div { class: "mt-2", style: "word-break: break-all;",
+                    div { "Issuer: " code { "{expected.issuer}" } }
+                    div { "Erwartet ({expected.mitgliedsnr}): " code { "{expected.identity}" } }
+                    if let Some(live) = live {
+                        div { "Verbunden: " code { "{live}" } }
+                        if live == expected.identity {
+                            Badge { class: "mt-1", color: Color::Success, "Identity stimmt überein" }
+                        } else {
+                            Badge { class: "mt-1", color: Color::Danger, "Identity weicht ab" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Skew above this many seconds is highlighted in the JWT timing panel.
+const JWT_SKEW_WARN_SECS: i64 = 60;
+
+/// The JWT timing panel is shown only with `?debug_jwt` in the URL.
+fn jwt_debug_enabled() -> bool {
+    web_sys::window()
+        .and_then(|w| w.location().search().ok())
+        .map_or(false, |search| {
+            search
+                .trim_start_matches('?')
+                .split('&')
+                .any(|p| p == "debug_jwt" || p.starts_with("debug_jwt="))
+        })
+}
+
+/// Decoded token timing against the browser clock, for chasing clock-skew auth failures.
+/// A positive skew means the token was issued "in the future", i.e. the browser clock is behind.
+#[component]
+fn JwtTimingPanel(times: Option<JwtTimes>) -> Element {
+    let Some(times) = times else {
+        return rsx! {
+            div { class: "alert alert-secondary small mt-3 mb-0", "Kein dekodierbares ID Token." }
+        };
+    };
+    let now = unix_now();
+    let expires_in = times.expires_in(now);
+    let skew = times.iat.map(|iat| (iat - now).max(0));
+    let expired = expires_in.map_or(false, |secs| secs <= 0);
+    let large_skew = skew.map_or(false, |secs| secs > JWT_SKEW_WARN_SECS);
+    let class = if expired || large_skew {
+        "alert alert-danger small mt-3 mb-0"
+    } else {
+        "alert alert-light border small mt-3 mb-0"
+    };
+    let fmt = |v: Option<i64>| v.map_or("–".to_string(), |v| v.to_string());
+
+    rsx! {
+        div { class: "{class}",
+            h6 { class: "mb-2",
+                Icon { name: "clock-history", class: "me-1" }
+                "JWT-Zeiten"
+            }
+            table { class: "table table-sm mb-0 font-monospace",
+                tbody {
+                    tr { th { "iat" } td { "{fmt(times.iat)}" } }
+                    tr { th { "exp" } td { "{fmt(times.exp)}" } }
+                    tr { th { "Browser-Zeit" } td { "{now}" } }
+                    tr {
+                        th { "expires_in (s)" }
+                        td { "{fmt(expires_in)}" if expired { " (abgelaufen)" } }
+                    }
+                    tr {
+                        th { "Uhrabweichung (s)" }
+                        td {
+                            "{fmt(skew)}"
+                            if large_skew { " (Browser-Uhr geht nach)" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
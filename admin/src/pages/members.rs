@@ -3,26 +3,64 @@ use ::dioxus::{
     prelude::*,
 };
 use dioxus_bootstrap_css::prelude::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 
+use crate::config::AdminConfig;
+use crate::hooks::reducer_outcome::use_reducer_add_account_alias_then;
 use crate::module_bindings::dioxus::{
-    use_reducer_add_subscription, use_reducer_remove_subscription, use_table_message_categories,
-    use_table_visible_accounts, use_table_visible_subscriptions,
+    use_reducer_add_account_tag, use_reducer_add_subscription, use_reducer_remove_account_alias,
+    use_reducer_remove_account_tag, use_reducer_remove_subscription, use_reducer_set_account_name,
+    use_reducer_update_account, use_table_message_categories, use_table_visible_account_aliases,
+    use_table_visible_account_tags, use_table_visible_accounts, use_table_visible_subscriptions,
 };
+use crate::module_bindings::Account;
 
 /// Admin-only view: all members with their current subscriptions.
 /// Admins can add or remove subscriptions on behalf of any member.
 #[component]
 pub fn MembersPage() -> Element {
     let accounts = use_table_visible_accounts();
+    let tags = use_table_visible_account_tags();
     let subscriptions = use_table_visible_subscriptions();
     let categories = use_table_message_categories();
     let add_subscription = use_reducer_add_subscription();
     let remove_subscription = use_reducer_remove_subscription();
+    let update_account = use_reducer_update_account();
+    let set_account_name = use_reducer_set_account_name();
+    let config = use_context::<AdminConfig>();
 
     // Which account's inline add-subscription form is currently open.
     let mut add_form_account: Signal<Option<u64>> = use_signal(|| None);
     // Selected category id in that form (0 = nothing selected).
     let mut add_form_category: Signal<u64> = use_signal(|| 0);
+    // Account whose identity/claims panel is expanded.
+    let mut claims_account: Signal<Option<u64>> = use_signal(|| None);
+    // Account whose identity was copied last (for the button feedback).
+    let mut copied_account: Signal<Option<u64>> = use_signal(|| None);
+    // Account currently being edited in the modal.
+    let mut editing: Signal<Option<Account>> = use_signal(|| None);
+    // Account whose name is being changed inline, and the name typed so far.
+    let mut renaming: Signal<Option<u64>> = use_signal(|| None);
+    let mut rename_value = use_signal(String::new);
+    // Only accounts with this tag are listed (empty = all).
+    let mut tag_filter = use_signal(String::new);
+
+    let mut all_tags: Vec<String> = tags().into_iter().map(|t| t.tag).collect();
+    all_tags.sort();
+    all_tags.dedup();
+    let shown_accounts: Vec<Account> = if tag_filter.read().is_empty() {
+        accounts()
+    } else {
+        let tagged: Vec<u64> = tags()
+            .into_iter()
+            .filter(|t| t.tag == *tag_filter.read())
+            .map(|t| t.account_id)
+            .collect();
+        accounts()
+            .into_iter()
+            .filter(|a| tagged.contains(&a.id))
+            .collect()
+    };
 
     rsx! {
         Container { fluid: true, class: "mt-4",
@@ -37,6 +75,20 @@ pub fn MembersPage() -> Element {
                         "registrierte Mitglieder"
                     }
                 }
+                if !all_tags.is_empty() {
+                    Col { class: "d-flex align-items-center justify-content-end",
+                        Icon { name: "tags", class: "me-2 text-muted" }
+                        select {
+                            class: "form-select form-select-sm",
+                            style: "width: auto; min-width: 10rem;",
+                            onchange: move |e| tag_filter.set(e.value()),
+                            option { value: "", selected: tag_filter.read().is_empty(), "Alle Mitglieder" }
+                            for tag in all_tags {
+                                option { value: "{tag}", selected: *tag_filter.read() == tag, "{tag}" }
+                            }
+                        }
+                    }
+                }
             }
 
             if accounts().is_empty() {
@@ -62,7 +114,7 @@ pub fn MembersPage() -> Element {
                                     }
                                 }
                                 tbody {
-                                    for account in accounts() {
+                                    for account in shown_accounts {
                                         {
                                             let acct_id = account.id;
                                             let acct_email = account.email.clone();
@@ -71,14 +123,131 @@ pub fn MembersPage() -> Element {
                                                 .filter(|s| { s.subscriber_account_id == acct_id && s.active })
                                                 .collect();
                                             let is_form_open = add_form_account() == Some(acct_id);
+                                            let identity_hex = account.identity.to_string();
+                                            let identity_short = format!("{}…", &identity_hex[..12]);
+                                            let claims_open = claims_account() == Some(acct_id);
                                             rsx! {
                                                 tr {
                                                     td {
                                                         code { "{account.id}" }
+                                                        div { class: "d-flex align-items-center gap-1 mt-1",
+                                                            code { class: "small text-muted", title: "{identity_hex}", "{identity_short}" }
+                                                            {
+                                                                let hex_to_copy = identity_hex.clone();
+                                                                rsx! {
+                                                                    button {
+                                                                        class: "btn btn-sm btn-link p-0",
+                                                                        title: "Identität kopieren",
+                                                                        onclick: move |_| {
+                                                                            let hex_to_copy = hex_to_copy.clone();
+                                                                            spawn_local(async move {
+                                                                                if let Some(window) = web_sys::window() {
+                                                                                    let promise = window.navigator().clipboard().write_text(&hex_to_copy);
+                                                                                    match JsFuture::from(promise).await {
+                                                                                        Ok(_) => copied_account.set(Some(acct_id)),
+                                                                                        Err(e) => error!("Failed to copy identity to clipboard: {e:?}"),
+                                                                                    }
+                                                                                } else {
+                                                                                    error!("No window object available to access clipboard");
+                                                                                }
+                                                                            });
+                                                                        },
+                                                                        if copied_account() == Some(acct_id) {
+                                                                            Icon { name: "clipboard-check" }
+                                                                        } else {
+                                                                            Icon { name: "clipboard" }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
+                                                            button {
+                                                                class: "btn btn-sm btn-link p-0",
+                                                                title: "Claims anzeigen",
+                                                                onclick: move |_| {
+                                                                    if claims_open {
+                                                                        claims_account.set(None);
+                                                                    } else {
+                                                                        claims_account.set(Some(acct_id));
+                                                                    }
+                                                                },
+                                                                if claims_open {
+                                                                    Icon { name: "chevron-up" }
+                                                                } else {
+                                                                    Icon { name: "chevron-down" }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                    td {
+                                                        if renaming() == Some(acct_id) {
+                                                            {
+                                                                let rename = set_account_name.clone();
+                                                                rsx! {
+                                                                    div { class: "input-group input-group-sm",
+                                                                        input {
+                                                                            class: "form-control",
+                                                                            r#type: "text",
+                                                                            value: "{rename_value}",
+                                                                            oninput: move |e| rename_value.set(e.value()),
+                                                                        }
+                                                                        Button {
+                                                                            color: Color::Success,
+                                                                            size: Size::Sm,
+                                                                            disabled: rename_value.read().trim().is_empty(),
+                                                                            onclick: move |_| {
+                                                                                info!("Renaming account {acct_id}");
+                                                                                if let Err(e) = rename(acct_id, rename_value()) {
+                                                                                    error!("set_account_name failed: {e:?}");
+                                                                                }
+                                                                                renaming.set(None);
+                                                                            },
+                                                                            Icon { name: "check-lg" }
+                                                                        }
+                                                                        Button {
+                                                                            color: Color::Secondary,
+                                                                            size: Size::Sm,
+                                                                            onclick: move |_| renaming.set(None),
+                                                                            Icon { name: "x-lg" }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
+                                                        } else {
+                                                            "{account.name}"
+                                                            if account.name_overridden {
+                                                                Badge { color: Color::Secondary, class: "ms-2", "manuell" }
+                                                            }
+                                                            {
+                                                                let current_name = account.name.clone();
+                                                                rsx! {
+                                                                    button {
+                                                                        class: "btn btn-sm btn-link p-0 ms-2",
+                                                                        title: "Namen ändern",
+                                                                        onclick: move |_| {
+                                                                            rename_value.set(current_name.clone());
+                                                                            renaming.set(Some(acct_id));
+                                                                        },
+                                                                        Icon { name: "input-cursor-text" }
+                                                                    }
+                                                                }
+                                                            }
+                                                            {
+                                                                let account_to_edit = account.clone();
+                                                                rsx! {
+                                                                    button {
+                                                                        class: "btn btn-sm btn-link p-0 ms-2",
+                                                                        title: "Mitglied bearbeiten",
+                                                                        onclick: move |_| editing.set(Some(account_to_edit.clone())),
+                                                                        Icon { name: "pencil" }
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                        AccountTags { account_id: acct_id }
                                                     }
-                                                    td { "{account.name}" }
                                                     td {
                                                         small { class: "text-muted", "{account.email}" }
+                                                        AccountAliases { account_id: acct_id }
                                                     }
                                                     td {
                                                         if account.is_active {
@@ -130,7 +299,7 @@ pub fn MembersPage() -> Element {
                                                                         }
                                                                     },
                                                                     option { value: "0", "– Kein Thema gewählt –" }
-                                                                    for cat in categories().into_iter().filter(|c| c.active) {
+                                                                    for cat in categories().into_iter().filter(|c| c.active && c.deleted_at.is_none()) {
                                                                         {
                                                                             let already = member_subs
                                                                                 .iter()
@@ -195,6 +364,31 @@ pub fn MembersPage() -> Element {
                                                         }
                                                     }
                                                 }
+                                                if claims_open {
+                                                    tr { class: "table-light",
+                                                        td { colspan: "6",
+                                                            table { class: "table table-sm table-borderless mb-0 small",
+                                                                tbody {
+                                                                    tr {
+                                                                        th { class: "text-muted pe-3", style: "width: 6rem;", "Identität" }
+                                                                        td { class: "font-monospace text-break", "{identity_hex}" }
+                                                                    }
+                                                                    tr {
+                                                                        th { class: "text-muted pe-3", "Issuer" }
+                                                                        td { class: "font-monospace text-break", "{config.oauth.issuer_url}" }
+                                                                    }
+                                                                    tr {
+                                                                        th { class: "text-muted pe-3", "Subject" }
+                                                                        td { class: "font-monospace", "{account.id}" }
+                                                                    }
+                                                                }
+                                                            }
+                                                            p { class: "text-muted small mb-0 mt-1",
+                                                                "Ohne identity_hex von Django wird die Identität aus Issuer und Subject (Mitgliedsnummer) abgeleitet."
+                                                            }
+                                                        }
+                                                    }
+                                                }
                                             }
                                         }
                                     }
@@ -204,6 +398,258 @@ pub fn MembersPage() -> Element {
                     },
                 }
             }
+
+            if let Some(account) = editing() {
+                EditAccountModal {
+                    account,
+                    on_save: move |(id, name, email, is_active): (u64, String, String, bool)| {
+                        info!("Updating account {id}");
+                        if let Err(e) = update_account(id, name, email, is_active) {
+                            error!("update_account failed: {e:?}");
+                        }
+                        editing.set(None);
+                    },
+                    on_close: move |_| editing.set(None),
+                }
+            }
+        }
+    }
+}
+
+/// Modal form for correcting a member's name, email and active flag without a Django sync.
+#[component]
+fn EditAccountModal(
+    account: Account,
+    on_save: EventHandler<(u64, String, String, bool)>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut name = use_signal(|| account.name.clone());
+    let mut email = use_signal(|| account.email.clone());
+    let mut is_active = use_signal(|| account.is_active);
+    let id = account.id;
+
+    rsx! {
+        div { class: "modal d-block", tabindex: "-1", role: "dialog",
+            div { class: "modal-dialog modal-dialog-centered",
+                div { class: "modal-content",
+                    div { class: "modal-header",
+                        h5 { class: "modal-title",
+                            Icon { name: "pencil", class: "me-2" }
+                            "Mitglied {id} bearbeiten"
+                        }
+                        button {
+                            class: "btn-close",
+                            "aria-label": "Schließen",
+                            onclick: move |_| on_close.call(()),
+                        }
+                    }
+                    div { class: "modal-body",
+                        div { class: "mb-3",
+                            label { class: "form-label", "Name" }
+                            input {
+                                class: "form-control",
+                                r#type: "text",
+                                value: "{name}",
+                                oninput: move |e| name.set(e.value()),
+                            }
+                        }
+                        div { class: "mb-3",
+                            label { class: "form-label", "E-Mail" }
+                            input {
+                                class: "form-control",
+                                r#type: "email",
+                                value: "{email}",
+                                oninput: move |e| email.set(e.value()),
+                            }
+                        }
+                        div { class: "form-check",
+                            input {
+                                class: "form-check-input",
+                                r#type: "checkbox",
+                                id: "edit-account-active",
+                                checked: is_active(),
+                                onchange: move |e| is_active.set(e.checked()),
+                            }
+                            label { class: "form-check-label", r#for: "edit-account-active", "Aktiv" }
+                        }
+                        p { class: "text-muted small mt-3 mb-0",
+                            "Ein späterer Django-Sync mit neuerem Änderungsdatum überschreibt diese Werte."
+                        }
+                    }
+                    div { class: "modal-footer",
+                        Button {
+                            color: Color::Secondary,
+                            onclick: move |_| on_close.call(()),
+                            "Abbrechen"
+                        }
+                        Button {
+                            color: Color::Primary,
+                            disabled: email.read().trim().is_empty(),
+                            onclick: move |_| on_save.call((id, name(), email(), is_active())),
+                            Icon { name: "check-lg", class: "me-1" }
+                            "Speichern"
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "modal-backdrop show" }
+    }
+}
+
+/// Further sender addresses of one account, removable, with an inline field to add another one.
+/// Mail from these addresses counts as the account's for subscription checks. The field is only
+/// cleared once the server committed the alias; a refused one stays there with the error.
+#[component]
+fn AccountAliases(account_id: u64) -> Element {
+    let aliases = use_table_visible_account_aliases();
+    let (add_alias, add_result) = use_reducer_add_account_alias_then();
+    let remove_alias = use_reducer_remove_account_alias();
+    let mut new_alias = use_signal(String::new);
+    let mut add_error: Signal<Option<String>> = use_signal(|| None);
+    let mut is_sending = use_signal(|| false);
+
+    {
+        let mut add_result = add_result;
+        use_effect(move || {
+            if let Some(res) = add_result() {
+                is_sending.set(false);
+                match res {
+                    Ok(()) => {
+                        new_alias.set(String::new());
+                        add_error.set(None);
+                    }
+                    Err(e) => add_error.set(Some(e)),
+                }
+                // clear the result so the next invocation can be observed
+                add_result.set(None);
+            }
+        });
+    }
+
+    let own: Vec<_> = aliases()
+        .into_iter()
+        .filter(|a| a.account_id == account_id)
+        .collect();
+
+    rsx! {
+        for alias in own {
+            {
+                let alias_id = alias.id;
+                let remove = remove_alias.clone();
+                rsx! {
+                    div { class: "d-flex align-items-center",
+                        small { class: if !alias.verified { "text-muted fst-italic" } else { "text-muted" }, "{alias.email}" }
+                        button {
+                            class: "btn btn-link btn-sm text-danger p-0 ms-2",
+                            title: "Alias entfernen",
+                            onclick: move |_| {
+                                info!("Removing account alias {alias_id}");
+                                if let Err(e) = remove(alias_id) {
+                                    error!("remove_account_alias failed: {e:?}");
+                                }
+                            },
+                            Icon { name: "x-circle" }
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "input-group input-group-sm mt-1",
+            input {
+                class: "form-control",
+                r#type: "email",
+                placeholder: "alias@example.com",
+                value: "{new_alias}",
+                oninput: move |e| new_alias.set(e.value()),
+            }
+            Button {
+                color: Color::Secondary,
+                outline: true,
+                size: Size::Sm,
+                disabled: new_alias.read().trim().is_empty() || is_sending(),
+                onclick: {
+                    let add_alias = add_alias.clone();
+                    move |_| {
+                        let email = new_alias.read().trim().to_string();
+                        info!("Adding alias {email} to account {account_id}");
+                        is_sending.set(true);
+                        add_alias(account_id, email);
+                    }
+                },
+                Icon { name: "plus-lg" }
+            }
+        }
+        if let Some(e) = add_error() {
+            small { class: "text-danger d-block", "{e}" }
+        }
+    }
+}
+
+/// Admin tags of one account as removable badges, with an inline field to add another one.
+/// Tags are only labels for filtering; they don't affect routing.
+#[component]
+fn AccountTags(account_id: u64) -> Element {
+    let tags = use_table_visible_account_tags();
+    let add_tag = use_reducer_add_account_tag();
+    let remove_tag = use_reducer_remove_account_tag();
+    let mut new_tag = use_signal(String::new);
+
+    let own: Vec<_> = tags()
+        .into_iter()
+        .filter(|t| t.account_id == account_id)
+        .collect();
+
+    rsx! {
+        div { class: "mt-1",
+            for tag in own {
+                {
+                    let remove = remove_tag.clone();
+                    let tag_name = tag.tag.clone();
+                    rsx! {
+                        Badge {
+                            color: Color::Secondary,
+                            class: "me-1 mb-1 d-inline-flex align-items-center gap-1",
+                            "{tag.tag}"
+                            button {
+                                class: "btn-close btn-close-white",
+                                style: "font-size: 0.5rem;",
+                                "aria-label": "Tag entfernen",
+                                onclick: move |_| {
+                                    info!("Removing tag {tag_name} from account {account_id}");
+                                    if let Err(e) = remove(account_id, tag_name.clone()) {
+                                        error!("remove_account_tag failed: {e:?}");
+                                    }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "input-group input-group-sm mt-1",
+            input {
+                class: "form-control",
+                r#type: "text",
+                placeholder: "Tag",
+                value: "{new_tag}",
+                oninput: move |e| new_tag.set(e.value()),
+            }
+            Button {
+                color: Color::Secondary,
+                outline: true,
+                size: Size::Sm,
+                disabled: new_tag.read().trim().is_empty(),
+                onclick: move |_| {
+                    let tag = new_tag.read().trim().to_string();
+                    info!("Adding tag {tag} to account {account_id}");
+                    match add_tag(account_id, tag) {
+                        Ok(()) => new_tag.set(String::new()),
+                        Err(e) => error!("add_account_tag failed: {e:?}"),
+                    }
+                },
+                Icon { name: "tag" }
+            }
         }
     }
 }
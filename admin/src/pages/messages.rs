@@ -1,7 +1,9 @@
 use ::dioxus::prelude::*;
 use dioxus_bootstrap_css::prelude::*;
 
-use crate::module_bindings::dioxus::{use_table_message_categories, use_table_visible_messages};
+use crate::module_bindings::dioxus::{
+    use_table_message_categories, use_table_visible_delivery_outcomes, use_table_visible_messages,
+};
 
 // ---------------------------------------------------------------------------
 // Visual helpers
@@ -49,6 +51,16 @@ fn cat_btn_class(category_id: u64, selected: bool) -> &'static str {
     }
 }
 
+/// Badge colour of a `delivery_outcome.outcome`.
+fn outcome_badge_color(outcome: &str) -> Color {
+    match outcome {
+        "delivered" => Color::Success,
+        "deferred" => Color::Warning,
+        "bounced" => Color::Danger,
+        _ => Color::Secondary,
+    }
+}
+
 fn cat_badge_color(category_id: u64) -> Color {
     match category_id % 5 {
         0 => Color::Primary,
@@ -67,6 +79,7 @@ fn cat_badge_color(category_id: u64) -> Color {
 pub fn MessagesPage() -> Element {
     let messages = use_table_visible_messages();
     let categories = use_table_message_categories();
+    let outcomes = use_table_visible_delivery_outcomes();
 
     let mut selected_id: Signal<Option<u64>> = use_signal(|| None);
     let mut filter_category: Signal<Option<u64>> = use_signal(|| None);
@@ -86,6 +99,18 @@ pub fn MessagesPage() -> Element {
         .collect();
 
     let selected_msg = selected_id().and_then(|id| filtered.iter().find(|m| m.id == id).cloned());
+    // Delivery reports of the selected message, oldest first (only visible to admins).
+    let mut selected_outcomes: Vec<_> = selected_msg
+        .as_ref()
+        .and_then(|msg| msg.queue_id.clone())
+        .map(|queue_id| {
+            outcomes()
+                .into_iter()
+                .filter(|o| o.queue_id == queue_id)
+                .collect()
+        })
+        .unwrap_or_default();
+    selected_outcomes.sort_by_key(|o| o.recorded_at);
 
     rsx! {
         Container { fluid: true, class: "mt-4",
@@ -258,6 +283,20 @@ pub fn MessagesPage() -> Element {
                                                     td { class: "small", "{rt}" }
                                                 }
                                             }
+                                            if !selected_outcomes.is_empty() {
+                                                tr {
+                                                    th { class: "text-muted small pe-3", "Zustellung" }
+                                                    td { class: "small",
+                                                        for outcome in selected_outcomes {
+                                                            div { key: "{outcome.id}",
+                                                                Badge { color: outcome_badge_color(&outcome.outcome), class: "me-2", "{outcome.outcome}" }
+                                                                span { class: "text-muted me-2", "{outcome.recorded_at}" }
+                                                                "{outcome.detail}"
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
                                         }
                                     }
                                     hr { class: "my-3" }
@@ -39,7 +39,7 @@ pub fn SubscriptionsPage(user_info: UserInfo) -> Element {
             {
                 let active_cats: Vec<_> = categories()
                     .into_iter()
-                    .filter(|c| c.active)
+                    .filter(|c| c.active && c.deleted_at.is_none())
                     .collect();
                 if active_cats.is_empty() {
                     rsx! {
@@ -1,3 +1,5 @@
+use ::dioxus::prelude::*;
+
 use crate::module_bindings::dioxus::{
     use_connection_state, use_table_visible_admin_identities, ConnectionState,
 };
@@ -11,11 +13,21 @@ pub enum ActiveView {
     Debug,
 }
 
+/// Whether the logged-in user is in `OAuthConfig.admin_group`, provided by `AuthenticatedApp`.
+#[derive(Clone, Copy)]
+pub struct AdminGroupMember(pub Signal<bool>);
+
 /// Returns `true` when the currently connected SpacetimeDB identity is present
-/// in the `admin_identities` table.  Returns `false` while not yet connected.
+/// in the `admin_identities` table, or the user is in the configured admin group.
+/// Returns `false` while not yet connected.
+/// Group membership only unlocks the UI; reducers still check `admin_identities`.
 pub fn use_is_admin() -> bool {
     let admin_identities = use_table_visible_admin_identities();
     let state = use_connection_state();
+    let group_member = try_use_context::<AdminGroupMember>();
+    if group_member.is_some_and(|member| (member.0)()) {
+        return true;
+    }
     if let ConnectionState::Connected(identity, _) = state() {
         admin_identities().iter().any(|a| a.identity == identity)
     } else {
@@ -1,4 +1,41 @@
-use std::{env, time::Duration};
+use std::{env, error::Error, fs, path::Path, time::Duration};
+
+use serde::Deserialize;
+
+/// Settings read from the JSON file named by `CONFIG_FILE`. Every field is optional and is
+/// overridden by the environment variable of the same setting.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SenderFileConfig {
+    pub spacetimedb_uri: Option<String>,
+    pub spacetimedb_database_name: Option<String>,
+    pub spacetimedb_token: Option<String>,
+    pub smtp_host: Option<String>,
+    pub smtp_port: Option<u16>,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_use_tls: Option<bool>,
+    pub poll_interval_ms: Option<u64>,
+    pub message_id_domain: Option<String>,
+    pub unsubscribe_base_url: Option<String>,
+    pub otlp_endpoint: Option<String>,
+    pub notification_webhook_url: Option<String>,
+    pub status_addr: Option<String>,
+    pub oidc_issuer_url: Option<String>,
+}
+
+/// The environment variable `name`, or `fallback` (the file value) when it is unset.
+fn env_or(name: &str, fallback: Option<String>) -> Option<String> {
+    env::var(name).ok().or(fallback)
+}
+
+/// Like `env_or`, for values that have to be parsed. An unparsable variable falls back too.
+fn parsed_env_or<T: std::str::FromStr>(name: &str, fallback: Option<T>) -> Option<T> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse::<T>().ok())
+        .or(fallback)
+}
 
 #[derive(Debug, Clone)]
 pub struct SenderConfig {
@@ -13,51 +50,163 @@ pub struct SenderConfig {
     pub poll_interval: Duration,
     pub message_id_domain: String,
     pub unsubscribe_base_url: String,
-    pub otlp_endpoint: String,
+    /// OTLP gRPC endpoint for traces and logs; unset disables the export.
+    pub otlp_endpoint: Option<String>,
+    /// Slack/Matrix-style webhook for quarantine and reject alerts; unset disables them.
+    pub notification_webhook_url: Option<String>,
+    /// Listen address of the `/status` endpoint (e.g. `127.0.0.1:9090`); unset disables it.
+    pub status_addr: Option<String>,
+    /// OIDC issuer whose discovery endpoint `/status` checks; unset skips the check.
+    pub oidc_issuer_url: Option<String>,
 }
 
 impl SenderConfig {
+    /// Settings a reload (SIGHUP) leaves alone: they belong to the SpacetimeDB connection,
+    /// the telemetry setup or the status endpoint, which are only set up at startup.
+    pub const RESTART_ONLY: [&'static str; 6] = [
+        "spacetimedb_uri",
+        "spacetimedb_database_name",
+        "spacetimedb_token",
+        "otlp_endpoint",
+        "status_addr",
+        "oidc_issuer_url",
+    ];
+
+    /// Names of the settings that differ from `other`. Only the names, since some values are
+    /// secrets.
+    pub fn changed_fields(&self, other: &SenderConfig) -> Vec<&'static str> {
+        [
+            (
+                "spacetimedb_uri",
+                self.spacetimedb_uri != other.spacetimedb_uri,
+            ),
+            (
+                "spacetimedb_database_name",
+                self.spacetimedb_database_name != other.spacetimedb_database_name,
+            ),
+            (
+                "spacetimedb_token",
+                self.spacetimedb_token != other.spacetimedb_token,
+            ),
+            ("smtp_host", self.smtp_host != other.smtp_host),
+            ("smtp_port", self.smtp_port != other.smtp_port),
+            ("smtp_username", self.smtp_username != other.smtp_username),
+            ("smtp_password", self.smtp_password != other.smtp_password),
+            ("smtp_use_tls", self.smtp_use_tls != other.smtp_use_tls),
+            ("poll_interval", self.poll_interval != other.poll_interval),
+            (
+                "message_id_domain",
+                self.message_id_domain != other.message_id_domain,
+            ),
+            (
+                "unsubscribe_base_url",
+                self.unsubscribe_base_url != other.unsubscribe_base_url,
+            ),
+            ("otlp_endpoint", self.otlp_endpoint != other.otlp_endpoint),
+            (
+                "notification_webhook_url",
+                self.notification_webhook_url != other.notification_webhook_url,
+            ),
+            ("status_addr", self.status_addr != other.status_addr),
+            (
+                "oidc_issuer_url",
+                self.oidc_issuer_url != other.oidc_issuer_url,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    /// The names of `changed_fields`, split into the `RESTART_ONLY` ones, which wait for a
+    /// restart (a new SpacetimeDB connection among them), and the ones a reload applies.
+    pub fn reload_changes(
+        &self,
+        reloaded: &SenderConfig,
+    ) -> (Vec<&'static str>, Vec<&'static str>) {
+        self.changed_fields(reloaded)
+            .into_iter()
+            .partition(|field| Self::RESTART_ONLY.contains(field))
+    }
+
+    /// `reloaded` with the `RESTART_ONLY` settings of `self`, i.e. what a reload applies.
+    pub fn apply_reload(&self, reloaded: SenderConfig) -> SenderConfig {
+        SenderConfig {
+            spacetimedb_uri: self.spacetimedb_uri.clone(),
+            spacetimedb_database_name: self.spacetimedb_database_name.clone(),
+            spacetimedb_token: self.spacetimedb_token.clone(),
+            otlp_endpoint: self.otlp_endpoint.clone(),
+            status_addr: self.status_addr.clone(),
+            oidc_issuer_url: self.oidc_issuer_url.clone(),
+            ..reloaded
+        }
+    }
+
+    /// Read the file named by `CONFIG_FILE` if set, otherwise only the environment.
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        match env::var("CONFIG_FILE") {
+            Ok(path) if !path.trim().is_empty() => Self::from_file(path),
+            _ => Ok(Self::from_env()),
+        }
+    }
+
+    /// Settings from a JSON file, with environment variables taking precedence over it.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Cannot read config file {}: {e}", path.display()))?;
+        let file: SenderFileConfig = serde_json::from_str(&content)
+            .map_err(|e| format!("Invalid config file {}: {e}", path.display()))?;
+        Ok(Self::from_sources(file))
+    }
+
+    /// Settings from environment variables only.
     pub fn from_env() -> Self {
-        let spacetimedb_uri =
-            env::var("SPACETIMEDB_URI").unwrap_or_else(|_| "http://127.0.0.1:3000".to_string());
+        Self::from_sources(SenderFileConfig::default())
+    }
+
+    fn from_sources(file: SenderFileConfig) -> Self {
+        let spacetimedb_uri = env_or("SPACETIMEDB_URI", file.spacetimedb_uri)
+            .unwrap_or_else(|| "http://127.0.0.1:3000".to_string());
         let spacetimedb_database_name =
-            env::var("SPACETIMEDB_DATABASE_NAME").unwrap_or_else(|_| "kommunikation".to_string());
-        let otlp_endpoint =
-            env::var("OTLP_ENDPOINT").unwrap_or_else(|_| "http://localhost:4317".to_string());
-        let smtp_host = env::var("SMTP_HOST").unwrap_or_else(|_| "mail-eu.smtp2go.com".to_string());
-        let smtp_port = env::var("SMTP_PORT")
-            .ok()
-            .and_then(|value| value.parse::<u16>().ok())
-            .unwrap_or(8465);
-        let smtp_username = env::var("SMTP_USERNAME").ok();
-        let smtp_password = env::var("SMTP_PASSWORD").ok();
-        let smtp_use_tls = env::var("SMTP_USE_TLS")
-            .ok()
-            .and_then(|value| value.parse::<bool>().ok())
-            .unwrap_or(true);
-        let poll_interval = env::var("SENDER_POLL_INTERVAL_MS")
+            env_or("SPACETIMEDB_DATABASE_NAME", file.spacetimedb_database_name)
+                .unwrap_or_else(|| "kommunikation".to_string());
+        // The standard OpenTelemetry variable wins; `OTLP_ENDPOINT` is kept for existing setups.
+        let otlp_endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .or_else(|_| env::var("OTLP_ENDPOINT"))
             .ok()
-            .and_then(|value| value.parse::<u64>().ok())
+            .or(file.otlp_endpoint)
+            .filter(|endpoint| !endpoint.trim().is_empty());
+        let smtp_host = env_or("SMTP_HOST", file.smtp_host)
+            .unwrap_or_else(|| "mail-eu.smtp2go.com".to_string());
+        let smtp_port = parsed_env_or("SMTP_PORT", file.smtp_port).unwrap_or(8465);
+        let smtp_username = env_or("SMTP_USERNAME", file.smtp_username);
+        let smtp_password = env_or("SMTP_PASSWORD", file.smtp_password);
+        let smtp_use_tls = parsed_env_or("SMTP_USE_TLS", file.smtp_use_tls).unwrap_or(true);
+        let poll_interval = parsed_env_or("SENDER_POLL_INTERVAL_MS", file.poll_interval_ms)
             .map(Duration::from_millis)
             .unwrap_or_else(|| Duration::from_millis(5000));
-        let message_id_domain = env::var("MAIL_MESSAGE_ID_DOMAIN").unwrap_or_else(|_| {
-            spacetimedb_uri
-                .split_once("//")
-                .map(|(_, rest)| rest.split('/').next().unwrap_or("solawis.de").to_string())
-                .unwrap_or_else(|| "solawis.de".to_string())
-        });
-        let unsubscribe_base_url = env::var("MAIL_UNSUBSCRIBE_BASE_URL").unwrap_or_else(|_| {
-            format!(
-                "{}/v1/database/{}/route/mailing-list/unsubscribe",
-                spacetimedb_uri.trim_end_matches('/'),
-                spacetimedb_database_name
-            )
-        });
+        let message_id_domain = env_or("MAIL_MESSAGE_ID_DOMAIN", file.message_id_domain)
+            .unwrap_or_else(|| {
+                spacetimedb_uri
+                    .split_once("//")
+                    .map(|(_, rest)| rest.split('/').next().unwrap_or("solawis.de").to_string())
+                    .unwrap_or_else(|| "solawis.de".to_string())
+            });
+        let unsubscribe_base_url = env_or("MAIL_UNSUBSCRIBE_BASE_URL", file.unsubscribe_base_url)
+            .unwrap_or_else(|| {
+                format!(
+                    "{}/v1/database/{}/route/mailing-list/unsubscribe",
+                    spacetimedb_uri.trim_end_matches('/'),
+                    spacetimedb_database_name
+                )
+            });
 
         Self {
             spacetimedb_uri,
             spacetimedb_database_name,
-            spacetimedb_token: env::var("SPACETIMEDB_TOKEN").ok(),
+            spacetimedb_token: env_or("SPACETIMEDB_TOKEN", file.spacetimedb_token),
             smtp_host,
             smtp_port,
             smtp_username,
@@ -67,6 +216,84 @@ impl SenderConfig {
             message_id_domain,
             unsubscribe_base_url,
             otlp_endpoint,
+            notification_webhook_url: env_or(
+                "NOTIFICATION_WEBHOOK_URL",
+                file.notification_webhook_url,
+            )
+            .filter(|url| !url.trim().is_empty()),
+            status_addr: env_or("SENDER_STATUS_ADDR", file.status_addr)
+                .filter(|addr| !addr.trim().is_empty()),
+            oidc_issuer_url: env_or("OIDC_ISSUER_URL", file.oidc_issuer_url)
+                .filter(|url| !url.trim().is_empty()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SenderConfig {
+        SenderConfig {
+            spacetimedb_uri: "http://localhost:3000".to_string(),
+            spacetimedb_database_name: "kommunikationszentrum".to_string(),
+            spacetimedb_token: Some("stdb-token".to_string()),
+            smtp_host: "smtp.example.org".to_string(),
+            smtp_port: 587,
+            smtp_username: Some("sender".to_string()),
+            smtp_password: Some("altes-geheimnis".to_string()),
+            smtp_use_tls: true,
+            poll_interval: Duration::from_millis(1000),
+            message_id_domain: "example.org".to_string(),
+            unsubscribe_base_url: "https://example.org/unsubscribe".to_string(),
+            otlp_endpoint: None,
+            notification_webhook_url: None,
+            status_addr: None,
+            oidc_issuer_url: None,
+        }
+    }
+
+    #[test]
+    fn reloaded_secret_is_applied_and_named_without_its_value() {
+        let current = config();
+        let reloaded = SenderConfig {
+            smtp_password: Some("neues-geheimnis".to_string()),
+            ..config()
+        };
+
+        let (restart_only, applied) = current.reload_changes(&reloaded);
+        assert!(restart_only.is_empty());
+        assert_eq!(applied, ["smtp_password"]);
+        // What the reload logs is the names only
+        assert!(!applied.join(", ").contains("geheimnis"));
+
+        let next = current.apply_reload(reloaded);
+        assert_eq!(next.smtp_password.as_deref(), Some("neues-geheimnis"));
+    }
+
+    #[test]
+    fn new_spacetimedb_uri_or_module_waits_for_a_reconnect() {
+        let current = config();
+        let reloaded = SenderConfig {
+            spacetimedb_uri: "https://stdb.example.org".to_string(),
+            spacetimedb_database_name: "kommunikationszentrum-neu".to_string(),
+            poll_interval: Duration::from_millis(500),
+            ..config()
+        };
+
+        let (restart_only, applied) = current.reload_changes(&reloaded);
+        assert_eq!(
+            restart_only,
+            ["spacetimedb_uri", "spacetimedb_database_name"]
+        );
+        assert_eq!(applied, ["poll_interval"]);
+
+        let next = current.apply_reload(reloaded);
+        assert_eq!(next.spacetimedb_uri, current.spacetimedb_uri);
+        assert_eq!(
+            next.spacetimedb_database_name,
+            current.spacetimedb_database_name
+        );
+        assert_eq!(next.poll_interval, Duration::from_millis(500));
+    }
+}
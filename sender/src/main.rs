@@ -1,6 +1,8 @@
 mod config;
 mod mail;
 mod module_bindings;
+mod notify;
+mod status;
 
 use config::SenderConfig;
 use lettre::{SmtpTransport, Transport};
@@ -8,18 +10,22 @@ use mail::{build_transport, compose_delivery, is_permanent_error, is_transient_e
 use module_bindings::{
     claim_next_mail_delivery, claim_next_mail_ingress, complete_mail_ingress,
     enqueue_mail_delivery, ensure_subscription_unsubscribe_token, fail_mail_delivery,
-    fail_mail_ingress, mark_mail_delivery_sent, retry_mail_ingress, schedule_mail_delivery_retry,
-    DbConnection, MailDelivery, MailIngress, MessageCategory, Subscription,
+    fail_mail_ingress, mark_mail_delivery_sent, mark_notification_delivered, ping,
+    retry_mail_ingress, schedule_mail_delivery_retry, DbConnection, MailDelivery, MailIngress,
+    MessageCategory, NotificationOut, Subscription,
 };
 use spacetimedb_sdk::{DbContext, Table, TableWithPrimaryKey as _, Timestamp};
+use status::{StatusContext, StatusState};
 use std::sync::{Arc, Mutex};
 use std::{collections::HashSet, error::Error, time::Duration};
+use tokio::signal::unix::{signal, SignalKind};
 use tokio::sync::Notify;
 
 use crate::module_bindings::{
     ActiveSubscriptionsTableAccess as _, ActiveUnsubscribeTokensTableAccess as _,
-    MessageCategoriesTableAccess as _, SenderMailDeliveriesTableAccess as _,
-    SenderMailIngressTableAccess as _,
+    MessageCategoriesTableAccess as _, MyPingResultsTableAccess as _,
+    SenderMailDeliveriesTableAccess as _, SenderMailIngressTableAccess as _,
+    VisibleNotificationsTableAccess as _,
 };
 use opentelemetry::global;
 use opentelemetry::trace::TracerProvider as _;
@@ -36,12 +42,34 @@ use tracing_subscriber::util::SubscriberInitExt;
 const INGESTED_STATE: &str = "processing";
 const DELIVERY_STATE: &str = "sending";
 
+/// How often the sender checks the round trip to the module with `ping`.
+const PING_INTERVAL: Duration = Duration::from_secs(60);
+/// A ping whose `my_ping_results` row has not arrived after this long counts as failed.
+const PING_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest an outgoing HTTP request (webhook post, OIDC check) may take before it is abandoned.
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
 struct OTelProviders {
     tracer_provider: SdkTracerProvider,
     logger_provider: SdkLoggerProvider,
 }
 
-fn init_tracing(config: &SenderConfig) -> OTelProviders {
+/// Set up the tracing subscriber. Spans and log events are exported via OTLP only when an
+/// endpoint is configured; otherwise they go to stdout only and `None` is returned.
+fn init_tracing(config: &SenderConfig) -> Option<OTelProviders> {
+    let env_filter = tracing_subscriber::EnvFilter::builder()
+        .with_default_directive("sender=info".parse().unwrap())
+        .from_env_lossy();
+
+    let Some(otlp_endpoint) = &config.otlp_endpoint else {
+        tracing_subscriber::registry()
+            .with(env_filter)
+            .with(tracing_subscriber::fmt::layer())
+            .init();
+        info!("OTLP export disabled (OTEL_EXPORTER_OTLP_ENDPOINT not set)");
+        return None;
+    };
+
     global::set_text_map_propagator(TraceContextPropagator::new());
 
     let resource = Resource::builder()
@@ -54,7 +82,7 @@ fn init_tracing(config: &SenderConfig) -> OTelProviders {
     // Tracing / span setup
     let span_exporter = opentelemetry_otlp::SpanExporter::builder()
         .with_tonic()
-        .with_endpoint(&config.otlp_endpoint)
+        .with_endpoint(otlp_endpoint)
         .build()
         .expect("Failed to build OTLP span exporter");
 
@@ -74,7 +102,7 @@ fn init_tracing(config: &SenderConfig) -> OTelProviders {
     // Log export setup: bridge tracing log events → OTLP logs → Alloy → Loki
     let log_exporter = opentelemetry_otlp::LogExporter::builder()
         .with_tonic()
-        .with_endpoint(&config.otlp_endpoint)
+        .with_endpoint(otlp_endpoint)
         .build()
         .expect("Failed to build OTLP log exporter");
 
@@ -87,31 +115,29 @@ fn init_tracing(config: &SenderConfig) -> OTelProviders {
         opentelemetry_appender_tracing::layer::OpenTelemetryTracingBridge::new(&logger_provider);
 
     tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::builder()
-                .with_default_directive("sender=info".parse().unwrap())
-                .from_env_lossy(),
-        )
+        .with(env_filter)
         .with(telemetry_layer)
         .with(log_bridge)
         .with(tracing_subscriber::fmt::layer())
         .init();
+    info!("Exporting traces and logs to {}", otlp_endpoint);
 
-    OTelProviders {
+    Some(OTelProviders {
         tracer_provider,
         logger_provider,
-    }
+    })
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let config = SenderConfig::from_env();
+    let mut config = SenderConfig::load()?;
     let otel_providers = init_tracing(&config);
 
     info!(event = "service_startup", "Starting sender service");
 
-    let connection = connect(&config)?;
+    let connection = Arc::new(connect(&config)?);
     subscribe(&connection);
+    let status_state = Arc::new(StatusState::default());
 
     // Drive SpacetimeDB natively as a pinned Tokio future
     let database_pump = connection.run_async();
@@ -125,6 +151,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // removed via on_update callbacks once the server confirms the state transition.
     let in_flight_ingresses: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
     let in_flight_deliveries: Arc<Mutex<HashSet<String>>> = Arc::new(Mutex::new(HashSet::new()));
+    let in_flight_notifications: Arc<Mutex<HashSet<u64>>> = Arc::new(Mutex::new(HashSet::new()));
 
     // Wake the loop when ingress rows are inserted
     {
@@ -178,13 +205,69 @@ async fn main() -> Result<(), Box<dyn Error>> {
             });
     }
 
-    let transport = build_transport(&config)?;
+    // Wake the loop when notifications arrive; forget delivered ones
+    {
+        let notify = notify.clone();
+        connection
+            .db
+            .visible_notifications()
+            .on_insert(move |_ctx, _row| {
+                notify.notify_one();
+            });
+    }
+    {
+        let set = in_flight_notifications.clone();
+        connection
+            .db
+            .visible_notifications()
+            .on_update(move |_ctx, _old_row, new_row| {
+                if new_row.delivered_at.is_some() {
+                    set.lock().unwrap().remove(&new_row.id);
+                }
+            });
+    }
+
+    // Forget the outstanding ping once its answer arrives
+    let pending_ping: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    {
+        let pending = pending_ping.clone();
+        let status_state = status_state.clone();
+        connection.db.my_ping_results().on_insert(move |_ctx, row| {
+            let mut pending = pending.lock().unwrap();
+            if pending.as_deref() == Some(row.request_id.as_str()) {
+                trace!("Ping {} answered", row.request_id);
+                *pending = None;
+                *status_state.last_reducer_ok.lock().unwrap() = Some(row.pinged_at);
+            }
+        });
+    }
+    let mut ping_interval = tokio::time::interval(PING_INTERVAL);
+
+    let mut transport = build_transport(&config)?;
+    let http_client = reqwest::Client::builder().timeout(HTTP_TIMEOUT).build()?;
     info!("sender connected as {:?}", connection.try_identity());
 
+    if let Some(addr) = config.status_addr.clone() {
+        let status_ctx = StatusContext {
+            connection: connection.clone(),
+            state: status_state.clone(),
+            client: http_client.clone(),
+            oidc_issuer_url: config.oidc_issuer_url.clone(),
+            // One missed ping is tolerated before the module counts as stale.
+            max_reducer_age: PING_INTERVAL * 2 + PING_TIMEOUT,
+        };
+        tokio::spawn(async move {
+            if let Err(error) = status::serve(addr, status_ctx).await {
+                error!("Status endpoint stopped: {}", error);
+            }
+        });
+    }
+
     info!("Entering purely reactive processing loop. Press Ctrl+C to stop.");
 
     let shutdown_signal = tokio::signal::ctrl_c();
     tokio::pin!(shutdown_signal);
+    let mut reload_signal = signal(SignalKind::hangup())?;
 
     // Bootstrap: trigger the doorbell once immediately so it checks for work upon startup
     notify.notify_one();
@@ -203,12 +286,23 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 break;
             }
 
+            // Re-read the config, e.g. after rotating the SMTP password
+            _ = reload_signal.recv() => {
+                reload_config(&mut config, &mut transport);
+            }
+
+            // Check that reducers still execute, not just that the socket is open
+            _ = ping_interval.tick() => {
+                send_ping(&connection, &pending_ping);
+            }
+
             // Wakes up immediately when notify_one() is called in callbacks
             _ = notify.notified() => {
                 trace!("Database subscription updated. Processing jobs...");
 
                 let fanout_res = process_fanout_jobs(&connection, &config, &in_flight_ingresses).await?;
                 let delivery_res = process_delivery_jobs(&connection, &transport, &in_flight_deliveries).await?;
+                process_notifications(&connection, &config, &http_client, &in_flight_notifications);
 
                 // If work was successfully performed, there might be more immediate backlogs.
                 // Re-trigger the doorbell so we loop again without sleeping.
@@ -222,12 +316,61 @@ async fn main() -> Result<(), Box<dyn Error>> {
     }
 
     info!("Shutting down tracing and logging...");
-    otel_providers.tracer_provider.shutdown()?;
-    otel_providers.logger_provider.shutdown()?;
+    if let Some(otel_providers) = otel_providers {
+        otel_providers.tracer_provider.shutdown()?;
+        otel_providers.logger_provider.shutdown()?;
+    }
     info!("Sender service stopped.");
     Ok(())
 }
 
+/// Re-read the configuration (SIGHUP). SMTP, mail and notification settings apply right away;
+/// the SMTP transport is rebuilt when an SMTP setting changed. `SenderConfig::RESTART_ONLY`
+/// settings keep their current values until the next restart. If the new config can't be read
+/// or the transport can't be built, everything stays as it was.
+fn reload_config(config: &mut SenderConfig, transport: &mut SmtpTransport) {
+    let reloaded = match SenderConfig::load() {
+        Ok(reloaded) => reloaded,
+        Err(error) => {
+            error!(
+                "Config reload failed, keeping the current config: {}",
+                error
+            );
+            return;
+        }
+    };
+    let (restart_only, applied) = config.reload_changes(&reloaded);
+    if restart_only.is_empty() && applied.is_empty() {
+        info!("Config reloaded, nothing changed");
+        return;
+    }
+    if !restart_only.is_empty() {
+        warn!(
+            "Config reload: {} changed, but only take effect after a restart",
+            restart_only.join(", ")
+        );
+    }
+    if applied.is_empty() {
+        return;
+    }
+
+    let next = config.apply_reload(reloaded);
+    if applied.iter().any(|field| field.starts_with("smtp_")) {
+        match build_transport(&next) {
+            Ok(rebuilt) => *transport = rebuilt,
+            Err(error) => {
+                error!(
+                    "Config reload: cannot build the SMTP transport, keeping the current config: {}",
+                    error
+                );
+                return;
+            }
+        }
+    }
+    *config = next;
+    info!("Config reloaded, applied: {}", applied.join(", "));
+}
+
 fn connect(config: &SenderConfig) -> Result<DbConnection, Box<dyn Error>> {
     let mut builder = DbConnection::builder()
         .with_uri(config.spacetimedb_uri.clone())
@@ -247,9 +390,32 @@ fn subscribe(connection: &DbConnection) {
         "SELECT * FROM active_subscriptions",
         "SELECT * FROM message_categories",
         "SELECT * FROM active_unsubscribe_tokens",
+        "SELECT * FROM visible_notifications",
+        "SELECT * FROM my_ping_results",
     ]);
 }
 
+/// Call `ping` and log an error if its `my_ping_results` row has not arrived within
+/// `PING_TIMEOUT`: the connection is up, but reducers or subscription updates are stuck.
+fn send_ping(connection: &DbConnection, pending: &Arc<Mutex<Option<String>>>) {
+    let request_id = format!("sender-{}", Timestamp::now().to_micros_since_unix_epoch());
+    if let Err(error) = connection.reducers().ping(request_id.clone()) {
+        warn!("ping failed: {:?}", error);
+        return;
+    }
+    *pending.lock().unwrap() = Some(request_id.clone());
+    let pending = pending.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(PING_TIMEOUT).await;
+        if pending.lock().unwrap().as_deref() == Some(request_id.as_str()) {
+            error!(
+                "No answer to ping {} after {:?}; the module does not seem to execute reducers",
+                request_id, PING_TIMEOUT
+            );
+        }
+    });
+}
+
 #[instrument(skip_all, fields(ingress_id = tracing::field::Empty, ingress_job = tracing::field::Empty))]
 async fn process_fanout_jobs(
     connection: &DbConnection,
@@ -527,6 +693,59 @@ async fn process_delivery_jobs(
     Ok(did_work)
 }
 
+/// POST undelivered notifications to `NOTIFICATION_WEBHOOK_URL` and mark them delivered. Each post
+/// runs in its own task, so a slow webhook doesn't hold up fanout and delivery. Failed posts are
+/// logged and retried on the next wake-up.
+#[instrument(skip_all)]
+fn process_notifications(
+    connection: &Arc<DbConnection>,
+    config: &SenderConfig,
+    client: &reqwest::Client,
+    in_flight: &Arc<Mutex<HashSet<u64>>>,
+) {
+    let Some(url) = &config.notification_webhook_url else {
+        return;
+    };
+    let pending: Vec<NotificationOut> = {
+        let mut set = in_flight.lock().unwrap();
+        let pending: Vec<NotificationOut> = connection
+            .db
+            .visible_notifications()
+            .iter()
+            .filter(|n| n.delivered_at.is_none() && !set.contains(&n.id))
+            .collect();
+        // In flight until the post failed or the server confirmed the delivery.
+        set.extend(pending.iter().map(|n| n.id));
+        pending
+    };
+
+    for notification in pending {
+        let connection = connection.clone();
+        let client = client.clone();
+        let url = url.clone();
+        let in_flight = in_flight.clone();
+        tokio::spawn(async move {
+            match notify::post_notification(&client, &url, &notification).await {
+                Ok(()) => {
+                    if let Err(error) = connection
+                        .reducers()
+                        .mark_notification_delivered(notification.id)
+                    {
+                        warn!(
+                            "mark_notification_delivered {} failed: {:?}",
+                            notification.id, error
+                        );
+                    }
+                }
+                Err(error) => {
+                    warn!("notification {} failed: {}", notification.id, error);
+                    in_flight.lock().unwrap().remove(&notification.id);
+                }
+            }
+        });
+    }
+}
+
 #[instrument(skip_all)]
 fn self_owned_delivery_jobs(
     connection: &DbConnection,
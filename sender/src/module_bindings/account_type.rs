@@ -13,6 +13,10 @@ pub struct Account {
     pub email: String,
     pub is_active: bool,
     pub last_synced: __sdk::Timestamp,
+    pub name_overridden: bool,
+    pub django_updated_at: Option<__sdk::Timestamp>,
+    pub manually_edited_at: Option<__sdk::Timestamp>,
+    pub sync_correlation_id: String,
 }
 
 impl __sdk::InModule for Account {
@@ -29,6 +33,10 @@ pub struct AccountCols {
     pub email: __sdk::__query_builder::Col<Account, String>,
     pub is_active: __sdk::__query_builder::Col<Account, bool>,
     pub last_synced: __sdk::__query_builder::Col<Account, __sdk::Timestamp>,
+    pub name_overridden: __sdk::__query_builder::Col<Account, bool>,
+    pub django_updated_at: __sdk::__query_builder::Col<Account, Option<__sdk::Timestamp>>,
+    pub manually_edited_at: __sdk::__query_builder::Col<Account, Option<__sdk::Timestamp>>,
+    pub sync_correlation_id: __sdk::__query_builder::Col<Account, String>,
 }
 
 impl __sdk::__query_builder::HasCols for Account {
@@ -41,6 +49,13 @@ impl __sdk::__query_builder::HasCols for Account {
             email: __sdk::__query_builder::Col::new(table_name, "email"),
             is_active: __sdk::__query_builder::Col::new(table_name, "is_active"),
             last_synced: __sdk::__query_builder::Col::new(table_name, "last_synced"),
+            name_overridden: __sdk::__query_builder::Col::new(table_name, "name_overridden"),
+            django_updated_at: __sdk::__query_builder::Col::new(table_name, "django_updated_at"),
+            manually_edited_at: __sdk::__query_builder::Col::new(table_name, "manually_edited_at"),
+            sync_correlation_id: __sdk::__query_builder::Col::new(
+                table_name,
+                "sync_correlation_id",
+            ),
         }
     }
 }
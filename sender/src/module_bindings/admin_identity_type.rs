@@ -8,6 +8,9 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[sats(crate = __lib)]
 pub struct AdminIdentity {
     pub identity: __sdk::Identity,
+    pub last_seen: __sdk::Timestamp,
+    pub label: String,
+    pub account_id: Option<u64>,
 }
 
 impl __sdk::InModule for AdminIdentity {
@@ -19,6 +22,9 @@ impl __sdk::InModule for AdminIdentity {
 /// Provides typed access to columns for query building.
 pub struct AdminIdentityCols {
     pub identity: __sdk::__query_builder::Col<AdminIdentity, __sdk::Identity>,
+    pub last_seen: __sdk::__query_builder::Col<AdminIdentity, __sdk::Timestamp>,
+    pub label: __sdk::__query_builder::Col<AdminIdentity, String>,
+    pub account_id: __sdk::__query_builder::Col<AdminIdentity, Option<u64>>,
 }
 
 impl __sdk::__query_builder::HasCols for AdminIdentity {
@@ -26,6 +32,9 @@ impl __sdk::__query_builder::HasCols for AdminIdentity {
     fn cols(table_name: &'static str) -> Self::Cols {
         AdminIdentityCols {
             identity: __sdk::__query_builder::Col::new(table_name, "identity"),
+            last_seen: __sdk::__query_builder::Col::new(table_name, "last_seen"),
+            label: __sdk::__query_builder::Col::new(table_name, "label"),
+            account_id: __sdk::__query_builder::Col::new(table_name, "account_id"),
         }
     }
 }
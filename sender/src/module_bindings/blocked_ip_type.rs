@@ -11,6 +11,7 @@ pub struct BlockedIp {
     pub reason: String,
     pub blocked_at: __sdk::Timestamp,
     pub active: bool,
+    pub source: String,
 }
 
 impl __sdk::InModule for BlockedIp {
@@ -25,6 +26,7 @@ pub struct BlockedIpCols {
     pub reason: __sdk::__query_builder::Col<BlockedIp, String>,
     pub blocked_at: __sdk::__query_builder::Col<BlockedIp, __sdk::Timestamp>,
     pub active: __sdk::__query_builder::Col<BlockedIp, bool>,
+    pub source: __sdk::__query_builder::Col<BlockedIp, String>,
 }
 
 impl __sdk::__query_builder::HasCols for BlockedIp {
@@ -35,6 +37,7 @@ impl __sdk::__query_builder::HasCols for BlockedIp {
             reason: __sdk::__query_builder::Col::new(table_name, "reason"),
             blocked_at: __sdk::__query_builder::Col::new(table_name, "blocked_at"),
             active: __sdk::__query_builder::Col::new(table_name, "active"),
+            source: __sdk::__query_builder::Col::new(table_name, "source"),
         }
     }
 }
@@ -7,12 +7,14 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
 #[sats(crate = __lib)]
 pub(super) struct HandleMtaHookArgs {
+    pub request_id: String,
     pub hook_data: String,
 }
 
 impl From<HandleMtaHookArgs> for super::Reducer {
     fn from(args: HandleMtaHookArgs) -> Self {
         Self::HandleMtaHook {
+            request_id: args.request_id,
             hook_data: args.hook_data,
         }
     }
@@ -33,8 +35,8 @@ pub trait handle_mta_hook {
     /// The reducer will run asynchronously in the future,
     ///  and this method provides no way to listen for its completion status.
     /// /// Use [`handle_mta_hook:handle_mta_hook_then`] to run a callback after the reducer completes.
-    fn handle_mta_hook(&self, hook_data: String) -> __sdk::Result<()> {
-        self.handle_mta_hook_then(hook_data, |_, _| {})
+    fn handle_mta_hook(&self, request_id: String, hook_data: String) -> __sdk::Result<()> {
+        self.handle_mta_hook_then(request_id, hook_data, |_, _| {})
     }
 
     /// Request that the remote module invoke the reducer `handle_mta_hook` to run as soon as possible,
@@ -45,6 +47,7 @@ pub trait handle_mta_hook {
     ///  and its status can be observed with the `callback`.
     fn handle_mta_hook_then(
         &self,
+        request_id: String,
         hook_data: String,
 
         callback: impl FnOnce(&super::ReducerEventContext, Result<Result<(), String>, __sdk::InternalError>)
@@ -56,13 +59,19 @@ pub trait handle_mta_hook {
 impl handle_mta_hook for super::RemoteReducers {
     fn handle_mta_hook_then(
         &self,
+        request_id: String,
         hook_data: String,
 
         callback: impl FnOnce(&super::ReducerEventContext, Result<Result<(), String>, __sdk::InternalError>)
             + Send
             + 'static,
     ) -> __sdk::Result<()> {
-        self.imp
-            .invoke_reducer_with_callback(HandleMtaHookArgs { hook_data }, callback)
+        self.imp.invoke_reducer_with_callback(
+            HandleMtaHookArgs {
+                request_id,
+                hook_data,
+            },
+            callback,
+        )
     }
 }
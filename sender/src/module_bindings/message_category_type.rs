@@ -12,6 +12,13 @@ pub struct MessageCategory {
     pub email_address: String,
     pub description: String,
     pub active: bool,
+    pub digest_interval_secs: Option<u64>,
+    pub deleted_at: Option<__sdk::Timestamp>,
+    pub subscription_required: bool,
+    pub log_level: String,
+    pub rate_limit_per_hour: Option<u32>,
+    pub accept_code: Option<u16>,
+    pub accept_message: Option<String>,
 }
 
 impl __sdk::InModule for MessageCategory {
@@ -27,6 +34,13 @@ pub struct MessageCategoryCols {
     pub email_address: __sdk::__query_builder::Col<MessageCategory, String>,
     pub description: __sdk::__query_builder::Col<MessageCategory, String>,
     pub active: __sdk::__query_builder::Col<MessageCategory, bool>,
+    pub digest_interval_secs: __sdk::__query_builder::Col<MessageCategory, Option<u64>>,
+    pub deleted_at: __sdk::__query_builder::Col<MessageCategory, Option<__sdk::Timestamp>>,
+    pub subscription_required: __sdk::__query_builder::Col<MessageCategory, bool>,
+    pub log_level: __sdk::__query_builder::Col<MessageCategory, String>,
+    pub rate_limit_per_hour: __sdk::__query_builder::Col<MessageCategory, Option<u32>>,
+    pub accept_code: __sdk::__query_builder::Col<MessageCategory, Option<u16>>,
+    pub accept_message: __sdk::__query_builder::Col<MessageCategory, Option<String>>,
 }
 
 impl __sdk::__query_builder::HasCols for MessageCategory {
@@ -38,6 +52,22 @@ impl __sdk::__query_builder::HasCols for MessageCategory {
             email_address: __sdk::__query_builder::Col::new(table_name, "email_address"),
             description: __sdk::__query_builder::Col::new(table_name, "description"),
             active: __sdk::__query_builder::Col::new(table_name, "active"),
+            digest_interval_secs: __sdk::__query_builder::Col::new(
+                table_name,
+                "digest_interval_secs",
+            ),
+            deleted_at: __sdk::__query_builder::Col::new(table_name, "deleted_at"),
+            subscription_required: __sdk::__query_builder::Col::new(
+                table_name,
+                "subscription_required",
+            ),
+            log_level: __sdk::__query_builder::Col::new(table_name, "log_level"),
+            rate_limit_per_hour: __sdk::__query_builder::Col::new(
+                table_name,
+                "rate_limit_per_hour",
+            ),
+            accept_code: __sdk::__query_builder::Col::new(table_name, "accept_code"),
+            accept_message: __sdk::__query_builder::Col::new(table_name, "accept_message"),
         }
     }
 }
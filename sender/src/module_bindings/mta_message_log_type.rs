@@ -16,6 +16,11 @@ pub struct MtaMessageLog {
     pub action: String,
     pub timestamp: __sdk::Timestamp,
     pub queue_id: Option<String>,
+    pub recipient_count: u32,
+    pub matched_categories: String,
+    pub correlation_id: String,
+    pub processing_ms: u64,
+    pub reject_reason: String,
 }
 
 impl __sdk::InModule for MtaMessageLog {
@@ -35,6 +40,11 @@ pub struct MtaMessageLogCols {
     pub action: __sdk::__query_builder::Col<MtaMessageLog, String>,
     pub timestamp: __sdk::__query_builder::Col<MtaMessageLog, __sdk::Timestamp>,
     pub queue_id: __sdk::__query_builder::Col<MtaMessageLog, Option<String>>,
+    pub recipient_count: __sdk::__query_builder::Col<MtaMessageLog, u32>,
+    pub matched_categories: __sdk::__query_builder::Col<MtaMessageLog, String>,
+    pub correlation_id: __sdk::__query_builder::Col<MtaMessageLog, String>,
+    pub processing_ms: __sdk::__query_builder::Col<MtaMessageLog, u64>,
+    pub reject_reason: __sdk::__query_builder::Col<MtaMessageLog, String>,
 }
 
 impl __sdk::__query_builder::HasCols for MtaMessageLog {
@@ -50,6 +60,11 @@ impl __sdk::__query_builder::HasCols for MtaMessageLog {
             action: __sdk::__query_builder::Col::new(table_name, "action"),
             timestamp: __sdk::__query_builder::Col::new(table_name, "timestamp"),
             queue_id: __sdk::__query_builder::Col::new(table_name, "queue_id"),
+            recipient_count: __sdk::__query_builder::Col::new(table_name, "recipient_count"),
+            matched_categories: __sdk::__query_builder::Col::new(table_name, "matched_categories"),
+            correlation_id: __sdk::__query_builder::Col::new(table_name, "correlation_id"),
+            processing_ms: __sdk::__query_builder::Col::new(table_name, "processing_ms"),
+            reject_reason: __sdk::__query_builder::Col::new(table_name, "reject_reason"),
         }
     }
 }
@@ -58,6 +73,7 @@ impl __sdk::__query_builder::HasCols for MtaMessageLog {
 ///
 /// Provides typed access to indexed columns for query building.
 pub struct MtaMessageLogIxCols {
+    pub correlation_id: __sdk::__query_builder::IxCol<MtaMessageLog, String>,
     pub id: __sdk::__query_builder::IxCol<MtaMessageLog, u64>,
 }
 
@@ -65,6 +81,7 @@ impl __sdk::__query_builder::HasIxCols for MtaMessageLog {
     type IxCols = MtaMessageLogIxCols;
     fn ix_cols(table_name: &'static str) -> Self::IxCols {
         MtaMessageLogIxCols {
+            correlation_id: __sdk::__query_builder::IxCol::new(table_name, "correlation_id"),
             id: __sdk::__query_builder::IxCol::new(table_name, "id"),
         }
     }
@@ -13,6 +13,9 @@ pub struct Subscription {
     pub category_id: u64,
     pub subscribed_at: __sdk::Timestamp,
     pub active: bool,
+    pub expires_at: Option<__sdk::Timestamp>,
+    pub from_group_sync: bool,
+    pub deactivated_by_group_sync: bool,
 }
 
 impl __sdk::InModule for Subscription {
@@ -29,6 +32,9 @@ pub struct SubscriptionCols {
     pub category_id: __sdk::__query_builder::Col<Subscription, u64>,
     pub subscribed_at: __sdk::__query_builder::Col<Subscription, __sdk::Timestamp>,
     pub active: __sdk::__query_builder::Col<Subscription, bool>,
+    pub expires_at: __sdk::__query_builder::Col<Subscription, Option<__sdk::Timestamp>>,
+    pub from_group_sync: __sdk::__query_builder::Col<Subscription, bool>,
+    pub deactivated_by_group_sync: __sdk::__query_builder::Col<Subscription, bool>,
 }
 
 impl __sdk::__query_builder::HasCols for Subscription {
@@ -44,6 +50,12 @@ impl __sdk::__query_builder::HasCols for Subscription {
             category_id: __sdk::__query_builder::Col::new(table_name, "category_id"),
             subscribed_at: __sdk::__query_builder::Col::new(table_name, "subscribed_at"),
             active: __sdk::__query_builder::Col::new(table_name, "active"),
+            expires_at: __sdk::__query_builder::Col::new(table_name, "expires_at"),
+            from_group_sync: __sdk::__query_builder::Col::new(table_name, "from_group_sync"),
+            deactivated_by_group_sync: __sdk::__query_builder::Col::new(
+                table_name,
+                "deactivated_by_group_sync",
+            ),
         }
     }
 }
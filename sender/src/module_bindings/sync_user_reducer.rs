@@ -9,6 +9,7 @@ use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 pub(super) struct SyncUserArgs {
     pub action: String,
     pub user_data: String,
+    pub correlation_id: String,
 }
 
 impl From<SyncUserArgs> for super::Reducer {
@@ -16,6 +17,7 @@ impl From<SyncUserArgs> for super::Reducer {
         Self::SyncUser {
             action: args.action,
             user_data: args.user_data,
+            correlation_id: args.correlation_id,
         }
     }
 }
@@ -35,8 +37,13 @@ pub trait sync_user {
     /// The reducer will run asynchronously in the future,
     ///  and this method provides no way to listen for its completion status.
     /// /// Use [`sync_user:sync_user_then`] to run a callback after the reducer completes.
-    fn sync_user(&self, action: String, user_data: String) -> __sdk::Result<()> {
-        self.sync_user_then(action, user_data, |_, _| {})
+    fn sync_user(
+        &self,
+        action: String,
+        user_data: String,
+        correlation_id: String,
+    ) -> __sdk::Result<()> {
+        self.sync_user_then(action, user_data, correlation_id, |_, _| {})
     }
 
     /// Request that the remote module invoke the reducer `sync_user` to run as soon as possible,
@@ -49,6 +56,7 @@ pub trait sync_user {
         &self,
         action: String,
         user_data: String,
+        correlation_id: String,
 
         callback: impl FnOnce(&super::ReducerEventContext, Result<Result<(), String>, __sdk::InternalError>)
             + Send
@@ -61,12 +69,19 @@ impl sync_user for super::RemoteReducers {
         &self,
         action: String,
         user_data: String,
+        correlation_id: String,
 
         callback: impl FnOnce(&super::ReducerEventContext, Result<Result<(), String>, __sdk::InternalError>)
             + Send
             + 'static,
     ) -> __sdk::Result<()> {
-        self.imp
-            .invoke_reducer_with_callback(SyncUserArgs { action, user_data }, callback)
+        self.imp.invoke_reducer_with_callback(
+            SyncUserArgs {
+                action,
+                user_data,
+                correlation_id,
+            },
+            callback,
+        )
     }
 }
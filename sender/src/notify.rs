@@ -0,0 +1,46 @@
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+use crate::module_bindings::NotificationOut;
+
+/// POST one notification to the configured webhook. `text` carries a one-line summary, which is
+/// what Slack and Matrix incoming webhooks display; the structured fields follow it.
+pub async fn post_notification(
+    client: &reqwest::Client,
+    url: &str,
+    notification: &NotificationOut,
+) -> Result<(), Box<dyn Error>> {
+    let payload: Value = serde_json::from_str(&notification.payload).unwrap_or(Value::Null);
+    let reason = payload
+        .get("reason")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown reason");
+    let categories = payload
+        .get("categories")
+        .and_then(Value::as_array)
+        .map(|c| {
+            c.iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .filter(|c| !c.is_empty())
+        .unwrap_or_else(|| "-".to_string());
+
+    let body = json!({
+        "text": format!(
+            "Kommunikationszentrum: message {} ({}), categories: {}",
+            notification.kind, reason, categories
+        ),
+        "kind": notification.kind,
+        "payload": payload,
+    });
+    client
+        .post(url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
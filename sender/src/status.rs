@@ -0,0 +1,256 @@
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+use spacetimedb_sdk::{DbContext, Timestamp};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{info, warn};
+
+use crate::module_bindings::DbConnection;
+
+/// How long one OIDC discovery check is reused, so dashboards polling `/status` don't hammer
+/// the issuer.
+const OIDC_CHECK_TTL: Duration = Duration::from_secs(60);
+/// Timeout of the OIDC discovery request.
+const OIDC_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What `/status` reports, shared between the main loop and the status server.
+#[derive(Default)]
+pub struct StatusState {
+    /// `pinged_at` of the last answered `ping`, i.e. the last reducer call known to succeed.
+    pub last_reducer_ok: Mutex<Option<Timestamp>>,
+    /// Last OIDC discovery result and when it was taken.
+    oidc_check: Mutex<Option<(Instant, bool)>>,
+}
+
+impl StatusState {
+    /// Whether the issuer's discovery document could be fetched, reusing a check younger than
+    /// `OIDC_CHECK_TTL`.
+    async fn oidc_reachable(&self, client: &reqwest::Client, issuer: &str) -> bool {
+        let cached = cached_oidc_check(*self.oidc_check.lock().unwrap(), Instant::now());
+        if let Some(reachable) = cached {
+            return reachable;
+        }
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let reachable = match client.get(&url).timeout(OIDC_CHECK_TIMEOUT).send().await {
+            Ok(response) if response.status().is_success() => true,
+            Ok(response) => {
+                warn!("OIDC discovery {} answered {}", url, response.status());
+                false
+            }
+            Err(error) => {
+                warn!("OIDC discovery {} failed: {}", url, error);
+                false
+            }
+        };
+        *self.oidc_check.lock().unwrap() = Some((Instant::now(), reachable));
+        reachable
+    }
+}
+
+/// The result of `check`, an OIDC discovery result and when it was taken, while it is younger
+/// than `OIDC_CHECK_TTL` at `now`; None when a new check is due.
+fn cached_oidc_check(check: Option<(Instant, bool)>, now: Instant) -> Option<bool> {
+    let (checked_at, reachable) = check?;
+    (now.saturating_duration_since(checked_at) < OIDC_CHECK_TTL).then_some(reachable)
+}
+
+/// Everything `current_status` needs besides the shared state.
+pub struct StatusContext {
+    pub connection: Arc<DbConnection>,
+    pub state: Arc<StatusState>,
+    pub client: reqwest::Client,
+    pub oidc_issuer_url: Option<String>,
+    /// A reducer answer older than this counts as stale.
+    pub max_reducer_age: Duration,
+}
+
+/// The `/status` document for the live connection and shared state, and whether everything is
+/// healthy.
+async fn current_status(ctx: &StatusContext) -> (bool, Value) {
+    let connected = ctx.connection.is_active();
+    let last_reducer_ok = *ctx.state.last_reducer_ok.lock().unwrap();
+    let oidc_reachable = match &ctx.oidc_issuer_url {
+        Some(issuer) => Some(ctx.state.oidc_reachable(&ctx.client, issuer).await),
+        None => None,
+    };
+    status_body(
+        connected,
+        last_reducer_ok,
+        Timestamp::now(),
+        ctx.max_reducer_age,
+        ctx.oidc_issuer_url.as_deref(),
+        oidc_reachable,
+    )
+}
+
+/// The `/status` document at `now` and whether everything is healthy: connected, a reducer
+/// answered within `max_reducer_age`, and the OIDC issuer, if one is configured, reachable.
+fn status_body(
+    connected: bool,
+    last_reducer_ok: Option<Timestamp>,
+    now: Timestamp,
+    max_reducer_age: Duration,
+    oidc_issuer_url: Option<&str>,
+    oidc_reachable: Option<bool>,
+) -> (bool, Value) {
+    let reducer_fresh = last_reducer_ok.is_some_and(|at| {
+        let age_micros = now.to_micros_since_unix_epoch() - at.to_micros_since_unix_epoch();
+        age_micros <= max_reducer_age.as_micros() as i64
+    });
+
+    let last_reducer_ok_at = last_reducer_ok
+        .and_then(|at| chrono::DateTime::from_timestamp_micros(at.to_micros_since_unix_epoch()))
+        .map(|at| at.to_rfc3339());
+
+    let healthy = connected && reducer_fresh && oidc_reachable != Some(false);
+    let body = json!({
+        "status": if healthy { "healthy" } else { "degraded" },
+        "spacetimedb": {
+            "connected": connected,
+            "last_reducer_ok": last_reducer_ok_at,
+            "reducer_fresh": reducer_fresh,
+        },
+        "oidc": {
+            "issuer": oidc_issuer_url,
+            "reachable": oidc_reachable,
+        },
+    });
+    (healthy, body)
+}
+
+/// Serve `GET /status` on `addr`: 200 when healthy, 503 when degraded, 404 for anything else.
+/// Plain HTTP/1.1 with one request per connection; meant for an internal dashboard or probe.
+pub async fn serve(addr: String, ctx: StatusContext) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let listener = TcpListener::bind(&addr).await?;
+    info!("Status endpoint listening on http://{}/status", addr);
+    let ctx = Arc::new(ctx);
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let ctx = ctx.clone();
+        tokio::spawn(async move {
+            if let Err(error) = handle_connection(stream, &ctx).await {
+                warn!("status request failed: {}", error);
+            }
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    ctx: &StatusContext,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut buffer = [0u8; 1024];
+    let read = stream.read(&mut buffer).await?;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+    let target = request
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("GET "))
+        .and_then(|rest| rest.split_whitespace().next());
+
+    let (status_line, body) = match target {
+        Some("/status") => {
+            let (healthy, body) = current_status(ctx).await;
+            let status_line = if healthy {
+                "200 OK"
+            } else {
+                "503 Service Unavailable"
+            };
+            (status_line, body.to_string())
+        }
+        _ => ("404 Not Found", json!({"error": "not found"}).to_string()),
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status_line,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "https://auth.example.org";
+
+    fn at_secs(secs: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(secs * 1_000_000)
+    }
+
+    #[test]
+    fn connected_with_a_fresh_reducer_and_reachable_oidc_is_healthy() {
+        let max_age = Duration::from_secs(120);
+        let (healthy, body) = status_body(
+            true,
+            Some(at_secs(1_000)),
+            at_secs(1_100),
+            max_age,
+            Some(ISSUER),
+            Some(true),
+        );
+        assert!(healthy);
+        assert_eq!(body["status"], "healthy");
+        assert_eq!(body["spacetimedb"]["reducer_fresh"], true);
+        assert_eq!(body["oidc"]["issuer"], ISSUER);
+        // Without an issuer there is nothing to check
+        let (healthy, body) = status_body(
+            true,
+            Some(at_secs(1_000)),
+            at_secs(1_100),
+            max_age,
+            None,
+            None,
+        );
+        assert!(healthy);
+        assert!(body["oidc"]["reachable"].is_null());
+    }
+
+    #[test]
+    fn any_failing_check_degrades_the_status() {
+        let max_age = Duration::from_secs(120);
+        let status = |connected, last_ok, oidc| {
+            status_body(
+                connected,
+                last_ok,
+                at_secs(1_100),
+                max_age,
+                Some(ISSUER),
+                oidc,
+            )
+        };
+        let cases = [
+            status(false, Some(at_secs(1_000)), Some(true)),
+            // Last answer older than `max_reducer_age`, or none yet
+            status(true, Some(at_secs(900)), Some(true)),
+            status(true, None, Some(true)),
+            status(true, Some(at_secs(1_000)), Some(false)),
+        ];
+        for (healthy, body) in cases {
+            assert!(!healthy, "{body}");
+            assert_eq!(body["status"], "degraded");
+        }
+    }
+
+    #[test]
+    fn oidc_check_is_reused_for_a_minute() {
+        let checked_at = Instant::now();
+        let check = Some((checked_at, false));
+        assert_eq!(cached_oidc_check(check, checked_at), Some(false));
+        assert_eq!(
+            cached_oidc_check(check, checked_at + Duration::from_secs(59)),
+            Some(false)
+        );
+        assert_eq!(cached_oidc_check(check, checked_at + OIDC_CHECK_TTL), None);
+        assert_eq!(cached_oidc_check(None, checked_at), None);
+    }
+}
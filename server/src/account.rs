@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use spacetimedb::{Filter, Identity, Query, ReducerContext, Table, Timestamp, ViewContext};
 
+use crate::mailing;
+use crate::settings;
+
 // Configuration constants that can be set at compile time via environment variables
 const DJANGO_OAUTH_BASE_URL: &str = match option_env!("DJANGO_BASE_URL") {
     Some(url) => url,
@@ -9,6 +12,12 @@ const DJANGO_OAUTH_BASE_URL: &str = match option_env!("DJANGO_BASE_URL") {
 
 const DJANGO_OAUTH_ISSUER_PATH: &str = "/o";
 
+/// Whether a Django sync keeps a name an admin set manually (`SYNC_RESPECT_MANUAL_NAMES=true`).
+/// Off by default: the synced name wins and the override flag is cleared.
+fn sync_respects_manual_names() -> bool {
+    option_env!("SYNC_RESPECT_MANUAL_NAMES") == Some("true")
+}
+
 #[derive(Debug)]
 #[spacetimedb::table(accessor = account, public)]
 pub struct Account {
@@ -22,6 +31,18 @@ pub struct Account {
     pub is_active: bool,
     #[index(btree)]
     pub last_synced: Timestamp,
+    /// Set when an admin changed the name in the UI; see `sync_respects_manual_names`.
+    #[default(false)]
+    pub name_overridden: bool,
+    /// Django's `updated_at` of the last applied sync; older payloads are stale.
+    #[default(None)]
+    pub django_updated_at: Option<Timestamp>,
+    /// Last `update_account` edit; syncs of an older Django version keep email and active flag.
+    #[default(None)]
+    pub manually_edited_at: Option<Timestamp>,
+    /// Correlation id of the request whose sync last wrote this row, see `sync_user`
+    #[default(String::new())]
+    pub sync_correlation_id: String,
 }
 
 // Direct queries to `account` are restricted to the caller's own row.
@@ -56,6 +77,52 @@ pub fn visible_accounts(ctx: &ViewContext) -> Vec<Account> {
 pub struct AdminIdentity {
     #[primary_key]
     pub identity: Identity,
+    /// Last time this identity connected; the registration time until it first does.
+    /// `UNIX_EPOCH` for identities registered before this was recorded, i.e. unknown.
+    #[default(Timestamp::UNIX_EPOCH)]
+    pub last_seen: Timestamp,
+    /// Who the identity belongs to, e.g. "Django 1234" or a name an admin entered.
+    #[default(String::new())]
+    pub label: String,
+    /// Account the Django sync granted this identity for; `None` for identities registered by
+    /// hand, such as the publisher and the sender service, which have no account.
+    #[default(None)]
+    pub account_id: Option<u64>,
+}
+
+impl AdminIdentity {
+    pub(crate) fn new(ctx: &ReducerContext, identity: Identity, label: String) -> Self {
+        Self {
+            identity,
+            last_seen: ctx.timestamp,
+            label,
+            account_id: None,
+        }
+    }
+
+    /// The identity as granted by the sync of account `account_id`.
+    fn for_account(self, account_id: u64) -> Self {
+        Self {
+            account_id: Some(account_id),
+            ..self
+        }
+    }
+
+    /// True if the identity has not connected for more than `max_idle_micros` before `now`.
+    /// An unknown `last_seen` never counts as idle.
+    fn is_idle(&self, now: Timestamp, max_idle_micros: i64) -> bool {
+        self.last_seen != Timestamp::UNIX_EPOCH
+            && now.to_micros_since_unix_epoch() - self.last_seen.to_micros_since_unix_epoch()
+                > max_idle_micros
+    }
+
+    /// The identity as it is after connecting at `now`.
+    fn connected_at(self, now: Timestamp) -> Self {
+        Self {
+            last_seen: now,
+            ..self
+        }
+    }
 }
 
 /// A view that restricts admin_identities to only show the admin identities to admins.
@@ -71,6 +138,16 @@ pub fn visible_admin_identities(ctx: &ViewContext) -> impl Query<AdminIdentity>
     ctx.from.admin_identities().r#filter(move |_| is_admin)
 }
 
+/// Body of `POST /user-sync`. Together with `UserSyncData` this is the whole JSON contract with
+/// Django; both live here so the route and `sync_user` cannot drift apart.
+#[derive(Deserialize)]
+pub struct UserSyncPayload {
+    /// "upsert" or "delete"
+    pub action: String,
+    pub user: UserSyncData,
+}
+
+/// One user as sent by Django, the `user` of `UserSyncPayload` and the JSON `sync_user` takes.
 #[derive(Serialize, Deserialize)]
 pub struct UserSyncData {
     pub mitgliedsnr: u64,
@@ -81,6 +158,10 @@ pub struct UserSyncData {
     pub updated_at: Option<String>,
     // Optional: precomputed Spacetime Identity as hex string (provided by Django)
     pub identity_hex: Option<String>,
+    /// Django groups of the user. When present, subscriptions of categories in
+    /// `group_category_map` follow them; when absent, subscriptions are left alone.
+    #[serde(default)]
+    pub groups: Option<Vec<String>>,
 }
 
 // Webhook token table: stores hashed bearer tokens and permissions.
@@ -125,6 +206,22 @@ pub(crate) fn is_admin_identity(ctx: &ReducerContext, who: Identity) -> bool {
     ctx.db.admin_identities().identity().find(&who).is_some()
 }
 
+/// Whether `sender` may write the result row of `request_id`, which `owner` asked for if it
+/// exists. A request id stays with the caller that used it first, so nobody can overwrite or
+/// take over another caller's result.
+pub(crate) fn check_request_owner(
+    owner: Option<Identity>,
+    sender: Identity,
+    request_id: &str,
+) -> Result<(), String> {
+    match owner {
+        Some(owner) if owner != sender => {
+            Err(format!("request_id {request_id} belongs to another caller"))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Add an identity to admin_identities. Only existing admins may call this.
 /// `identity_hex` is the 64-character hex string.
 #[spacetimedb::reducer]
@@ -143,7 +240,9 @@ pub fn register_admin_identity(ctx: &ReducerContext, identity_hex: String) -> Re
     {
         return Ok(()); // idempotent
     }
-    ctx.db.admin_identities().insert(AdminIdentity { identity });
+    ctx.db
+        .admin_identities()
+        .insert(AdminIdentity::new(ctx, identity, String::new()));
     log::info!("Registered admin identity: {:?}", identity);
     Ok(())
 }
@@ -162,6 +261,245 @@ pub fn unregister_admin_identity(ctx: &ReducerContext, identity_hex: String) ->
     Ok(())
 }
 
+/// The `admin_identities` row to write when `sender` connects at `now`, looked up with `find`.
+/// `None` for an identity that is no registered admin; connecting grants nothing.
+fn admin_after_connect(
+    find: impl FnOnce(&Identity) -> Option<AdminIdentity>,
+    sender: Identity,
+    now: Timestamp,
+) -> Option<AdminIdentity> {
+    find(&sender).map(|admin| admin.connected_at(now))
+}
+
+/// Record that an admin identity just connected. Called from `identity_connected`.
+pub(crate) fn touch_admin_identity(ctx: &ReducerContext) {
+    let find = |identity: &Identity| ctx.db.admin_identities().identity().find(identity);
+    if let Some(admin) = admin_after_connect(find, ctx.sender(), ctx.timestamp) {
+        ctx.db.admin_identities().identity().update(admin);
+    }
+}
+
+/// Set the label shown next to an admin identity. Only existing admins may call this.
+#[spacetimedb::reducer]
+pub fn set_admin_identity_label(
+    ctx: &ReducerContext,
+    identity_hex: String,
+    label: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let identity = Identity::from_hex(&identity_hex)
+        .map_err(|e| format!("Invalid identity hex '{}': {}", identity_hex, e))?;
+    let mut admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&identity)
+        .ok_or_else(|| format!("Admin identity {} not found", identity_hex))?;
+    admin.label = label.trim().to_string();
+    ctx.db.admin_identities().identity().update(admin);
+    Ok(())
+}
+
+/// Remove every admin identity that has not connected for `max_idle_days` days.
+/// The caller's own identity and identities without a known `last_seen` are never removed.
+/// Only existing admins may call this.
+#[spacetimedb::reducer]
+pub fn prune_stale_admin_identities(
+    ctx: &ReducerContext,
+    max_idle_days: u32,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if max_idle_days == 0 {
+        return Err("max_idle_days must be at least 1".to_string());
+    }
+    let max_idle_micros = i64::from(max_idle_days) * 86_400 * 1_000_000;
+    let stale: Vec<Identity> = ctx
+        .db
+        .admin_identities()
+        .iter()
+        .filter(|admin| admin.identity != ctx.sender())
+        .filter(|admin| admin.is_idle(ctx.timestamp, max_idle_micros))
+        .map(|admin| admin.identity)
+        .collect();
+    for identity in &stale {
+        ctx.db.admin_identities().identity().delete(identity);
+    }
+    log::info!(
+        "Pruned {} admin identities idle for more than {} days (by identity: {:?})",
+        stale.len(),
+        max_idle_days,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// `ExportBlob.kind` of `export_admins`.
+pub const EXPORT_KIND_ADMINS: &str = "admins";
+/// `ExportBlob.kind` of `export_message_logs`.
+pub const EXPORT_KIND_MESSAGE_LOGS: &str = "message_logs";
+
+/// Result of an export reducer: a JSON document the caller downloads from
+/// `my_export_blobs`. One row per caller and kind; a new export replaces the old one.
+#[spacetimedb::table(accessor = export_blob)]
+pub struct ExportBlob {
+    /// `"{kind}:{caller identity hex}"`, or `"{kind}:{caller identity hex}:{part:04}"` for
+    /// exports split into parts by `store_export_parts`
+    #[primary_key]
+    pub id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    /// One of the `EXPORT_KIND_*` constants
+    pub kind: String,
+    pub content: String,
+    pub created_at: Timestamp,
+}
+
+/// The caller's own exports.
+#[spacetimedb::view(accessor = my_export_blobs, public)]
+pub fn my_export_blobs(ctx: &ViewContext) -> Vec<ExportBlob> {
+    ctx.db
+        .export_blob()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Store `content` as the caller's export of `kind`, replacing an earlier one.
+pub(crate) fn store_export(ctx: &ReducerContext, kind: &str, content: String) {
+    let row = ExportBlob {
+        id: format!("{}:{}", kind, ctx.sender().to_hex()),
+        requested_by: ctx.sender(),
+        kind: kind.to_string(),
+        content,
+        created_at: ctx.timestamp,
+    };
+    if ctx.db.export_blob().id().find(&row.id).is_some() {
+        ctx.db.export_blob().id().update(row);
+    } else {
+        ctx.db.export_blob().insert(row);
+    }
+}
+
+/// Store `parts` as the caller's export of `kind`, one row per part, replacing all earlier
+/// rows of that kind. Part ids are zero-padded, so sorting by id gives the original order.
+pub(crate) fn store_export_parts(ctx: &ReducerContext, kind: &str, parts: Vec<String>) {
+    let old: Vec<String> = ctx
+        .db
+        .export_blob()
+        .requested_by()
+        .filter(&ctx.sender())
+        .filter(|blob| blob.kind == kind)
+        .map(|blob| blob.id)
+        .collect();
+    for id in old {
+        ctx.db.export_blob().id().delete(&id);
+    }
+    for (part, content) in parts.into_iter().enumerate() {
+        ctx.db.export_blob().insert(ExportBlob {
+            id: format!("{}:{}:{:04}", kind, ctx.sender().to_hex(), part),
+            requested_by: ctx.sender(),
+            kind: kind.to_string(),
+            content,
+            created_at: ctx.timestamp,
+        });
+    }
+}
+
+/// One entry of the `export_admins` / `import_admins` JSON array.
+#[derive(Serialize, Deserialize)]
+struct AdminExportEntry {
+    identity_hex: String,
+    #[serde(default)]
+    label: String,
+}
+
+/// The `export_admins` document of `admins`.
+fn admin_export(admins: impl Iterator<Item = AdminIdentity>) -> Result<String, String> {
+    let entries: Vec<AdminExportEntry> = admins
+        .map(|admin| AdminExportEntry {
+            identity_hex: admin.identity.to_hex().to_string(),
+            label: admin.label,
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries)
+        .map_err(|e| format!("Failed to serialize admin export: {}", e))
+}
+
+/// The identities and labels of an `export_admins` document; an error for the first invalid
+/// hex, so nothing of a broken document is imported.
+fn parse_admin_import(json: &str) -> Result<Vec<(Identity, String)>, String> {
+    let entries: Vec<AdminExportEntry> =
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse admin import: {}", e))?;
+    entries
+        .into_iter()
+        .map(|entry| {
+            let identity = Identity::from_hex(entry.identity_hex.trim())
+                .map_err(|e| format!("Invalid identity hex '{}': {}", entry.identity_hex, e))?;
+            Ok((identity, entry.label.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Write all admin identities with their labels as JSON into the caller's `export_blob` row
+/// of kind `admins`, for restoring them with `import_admins`. Only admins.
+#[spacetimedb::reducer]
+pub fn export_admins(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let count = ctx.db.admin_identities().count();
+    let content = admin_export(ctx.db.admin_identities().iter())?;
+    store_export(ctx, EXPORT_KIND_ADMINS, content);
+    log::info!(
+        "Exported {} admin identities (by identity: {:?})",
+        count,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Add the admin identities of an `export_admins` document. Every hex is validated before
+/// anything is written; one invalid entry fails the whole import. Identities that are already
+/// admins, and the module owner, are skipped. Nothing is ever removed. Only admins.
+#[spacetimedb::reducer]
+pub fn import_admins(ctx: &ReducerContext, json: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let parsed = parse_admin_import(&json)?;
+
+    let mut added = 0usize;
+    let mut skipped = 0usize;
+    for (identity, label) in parsed {
+        if identity == ctx.database_identity()
+            || ctx
+                .db
+                .admin_identities()
+                .identity()
+                .find(&identity)
+                .is_some()
+        {
+            skipped += 1;
+            continue;
+        }
+        ctx.db
+            .admin_identities()
+            .insert(AdminIdentity::new(ctx, identity, label));
+        added += 1;
+    }
+    log::info!(
+        "Imported admin identities: {} added, {} skipped (by identity: {:?})",
+        added,
+        skipped,
+        ctx.sender()
+    );
+    Ok(())
+}
+
 // New reducers for webhook token management
 #[spacetimedb::reducer]
 pub fn create_webhook_token(
@@ -205,16 +543,729 @@ pub fn revoke_webhook_token(ctx: &ReducerContext, token_hash: String) -> Result<
     Ok(())
 }
 
+/// Edit an account's name, email and active flag in place. Only admins may call this.
+/// Syncs of a Django version older than the edit keep email and active flag; a newer one
+/// overwrites them. The name follows `sync_respects_manual_names`.
+#[spacetimedb::reducer]
+pub fn update_account(
+    ctx: &ReducerContext,
+    id: u64,
+    name: String,
+    email: String,
+    is_active: bool,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: only admins can edit accounts".into());
+    }
+    let existing = ctx
+        .db
+        .account()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Account {} not found", id))?;
+    let name = name.trim().to_string();
+    let name_overridden = existing.name_overridden || name != existing.name;
+    ctx.db.account().id().update(Account {
+        name,
+        email: email.trim().to_string(),
+        is_active,
+        last_synced: ctx.timestamp,
+        name_overridden,
+        manually_edited_at: Some(ctx.timestamp),
+        ..existing
+    });
+    log::info!(
+        "Account {} edited manually (by identity: {:?})",
+        id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Change only an account's display name, leaving email and active flag alone. Only admins.
+/// Marks the name as manually overridden, which a later sync keeps if configured to.
+#[spacetimedb::reducer]
+pub fn set_account_name(ctx: &ReducerContext, id: u64, name: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: only admins can edit accounts".into());
+    }
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Name must not be empty".to_string());
+    }
+    let existing = ctx
+        .db
+        .account()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Account {} not found", id))?;
+    ctx.db.account().id().update(Account {
+        name,
+        last_synced: ctx.timestamp,
+        name_overridden: true,
+        ..existing
+    });
+    log::info!(
+        "Account {} renamed manually (by identity: {:?})",
+        id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Further sender address of a member (e.g. a work address next to `account.email`).
+/// Verified aliases count as the account when checking who may post to a category.
+#[spacetimedb::table(accessor = account_alias)]
+pub struct AccountAlias {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub account_id: u64, // FK → Account.id
+    /// Stored trimmed and lowercased
+    #[unique]
+    pub email: String,
+    /// Aliases added by an admin are verified; unverified ones are ignored by the sender lookup.
+    pub verified: bool,
+    pub created_at: Timestamp,
+}
+
+/// All account aliases for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_account_aliases, public)]
+pub fn visible_account_aliases(ctx: &ViewContext) -> impl Query<AccountAlias> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.account_alias().r#filter(move |_| is_admin)
+}
+
+/// Resolve the lookups of `account_for_sender`: `primary`, the account with the address as
+/// primary address, wins; otherwise the `alias` row of the address leads to its owner via
+/// `owner_of`, if it is verified.
+fn sender_account(
+    primary: Option<Account>,
+    alias: Option<AccountAlias>,
+    owner_of: impl FnOnce(u64) -> Option<Account>,
+) -> Option<Account> {
+    primary.or_else(|| {
+        alias
+            .filter(|alias| alias.verified)
+            .and_then(|alias| owner_of(alias.account_id))
+    })
+}
+
+/// The account mail from `email` belongs to: the account with that primary address, else the
+/// owner of a verified alias. None for external senders. Aliases are stored lowercased, primary
+/// addresses as synced, so the primary address is looked up as given and lowercased.
+pub(crate) fn account_for_sender(ctx: &ReducerContext, email: &str) -> Option<Account> {
+    let trimmed = email.trim().to_string();
+    let normalized = trimmed.to_lowercase();
+    let primary = ctx
+        .db
+        .account()
+        .email()
+        .filter(&trimmed)
+        .next()
+        .or_else(|| ctx.db.account().email().filter(&normalized).next());
+    let alias = ctx.db.account_alias().email().find(&normalized);
+    sender_account(primary, alias, |id| ctx.db.account().id().find(&id))
+}
+
+/// Register `email` as a verified alias of account `account_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn add_account_alias(
+    ctx: &ReducerContext,
+    account_id: u64,
+    email: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let email = email.trim().to_lowercase();
+    if !email.contains('@') {
+        return Err(format!("Invalid alias address '{}'", email));
+    }
+    if ctx.db.account().id().find(&account_id).is_none() {
+        return Err(format!("Account {} not found", account_id));
+    }
+    if ctx.db.account().email().filter(&email).next().is_some()
+        || ctx.db.account_alias().email().find(&email).is_some()
+    {
+        return Err(format!("Address {} already belongs to an account", email));
+    }
+    ctx.db.account_alias().insert(AccountAlias {
+        id: 0,
+        account_id,
+        email,
+        verified: true,
+        created_at: ctx.timestamp,
+    });
+    log::info!(
+        "Added alias for account {} (by identity: {:?})",
+        account_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Remove an account alias. Only admins.
+#[spacetimedb::reducer]
+pub fn remove_account_alias(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if !ctx.db.account_alias().id().delete(&id) {
+        return Err(format!("Account alias {} not found", id));
+    }
+    log::info!(
+        "Removed account alias {} (by identity: {:?})",
+        id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Longest tag `add_account_tag` accepts.
+const MAX_TAG_LEN: usize = 40;
+
+/// Free-form label an admin put on a member (e.g. `vorstand`, `neu`, `zahlt-bar`).
+/// Not part of the Django sync, so upserts keep them; deleting the account removes them.
+#[spacetimedb::table(
+    accessor = account_tag,
+    index(accessor = account_tag_pair, btree(columns = [account_id, tag]))
+)]
+pub struct AccountTag {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub account_id: u64, // FK → Account.id
+    /// Stored trimmed and lowercased
+    pub tag: String,
+    pub created_at: Timestamp,
+}
+
+/// All account tags for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_account_tags, public)]
+pub fn visible_account_tags(ctx: &ViewContext) -> impl Query<AccountTag> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.account_tag().r#filter(move |_| is_admin)
+}
+
+/// Label account `account_id` with `tag`. Adding a tag the account already has is a no-op.
+/// Only admins.
+#[spacetimedb::reducer]
+pub fn add_account_tag(ctx: &ReducerContext, account_id: u64, tag: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() || tag.chars().count() > MAX_TAG_LEN {
+        return Err(format!(
+            "Tag must be between 1 and {} characters long",
+            MAX_TAG_LEN
+        ));
+    }
+    if ctx.db.account().id().find(&account_id).is_none() {
+        return Err(format!("Account {} not found", account_id));
+    }
+    if ctx
+        .db
+        .account_tag()
+        .account_tag_pair()
+        .filter((account_id, &tag))
+        .next()
+        .is_some()
+    {
+        return Ok(());
+    }
+    ctx.db.account_tag().insert(AccountTag {
+        id: 0,
+        account_id,
+        tag: tag.clone(),
+        created_at: ctx.timestamp,
+    });
+    log::info!(
+        "Tagged account {} with '{}' (by identity: {:?})",
+        account_id,
+        tag,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Remove `tag` from account `account_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn remove_account_tag(
+    ctx: &ReducerContext,
+    account_id: u64,
+    tag: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let tag = tag.trim().to_lowercase();
+    let ids: Vec<u64> = ctx
+        .db
+        .account_tag()
+        .account_tag_pair()
+        .filter((account_id, &tag))
+        .map(|t| t.id)
+        .collect();
+    if ids.is_empty() {
+        return Err(format!("Account {} has no tag '{}'", account_id, tag));
+    }
+    for id in ids {
+        ctx.db.account_tag().id().delete(&id);
+    }
+    log::info!(
+        "Removed tag '{}' from account {} (by identity: {:?})",
+        tag,
+        account_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Django's `updated_at` of a sync payload. A missing or unparsable value is `None`.
+fn sync_updated_at(data: &UserSyncData) -> Option<Timestamp> {
+    match Timestamp::parse_from_rfc3339(data.updated_at.as_deref()?) {
+        Ok(updated_at) => Some(updated_at),
+        Err(e) => {
+            log::warn!(
+                "Ignoring unparsable updated_at for account {}: {}",
+                data.mitgliedsnr,
+                e
+            );
+            None
+        }
+    }
+}
+
+/// True if a payload with Django `updated_at` is older than the last sync applied to
+/// `existing`, i.e. it arrived out of order. A resend of the same version is not stale, and
+/// neither is a payload without `updated_at`.
+fn is_stale_sync(updated_at: Option<Timestamp>, existing: &Account) -> bool {
+    match (updated_at, existing.django_updated_at) {
+        (Some(updated_at), Some(applied)) => updated_at < applied,
+        _ => false,
+    }
+}
+
+/// True if an admin edited `existing` with `update_account` after the Django version
+/// `updated_at` was written, so that payload must not overwrite the edited email and active
+/// flag. A payload without `updated_at` cannot be ordered and is applied.
+fn predates_manual_edit(updated_at: Option<Timestamp>, existing: &Account) -> bool {
+    match (updated_at, existing.manually_edited_at) {
+        (Some(updated_at), Some(edited_at)) => updated_at < edited_at,
+        _ => false,
+    }
+}
+
+/// `existing` updated by the sync of `data`, Django version `updated_at`. A manually set name
+/// stays when `respect_manual_names`; email and active flag stay when the version predates the
+/// last manual edit.
+fn synced_account_update(
+    existing: Account,
+    data: &UserSyncData,
+    identity: Identity,
+    updated_at: Option<Timestamp>,
+    timestamp: Timestamp,
+    respect_manual_names: bool,
+) -> Account {
+    let keep_manual_name = existing.name_overridden && respect_manual_names;
+    let name = if keep_manual_name {
+        log::info!("Keeping manually set name of account {}", data.mitgliedsnr);
+        existing.name.clone()
+    } else {
+        data.name.clone().unwrap_or_default()
+    };
+    let (email, is_active) = if predates_manual_edit(updated_at, &existing) {
+        log::info!(
+            "Keeping manually edited email and active flag of account {}",
+            data.mitgliedsnr
+        );
+        (existing.email.clone(), existing.is_active)
+    } else {
+        (
+            data.email.clone().unwrap_or_default(),
+            data.is_active.unwrap_or(true),
+        )
+    };
+    Account {
+        identity,
+        name,
+        email,
+        is_active,
+        last_synced: timestamp,
+        name_overridden: keep_manual_name,
+        django_updated_at: updated_at.or(existing.django_updated_at),
+        ..existing
+    }
+}
+
+/// Resolve the SpacetimeDB identity for a synced user.
+/// Uses `identity_hex` when Django provides one and derives it from the OIDC claims otherwise.
+/// A present but malformed `identity_hex` is rejected instead of silently falling back.
+fn resolve_sync_identity(data: &UserSyncData) -> Result<Identity, String> {
+    match data.identity_hex.as_deref().map(str::trim) {
+        Some(hex) if !hex.is_empty() => {
+            if hex.len() != 64 {
+                return Err(format!(
+                    "Invalid identity_hex for {}: expected 64 hex characters, got {}",
+                    data.mitgliedsnr,
+                    hex.len()
+                ));
+            }
+            let identity = Identity::from_hex(hex)
+                .map_err(|e| format!("Invalid identity_hex for {}: {}", data.mitgliedsnr, e))?;
+            log::info!(
+                "Using provided identity_hex for account {}",
+                data.mitgliedsnr
+            );
+            Ok(identity)
+        }
+        _ => {
+            log::info!(
+                "No identity_hex for account {}, deriving identity from claims",
+                data.mitgliedsnr
+            );
+            Ok(expected_identity(data.mitgliedsnr))
+        }
+    }
+}
+
+/// Issuer of the Django OIDC tokens: `OIDC_ISSUER_URL` if set at build time, otherwise
+/// `DJANGO_BASE_URL` + `/o`.
+fn oauth_issuer_url() -> String {
+    match option_env!("OIDC_ISSUER_URL").filter(|url| !url.is_empty()) {
+        Some(url) => url.to_string(),
+        None => format!("{}{}", DJANGO_OAUTH_BASE_URL, DJANGO_OAUTH_ISSUER_PATH),
+    }
+}
+
+/// How Django writes a `mitgliedsnr` into the `sub` claim of its tokens.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum SubjectFormat {
+    /// The number as-is, e.g. `42`.
+    Plain,
+    /// Zero-padded to the given width, e.g. `000042` for width 6.
+    ZeroPadded(usize),
+}
+
+/// `OIDC_SUBJECT_FORMAT` as parsed at build time; a malformed value fails the build instead
+/// of silently deriving `plain` identities that match no token.
+const CONFIGURED_SUBJECT_FORMAT: SubjectFormat = match option_env!("OIDC_SUBJECT_FORMAT") {
+    None => SubjectFormat::Plain,
+    Some(value) => match SubjectFormat::parse(value) {
+        Ok(format) => format,
+        Err(_) => panic!("OIDC_SUBJECT_FORMAT must be `plain` or `padded:<width>`"),
+    },
+};
+
+impl SubjectFormat {
+    /// Parse `plain` or `padded:<width>`, with `<width>` a decimal number.
+    const fn parse(value: &str) -> Result<Self, &'static str> {
+        let width = match value.as_bytes() {
+            b"plain" => return Ok(SubjectFormat::Plain),
+            [b'p', b'a', b'd', b'd', b'e', b'd', b':', width @ ..] if !width.is_empty() => width,
+            _ => return Err("expected `plain` or `padded:<width>`"),
+        };
+        let mut parsed: usize = 0;
+        let mut i = 0;
+        while i < width.len() {
+            if !width[i].is_ascii_digit() {
+                return Err("padded width is not a number");
+            }
+            let digit = (width[i] - b'0') as usize;
+            parsed = match parsed.checked_mul(10) {
+                Some(n) if n <= usize::MAX - digit => n + digit,
+                _ => return Err("padded width is too large"),
+            };
+            i += 1;
+        }
+        Ok(SubjectFormat::ZeroPadded(parsed))
+    }
+
+    /// Set at build time via `OIDC_SUBJECT_FORMAT`: `plain` (default) or `padded:<width>`.
+    fn configured() -> Self {
+        CONFIGURED_SUBJECT_FORMAT
+    }
+
+    /// The `sub` claim for `mitgliedsnr`.
+    pub(crate) fn subject(self, mitgliedsnr: u64) -> String {
+        match self {
+            SubjectFormat::Plain => mitgliedsnr.to_string(),
+            SubjectFormat::ZeroPadded(width) => format!("{:0width$}", mitgliedsnr, width = width),
+        }
+    }
+}
+
+/// The identity SpacetimeDB assigns to a token from `issuer` for `mitgliedsnr`, with the
+/// subject written as `format` says. The only place the formula lives.
+pub(crate) fn derive_identity(issuer: &str, format: SubjectFormat, mitgliedsnr: u64) -> Identity {
+    Identity::from_claims(issuer, &format.subject(mitgliedsnr))
+}
+
+/// The identity SpacetimeDB assigns to a Django token for `mitgliedsnr`, with the configured
+/// issuer and subject format. Sync and `compute_expected_identity` both use it.
+pub(crate) fn expected_identity(mitgliedsnr: u64) -> Identity {
+    derive_identity(
+        &oauth_issuer_url(),
+        SubjectFormat::configured(),
+        mitgliedsnr,
+    )
+}
+
+/// Answer of `compute_expected_identity`, keyed by the caller-chosen `request_id`.
+#[spacetimedb::table(accessor = expected_identity_result)]
+pub struct ExpectedIdentityResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub mitgliedsnr: u64,
+    pub issuer: String,
+    pub identity: Identity,
+    pub computed_at: Timestamp,
+}
+
+/// The caller's own `compute_expected_identity` answers.
+#[spacetimedb::view(accessor = my_expected_identity_results, public)]
+pub fn my_expected_identity_results(ctx: &ViewContext) -> Vec<ExpectedIdentityResult> {
+    ctx.db
+        .expected_identity_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Debug helper: derive the identity a token for `mitgliedsnr` maps to, exactly as the sync
+/// does, and write it into `expected_identity_result` under `request_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn compute_expected_identity(
+    ctx: &ReducerContext,
+    request_id: String,
+    mitgliedsnr: u64,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let identity = expected_identity(mitgliedsnr);
+    let result = ExpectedIdentityResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        mitgliedsnr,
+        issuer: oauth_issuer_url(),
+        identity,
+        computed_at: ctx.timestamp,
+    };
+    let owner = ctx
+        .db
+        .expected_identity_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db
+            .expected_identity_result()
+            .request_id()
+            .update(result);
+    } else {
+        ctx.db.expected_identity_result().insert(result);
+    }
+    log::info!(
+        "Computed expected identity {:?} for account {} (subject '{}', by identity: {:?})",
+        identity,
+        mitgliedsnr,
+        SubjectFormat::configured().subject(mitgliedsnr),
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Answer of `lookup_account_by_identity`, keyed by the caller-chosen `request_id`.
+/// `account_id` is None when no account has the identity.
+#[spacetimedb::table(accessor = account_lookup_result)]
+pub struct AccountLookupResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub identity: Identity,
+    pub account_id: Option<u64>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub looked_up_at: Timestamp,
+}
+
+/// The caller's own `lookup_account_by_identity` answers.
+#[spacetimedb::view(accessor = my_account_lookup_results, public)]
+pub fn my_account_lookup_results(ctx: &ViewContext) -> Vec<AccountLookupResult> {
+    ctx.db
+        .account_lookup_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Support lookup: which account has the identity `identity_hex` (e.g. copied from a log)?
+/// Writes the account, or a row with `account_id = None`, into `account_lookup_result`
+/// under `request_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn lookup_account_by_identity(
+    ctx: &ReducerContext,
+    request_id: String,
+    identity_hex: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let identity_hex = identity_hex.trim();
+    let identity = Identity::from_hex(identity_hex)
+        .map_err(|e| format!("Invalid identity hex '{}': {}", identity_hex, e))?;
+    let account = ctx.db.account().identity().find(&identity);
+    let result = AccountLookupResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        identity,
+        account_id: account.as_ref().map(|a| a.id),
+        name: account.as_ref().map(|a| a.name.clone()),
+        email: account.as_ref().map(|a| a.email.clone()),
+        looked_up_at: ctx.timestamp,
+    };
+    let account_id = result.account_id;
+    let owner = ctx
+        .db
+        .account_lookup_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db.account_lookup_result().request_id().update(result);
+    } else {
+        ctx.db.account_lookup_result().insert(result);
+    }
+    log::info!(
+        "Looked up identity {:?} for request {}: account {:?} (by identity: {:?})",
+        identity,
+        request_id,
+        account_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// A Django sync received while `module_settings.sync_paused` was on, waiting for
+/// `flush_pending_syncs`. Private: `user_data` is the raw payload.
+#[spacetimedb::table(accessor = pending_sync)]
+pub struct PendingSync {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub action: String,
+    pub user_data: String,
+    pub received_at: Timestamp,
+    /// Correlation id of the request that sent the sync, applied with it on flush
+    #[default(String::new())]
+    pub correlation_id: String,
+}
+
 // Keep existing sync_user logic but factor into helper so HTTP handler can call it.
 
+/// Apply a Django sync, or queue it into `pending_sync` while syncs are paused. The rows it
+/// writes carry `correlation_id`, the id of the request that sent it.
 pub(crate) fn do_sync_user(
     ctx: &ReducerContext,
     action: String,
     user_data: String,
+    correlation_id: &str,
+) -> Result<(), String> {
+    if settings::current(ctx).sync_paused {
+        // Reject what the sync would reject now, so a bad payload cannot block the flush later.
+        check_sync_payload(&action, &user_data)?;
+        let queued = ctx.db.pending_sync().insert(PendingSync {
+            id: 0,
+            action,
+            user_data,
+            received_at: ctx.timestamp,
+            correlation_id: correlation_id.to_string(),
+        });
+        log::info!(
+            "[{}] Sync paused, queued {} sync as pending sync {}",
+            correlation_id,
+            queued.action,
+            queued.id
+        );
+        return Ok(());
+    }
+    apply_sync_user(ctx, action, user_data, correlation_id)
+}
+
+/// The checks `apply_sync_user` fails on before it writes anything: readable `UserSyncData`, a
+/// known action and, for an upsert, a usable `identity_hex`.
+fn check_sync_payload(action: &str, user_data: &str) -> Result<(), String> {
+    let data = serde_json::from_str::<UserSyncData>(user_data)
+        .map_err(|e| format!("Failed to parse user sync data: {}", e))?;
+    match action {
+        "upsert" => resolve_sync_identity(&data).map(|_| ()),
+        "delete" => Ok(()),
+        _ => Err(format!("Unknown sync action: {}", action)),
+    }
+}
+
+/// Apply all syncs queued while paused, oldest first. Only admins, and only after
+/// `set_sync_paused(false)`. If one queued sync fails, the whole flush fails and rolls back, so
+/// no sync is lost; the error names the failing pending sync.
+#[spacetimedb::reducer]
+pub fn flush_pending_syncs(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if settings::current(ctx).sync_paused {
+        return Err("Syncs are still paused".to_string());
+    }
+    // Auto-increment ids follow arrival order.
+    let mut pending: Vec<PendingSync> = ctx.db.pending_sync().iter().collect();
+    pending.sort_by_key(|p| p.id);
+
+    let count = pending.len();
+    for sync in pending {
+        ctx.db.pending_sync().id().delete(&sync.id);
+        apply_sync_user(ctx, sync.action, sync.user_data, &sync.correlation_id).map_err(|e| {
+            format!(
+                "Pending sync {} failed, no pending sync was applied: {}",
+                sync.id, e
+            )
+        })?;
+    }
+    log::info!(
+        "Flushed {} pending syncs (by identity: {:?})",
+        count,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+fn apply_sync_user(
+    ctx: &ReducerContext,
+    action: String,
+    user_data: String,
+    correlation_id: &str,
 ) -> Result<(), String> {
     let timestamp = ctx.timestamp;
 
-    log::info!("Syncing user with action: {}", action);
+    log::info!("[{}] Syncing user with action: {}", correlation_id, action);
     log::info!("User data: {}", user_data);
 
     match serde_json::from_str::<UserSyncData>(&user_data) {
@@ -222,23 +1273,35 @@ pub(crate) fn do_sync_user(
             "upsert" => {
                 log::info!("Syncing user: {} ({})", data.mitgliedsnr, action);
 
-                let mitgliedsnr = data.mitgliedsnr.to_string();
-                let issuer_url = format!("{}{}", DJANGO_OAUTH_BASE_URL, DJANGO_OAUTH_ISSUER_PATH);
-                let identity_of_user = Identity::from_claims(&issuer_url, &mitgliedsnr);
+                let identity_of_user = resolve_sync_identity(&data)?;
                 let is_admin = data.is_admin.unwrap_or(false);
+                let updated_at = sync_updated_at(&data);
 
                 if let Some(existing) = ctx.db.account().id().find(&data.mitgliedsnr) {
-                    // Update in place — Django is source of truth for is_admin
-                    let updated = Account {
-                        identity: identity_of_user,
-                        name: data.name.unwrap_or_default(),
-                        email: data.email.unwrap_or_default(),
-                        is_active: data.is_active.unwrap_or(true),
-                        last_synced: timestamp,
-                        ..existing
-                    };
-                    ctx.db.account().id().update(updated);
-                    log::info!("Updated existing account: {}", data.mitgliedsnr);
+                    if is_stale_sync(updated_at, &existing) {
+                        // Out of order: groups and admin flag of an older version must not win
+                        // over the newer one already applied either.
+                        log::info!(
+                            "Ignoring sync of account {}, updated_at is older than the last applied sync",
+                            data.mitgliedsnr
+                        );
+                        return Ok(());
+                    } else {
+                        // Update in place — Django is source of truth for is_admin
+                        let updated = synced_account_update(
+                            existing,
+                            &data,
+                            identity_of_user,
+                            updated_at,
+                            timestamp,
+                            sync_respects_manual_names(),
+                        );
+                        ctx.db.account().id().update(Account {
+                            sync_correlation_id: correlation_id.to_string(),
+                            ..updated
+                        });
+                        log::info!("Updated existing account: {}", data.mitgliedsnr);
+                    }
                 } else {
                     // Insert new account
                     let account = Account {
@@ -248,25 +1311,49 @@ pub(crate) fn do_sync_user(
                         email: data.email.unwrap_or_default(),
                         is_active: data.is_active.unwrap_or(true),
                         last_synced: timestamp,
+                        name_overridden: false,
+                        django_updated_at: updated_at,
+                        manually_edited_at: None,
+                        sync_correlation_id: correlation_id.to_string(),
                     };
                     log::info!("Inserting new account: {:#?}", account);
                     ctx.db.account().insert(account);
                     log::info!("Inserted new account: {}", data.mitgliedsnr);
                 }
+                let Some(account) = ctx.db.account().id().find(&data.mitgliedsnr) else {
+                    return Ok(());
+                };
+                mailing::link_unresolved_subscriptions(ctx, account.id, &account.email);
+                if let Some(groups) = data.groups.as_deref() {
+                    mailing::apply_group_subscriptions(ctx, &account, groups)?;
+                }
 
                 // Keep admin_identities table in sync with Django's admin flag
+                let identity_of_user = account.identity;
                 if is_admin {
-                    if ctx
-                        .db
-                        .admin_identities()
-                        .identity()
-                        .find(&identity_of_user)
-                        .is_none()
-                    {
-                        ctx.db.admin_identities().insert(AdminIdentity {
-                            identity: identity_of_user,
-                        });
-                        log::info!("Granted admin_identities for account: {}", data.mitgliedsnr);
+                    match ctx.db.admin_identities().identity().find(&identity_of_user) {
+                        None => {
+                            ctx.db.admin_identities().insert(
+                                AdminIdentity::new(
+                                    ctx,
+                                    identity_of_user,
+                                    format!("Django {}", data.mitgliedsnr),
+                                )
+                                .for_account(data.mitgliedsnr),
+                            );
+                            log::info!(
+                                "Granted admin_identities for account: {}",
+                                data.mitgliedsnr
+                            );
+                        }
+                        // Granted before admins recorded their account
+                        Some(admin) if admin.account_id != Some(data.mitgliedsnr) => {
+                            ctx.db
+                                .admin_identities()
+                                .identity()
+                                .update(admin.for_account(data.mitgliedsnr));
+                        }
+                        Some(_) => {}
                     }
                 } else if ctx
                     .db
@@ -287,7 +1374,18 @@ pub(crate) fn do_sync_user(
                 if let Some(existing) = ctx.db.account().id().find(&data.mitgliedsnr) {
                     let identity_of_user = existing.identity;
                     ctx.db.account().delete(existing);
-                    log::info!("Deleted user: {} ({})", data.mitgliedsnr, action);
+                    ctx.db
+                        .account_alias()
+                        .account_id()
+                        .delete(&data.mitgliedsnr);
+                    // Tags are not part of the Django data; only deleting the account drops them.
+                    ctx.db.account_tag().account_id().delete(&data.mitgliedsnr);
+                    log::info!(
+                        "[{}] Deleted user: {} ({})",
+                        correlation_id,
+                        data.mitgliedsnr,
+                        action
+                    );
                     // Also remove from admin_identities if present
                     if ctx
                         .db
@@ -318,14 +1416,555 @@ pub(crate) fn do_sync_user(
     Ok(())
 }
 
+/// Audience a Django token must carry to call `sync_user` without a registered admin identity,
+/// set at build time via `SYNC_JWT_AUDIENCE`. Unset, only admin identities may sync.
+fn sync_jwt_audience() -> Option<&'static str> {
+    option_env!("SYNC_JWT_AUDIENCE").filter(|aud| !aud.is_empty())
+}
+
+/// Scope a Django token must carry in its space-separated `scope` claim to sync by token, set at
+/// build time via `SYNC_JWT_SCOPE`. Issuer and audience alone also match every member's login
+/// token, and a sync may grant admin rights, so the scope marks the dedicated sync client.
+fn sync_jwt_scope() -> &'static str {
+    match option_env!("SYNC_JWT_SCOPE") {
+        Some(scope) if !scope.is_empty() => scope,
+        _ => "spacetime:sync",
+    }
+}
+
+/// The claim checks of `verify_sync_token` on the token payload: `iss` is `issuer`, `aud`
+/// (a string or a list) contains `audience`, `scope` contains `scope` and `exp` lies after
+/// `now_secs`.
+fn check_sync_claims(
+    claims: &serde_json::Value,
+    issuer: &str,
+    audience: &str,
+    scope: &str,
+    now_secs: i64,
+) -> Result<(), String> {
+    let token_issuer = claims.get("iss").and_then(|iss| iss.as_str()).unwrap_or("");
+    if token_issuer != issuer {
+        return Err(format!(
+            "wrong issuer '{}', expected '{}'",
+            token_issuer, issuer
+        ));
+    }
+    let audience_matches = match claims.get("aud") {
+        Some(serde_json::Value::String(aud)) => aud == audience,
+        Some(serde_json::Value::Array(auds)) => auds.iter().any(|aud| aud == audience),
+        _ => false,
+    };
+    if !audience_matches {
+        return Err(format!("token is not issued for audience '{}'", audience));
+    }
+    let scope_matches = claims
+        .get("scope")
+        .and_then(|s| s.as_str())
+        .is_some_and(|scopes| scopes.split_whitespace().any(|s| s == scope));
+    if !scope_matches {
+        return Err(format!("token lacks the '{}' scope", scope));
+    }
+    let exp = claims
+        .get("exp")
+        .and_then(|exp| exp.as_i64())
+        .ok_or("token has no exp claim")?;
+    if exp <= now_secs {
+        return Err(format!("token expired at {}", exp));
+    }
+    Ok(())
+}
+
+/// Check the caller's connection token for the sync path: issued by the Django issuer, meant for
+/// `SYNC_JWT_AUDIENCE`, carrying `SYNC_JWT_SCOPE` and not expired. SpacetimeDB already verified
+/// the signature against the issuer's JWKS (found via OIDC discovery and cached by the host) when
+/// the connection was opened; this adds the checks the host does not know about and catches
+/// tokens that expired since.
+fn verify_sync_token(ctx: &ReducerContext) -> Result<(), String> {
+    let audience = sync_jwt_audience().ok_or("token sync is not configured")?;
+    let auth = ctx.sender_auth();
+    let jwt = auth.jwt().ok_or("caller presented no token")?;
+    let claims = serde_json::from_str::<serde_json::Value>(jwt.raw_payload())
+        .map_err(|e| format!("unreadable token payload: {}", e))?;
+    let now = ctx.timestamp.to_micros_since_unix_epoch() / 1_000_000;
+    check_sync_claims(
+        &claims,
+        &oauth_issuer_url(),
+        audience,
+        sync_jwt_scope(),
+        now,
+    )
+}
+
+/// Reducer form of the Django sync. Allowed for admin identities and, with `SYNC_JWT_AUDIENCE`
+/// set, for callers whose token passes `verify_sync_token`. `correlation_id` is the caller's id
+/// of the request, stored on the account row (or pending sync) the sync writes.
 #[spacetimedb::reducer]
-pub fn sync_user(ctx: &ReducerContext, action: String, user_data: String) -> Result<(), String> {
+pub fn sync_user(
+    ctx: &ReducerContext,
+    action: String,
+    user_data: String,
+    correlation_id: String,
+) -> Result<(), String> {
     if !is_admin_identity(ctx, ctx.sender()) {
-        log::warn!("Unauthorized sync_user call from {:?}", ctx.sender());
-        return Err(format!(
-            "Unauthorized: sync_user called by {:?}",
-            ctx.sender()
+        if let Err(reason) = verify_sync_token(ctx) {
+            log::warn!(
+                "Unauthorized sync_user call from {:?}: {}",
+                ctx.sender(),
+                reason
+            );
+            return Err(format!(
+                "Unauthorized: sync_user called by {:?}",
+                ctx.sender()
+            ));
+        }
+        log::info!("sync_user authorized by token of {:?}", ctx.sender());
+    }
+    do_sync_user(ctx, action, user_data, &correlation_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ISSUER: &str = "https://django.example.org/o";
+    const AUDIENCE: &str = "kommunikationszentrum-sync";
+    const SCOPE: &str = "spacetime:sync";
+    const NOW: i64 = 1_700_000_000;
+
+    fn claims() -> serde_json::Value {
+        json!({
+            "iss": ISSUER,
+            "aud": [AUDIENCE, "other"],
+            "scope": "openid spacetime:sync",
+            "sub": "sync-service",
+            "exp": NOW + 300,
+        })
+    }
+
+    fn check(claims: &serde_json::Value) -> Result<(), String> {
+        check_sync_claims(claims, ISSUER, AUDIENCE, SCOPE, NOW)
+    }
+
+    #[test]
+    fn sync_claims_accept_valid_token() {
+        assert_eq!(check(&claims()), Ok(()));
+        let mut single_aud = claims();
+        single_aud["aud"] = json!(AUDIENCE);
+        assert_eq!(check(&single_aud), Ok(()));
+    }
+
+    #[test]
+    fn sync_claims_reject_expired_token() {
+        let mut expired = claims();
+        expired["exp"] = json!(NOW);
+        assert!(check(&expired).unwrap_err().contains("expired"));
+        expired.as_object_mut().unwrap().remove("exp");
+        assert!(check(&expired).is_err());
+    }
+
+    #[test]
+    fn sync_claims_reject_wrong_issuer() {
+        let mut wrong = claims();
+        wrong["iss"] = json!("https://evil.example.org/o");
+        assert!(check(&wrong).unwrap_err().contains("wrong issuer"));
+    }
+
+    #[test]
+    fn sync_claims_reject_member_token_without_scope() {
+        let mut member = claims();
+        member["scope"] = json!("openid profile email");
+        assert!(check(&member).unwrap_err().contains("scope"));
+        member.as_object_mut().unwrap().remove("scope");
+        assert!(check(&member).is_err());
+        let mut wrong_aud = claims();
+        wrong_aud["aud"] = json!("admin-app");
+        assert!(check(&wrong_aud).unwrap_err().contains("audience"));
+    }
+
+    fn sync_data(identity_hex: Option<&str>) -> UserSyncData {
+        UserSyncData {
+            mitgliedsnr: 42,
+            name: None,
+            email: None,
+            is_active: None,
+            is_admin: None,
+            updated_at: None,
+            identity_hex: identity_hex.map(str::to_string),
+            groups: None,
+        }
+    }
+
+    #[test]
+    fn resolve_sync_identity_uses_valid_identity_hex() {
+        let hex = "c200".to_string() + &"ab".repeat(30);
+        let resolved = resolve_sync_identity(&sync_data(Some(&format!(" {hex} "))));
+        assert_eq!(resolved, Ok(Identity::from_hex(&hex).unwrap()));
+    }
+
+    #[test]
+    fn resolve_sync_identity_rejects_invalid_identity_hex() {
+        let short = resolve_sync_identity(&sync_data(Some("c200ab")));
+        assert!(short.unwrap_err().contains("expected 64 hex characters"));
+        let not_hex = "zz".repeat(32);
+        assert!(resolve_sync_identity(&sync_data(Some(&not_hex))).is_err());
+    }
+
+    #[test]
+    fn resolve_sync_identity_derives_identity_when_absent() {
+        assert_eq!(
+            resolve_sync_identity(&sync_data(None)),
+            Ok(expected_identity(42))
+        );
+        assert_eq!(
+            resolve_sync_identity(&sync_data(Some("  "))),
+            Ok(expected_identity(42))
+        );
+    }
+
+    #[test]
+    fn subject_format_parses_plain_and_padded() {
+        assert_eq!(SubjectFormat::parse("plain"), Ok(SubjectFormat::Plain));
+        assert_eq!(
+            SubjectFormat::parse("padded:6"),
+            Ok(SubjectFormat::ZeroPadded(6))
+        );
+    }
+
+    #[test]
+    fn subject_format_rejects_malformed_values() {
+        for value in [
+            "",
+            "padded",
+            "padded:",
+            "padded:x",
+            "padded:6x",
+            "Plain",
+            "padded:-1",
+        ] {
+            assert!(SubjectFormat::parse(value).is_err(), "accepted {value:?}");
+        }
+    }
+
+    #[test]
+    fn subject_format_writes_subject() {
+        assert_eq!(SubjectFormat::Plain.subject(42), "42");
+        assert_eq!(SubjectFormat::ZeroPadded(6).subject(42), "000042");
+        assert_eq!(SubjectFormat::ZeroPadded(2).subject(1234), "1234");
+    }
+
+    #[test]
+    fn derive_identity_pins_issuer_and_subject() {
+        assert_eq!(
+            derive_identity(ISSUER, SubjectFormat::Plain, 42),
+            Identity::from_claims(ISSUER, "42")
+        );
+        assert_eq!(
+            derive_identity(ISSUER, SubjectFormat::ZeroPadded(6), 42),
+            Identity::from_claims(ISSUER, "000042")
+        );
+        assert_ne!(
+            derive_identity(ISSUER, SubjectFormat::Plain, 42),
+            derive_identity(ISSUER, SubjectFormat::ZeroPadded(6), 42)
+        );
+        assert_ne!(
+            derive_identity(ISSUER, SubjectFormat::Plain, 42),
+            derive_identity("https://other.example.org/o", SubjectFormat::Plain, 42)
+        );
+    }
+
+    fn synced_account(django_updated_at: Option<Timestamp>) -> Account {
+        Account {
+            id: 42,
+            identity: Identity::ZERO,
+            name: "Alice".to_string(),
+            email: "alice@example.org".to_string(),
+            is_active: true,
+            // Bumped by every sync and manual edit; staleness must not depend on it.
+            last_synced: Timestamp::from_micros_since_unix_epoch(i64::MAX / 2),
+            name_overridden: false,
+            django_updated_at,
+            manually_edited_at: None,
+            sync_correlation_id: String::new(),
+        }
+    }
+
+    fn alias(account_id: u64, verified: bool) -> AccountAlias {
+        AccountAlias {
+            id: 1,
+            account_id,
+            email: "alice@work.example.org".to_string(),
+            verified,
+            created_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn mail_from_a_verified_alias_belongs_to_the_owner() {
+        let owner_of = |id: u64| (id == 42).then(|| synced_account(None));
+        let account = sender_account(None, Some(alias(42, true)), owner_of).unwrap();
+        // The owner's subscriptions are keyed by this id, so the alias posts as the owner.
+        assert_eq!(account.id, 42);
+        assert!(sender_account(None, Some(alias(42, false)), owner_of).is_none());
+        assert!(sender_account(None, None, owner_of).is_none());
+    }
+
+    #[test]
+    fn primary_address_wins_over_an_alias() {
+        let primary = Account {
+            id: 7,
+            ..synced_account(None)
+        };
+        let account = sender_account(Some(primary), Some(alias(42, true)), |_| None).unwrap();
+        assert_eq!(account.id, 7);
+    }
+
+    fn rfc3339(value: &str) -> Option<Timestamp> {
+        Some(Timestamp::parse_from_rfc3339(value).unwrap())
+    }
+
+    #[test]
+    fn stale_sync_compares_against_django_updated_at() {
+        let applied = synced_account(rfc3339("2026-05-01T12:00:00Z"));
+        assert!(is_stale_sync(rfc3339("2026-04-30T12:00:00Z"), &applied));
+        assert!(!is_stale_sync(rfc3339("2026-05-01T12:00:00Z"), &applied));
+        assert!(!is_stale_sync(rfc3339("2026-05-02T12:00:00Z"), &applied));
+    }
+
+    #[test]
+    fn sync_of_a_version_before_the_manual_edit_keeps_it() {
+        let edited = Account {
+            manually_edited_at: rfc3339("2026-05-03T09:00:00Z"),
+            ..synced_account(rfc3339("2026-05-01T12:00:00Z"))
+        };
+        // The same version resent, and one written before the edit
+        assert!(predates_manual_edit(
+            rfc3339("2026-05-01T12:00:00Z"),
+            &edited
+        ));
+        assert!(predates_manual_edit(
+            rfc3339("2026-05-02T12:00:00Z"),
+            &edited
+        ));
+        // Django changed the member after the edit
+        assert!(!predates_manual_edit(
+            rfc3339("2026-05-04T12:00:00Z"),
+            &edited
+        ));
+        assert!(!predates_manual_edit(None, &edited));
+        assert!(!predates_manual_edit(
+            rfc3339("2026-05-01T12:00:00Z"),
+            &synced_account(None)
+        ));
+    }
+
+    #[test]
+    fn sync_keeps_a_manually_set_name_only_when_configured() {
+        let renamed = || Account {
+            name: "Alice B.".to_string(),
+            name_overridden: true,
+            ..synced_account(None)
+        };
+        let data: UserSyncData = serde_json::from_value(json!({
+            "mitgliedsnr": 42,
+            "name": "Alice Beispiel",
+            "email": "alice@example.org",
+        }))
+        .unwrap();
+        let sync = |existing, respect| {
+            synced_account_update(
+                existing,
+                &data,
+                Identity::ZERO,
+                None,
+                Timestamp::UNIX_EPOCH,
+                respect,
+            )
+        };
+
+        let kept = sync(renamed(), true);
+        assert_eq!(kept.name, "Alice B.");
+        assert!(kept.name_overridden);
+        // Off, the synced name wins and the override is cleared.
+        let overwritten = sync(renamed(), false);
+        assert_eq!(overwritten.name, "Alice Beispiel");
+        assert!(!overwritten.name_overridden);
+        // Without a manual name there is nothing to keep.
+        assert_eq!(sync(synced_account(None), true).name, "Alice Beispiel");
+    }
+
+    fn tag(id: u64, account_id: u64, tag: &str) -> AccountTag {
+        AccountTag {
+            id,
+            account_id,
+            tag: tag.to_string(),
+            created_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn sync_keeps_the_tags_of_the_updated_account() {
+        let tags = [
+            tag(1, 42, "vorstand"),
+            tag(2, 42, "zahlt-bar"),
+            tag(3, 7, "neu"),
+        ];
+        let data: UserSyncData = serde_json::from_value(json!({
+            "mitgliedsnr": 42,
+            "name": "Alice Neu",
+            "email": "alice.neu@example.org",
+            "groups": ["vorstand"],
+        }))
+        .unwrap();
+        let updated = synced_account_update(
+            synced_account(None),
+            &data,
+            Identity::ZERO,
+            None,
+            Timestamp::UNIX_EPOCH,
+            false,
+        );
+
+        // The upsert keeps the account id the tags point at, so both of Alice's stay hers.
+        assert_eq!(updated.id, 42);
+        let kept: Vec<&str> = tags
+            .iter()
+            .filter(|t| t.account_id == updated.id)
+            .map(|t| t.tag.as_str())
+            .collect();
+        assert_eq!(kept, ["vorstand", "zahlt-bar"]);
+    }
+
+    #[test]
+    fn connecting_updates_last_seen_of_a_registered_admin() {
+        let admin = Identity::from_hex(&"a1".repeat(32)).unwrap();
+        let stranger = Identity::from_hex(&"b2".repeat(32)).unwrap();
+        let registered = |identity: &Identity| {
+            (*identity == admin).then(|| AdminIdentity {
+                identity: admin,
+                last_seen: Timestamp::UNIX_EPOCH,
+                label: "Django 42".to_string(),
+                account_id: Some(42),
+            })
+        };
+        let now = Timestamp::from_micros_since_unix_epoch(1_700_000_000_000_000);
+
+        let connected = admin_after_connect(registered, admin, now).unwrap();
+        assert_eq!(connected.identity, admin);
+        assert_eq!(connected.last_seen, now);
+        assert_eq!(connected.label, "Django 42");
+        // Any other identity connecting writes no row
+        assert!(admin_after_connect(registered, stranger, now).is_none());
+    }
+
+    #[test]
+    fn admin_without_a_known_last_seen_is_never_idle() {
+        let day = 86_400 * 1_000_000;
+        let now = Timestamp::from_micros_since_unix_epoch(1_700_000_000_000_000);
+        let admin = |last_seen| AdminIdentity {
+            identity: Identity::ZERO,
+            last_seen,
+            label: String::new(),
+            account_id: None,
+        };
+
+        let long_ago = Timestamp::from_micros_since_unix_epoch(1_700_000_000_000_000 - 31 * day);
+        assert!(admin(long_ago).is_idle(now, 30 * day));
+        assert!(!admin(long_ago).is_idle(now, 31 * day));
+        // Registered before `last_seen` was recorded
+        assert!(!admin(Timestamp::UNIX_EPOCH).is_idle(now, 30 * day));
+    }
+
+    #[test]
+    fn admin_export_round_trips_through_import() {
+        let identity = Identity::from_hex(&"c2".repeat(32)).unwrap();
+        let admins = [
+            AdminIdentity {
+                identity,
+                last_seen: Timestamp::UNIX_EPOCH,
+                label: "Django 42".to_string(),
+                account_id: Some(42),
+            },
+            AdminIdentity {
+                identity: Identity::ZERO,
+                last_seen: Timestamp::UNIX_EPOCH,
+                label: String::new(),
+                account_id: None,
+            },
+        ];
+        let json = admin_export(admins.into_iter()).unwrap();
+        assert_eq!(
+            parse_admin_import(&json).unwrap(),
+            vec![
+                (identity, "Django 42".to_string()),
+                (Identity::ZERO, String::new())
+            ]
+        );
+        let broken = r#"[{"identity_hex": "c2", "label": "kaputt"}]"#;
+        assert!(parse_admin_import(broken)
+            .unwrap_err()
+            .contains("Invalid identity hex"));
+    }
+
+    #[test]
+    fn stale_sync_needs_both_timestamps() {
+        assert!(!is_stale_sync(
+            None,
+            &synced_account(rfc3339("2026-05-01T12:00:00Z"))
         ));
+        assert!(!is_stale_sync(
+            rfc3339("2020-01-01T00:00:00Z"),
+            &synced_account(None)
+        ));
+    }
+
+    #[test]
+    fn sync_updated_at_ignores_unparsable_values() {
+        let mut data = sync_data(None);
+        assert_eq!(sync_updated_at(&data), None);
+        data.updated_at = Some("gestern".to_string());
+        assert_eq!(sync_updated_at(&data), None);
+        data.updated_at = Some("2026-05-01T12:00:00+02:00".to_string());
+        assert_eq!(sync_updated_at(&data), rfc3339("2026-05-01T10:00:00Z"));
+    }
+
+    #[test]
+    fn queued_sync_payload_is_checked_like_a_direct_sync() {
+        let valid = r#"{"mitgliedsnr": 42, "name": "Alice"}"#;
+        assert_eq!(check_sync_payload("upsert", valid), Ok(()));
+        assert_eq!(check_sync_payload("delete", valid), Ok(()));
+        assert!(check_sync_payload("rename", valid)
+            .unwrap_err()
+            .contains("Unknown sync action"));
+        assert!(check_sync_payload("upsert", "{").is_err());
+        let bad_identity = r#"{"mitgliedsnr": 42, "identity_hex": "abc"}"#;
+        assert!(check_sync_payload("upsert", bad_identity).is_err());
+    }
+
+    #[test]
+    fn user_sync_payload_round_trips() {
+        let user = json!({
+            "mitgliedsnr": 1042,
+            "name": "Alice Beispiel",
+            "email": "alice@example.org",
+            "is_active": true,
+            "is_admin": false,
+            "updated_at": "2026-10-01T12:00:00Z",
+            "identity_hex": "c200abcdef",
+            "groups": ["vorstand", "gaertner"],
+        });
+        let payload: UserSyncPayload =
+            serde_json::from_value(json!({"action": "upsert", "user": user.clone()})).unwrap();
+        assert_eq!(payload.action, "upsert");
+        assert_eq!(serde_json::to_value(&payload.user).unwrap(), user);
+    }
+
+    #[test]
+    fn user_sync_data_needs_only_the_member_number() {
+        let user: UserSyncData = serde_json::from_value(json!({"mitgliedsnr": 7})).unwrap();
+        assert_eq!(user.mitgliedsnr, 7);
+        assert!(user.email.is_none() && user.identity_hex.is_none() && user.groups.is_none());
+        // A string member number is a contract break, not a silent 0.
+        assert!(serde_json::from_value::<UserSyncData>(json!({"mitgliedsnr": "7"})).is_err());
     }
-    do_sync_user(ctx, action, user_data)
 }
@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::account::is_admin_user;
+use crate::mta::{blocked_ips, insert_block, BLOCK_SOURCE_RATE_LIMIT};
+
+/// Failed AUTH attempts per IP within the window before the IP is blocked.
+/// Set at build time via `AUTH_FAILURE_THRESHOLD`.
+fn auth_failure_threshold() -> usize {
+    option_env!("AUTH_FAILURE_THRESHOLD")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(5)
+}
+
+/// Sliding window for counting failures, set at build time via `AUTH_FAILURE_WINDOW_SECS`.
+fn auth_failure_window() -> Duration {
+    let secs = option_env!("AUTH_FAILURE_WINDOW_SECS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(600);
+    Duration::from_secs(secs)
+}
+
+/// One failed SMTP AUTH attempt. Rows older than the window are dropped on the next failure
+/// from the same IP.
+#[spacetimedb::table(accessor = auth_failure)]
+pub struct AuthFailure {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub client_ip: String,
+    pub failed_at: Timestamp,
+}
+
+/// Split an IP's earlier failures for a new one at `now`: the ids of those older than `window`,
+/// and the number of failures within it, the new one included.
+fn tally_failures(previous: &[AuthFailure], now: Timestamp, window: Duration) -> (Vec<u64>, usize) {
+    let (expired, recent): (Vec<&AuthFailure>, Vec<&AuthFailure>) = previous
+        .iter()
+        .partition(|f| now.duration_since(f.failed_at).unwrap_or_default() > window);
+    (expired.iter().map(|f| f.id).collect(), recent.len() + 1)
+}
+
+/// Whether `recent` failures within the window block an IP. An active block is not renewed.
+fn blocks_ip(recent: usize, threshold: usize, already_blocked: bool) -> bool {
+    recent >= threshold && !already_blocked
+}
+
+/// Count a failed AUTH from `client_ip`. After `AUTH_FAILURE_THRESHOLD` failures within the
+/// window, the IP gets a `RateLimit` block; `prune_blocks_by_source` expires those again.
+/// Returns `true` if this failure caused a block.
+pub(crate) fn record_auth_failure_for(ctx: &ReducerContext, client_ip: &str) -> bool {
+    let client_ip = client_ip.trim().to_string();
+    let window = auth_failure_window();
+
+    let previous: Vec<AuthFailure> = ctx
+        .db
+        .auth_failure()
+        .client_ip()
+        .filter(&client_ip)
+        .collect();
+    let (expired, recent) = tally_failures(&previous, ctx.timestamp, window);
+    for id in expired {
+        ctx.db.auth_failure().id().delete(&id);
+    }
+    ctx.db.auth_failure().insert(AuthFailure {
+        id: 0,
+        client_ip: client_ip.clone(),
+        failed_at: ctx.timestamp,
+    });
+
+    let already_blocked = ctx
+        .db
+        .blocked_ips()
+        .ip()
+        .find(&client_ip)
+        .map_or(false, |b| b.active);
+    if !blocks_ip(recent, auth_failure_threshold(), already_blocked) {
+        return false;
+    }
+    insert_block(
+        ctx,
+        &client_ip,
+        &format!(
+            "{} failed AUTH attempts within {}s",
+            recent,
+            window.as_secs()
+        ),
+        BLOCK_SOURCE_RATE_LIMIT,
+    );
+    log::warn!("Blocked IP after {} failed AUTH attempts", recent);
+    true
+}
+
+/// Report a failed SMTP AUTH attempt, e.g. from a log watcher. Only admins.
+#[spacetimedb::reducer]
+pub fn record_auth_failure(ctx: &ReducerContext, client_ip: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if client_ip.trim().parse::<std::net::IpAddr>().is_err() {
+        return Err(format!("Invalid IP address: {}", client_ip));
+    }
+    record_auth_failure_for(ctx, &client_ip);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(id: u64, secs: i64) -> AuthFailure {
+        AuthFailure {
+            id,
+            client_ip: "192.0.2.1".to_string(),
+            failed_at: Timestamp::from_micros_since_unix_epoch(secs * 1_000_000),
+        }
+    }
+
+    #[test]
+    fn failures_outside_the_window_expire_and_do_not_count() {
+        let now = Timestamp::from_micros_since_unix_epoch(1_000 * 1_000_000);
+        let window = Duration::from_secs(600);
+        let previous = [
+            failure(1, 100),
+            failure(2, 400),
+            failure(3, 700),
+            failure(4, 990),
+        ];
+        // 1000 - 400 = 600 is still within the window
+        assert_eq!(tally_failures(&previous, now, window), (vec![1], 4));
+        assert_eq!(tally_failures(&[], now, window), (vec![], 1));
+    }
+
+    #[test]
+    fn the_threshold_failure_blocks_once() {
+        assert!(!blocks_ip(4, 5, false));
+        assert!(blocks_ip(5, 5, false));
+        assert!(blocks_ip(6, 5, false));
+        // An IP that is blocked already gets no second block row
+        assert!(!blocks_ip(6, 5, true));
+    }
+}
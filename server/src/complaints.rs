@@ -0,0 +1,307 @@
+use std::time::Duration;
+
+use spacetimedb::{Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{admin_identities__view, is_admin_user};
+use crate::mailing::{deactivate_subscription, subscriptions, Subscription};
+
+/// Complaints about one address within the window before its subscriptions are deactivated.
+/// Set at build time via `COMPLAINT_THRESHOLD`.
+fn complaint_threshold() -> usize {
+    option_env!("COMPLAINT_THRESHOLD")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3)
+}
+
+/// Window for counting complaints, set at build time via `COMPLAINT_WINDOW_SECS`.
+fn complaint_window() -> Duration {
+    let secs = option_env!("COMPLAINT_WINDOW_SECS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30 * 24 * 60 * 60);
+    Duration::from_secs(secs)
+}
+
+/// ARF `Feedback-Type`s that count toward `COMPLAINT_THRESHOLD`. Other reports, e.g. `not-spam`
+/// (the recipient took a message out of the spam folder), are stored but never counted.
+const COUNTED_FEEDBACK_TYPES: [&str; 2] = ["abuse", "fraud"];
+
+/// Whether the complaints of `kinds`, all within the window, reach `threshold`.
+fn threshold_reached<'a>(kinds: impl IntoIterator<Item = &'a str>, threshold: usize) -> bool {
+    kinds
+        .into_iter()
+        .filter(|kind| COUNTED_FEEDBACK_TYPES.contains(kind))
+        .count()
+        >= threshold
+}
+
+/// One feedback loop complaint (ARF report) about mail we delivered to `from_address`.
+/// Rows older than the window are dropped on the next complaint about the same address.
+#[spacetimedb::table(accessor = complaint)]
+pub struct Complaint {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Lowercased address the complaint came from
+    #[index(btree)]
+    pub from_address: String,
+    /// ARF `Feedback-Type`, e.g. `abuse`, `fraud`, `not-spam`
+    pub kind: String,
+    pub received_at: Timestamp,
+}
+
+/// Returns all complaints for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_complaints, public)]
+pub fn visible_complaints(ctx: &ViewContext) -> impl Query<Complaint> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.complaint().r#filter(move |_| is_admin)
+}
+
+/// The fields of an ARF report (RFC 5965) that `record_complaint_for` needs.
+pub(crate) struct ArfReport {
+    pub from_address: String,
+    pub kind: String,
+}
+
+/// Read the machine-readable part of an ARF report: `Feedback-Type` and the complaining
+/// address from `Original-Rcpt-To` (or `Removal-Recipient`). The MIME structure is not
+/// parsed; the first matching field lines of the whole report are used.
+pub(crate) fn parse_arf_report(raw: &str) -> Option<ArfReport> {
+    let field = |name: &str| {
+        raw.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| {
+                value
+                    .trim()
+                    .trim_matches(|c| c == '<' || c == '>')
+                    .to_string()
+            })
+        })
+    };
+    let kind = field("Feedback-Type")?.to_lowercase();
+    let from_address = field("Original-Rcpt-To").or_else(|| field("Removal-Recipient"))?;
+    Some(ArfReport { from_address, kind })
+}
+
+/// Record a complaint from `from_address`. Once `COMPLAINT_THRESHOLD` `abuse` or `fraud`
+/// complaints fall within the window, all active subscriptions of that address are deactivated.
+/// Returns how many subscriptions were deactivated.
+pub(crate) fn record_complaint_for(
+    ctx: &ReducerContext,
+    from_address: &str,
+    kind: &str,
+    received_at: Timestamp,
+) -> usize {
+    let reported = from_address.trim().to_string();
+    let from_address = reported.to_lowercase();
+    let window = complaint_window();
+
+    let mut recent: Vec<String> = Vec::new();
+    let previous: Vec<Complaint> = ctx
+        .db
+        .complaint()
+        .from_address()
+        .filter(&from_address)
+        .collect();
+    for complaint in previous {
+        if ctx
+            .timestamp
+            .duration_since(complaint.received_at)
+            .unwrap_or_default()
+            > window
+        {
+            ctx.db.complaint().id().delete(&complaint.id);
+        } else {
+            recent.push(complaint.kind);
+        }
+    }
+    let kind = kind.trim().to_lowercase();
+    ctx.db.complaint().insert(Complaint {
+        id: 0,
+        from_address: from_address.clone(),
+        kind: kind.clone(),
+        received_at,
+    });
+    recent.push(kind);
+
+    if !threshold_reached(recent.iter().map(String::as_str), complaint_threshold()) {
+        return 0;
+    }
+    // Looked up as reported and lowercased; the index only finds exact matches.
+    let mut emails = vec![from_address.clone()];
+    if reported != from_address {
+        emails.push(reported);
+    }
+    let active: Vec<Subscription> = emails
+        .iter()
+        .flat_map(|email| ctx.db.subscriptions().subscriber_email().filter(email))
+        .filter(|sub| sub.active)
+        .collect();
+    let deactivated = active.len();
+    for sub in active {
+        deactivate_subscription(ctx, sub);
+    }
+    if deactivated > 0 {
+        log::warn!(
+            "Deactivated {} subscriptions of {} after {} complaints",
+            deactivated,
+            from_address,
+            recent.len()
+        );
+    }
+    deactivated
+}
+
+/// Report a feedback loop complaint, e.g. from a mailbox that collects ARF reports.
+/// Only admins.
+#[spacetimedb::reducer]
+pub fn record_complaint(
+    ctx: &ReducerContext,
+    from_address: String,
+    kind: String,
+    received_at: Timestamp,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if !from_address.contains('@') {
+        return Err(format!("Invalid address: {}", from_address));
+    }
+    if kind.trim().is_empty() {
+        return Err("kind must not be empty".to_string());
+    }
+    record_complaint_for(ctx, &from_address, &kind, received_at);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = "Content-Type: message/feedback-report\r\n\
+        \r\n\
+        Feedback-Type: Abuse\r\n\
+        User-Agent: ExampleFBL/1.0\r\n\
+        Version: 1\r\n\
+        Original-Rcpt-To: <Alice@example.org>\r\n\
+        Removal-Recipient: bob@example.org\r\n";
+
+    /// The complete report of RFC 5965, Appendix B.2: a human-readable part, the
+    /// machine-readable `message/feedback-report` part and the original message.
+    const RFC_5965_REPORT: &str = r#"From: <abusedesk@example.com>
+Date: Thu, 8 Mar 2005 17:40:36 EDT
+Subject: FW: Earn money
+To: <abuse@example.net>
+MIME-Version: 1.0
+Content-Type: multipart/report; report-type=feedback-report;
+     boundary="part1_13d.2e68ed54_boundary"
+
+--part1_13d.2e68ed54_boundary
+Content-Type: text/plain; charset="US-ASCII"
+Content-Transfer-Encoding: 7bit
+
+This is an email abuse report for an email message received from IP
+192.0.2.1 on Thu, 8 Mar 2005 14:00:00 EDT.  For more information
+about this format please see http://www.mipassoc.org/arf/.
+
+--part1_13d.2e68ed54_boundary
+Content-Type: message/feedback-report
+
+Feedback-Type: abuse
+User-Agent: SomeGenerator/1.0
+Version: 1
+Original-Mail-From: <somespammer@example.net>
+Original-Rcpt-To: <user@example.com>
+Arrival-Date: Thu, 8 Mar 2005 14:00:00 EDT
+Reporting-MTA: dns; mail.example.com
+Source-IP: 192.0.2.1
+Authentication-Results: mail.example.com;
+               spf=fail smtp.mail=somespammer@example.com
+Reported-Domain: example.net
+Reported-Uri: http://example.net/earn_money.html
+Reported-Uri: mailto:user@example.com
+Removal-Recipient: user@example.com
+
+--part1_13d.2e68ed54_boundary
+Content-Type: message/rfc822
+Content-Disposition: inline
+
+From: <somespammer@example.net>
+Received: from mailserver.example.net (mailserver.example.net
+        [192.0.2.1]) by example.com with ESMTP id M63d4137594e46;
+        Thu, 08 Mar 2005 14:00:00 -0400
+To: <Undisclosed Recipients>
+Subject: Earn money
+MIME-Version: 1.0
+Content-type: text/plain
+Message-ID: 8787KJKJ3K4J3K4J3K4J3.mail@example.net
+Date: Thu, 02 Sep 2004 12:31:03 -0500
+
+Spam Spam Spam
+Spam Spam Spam
+--part1_13d.2e68ed54_boundary--
+"#;
+
+    /// `RFC_5965_REPORT` with another `Feedback-Type`, CRLF line ends as they arrive by mail.
+    fn report_of_kind(kind: &str) -> String {
+        RFC_5965_REPORT
+            .replace("Feedback-Type: abuse", &format!("Feedback-Type: {kind}"))
+            .replace('\n', "\r\n")
+    }
+
+    #[test]
+    fn parse_arf_report_reads_a_complete_report() {
+        let report = parse_arf_report(RFC_5965_REPORT).unwrap();
+        assert_eq!(report.kind, "abuse");
+        // The complaining recipient, not the spammer of `Original-Mail-From` or the `To` of the
+        // original message
+        assert_eq!(report.from_address, "user@example.com");
+
+        let report = parse_arf_report(&report_of_kind("not-spam")).unwrap();
+        assert_eq!(
+            (report.kind.as_str(), report.from_address.as_str()),
+            ("not-spam", "user@example.com")
+        );
+        // Lowercased, as `COUNTED_FEEDBACK_TYPES` lists them
+        assert_eq!(
+            parse_arf_report(&report_of_kind("Fraud")).unwrap().kind,
+            "fraud"
+        );
+    }
+
+    #[test]
+    fn parse_arf_report_reads_kind_and_recipient() {
+        let report = parse_arf_report(REPORT).unwrap();
+        assert_eq!(report.kind, "abuse");
+        assert_eq!(report.from_address, "Alice@example.org");
+    }
+
+    #[test]
+    fn parse_arf_report_falls_back_to_removal_recipient() {
+        let report =
+            parse_arf_report("feedback-type: not-spam\nRemoval-Recipient: bob@example.org\n")
+                .unwrap();
+        assert_eq!(report.kind, "not-spam");
+        assert_eq!(report.from_address, "bob@example.org");
+    }
+
+    #[test]
+    fn parse_arf_report_needs_kind_and_recipient() {
+        assert!(parse_arf_report("Original-Rcpt-To: alice@example.org\n").is_none());
+        assert!(parse_arf_report("Feedback-Type: abuse\n").is_none());
+        assert!(parse_arf_report("").is_none());
+    }
+
+    #[test]
+    fn only_abuse_and_fraud_reach_the_threshold() {
+        assert!(threshold_reached(["abuse", "fraud", "abuse"], 3));
+        assert!(!threshold_reached(["abuse", "abuse"], 3));
+        assert!(!threshold_reached(["abuse", "not-spam", "abuse"], 3));
+        assert!(!threshold_reached(["not-spam"; 5], 3));
+        assert!(!threshold_reached(["virus", "other", "abuse"], 3));
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::{HashMap, HashSet};
+
+use spacetimedb::{Identity, Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{
+    account, admin_identities, admin_identities__view, is_admin_user, Account, AdminIdentity,
+};
+use crate::mailing::{self, message_categories, subscriptions, UNRESOLVED_ACCOUNT_ID};
+
+/// `DataIssue.kind`: subscription whose `category_id` has no `message_categories` row.
+pub const ISSUE_ORPHANED_SUBSCRIPTION: &str = "OrphanedSubscription";
+/// `DataIssue.kind`: category address that is not a plausible `local@domain.tld`.
+pub const ISSUE_MALFORMED_CATEGORY_EMAIL: &str = "MalformedCategoryEmail";
+/// `DataIssue.kind`: more than one subscription for the same subscriber and category.
+pub const ISSUE_DUPLICATE_SUBSCRIPTION: &str = "DuplicateSubscription";
+/// `DataIssue.kind`: more than one account with the same email (case-insensitive).
+pub const ISSUE_DUPLICATE_ACCOUNT_EMAIL: &str = "DuplicateAccountEmail";
+
+/// One finding of the last `validate_data` run. The table is replaced on every run.
+#[spacetimedb::table(accessor = data_issue)]
+pub struct DataIssue {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// One of the `ISSUE_*` constants
+    pub kind: String,
+    /// Id of the offending row (subscription, category or account, depending on `kind`)
+    pub entity_id: u64,
+    pub detail: String,
+    pub found_at: Timestamp,
+}
+
+/// Findings of the last `validate_data` run; empty for non-admins.
+#[spacetimedb::view(accessor = visible_data_issues, public)]
+pub fn visible_data_issues(ctx: &ViewContext) -> impl Query<DataIssue> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.data_issue().r#filter(move |_| is_admin)
+}
+
+/// `OrphanedIdentity.kind`: admin identity whose account is gone or inactive.
+pub const ORPHAN_ADMIN_IDENTITY: &str = "AdminIdentity";
+/// `OrphanedIdentity.kind`: admin identity that never had an account, e.g. the publisher or
+/// the sender service. Reported only; `clean_orphaned_identities` keeps these.
+pub const ORPHAN_ACCOUNTLESS_ADMIN: &str = "AccountlessAdmin";
+/// `OrphanedIdentity.kind`: active subscription whose account is missing or inactive.
+pub const ORPHAN_SUBSCRIPTION: &str = "Subscription";
+
+/// One finding of the last `find_orphaned_identities` run. The table is replaced on every run.
+#[spacetimedb::table(accessor = orphaned_identity)]
+pub struct OrphanedIdentity {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// One of the `ORPHAN_*` constants
+    pub kind: String,
+    /// Hex of the affected identity; None for subscriptions whose account row is gone
+    pub identity_hex: Option<String>,
+    /// Id of the subscription for `ORPHAN_SUBSCRIPTION`, 0 for admin identities
+    pub entity_id: u64,
+    pub detail: String,
+    pub found_at: Timestamp,
+}
+
+/// Findings of the last `find_orphaned_identities` run; empty for non-admins.
+#[spacetimedb::view(accessor = visible_orphaned_identities, public)]
+pub fn visible_orphaned_identities(ctx: &ViewContext) -> impl Query<OrphanedIdentity> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.orphaned_identity().r#filter(move |_| is_admin)
+}
+
+fn is_plausible_email(address: &str) -> bool {
+    let Some((local, domain)) = address.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && !address.chars().any(char::is_whitespace)
+}
+
+/// Lint the category/subscription/account graph and write the findings into `data_issue`.
+/// Only reports: no category, subscription or account is changed. Only admins.
+#[spacetimedb::reducer]
+pub fn validate_data(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+
+    let stale: Vec<u64> = ctx.db.data_issue().iter().map(|i| i.id).collect();
+    for id in stale {
+        ctx.db.data_issue().id().delete(&id);
+    }
+
+    let mut findings: Vec<(&'static str, u64, String)> = Vec::new();
+
+    let category_ids: HashSet<u64> = ctx.db.message_categories().iter().map(|c| c.id).collect();
+    for category in ctx.db.message_categories().iter() {
+        if !is_plausible_email(&category.email_address) {
+            findings.push((
+                ISSUE_MALFORMED_CATEGORY_EMAIL,
+                category.id,
+                format!(
+                    "Category {:?} has address {:?}",
+                    category.name, category.email_address
+                ),
+            ));
+        }
+    }
+
+    // Subscribers are keyed by account where known, by address for unresolved imports.
+    let mut seen_subscriptions: HashMap<(u64, String, u64), u64> = HashMap::new();
+    for sub in ctx.db.subscriptions().iter() {
+        if !category_ids.contains(&sub.category_id) {
+            findings.push((
+                ISSUE_ORPHANED_SUBSCRIPTION,
+                sub.id,
+                format!(
+                    "Subscription of {} points at missing category {}",
+                    sub.subscriber_email, sub.category_id
+                ),
+            ));
+        }
+        let subscriber = if sub.subscriber_account_id == UNRESOLVED_ACCOUNT_ID {
+            sub.subscriber_email.to_lowercase()
+        } else {
+            String::new()
+        };
+        let key = (sub.subscriber_account_id, subscriber, sub.category_id);
+        if let Some(first_id) = seen_subscriptions.get(&key) {
+            findings.push((
+                ISSUE_DUPLICATE_SUBSCRIPTION,
+                sub.id,
+                format!(
+                    "Duplicate of subscription {} ({} → category {})",
+                    first_id, sub.subscriber_email, sub.category_id
+                ),
+            ));
+        } else {
+            seen_subscriptions.insert(key, sub.id);
+        }
+    }
+
+    let mut seen_emails: HashMap<String, u64> = HashMap::new();
+    for acc in ctx.db.account().iter() {
+        let email = acc.email.trim().to_lowercase();
+        if email.is_empty() {
+            continue;
+        }
+        if let Some(first_id) = seen_emails.get(&email) {
+            findings.push((
+                ISSUE_DUPLICATE_ACCOUNT_EMAIL,
+                acc.id,
+                format!("Account shares email {} with account {}", email, first_id),
+            ));
+        } else {
+            seen_emails.insert(email, acc.id);
+        }
+    }
+
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    for (kind, entity_id, detail) in findings {
+        *counts.entry(kind).or_default() += 1;
+        ctx.db.data_issue().insert(DataIssue {
+            id: 0,
+            kind: kind.to_string(),
+            entity_id,
+            detail,
+            found_at: ctx.timestamp,
+        });
+    }
+
+    log::info!(
+        "Data validation: {} orphaned subscriptions, {} malformed category emails, {} duplicate subscriptions, {} duplicate account emails (by identity: {:?})",
+        counts.get(ISSUE_ORPHANED_SUBSCRIPTION).unwrap_or(&0),
+        counts.get(ISSUE_MALFORMED_CATEGORY_EMAIL).unwrap_or(&0),
+        counts.get(ISSUE_DUPLICATE_SUBSCRIPTION).unwrap_or(&0),
+        counts.get(ISSUE_DUPLICATE_ACCOUNT_EMAIL).unwrap_or(&0),
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Kind and detail of the finding for `admin`, whose identity has the account `account`; None
+/// while that account is active. An admin without an account is only orphaned if the sync
+/// granted it for an account that is gone now, otherwise it never had one.
+fn admin_orphan_finding(
+    admin: &AdminIdentity,
+    account: Option<&Account>,
+) -> Option<(&'static str, String)> {
+    match (account, admin.account_id) {
+        (Some(acc), _) if acc.is_active => None,
+        (Some(acc), _) => Some((
+            ORPHAN_ADMIN_IDENTITY,
+            format!("Admin identity belongs to inactive account {}", acc.id),
+        )),
+        (None, Some(account_id)) => Some((
+            ORPHAN_ADMIN_IDENTITY,
+            format!("Account {} of the admin identity is gone", account_id),
+        )),
+        (None, None) => Some((
+            ORPHAN_ACCOUNTLESS_ADMIN,
+            format!("Admin identity '{}' never had an account", admin.label),
+        )),
+    }
+}
+
+/// Report admin identities and active subscriptions without an active backing account
+/// (typically left behind when the Django sync deletes or deactivates a member) into
+/// `orphaned_identity`. Admins that never had an account are listed separately. Only reports;
+/// `clean_orphaned_identities` removes them. Only admins.
+#[spacetimedb::reducer]
+pub fn find_orphaned_identities(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+
+    let stale: Vec<u64> = ctx.db.orphaned_identity().iter().map(|o| o.id).collect();
+    for id in stale {
+        ctx.db.orphaned_identity().id().delete(&id);
+    }
+
+    let mut findings: Vec<OrphanedIdentity> = Vec::new();
+    for admin in ctx.db.admin_identities().iter() {
+        let account = ctx.db.account().identity().find(&admin.identity);
+        let Some((kind, detail)) = admin_orphan_finding(&admin, account.as_ref()) else {
+            continue;
+        };
+        findings.push(OrphanedIdentity {
+            id: 0,
+            kind: kind.to_string(),
+            identity_hex: Some(admin.identity.to_hex().to_string()),
+            entity_id: 0,
+            detail,
+            found_at: ctx.timestamp,
+        });
+    }
+
+    for sub in ctx.db.subscriptions().iter() {
+        // Unresolved imports are waiting for their account, not orphaned.
+        if !sub.active || sub.subscriber_account_id == UNRESOLVED_ACCOUNT_ID {
+            continue;
+        }
+        let (identity_hex, detail) = match ctx.db.account().id().find(&sub.subscriber_account_id) {
+            Some(acc) if acc.is_active => continue,
+            Some(acc) => (
+                Some(acc.identity.to_hex().to_string()),
+                format!(
+                    "Subscription of {} to category {} belongs to inactive account {}",
+                    sub.subscriber_email, sub.category_id, acc.id
+                ),
+            ),
+            None => (
+                None,
+                format!(
+                    "Subscription of {} to category {} points at missing account {}",
+                    sub.subscriber_email, sub.category_id, sub.subscriber_account_id
+                ),
+            ),
+        };
+        findings.push(OrphanedIdentity {
+            id: 0,
+            kind: ORPHAN_SUBSCRIPTION.to_string(),
+            identity_hex,
+            entity_id: sub.id,
+            detail,
+            found_at: ctx.timestamp,
+        });
+    }
+
+    let count = |kind: &str| findings.iter().filter(|f| f.kind == kind).count();
+    let admin_count = count(ORPHAN_ADMIN_IDENTITY);
+    let accountless_count = count(ORPHAN_ACCOUNTLESS_ADMIN);
+    let subscription_count = count(ORPHAN_SUBSCRIPTION);
+    for finding in findings {
+        ctx.db.orphaned_identity().insert(finding);
+    }
+    log::info!(
+        "Orphan check: {} admin identities, {} subscriptions without an active account, {} admin identities without any (by identity: {:?})",
+        admin_count,
+        subscription_count,
+        accountless_count,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Act on the last `find_orphaned_identities` report: unregister the listed admin identities
+/// and deactivate the listed subscriptions. Each finding is re-checked first, so rows fixed
+/// in the meantime are kept. The caller's own admin identity and admins that never had an
+/// account are never removed. Only admins.
+#[spacetimedb::reducer]
+pub fn clean_orphaned_identities(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+
+    let findings: Vec<OrphanedIdentity> = ctx.db.orphaned_identity().iter().collect();
+    let mut removed_admins = 0;
+    let mut deactivated_subscriptions = 0;
+    for finding in findings {
+        if finding.kind == ORPHAN_ADMIN_IDENTITY {
+            let identity = finding
+                .identity_hex
+                .as_deref()
+                .and_then(|hex| Identity::from_hex(hex).ok());
+            let admin =
+                identity.and_then(|identity| ctx.db.admin_identities().identity().find(&identity));
+            if let Some(admin) = admin {
+                let account = ctx.db.account().identity().find(&admin.identity);
+                let still_orphaned = matches!(
+                    admin_orphan_finding(&admin, account.as_ref()),
+                    Some((ORPHAN_ADMIN_IDENTITY, _))
+                );
+                if admin.identity != ctx.sender()
+                    && still_orphaned
+                    && ctx.db.admin_identities().identity().delete(&admin.identity)
+                {
+                    removed_admins += 1;
+                }
+            }
+        } else if finding.kind == ORPHAN_SUBSCRIPTION {
+            if let Some(sub) = ctx.db.subscriptions().id().find(&finding.entity_id) {
+                let still_orphaned = sub.active
+                    && !ctx
+                        .db
+                        .account()
+                        .id()
+                        .find(&sub.subscriber_account_id)
+                        .is_some_and(|a| a.is_active);
+                if still_orphaned {
+                    mailing::deactivate_subscription(ctx, sub);
+                    deactivated_subscriptions += 1;
+                }
+            }
+        }
+        ctx.db.orphaned_identity().id().delete(&finding.id);
+    }
+
+    log::info!(
+        "Orphan cleanup: removed {} admin identities, deactivated {} subscriptions (by identity: {:?})",
+        removed_admins,
+        deactivated_subscriptions,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admin(account_id: Option<u64>, label: &str) -> AdminIdentity {
+        AdminIdentity {
+            identity: Identity::from_hex(&"a1".repeat(32)).unwrap(),
+            last_seen: Timestamp::UNIX_EPOCH,
+            label: label.to_string(),
+            account_id,
+        }
+    }
+
+    fn member_account(is_active: bool) -> Account {
+        Account {
+            id: 42,
+            identity: Identity::from_hex(&"a1".repeat(32)).unwrap(),
+            name: "Erika".to_string(),
+            email: "erika@example.org".to_string(),
+            is_active,
+            last_synced: Timestamp::UNIX_EPOCH,
+            name_overridden: false,
+            django_updated_at: None,
+            manually_edited_at: None,
+            sync_correlation_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn admin_with_an_active_account_is_not_reported() {
+        assert!(
+            admin_orphan_finding(&admin(Some(42), "Django 42"), Some(&member_account(true)))
+                .is_none()
+        );
+        assert!(
+            admin_orphan_finding(&admin(None, "Django 42"), Some(&member_account(true))).is_none()
+        );
+    }
+
+    #[test]
+    fn admin_whose_account_is_inactive_or_gone_is_orphaned() {
+        let (kind, _) =
+            admin_orphan_finding(&admin(Some(42), "Django 42"), Some(&member_account(false)))
+                .unwrap();
+        assert_eq!(kind, ORPHAN_ADMIN_IDENTITY);
+        let (kind, detail) = admin_orphan_finding(&admin(Some(42), "Django 42"), None).unwrap();
+        assert_eq!(kind, ORPHAN_ADMIN_IDENTITY);
+        assert!(detail.contains("42"));
+    }
+
+    #[test]
+    fn admin_that_never_had_an_account_is_only_listed() {
+        // The publisher seeded in `init` and the sender service identity
+        let (kind, detail) = admin_orphan_finding(&admin(None, "Publisher"), None).unwrap();
+        assert_eq!(kind, ORPHAN_ACCOUNTLESS_ADMIN);
+        assert!(detail.contains("Publisher"));
+    }
+}
@@ -0,0 +1,334 @@
+use std::time::Duration;
+
+use spacetimedb::{Query, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, ViewContext};
+
+use crate::account::admin_identities__view;
+use crate::delivery;
+use crate::mailing::message_categories;
+use crate::mta::{received_message, ReceivedMessage};
+
+/// How often `flush_digests` checks for categories whose digest is due.
+fn digest_flush_period() -> TimeDuration {
+    TimeDuration::from_micros(60 * 1_000_000)
+}
+
+/// One accepted message waiting to be included in the next digest of its category.
+/// The message itself lives in `received_message`; this row only marks it for batching.
+#[spacetimedb::table(accessor = pending_digest)]
+pub struct PendingDigest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub category_id: u64,
+    /// FK → ReceivedMessage.id
+    pub received_message_id: u64,
+    pub queued_at: Timestamp,
+}
+
+/// A flushed digest: all pending messages of one category combined into a single mail.
+/// The mail itself goes out as the `mail_ingress` row `ingress_id`, fanned out to the
+/// category's subscribers like any other accepted message. Private: `body` holds the full
+/// messages; admins read it through `visible_digest_out`.
+#[spacetimedb::table(accessor = digest_out)]
+pub struct DigestOut {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    pub category_email: String,
+    pub subject: String,
+    pub body: String,
+    pub message_count: u32,
+    /// FK → ReceivedMessage.id, oldest first
+    pub received_message_ids: Vec<u64>,
+    /// FK → MailIngress.id of the digest mail
+    pub ingress_id: String,
+    pub created_at: Timestamp,
+}
+
+/// Returns all flushed digests for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_digest_out, public)]
+pub fn visible_digest_out(ctx: &ViewContext) -> impl Query<DigestOut> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.digest_out().r#filter(move |_| is_admin)
+}
+
+/// Repeating timer row that drives `flush_digests`.
+#[spacetimedb::table(accessor = digest_flush_schedule, scheduled(flush_digests))]
+pub struct DigestFlushSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Mark a received message for the next digest of `category_id`.
+pub(crate) fn queue_pending_digest(
+    ctx: &ReducerContext,
+    category_id: u64,
+    received_message_id: u64,
+) -> u64 {
+    let row = ctx.db.pending_digest().insert(PendingDigest {
+        id: 0,
+        category_id,
+        received_message_id,
+        queued_at: ctx.timestamp,
+    });
+    row.id
+}
+
+/// Insert the repeating flush timer unless one already exists.
+/// Called from `init` and whenever a category enables digests, so databases
+/// published before the timer existed pick it up as well.
+pub(crate) fn ensure_digest_flush_schedule(ctx: &ReducerContext) {
+    if ctx.db.digest_flush_schedule().iter().next().is_some() {
+        return;
+    }
+    ctx.db.digest_flush_schedule().insert(DigestFlushSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(digest_flush_period()),
+    });
+    log::info!("Scheduled digest flush every {:?}", digest_flush_period());
+}
+
+/// Whether a digest whose oldest pending message was queued at `oldest` is due at `now`.
+fn digest_due(oldest: Timestamp, now: Timestamp, interval_secs: u64) -> bool {
+    now.duration_since(oldest).unwrap_or_default() >= Duration::from_secs(interval_secs)
+}
+
+/// Whether the digest of a category with `interval_secs`, whose pending messages were queued
+/// at `queued`, is due at `now`. Never without pending messages.
+fn category_digest_due(
+    interval_secs: u64,
+    queued: impl IntoIterator<Item = Timestamp>,
+    now: Timestamp,
+) -> bool {
+    queued
+        .into_iter()
+        .min()
+        .is_some_and(|oldest| digest_due(oldest, now, interval_secs))
+}
+
+/// Append `msg` to a digest body: From/Date/Subject and the raw body, separated from the
+/// previous entry by a rule.
+fn append_digest_entry(body: &mut String, msg: &ReceivedMessage) {
+    if !body.is_empty() {
+        body.push_str("\n----------------------------------------\n\n");
+    }
+    body.push_str(&format!("From: {}\n", msg.from_header));
+    if let Some(date) = &msg.date_header {
+        body.push_str(&format!("Date: {}\n", date));
+    }
+    body.push_str(&format!("Subject: {}\n\n", msg.subject));
+    body.push_str(&msg.body_raw);
+    body.push('\n');
+}
+
+/// Scheduled reducer: combine the pending messages of every category whose digest
+/// interval has elapsed (measured from its oldest pending message) into one mail, queued as a
+/// `mail_ingress` row for the sender and recorded in `digest_out`.
+/// Categories without pending messages are skipped. The interval schedule repeats on its own.
+#[spacetimedb::reducer]
+pub fn flush_digests(ctx: &ReducerContext, _schedule: DigestFlushSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.database_identity() {
+        return Err("Unauthorized: flush_digests is only run by the scheduler".into());
+    }
+
+    for category in ctx.db.message_categories().iter() {
+        let Some(interval_secs) = category.digest_interval_secs else {
+            continue;
+        };
+        let mut pending: Vec<PendingDigest> = ctx
+            .db
+            .pending_digest()
+            .category_id()
+            .filter(&category.id)
+            .collect();
+        if !category_digest_due(
+            interval_secs,
+            pending.iter().map(|p| p.queued_at),
+            ctx.timestamp,
+        ) {
+            continue;
+        }
+
+        pending.sort_by_key(|p| (p.queued_at, p.id));
+        let mut body = String::new();
+        let mut received_message_ids = Vec::with_capacity(pending.len());
+        for item in &pending {
+            // The message may have been pruned in the meantime; drop it from the digest.
+            if let Some(msg) = ctx
+                .db
+                .received_message()
+                .id()
+                .find(&item.received_message_id)
+            {
+                append_digest_entry(&mut body, &msg);
+                received_message_ids.push(msg.id);
+            }
+            ctx.db.pending_digest().id().delete(&item.id);
+        }
+
+        if received_message_ids.is_empty() {
+            continue;
+        }
+        let message_count = received_message_ids.len() as u32;
+        let subject = format!("Digest: {} ({} messages)", category.name, message_count);
+        let message_size = body.len() as u64;
+        let ingress_id = delivery::upsert_mail_ingress(
+            ctx,
+            Some(format!(
+                "digest-{}",
+                ctx.timestamp.to_micros_since_unix_epoch()
+            )),
+            category.id,
+            category.email_address.clone(),
+            None,
+            category.email_address.clone(),
+            subject.clone(),
+            category.email_address.clone(),
+            None,
+            None,
+            None,
+            None,
+            "[]".to_string(),
+            body.clone(),
+            message_size,
+        );
+        let digest = ctx.db.digest_out().insert(DigestOut {
+            id: 0,
+            category_id: category.id,
+            category_email: category.email_address.clone(),
+            subject,
+            body,
+            message_count,
+            received_message_ids,
+            ingress_id: ingress_id.clone(),
+            created_at: ctx.timestamp,
+        });
+        log::info!(
+            "Flushed digest {} for category {} with {} messages as ingress {}",
+            digest.id,
+            category.id,
+            message_count,
+            ingress_id
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL_SECS: u64 = 3600;
+
+    fn at(secs: i64) -> Timestamp {
+        Timestamp::from_micros_since_unix_epoch(1_700_000_000_000_000 + secs * 1_000_000)
+    }
+
+    fn message(id: u64, subject: &str, body_raw: &str) -> ReceivedMessage {
+        ReceivedMessage {
+            id,
+            queue_id: None,
+            received_at: at(0),
+            sender_account_id: None,
+            sender_email: "alice@example.org".to_string(),
+            category_id: 1,
+            category_email: "list@example.org".to_string(),
+            subject: subject.to_string(),
+            from_header: "Alice <alice@example.org>".to_string(),
+            date_header: None,
+            message_id: None,
+            reply_to: None,
+            cc_header: None,
+            headers_raw: "[]".to_string(),
+            body_raw: body_raw.to_string(),
+            message_size: body_raw.len() as u64,
+        }
+    }
+
+    fn due(oldest: i64, now: i64) -> bool {
+        digest_due(at(oldest), at(now), INTERVAL_SECS)
+    }
+
+    #[test]
+    fn digest_is_due_once_per_interval() {
+        let interval = INTERVAL_SECS as i64;
+        // First interval: the oldest message waits the full interval.
+        assert!(!due(0, interval - 1));
+        assert!(due(0, interval));
+        // The second interval starts with the first message after the flush, not at the flush.
+        let second = interval + 600;
+        assert!(!due(second, 2 * interval));
+        assert!(due(second, second + interval));
+    }
+
+    #[test]
+    fn digest_is_not_due_for_a_message_from_the_future() {
+        assert!(!due(10, 0));
+    }
+
+    #[test]
+    fn each_category_is_flushed_on_its_own_interval() {
+        // Category 1 sends an hourly digest, category 2 a daily one.
+        let intervals = [(1, 3600), (2, 86_400)];
+        // (received message id, category, queued at)
+        let arrivals = [
+            (1, 1, 0),
+            (2, 2, 0),
+            (3, 1, 1800),
+            (4, 1, 4020),
+            (5, 2, 50_000),
+        ];
+        let mut flushed: Vec<(u64, i64, Vec<u64>)> = Vec::new();
+        let mut sent: Vec<u64> = Vec::new();
+        // Every run of `flush_digests` over two days.
+        for now in (0..=2 * 86_400).step_by(60) {
+            for (category_id, interval_secs) in intervals {
+                let pending: Vec<(u64, i64)> = arrivals
+                    .iter()
+                    .filter(|(id, category, queued)| {
+                        *category == category_id && *queued <= now && !sent.contains(id)
+                    })
+                    .map(|(id, _, queued)| (*id, *queued))
+                    .collect();
+                let queued = pending.iter().map(|(_, queued)| at(*queued));
+                if category_digest_due(interval_secs, queued, at(now)) {
+                    let ids: Vec<u64> = pending.iter().map(|(id, _)| *id).collect();
+                    sent.extend(&ids);
+                    flushed.push((category_id, now, ids));
+                }
+            }
+        }
+        assert_eq!(
+            flushed,
+            [
+                (1, 3600, vec![1, 3]),
+                (1, 4020 + 3600, vec![4]),
+                (2, 86_400, vec![2, 5]),
+            ]
+        );
+    }
+
+    #[test]
+    fn digest_body_separates_entries() {
+        let mut body = String::new();
+        append_digest_entry(&mut body, &message(1, "Erste", "Hallo"));
+        append_digest_entry(&mut body, &message(2, "Zweite", "Welt"));
+        assert_eq!(
+            body,
+            "From: Alice <alice@example.org>\nSubject: Erste\n\nHallo\n\
+             \n----------------------------------------\n\n\
+             From: Alice <alice@example.org>\nSubject: Zweite\n\nWelt\n"
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use spacetimedb::{Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{admin_identities__view, is_admin_user};
+use crate::mailing::message_categories;
+
+/// `ForwardRule.mode` values.
+/// `relay`: the message is handed to the external relay through a `relay_out` row.
+/// `envelope`: the destination is added as an envelope recipient in the DATA hook response,
+/// so Stalwart delivers it directly.
+pub const FORWARD_MODE_RELAY: &str = "relay";
+pub const FORWARD_MODE_ENVELOPE: &str = "envelope";
+
+/// A destination address that every accepted message of a category is forwarded to.
+#[spacetimedb::table(accessor = forward_rule)]
+pub struct ForwardRule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    pub destination_email: String,
+    pub active: bool,
+    /// `FORWARD_MODE_RELAY` or `FORWARD_MODE_ENVELOPE`
+    #[default(FORWARD_MODE_RELAY.to_string())]
+    pub mode: String,
+}
+
+/// One accepted message that an external relay should fan out to `destination_emails`.
+/// Private: `destination_emails` holds the forward addresses; admins read it through
+/// `visible_relay_out`.
+#[spacetimedb::table(accessor = relay_out)]
+pub struct RelayOut {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// FK → ReceivedMessage.id
+    pub received_message_id: u64,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    /// Stalwart queue ID of the original message
+    pub queue_id: Option<String>,
+    pub destination_emails: Vec<String>,
+    #[index(btree)]
+    pub created_at: Timestamp,
+}
+
+/// Returns all forward rules for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_forward_rules, public)]
+pub fn visible_forward_rules(ctx: &ViewContext) -> impl Query<ForwardRule> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.forward_rule().r#filter(move |_| is_admin)
+}
+
+/// Returns all relay_out rows for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_relay_out, public)]
+pub fn visible_relay_out(ctx: &ViewContext) -> impl Query<RelayOut> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.relay_out().r#filter(move |_| is_admin)
+}
+
+/// Validate a new forward rule's mode and destination. Returns the normalised destination.
+fn normalize_forward_rule(mode: &str, destination_email: &str) -> Result<String, String> {
+    if mode != FORWARD_MODE_RELAY && mode != FORWARD_MODE_ENVELOPE {
+        return Err(format!(
+            "Invalid forward mode '{}', expected '{}' or '{}'",
+            mode, FORWARD_MODE_RELAY, FORWARD_MODE_ENVELOPE
+        ));
+    }
+    let destination_email = destination_email.trim().to_lowercase();
+    if !destination_email.contains('@') {
+        return Err(format!(
+            "Invalid destination address '{}'",
+            destination_email
+        ));
+    }
+    Ok(destination_email)
+}
+
+/// Whether an active rule with the same destination and mode already exists.
+fn has_forward_rule<'a>(
+    rules: impl IntoIterator<Item = &'a ForwardRule>,
+    destination_email: &str,
+    mode: &str,
+) -> bool {
+    rules
+        .into_iter()
+        .any(|r| r.active && r.destination_email == destination_email && r.mode == mode)
+}
+
+/// Destinations of the active rules in `mode`.
+fn forward_destinations<'a>(
+    rules: impl IntoIterator<Item = &'a ForwardRule>,
+    mode: &str,
+) -> Vec<String> {
+    rules
+        .into_iter()
+        .filter(|r| r.active && r.mode == mode)
+        .map(|r| r.destination_email.clone())
+        .collect()
+}
+
+#[spacetimedb::reducer]
+pub fn add_forward_rule(
+    ctx: &ReducerContext,
+    category_id: u64,
+    destination_email: String,
+    mode: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let destination_email = normalize_forward_rule(&mode, &destination_email)?;
+    if ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .is_none()
+    {
+        return Err(format!("Message category {} not found", category_id));
+    }
+    let existing: Vec<ForwardRule> = ctx
+        .db
+        .forward_rule()
+        .category_id()
+        .filter(&category_id)
+        .collect();
+    if has_forward_rule(&existing, &destination_email, &mode) {
+        return Ok(()); // idempotent
+    }
+    let rule = ctx.db.forward_rule().insert(ForwardRule {
+        id: 0,
+        category_id,
+        destination_email,
+        active: true,
+        mode,
+    });
+    log::info!(
+        "Added forward rule {} for category {} (by identity: {:?})",
+        rule.id,
+        category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_forward_rule(ctx: &ReducerContext, rule_id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if ctx.db.forward_rule().id().find(&rule_id).is_none() {
+        return Err(format!("Forward rule {} not found", rule_id));
+    }
+    ctx.db.forward_rule().id().delete(&rule_id);
+    log::info!(
+        "Removed forward rule {} (by identity: {:?})",
+        rule_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Emit a `relay_out` row for a stored message if its category has active relay-mode forward rules.
+/// Returns the new row's id, or `None` when there is nothing to forward.
+pub(crate) fn emit_relay_out(
+    ctx: &ReducerContext,
+    category_id: u64,
+    received_message_id: u64,
+    queue_id: Option<String>,
+) -> Option<u64> {
+    let rules: Vec<ForwardRule> = ctx
+        .db
+        .forward_rule()
+        .category_id()
+        .filter(&category_id)
+        .collect();
+    let destination_emails = forward_destinations(&rules, FORWARD_MODE_RELAY);
+    if destination_emails.is_empty() {
+        return None;
+    }
+    let row = ctx.db.relay_out().insert(RelayOut {
+        id: 0,
+        received_message_id,
+        category_id,
+        queue_id,
+        destination_emails,
+        created_at: ctx.timestamp,
+    });
+    Some(row.id)
+}
+
+/// Destinations of the active envelope-mode forward rules of a category. The DATA hook adds
+/// these as envelope recipients instead of going through `relay_out`.
+pub(crate) fn envelope_forward_recipients(ctx: &ReducerContext, category_id: u64) -> Vec<String> {
+    let rules: Vec<ForwardRule> = ctx
+        .db
+        .forward_rule()
+        .category_id()
+        .filter(&category_id)
+        .collect();
+    forward_destinations(&rules, FORWARD_MODE_ENVELOPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: u64, destination_email: &str, active: bool, mode: &str) -> ForwardRule {
+        ForwardRule {
+            id,
+            category_id: 1,
+            destination_email: destination_email.to_string(),
+            active,
+            mode: mode.to_string(),
+        }
+    }
+
+    #[test]
+    fn destinations_are_split_by_mode_and_skip_inactive_rules() {
+        let rules = vec![
+            rule(1, "relay@example.org", true, FORWARD_MODE_RELAY),
+            rule(2, "envelope@example.org", true, FORWARD_MODE_ENVELOPE),
+            rule(3, "paused@example.org", false, FORWARD_MODE_RELAY),
+            rule(4, "second@example.org", true, FORWARD_MODE_RELAY),
+        ];
+        assert_eq!(
+            forward_destinations(&rules, FORWARD_MODE_RELAY),
+            vec!["relay@example.org", "second@example.org"]
+        );
+        assert_eq!(
+            forward_destinations(&rules, FORWARD_MODE_ENVELOPE),
+            vec!["envelope@example.org"]
+        );
+    }
+
+    #[test]
+    fn no_active_relay_rule_means_no_relay_out() {
+        let rules = vec![
+            rule(1, "paused@example.org", false, FORWARD_MODE_RELAY),
+            rule(2, "envelope@example.org", true, FORWARD_MODE_ENVELOPE),
+        ];
+        assert!(forward_destinations(&rules, FORWARD_MODE_RELAY).is_empty());
+    }
+
+    #[test]
+    fn new_rule_is_normalised_and_validated() {
+        assert_eq!(
+            normalize_forward_rule(FORWARD_MODE_RELAY, "  Team@Example.ORG "),
+            Ok("team@example.org".to_string())
+        );
+        assert!(normalize_forward_rule("smtp", "team@example.org")
+            .unwrap_err()
+            .contains("Invalid forward mode 'smtp'"));
+        assert!(
+            normalize_forward_rule(FORWARD_MODE_ENVELOPE, "not-an-address")
+                .unwrap_err()
+                .contains("Invalid destination address")
+        );
+    }
+
+    #[test]
+    fn adding_an_existing_active_rule_is_idempotent() {
+        let rules = vec![
+            rule(1, "team@example.org", true, FORWARD_MODE_RELAY),
+            rule(2, "old@example.org", false, FORWARD_MODE_RELAY),
+        ];
+        assert!(has_forward_rule(
+            &rules,
+            "team@example.org",
+            FORWARD_MODE_RELAY
+        ));
+        assert!(!has_forward_rule(
+            &rules,
+            "team@example.org",
+            FORWARD_MODE_ENVELOPE
+        ));
+        assert!(!has_forward_rule(
+            &rules,
+            "old@example.org",
+            FORWARD_MODE_RELAY
+        ));
+    }
+}
@@ -1,14 +1,12 @@
-use crate::account::webhook_tokens;
-use crate::account::UserSyncData;
-use crate::mailing::{message_categories, unsubscribe_subscription_by_token};
-use crate::mta::MtaConnectionLog;
-use crate::mta::{blocked_ips, mta_connection_log};
+use crate::account::UserSyncPayload;
+use crate::account::{webhook_tokens, WebhookToken};
+use crate::mailing::unsubscribe_subscription_by_token;
+use crate::mta::{DataAcceptance, StageRejection};
 use log::info;
-use serde::Deserialize;
 use serde_json::json;
 use spacetimedb::{
     http::{Body, HandlerContext, Request as HttpRequest, Response as HttpResponse, Router},
-    Table,
+    ReducerContext, Timestamp,
 };
 use stalwart_mta_hook_types::{
     Modification, Request as MtaHookRequest, Response as MtaHookResponse, Stage,
@@ -23,21 +21,82 @@ fn json_response(status: u16, value: serde_json::Value) -> HttpResponse {
         .unwrap()
 }
 
+/// The token of an `Authorization: Bearer <token>` header, if there is one.
+fn bearer_token(request: &HttpRequest) -> Option<String> {
+    request
+        .headers()
+        .get("authorization")
+        .and_then(|hv| hv.to_str().ok())
+        .and_then(|s| {
+            s.strip_prefix("Bearer ")
+                .or_else(|| s.strip_prefix("bearer "))
+        })
+        .map(|s| s.trim().to_string())
+}
+
 fn token_has_permission(ctx: &mut HandlerContext, token: &str, permission: &str) -> bool {
     info!("Check if token has permission");
     let hash = hex::encode(blake3::hash(token.as_bytes()).as_bytes());
     ctx.with_tx(|tx| {
-        if let Some(t) = tx.db.webhook_tokens().token_hash().find(&hash) {
-            let permission = t.active && t.permissions.iter().any(|p| p == permission);
-            info!("Token has permission: {}", permission);
-            permission
-        } else {
+        let token = tx.db.webhook_tokens().token_hash().find(&hash);
+        if token.is_none() {
             info!("Token not found");
-            false
+            return false;
         }
+        let permission = token_grants(token.as_ref(), permission);
+        info!("Token has permission: {}", permission);
+        permission
     })
 }
 
+/// Whether `token`, the row of the presented bearer token if there is one, grants `permission`.
+fn token_grants(token: Option<&WebhookToken>, permission: &str) -> bool {
+    token.is_some_and(|t| t.active && t.permissions.iter().any(|p| p == permission))
+}
+
+/// Header carrying the per-request correlation id, both ways.
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The caller's `X-Request-Id`, if it is a short token of `[A-Za-z0-9_-]`.
+fn supplied_request_id(request: &HttpRequest) -> Option<String> {
+    request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|hv| hv.to_str().ok())
+        .map(str::trim)
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= 64
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .map(str::to_string)
+}
+
+/// Correlation id of one request: the caller's `X-Request-Id`, otherwise 16 hex digits derived
+/// from the handler timestamp and the body. Written to the log rows of the request and echoed
+/// in the response.
+fn request_id(timestamp: Timestamp, supplied: Option<String>, body: &[u8]) -> String {
+    supplied.unwrap_or_else(|| derived_request_id(timestamp, body))
+}
+
+/// 16 hex digits of the hash of `timestamp` and `body`.
+fn derived_request_id(timestamp: Timestamp, body: &[u8]) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&timestamp.to_micros_since_unix_epoch().to_le_bytes());
+    hasher.update(body);
+    hex::encode(&hasher.finalize().as_bytes()[..8])
+}
+
+/// `resp` with the `X-Request-Id` header set to `id`.
+fn with_request_id(mut resp: HttpResponse, id: &str) -> HttpResponse {
+    if let Ok(value) = id.parse() {
+        resp.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    resp
+}
+
 fn query_param_token(request: &HttpRequest) -> Option<String> {
     let query = request.uri().query()?;
     for pair in query.split('&') {
@@ -51,248 +110,188 @@ fn query_param_token(request: &HttpRequest) -> Option<String> {
     None
 }
 
+/// Header added to every accepted message, set at build time via `PROCESSED_BY_HEADER`.
+/// Set it to an empty string to leave outgoing mail unbranded.
+const PROCESSED_BY_HEADER: &str = match option_env!("PROCESSED_BY_HEADER") {
+    Some(name) => name,
+    None => "X-Processed-By",
+};
+
+/// Value of `PROCESSED_BY_HEADER`, set at build time via `PROCESSED_BY_VALUE`.
+const PROCESSED_BY_VALUE: &str = match option_env!("PROCESSED_BY_VALUE") {
+    Some(value) => value,
+    None => "SpacetimeDB Kommunikationszentrum",
+};
+
+/// Modifications for an accepted DATA stage. Supported `Modification` variants:
+/// `add_header` (`PROCESSED_BY_HEADER`, unless disabled) and `add_recipient` (only when
+/// envelope-mode forward rules matched).
+fn data_modifications(acceptance: &DataAcceptance) -> Vec<Modification> {
+    let mut modifications = Vec::new();
+    if !PROCESSED_BY_HEADER.is_empty() {
+        modifications.push(Modification::add_header(
+            PROCESSED_BY_HEADER.to_string(),
+            PROCESSED_BY_VALUE.to_string(),
+        ));
+    }
+    modifications.extend(
+        acceptance
+            .add_recipients
+            .iter()
+            .map(|recipient| Modification::add_recipient(recipient.clone())),
+    );
+    modifications
+}
+
+/// Hook response for an accepted DATA stage, with the SMTP reply of a category that customized
+/// its accept. `Response::reject` is the only builder of a reply, so the reply is taken from a
+/// rejection and set on the accept. The same whether or not dry-run mode let a rejection
+/// through: the message was stored either way, so the MTA gets a plain accept.
+fn data_response(acceptance: &DataAcceptance) -> MtaHookResponse {
+    let accept = MtaHookResponse::accept().with_modifications(data_modifications(acceptance));
+    match acceptance.accept_reply.clone() {
+        Some((code, message)) => MtaHookResponse {
+            response: MtaHookResponse::reject(code, message).response,
+            ..accept
+        },
+        None => accept,
+    }
+}
+
+/// Run a stage that only accepts or rejects with the shared handler of `handle_mta_hook`, in
+/// one transaction that also counts the reject reason.
+fn run_stage(
+    ctx: &mut HandlerContext,
+    request: &MtaHookRequest,
+    request_id: &str,
+    handle: fn(&ReducerContext, &MtaHookRequest, &str, Timestamp) -> Result<(), StageRejection>,
+) -> Result<MtaHookResponse, StageRejection> {
+    let outcome = ctx.with_tx(|tx| {
+        let outcome = handle(tx, request, request_id, tx.timestamp);
+        if let Err(rejection) = &outcome {
+            crate::stats::record_reject_reason(tx, tx.timestamp, rejection.reason);
+        }
+        outcome
+    });
+    outcome.map(|()| MtaHookResponse::accept())
+}
+
 #[spacetimedb::http::handler]
 fn mta_hook_handler(ctx: &mut HandlerContext, request: HttpRequest) -> HttpResponse {
-    // Authentication
-    let token = match request
-        .headers()
-        .get("authorization")
-        .and_then(|hv| hv.to_str().ok())
-        .and_then(|s| {
-            s.strip_prefix("Bearer ")
-                .or_else(|| s.strip_prefix("bearer "))
-        })
-        .map(|s| s.trim().to_string())
-    {
-        Some(t) => t,
-        None => return json_response(401, json!({"error":"missing Authorization bearer token"})),
+    // Authentication, after reading the body so every answer carries the request id
+    let token = bearer_token(&request);
+    let supplied_id = supplied_request_id(&request);
+    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
+    let request_id = request_id(ctx.timestamp, supplied_id, &body_bytes);
+
+    let Some(token) = token else {
+        return with_request_id(
+            json_response(401, json!({"error":"missing Authorization bearer token"})),
+            &request_id,
+        );
     };
     if !token_has_permission(ctx, &token, "mta-hook") {
-        return json_response(403, json!({"error":"forbidden"}));
+        return with_request_id(
+            json_response(403, json!({"error":"forbidden"})),
+            &request_id,
+        );
     }
 
-    // Read body
-    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
     let mta_req: MtaHookRequest = match serde_json::from_slice(&body_bytes) {
-        Ok(r) => {
-            info!("Parsed MtaHookRequest: {:?}", r);
+        Ok(mut r) => {
+            crate::mta::cap_message_headers(&mut r, &request_id);
+            info!("[{}] Parsed MtaHookRequest: {:?}", request_id, r);
             r
         }
         Err(_) => {
-            info!("Failed to parse MtaHookRequest");
-            return json_response(400, json!({"error":"invalid JSON"}));
+            info!("[{}] Failed to parse MtaHookRequest", request_id);
+            return with_request_id(
+                json_response(400, json!({"error":"invalid JSON"})),
+                &request_id,
+            );
         }
     };
 
-    match mta_req.context.stage {
+    // Logs this stage's wall-clock time, transactions included, when the handler returns.
+    let _timing = crate::mta::stage_stopwatch(&mta_req.context.stage);
+    // Also stored: from the handler's start to the transaction that records it.
+    let started = ctx.timestamp;
+    let stage = crate::mta::stage_name(&mta_req.context.stage);
+
+    if let Some(deferral) =
+        ctx.with_tx(|tx| crate::mta::maintenance_deferral(tx, &mta_req, &request_id, tx.timestamp))
+    {
+        let resp = MtaHookResponse::reject(deferral.code, deferral.message);
+        let body = serde_json::to_vec(&resp).unwrap_or_default();
+        ctx.with_tx(|tx| crate::stats::record_stage_timing(tx, stage, &request_id, started));
+        return HttpResponse::builder()
+            .status(200)
+            .header("content-type", "application/json")
+            .header(REQUEST_ID_HEADER, request_id.as_str())
+            .body(Body::from_bytes(body))
+            .unwrap();
+    }
+
+    let outcome: Result<MtaHookResponse, StageRejection> = match mta_req.context.stage {
         Stage::Data => {
             // persist message using the existing module routines in a transaction
-            let _ = ctx.with_tx(|tx| {
-                crate::mta::handle_data_stage(tx, &mta_req, tx.timestamp);
-            });
-
-            let resp =
-                MtaHookResponse::accept().with_modifications(vec![Modification::add_header(
-                    "X-Processed-By".to_string(),
-                    "SpacetimeDB Kommunikationszentrum".to_string(),
-                )]);
-
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
-        }
-        Stage::Connect => {
-            let accepted = ctx.with_tx(|tx| {
-                let client_ip = &mta_req.context.client.ip;
-                if let Some(blocked) = tx.db.blocked_ips().ip().find(client_ip) {
-                    if blocked.active {
-                        tx.db.mta_connection_log().insert(MtaConnectionLog {
-                            id: 0,
-                            client_ip: "[REDACTED]".to_string(),
-                            stage: "connect".to_string(),
-                            action: "reject".to_string(),
-                            timestamp: tx.timestamp,
-                            details: "IP blocked".to_string(),
-                        });
-                        return false;
-                    }
+            let outcome = ctx.with_tx(|tx| {
+                let outcome =
+                    crate::mta::handle_data_stage(tx, &mta_req, &request_id, tx.timestamp);
+                // A rejection dry-run mode let through counts like on the other stages.
+                let rejection = match &outcome {
+                    Ok(acceptance) => acceptance.dry_run_rejection.as_ref(),
+                    Err(rejection) => Some(rejection),
+                };
+                if let Some(rejection) = rejection {
+                    crate::stats::record_reject_reason(tx, tx.timestamp, rejection.reason);
                 }
-                tx.db.mta_connection_log().insert(MtaConnectionLog {
-                    id: 0,
-                    client_ip: client_ip.to_string(),
-                    stage: "connect".to_string(),
-                    action: "accept".to_string(),
-                    timestamp: tx.timestamp,
-                    details: "Connection accepted".to_string(),
-                });
-                true
+                outcome
             });
-
-            let resp = if accepted {
-                MtaHookResponse::accept()
-            } else {
-                MtaHookResponse::reject(550, "IP blocked".to_string())
-            };
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
+            outcome.map(|acceptance| data_response(&acceptance))
         }
-        Stage::Ehlo => {
-            let accepted = ctx.with_tx(|tx| {
-                if let Some(helo) = &mta_req.context.client.helo {
-                    if helo.trim().is_empty() {
-                        tx.db.mta_connection_log().insert(MtaConnectionLog {
-                            id: 0,
-                            client_ip: mta_req.context.client.ip.clone(),
-                            stage: "ehlo".to_string(),
-                            action: "reject".to_string(),
-                            timestamp: tx.timestamp,
-                            details: "Invalid EHLO/HELO: empty".to_string(),
-                        });
-                        return false;
-                    }
-                }
-                tx.db.mta_connection_log().insert(MtaConnectionLog {
-                    id: 0,
-                    client_ip: mta_req.context.client.ip.clone(),
-                    stage: "ehlo".to_string(),
-                    action: "accept".to_string(),
-                    timestamp: tx.timestamp,
-                    details: "Valid EHLO".to_string(),
-                });
-                true
+        Stage::Connect => run_stage(ctx, &mta_req, &request_id, crate::mta::handle_connect_stage),
+        Stage::Ehlo => run_stage(ctx, &mta_req, &request_id, crate::mta::handle_ehlo_stage),
+        Stage::Mail => run_stage(ctx, &mta_req, &request_id, crate::mta::handle_mail_stage),
+        Stage::Rcpt => run_stage(ctx, &mta_req, &request_id, crate::mta::handle_rcpt_stage),
+        Stage::Auth => {
+            ctx.with_tx(|tx| {
+                crate::mta::handle_auth_stage(tx, &mta_req, &request_id, tx.timestamp)
             });
-
-            let resp = if accepted {
-                MtaHookResponse::accept()
-            } else {
-                MtaHookResponse::reject(501, "Invalid EHLO/HELO argument".to_string())
-            };
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
+            Ok(MtaHookResponse::accept())
         }
-        Stage::Mail => {
-            let accepted = ctx.with_tx(|tx| {
-                if let Some(envelope) = &mta_req.envelope {
-                    let from_address = &envelope.from.address;
-                    if !from_address.contains('@') || from_address.trim().is_empty() {
-                        tx.db.mta_connection_log().insert(MtaConnectionLog {
-                            id: 0,
-                            client_ip: "[REDACTED]".to_string(),
-                            stage: "mail".to_string(),
-                            action: "reject".to_string(),
-                            timestamp: tx.timestamp,
-                            details: "Invalid sender address".to_string(),
-                        });
-                        return false;
-                    }
-                }
-                tx.db.mta_connection_log().insert(MtaConnectionLog {
-                    id: 0,
-                    client_ip: mta_req.context.client.ip.clone(),
-                    stage: "mail".to_string(),
-                    action: "accept".to_string(),
-                    timestamp: tx.timestamp,
-                    details: "MAIL FROM accepted".to_string(),
-                });
-                true
-            });
+    };
 
-            let resp = if accepted {
-                MtaHookResponse::accept()
-            } else {
-                MtaHookResponse::reject(550, "Invalid sender address".to_string())
-            };
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
-        }
-        Stage::Rcpt => {
-            let accepted = ctx.with_tx(|tx| {
-                let mut accepted = false;
-                if let Some(envelope) = &mta_req.envelope {
-                    for recipient in &envelope.to {
-                        let to_address = recipient.address.clone();
-                        let category_found = tx
-                            .db
-                            .message_categories()
-                            .email_address()
-                            .find(&to_address)
-                            .map_or(false, |c| c.active);
-                        let action_str =
-                            if category_found { "accept" } else { "reject" }.to_string();
-                        tx.db.mta_connection_log().insert(MtaConnectionLog {
-                            id: 0,
-                            client_ip: "[REDACTED]".to_string(),
-                            stage: "rcpt".to_string(),
-                            action: action_str.clone(),
-                            timestamp: tx.timestamp,
-                            details: format!(
-                                "Category validation: {}",
-                                if category_found { "found" } else { "not found" }
-                            ),
-                        });
-                        if category_found {
-                            accepted = true;
-                            break;
-                        }
-                    }
-                }
-                accepted
+    // In dry-run mode the rejection is only logged and the MTA is told to accept. DATA does this
+    // itself, so the message still takes the accept path and is stored.
+    let resp = match outcome {
+        Ok(resp) => resp,
+        Err(rejection) => {
+            let overridden = ctx.with_tx(|tx| {
+                crate::mta::dry_run_override(
+                    tx,
+                    &mta_req,
+                    &request_id,
+                    rejection.code,
+                    &rejection.message,
+                    tx.timestamp,
+                )
             });
-
-            let resp = if accepted {
+            if overridden {
                 MtaHookResponse::accept()
             } else {
-                MtaHookResponse::reject(550, "No such mailing list".to_string())
-            };
-
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
-        }
-        Stage::Auth => {
-            ctx.with_tx(|tx| {
-                tx.db.mta_connection_log().insert(MtaConnectionLog {
-                    id: 0,
-                    client_ip: mta_req.context.client.ip.clone(),
-                    stage: "auth".to_string(),
-                    action: "accept".to_string(),
-                    timestamp: tx.timestamp,
-                    details: "Auth stage - accept".to_string(),
-                });
-            });
-            let resp = MtaHookResponse::accept();
-            let body = serde_json::to_vec(&resp).unwrap_or_default();
-            HttpResponse::builder()
-                .status(200)
-                .header("content-type", "application/json")
-                .body(Body::from_bytes(body))
-                .unwrap()
+                MtaHookResponse::reject(rejection.code, rejection.message)
+            }
         }
-    }
-}
-
-#[derive(Deserialize)]
-struct UserSyncPayload {
-    action: String,
-    user: UserSyncData,
+    };
+    let body = serde_json::to_vec(&resp).unwrap_or_default();
+    ctx.with_tx(|tx| crate::stats::record_stage_timing(tx, stage, &request_id, started));
+    HttpResponse::builder()
+        .status(200)
+        .header("content-type", "application/json")
+        .header(REQUEST_ID_HEADER, request_id.as_str())
+        .body(Body::from_bytes(body))
+        .unwrap()
 }
 
 #[spacetimedb::http::handler]
@@ -335,51 +334,185 @@ fn mailing_list_unsubscribe_handler(
 
 #[spacetimedb::http::handler]
 fn user_sync_handler(ctx: &mut HandlerContext, request: HttpRequest) -> HttpResponse {
-    let token = match request
-        .headers()
-        .get("authorization")
-        .and_then(|hv| hv.to_str().ok())
-        .and_then(|s| {
-            s.strip_prefix("Bearer ")
-                .or_else(|| s.strip_prefix("bearer "))
-        })
-        .map(|s| s.trim().to_string())
-    {
-        Some(t) => t,
-        None => return json_response(401, json!({"error":"missing Authorization bearer token"})),
+    let token = bearer_token(&request);
+    let supplied_id = supplied_request_id(&request);
+    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
+    let request_id = request_id(ctx.timestamp, supplied_id, &body_bytes);
+
+    let Some(token) = token else {
+        return with_request_id(
+            json_response(401, json!({"error":"missing Authorization bearer token"})),
+            &request_id,
+        );
     };
     if !token_has_permission(ctx, &token, "sync-user") {
-        return json_response(403, json!({"error":"forbidden"}));
+        return with_request_id(
+            json_response(403, json!({"error":"forbidden"})),
+            &request_id,
+        );
     }
 
-    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
     let payload: UserSyncPayload = match serde_json::from_slice(&body_bytes) {
         Ok(p) => p,
-        Err(_) => return json_response(400, json!({"error":"invalid JSON"})),
+        Err(_) => {
+            return with_request_id(
+                json_response(400, json!({"error":"invalid JSON"})),
+                &request_id,
+            )
+        }
     };
 
     let user_data_str = match serde_json::to_string(&payload.user) {
         Ok(s) => s,
-        Err(_) => return json_response(500, json!({"error":"serialization failed"})),
+        Err(_) => {
+            return with_request_id(
+                json_response(500, json!({"error":"serialization failed"})),
+                &request_id,
+            )
+        }
     };
 
+    info!(
+        "[{}] user-sync {} for {}",
+        request_id, payload.action, payload.user.mitgliedsnr
+    );
     let result: Result<(), String> = ctx.with_tx(|tx| {
-        crate::account::do_sync_user(tx, payload.action.clone(), user_data_str.clone())
+        crate::account::do_sync_user(
+            tx,
+            payload.action.clone(),
+            user_data_str.clone(),
+            &request_id,
+        )
     });
 
-    match result {
+    let resp = match result {
         Ok(()) => json_response(
             200,
             json!({"status":"success","action":payload.action,"mitgliedsnr":payload.user.mitgliedsnr}),
         ),
         Err(e) => {
+            info!("[{}] user-sync failed: {}", request_id, e);
             if e.contains("Unauthorized") {
                 json_response(403, json!({"error": e}))
             } else {
                 json_response(500, json!({"error": e}))
             }
         }
+    };
+    with_request_id(resp, &request_id)
+}
+
+/// Stalwart webhook receiver for `auth.failed` events. The MTA hook AUTH stage does not carry
+/// the authentication outcome, so failures are counted from these events instead.
+/// Other event types are ignored.
+#[spacetimedb::http::handler]
+fn auth_events_handler(ctx: &mut HandlerContext, request: HttpRequest) -> HttpResponse {
+    let token = bearer_token(&request);
+    let supplied_id = supplied_request_id(&request);
+    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
+    let request_id = request_id(ctx.timestamp, supplied_id, &body_bytes);
+
+    let Some(token) = token else {
+        return with_request_id(
+            json_response(401, json!({"error":"missing Authorization bearer token"})),
+            &request_id,
+        );
+    };
+    if !token_has_permission(ctx, &token, "mta-hook") {
+        return with_request_id(
+            json_response(403, json!({"error":"forbidden"})),
+            &request_id,
+        );
     }
+
+    let payload: serde_json::Value = match serde_json::from_slice(&body_bytes) {
+        Ok(p) => p,
+        Err(_) => {
+            return with_request_id(
+                json_response(400, json!({"error":"invalid JSON"})),
+                &request_id,
+            )
+        }
+    };
+    let failed_ips: Vec<String> = payload
+        .get("events")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+        .filter(|event| event.get("type").and_then(|t| t.as_str()) == Some("auth.failed"))
+        .filter_map(|event| event.pointer("/data/remoteIp").and_then(|ip| ip.as_str()))
+        // Events without a parsable IP can't be blocked by address and are dropped.
+        .filter_map(|ip| ip.trim().parse::<std::net::IpAddr>().ok())
+        .map(|ip| ip.to_string())
+        .collect();
+
+    let blocked = ctx.with_tx(|tx| {
+        failed_ips
+            .iter()
+            .filter(|ip| crate::auth_failures::record_auth_failure_for(tx, ip))
+            .count()
+    });
+    with_request_id(
+        json_response(
+            200,
+            json!({"status":"success","auth_failures":failed_ips.len(),"blocked":blocked}),
+        ),
+        &request_id,
+    )
+}
+
+/// Feedback loop receiver: takes one ARF report (RFC 5965) as the raw request body and records
+/// a complaint from the address in its `Original-Rcpt-To`. Repeated complaints deactivate that
+/// address's subscriptions; see `record_complaint_for`.
+#[spacetimedb::http::handler]
+fn complaint_handler(ctx: &mut HandlerContext, request: HttpRequest) -> HttpResponse {
+    let token = bearer_token(&request);
+    let supplied_id = supplied_request_id(&request);
+    let body_bytes: Vec<u8> = request.into_body().into_bytes().into();
+    let request_id = request_id(ctx.timestamp, supplied_id, &body_bytes);
+
+    let Some(token) = token else {
+        return with_request_id(
+            json_response(401, json!({"error":"missing Authorization bearer token"})),
+            &request_id,
+        );
+    };
+    if !token_has_permission(ctx, &token, "complaint") {
+        return with_request_id(
+            json_response(403, json!({"error":"forbidden"})),
+            &request_id,
+        );
+    }
+
+    let raw = String::from_utf8_lossy(&body_bytes);
+    let report = match crate::complaints::parse_arf_report(&raw) {
+        Some(r) => r,
+        None => {
+            return with_request_id(
+                json_response(
+                    400,
+                    json!({"error":"not an ARF report (Feedback-Type and Original-Rcpt-To required)"}),
+                ),
+                &request_id,
+            )
+        }
+    };
+
+    let deactivated = ctx.with_tx(|tx| {
+        crate::complaints::record_complaint_for(
+            tx,
+            &report.from_address,
+            &report.kind,
+            tx.timestamp,
+        )
+    });
+    with_request_id(
+        json_response(
+            200,
+            json!({"status":"success","kind":report.kind,"deactivated_subscriptions":deactivated}),
+        ),
+        &request_id,
+    )
 }
 
 #[spacetimedb::http::router]
@@ -387,8 +520,168 @@ fn router() -> Router {
     Router::new()
         .post("/mta-hook", mta_hook_handler)
         .post("/user-sync", user_sync_handler)
+        .post("/auth-events", auth_events_handler)
+        .post("/complaint", complaint_handler)
         .post(
             "/mailing-list/unsubscribe",
             mailing_list_unsubscribe_handler,
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mailing::LOG_LEVEL_FULL;
+    use crate::mta::{message_log_row, MtaConnectionLog, MtaMessageLog, RejectReason};
+
+    #[test]
+    fn dry_run_never_changes_the_data_response() {
+        let accepted = DataAcceptance {
+            add_recipients: vec!["archive@example.org".to_string()],
+            ..DataAcceptance::default()
+        };
+        let let_through = DataAcceptance {
+            add_recipients: accepted.add_recipients.clone(),
+            dry_run_rejection: Some(StageRejection::new(RejectReason::SenderNotPermitted)),
+            ..DataAcceptance::default()
+        };
+        assert_eq!(
+            serde_json::to_value(data_response(&let_through)).unwrap(),
+            serde_json::to_value(data_response(&accepted)).unwrap()
+        );
+    }
+
+    #[test]
+    fn custom_accept_reply_is_sent_with_the_accept() {
+        let acceptance = DataAcceptance {
+            accept_reply: Some((250, "Queued for the list".to_string())),
+            ..DataAcceptance::default()
+        };
+        let value = serde_json::to_value(data_response(&acceptance)).unwrap();
+        assert_eq!(value["action"], "accept");
+        assert_eq!(value["response"]["status"], 250);
+        assert_eq!(value["response"]["message"], "Queued for the list");
+
+        let plain = serde_json::to_value(data_response(&DataAcceptance::default())).unwrap();
+        assert_eq!(plain["action"], "accept");
+        assert!(plain["response"].is_null());
+    }
+
+    fn request_with_authorization(value: &str) -> HttpRequest {
+        HttpRequest::builder()
+            .header("authorization", value)
+            .body(Body::from_bytes(Vec::new()))
+            .unwrap()
+    }
+
+    #[test]
+    fn bearer_token_reads_the_authorization_header() {
+        assert_eq!(
+            bearer_token(&request_with_authorization("Bearer abc123")).as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            bearer_token(&request_with_authorization("bearer  abc123 ")).as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(
+            bearer_token(&request_with_authorization("Basic abc123")),
+            None
+        );
+        let without = HttpRequest::builder()
+            .body(Body::from_bytes(Vec::new()))
+            .unwrap();
+        assert_eq!(bearer_token(&without), None);
+    }
+
+    #[test]
+    fn complaint_needs_an_active_token_with_the_complaint_permission() {
+        let token = |permissions: &[&str], active| WebhookToken {
+            id: 1,
+            token_hash: String::new(),
+            label: "Feedback loop".to_string(),
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+            created_at: Timestamp::UNIX_EPOCH,
+            active,
+        };
+        assert!(token_grants(
+            Some(&token(&["auth-events", "complaint"], true)),
+            "complaint"
+        ));
+        assert!(!token_grants(
+            Some(&token(&["mta-hook", "user-sync"], true)),
+            "complaint"
+        ));
+        assert!(!token_grants(
+            Some(&token(&["complaint"], false)),
+            "complaint"
+        ));
+        assert!(!token_grants(None, "complaint"));
+    }
+
+    #[test]
+    fn request_id_reaches_both_log_rows_and_the_response() {
+        let request = |header: Option<&str>| {
+            let mut builder = HttpRequest::builder();
+            if let Some(value) = header {
+                builder = builder.header(REQUEST_ID_HEADER, value);
+            }
+            builder.body(Body::from_bytes(Vec::new())).unwrap()
+        };
+        let body = br#"{"context":{"stage":"data"}}"#;
+        let id_of = |header: Option<&str>| {
+            request_id(
+                Timestamp::UNIX_EPOCH,
+                supplied_request_id(&request(header)),
+                body,
+            )
+        };
+
+        // A valid header wins; a missing or unusable one falls back to the derived id.
+        let supplied = id_of(Some(" stalwart-7f3a "));
+        let derived = id_of(None);
+        assert_eq!(supplied, "stalwart-7f3a");
+        assert_eq!(derived, derived_request_id(Timestamp::UNIX_EPOCH, body));
+        assert_eq!(derived.len(), 16);
+        assert_eq!(id_of(Some("")), derived);
+        assert_eq!(id_of(Some("no spaces/slashes")), derived);
+        let too_long = "x".repeat(65);
+        assert_eq!(id_of(Some(&too_long)), derived);
+        assert_ne!(derived, derived_request_id(Timestamp::UNIX_EPOCH, b"{}"));
+
+        let data: MtaHookRequest = serde_json::from_value(crate::mta::test_mta_request(
+            "data",
+            "alice@example.org",
+            "gemuese@example.org",
+            "Ernte am Samstag",
+            "Q1".to_string(),
+        ))
+        .unwrap();
+        for id in [supplied, derived] {
+            let connection =
+                MtaConnectionLog::entry(&data, &id, "accept", String::new(), Timestamp::UNIX_EPOCH);
+            let message = message_log_row(
+                LOG_LEVEL_FULL,
+                MtaMessageLog::entry(
+                    &data,
+                    &id,
+                    &["gemuese@example.org".to_string()],
+                    "accept",
+                    "[1]".to_string(),
+                    Timestamp::UNIX_EPOCH,
+                ),
+            )
+            .unwrap();
+            let response = with_request_id(json_response(200, json!({})), &id);
+            assert_eq!(connection.correlation_id, id);
+            assert_eq!(message.correlation_id, id);
+            assert_eq!(
+                response
+                    .headers()
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|hv| hv.to_str().ok()),
+                Some(id.as_str())
+            );
+        }
+    }
+}
@@ -3,10 +3,21 @@ use spacetimedb::{ReducerContext, Table};
 use account::{admin_identities, AdminIdentity};
 
 mod account;
+mod auth_failures;
+mod complaints;
+mod data_check;
 mod delivery;
+mod digest;
+mod forwarding;
 mod http_handlers;
+mod logins;
 mod mailing;
 mod mta;
+mod notifications;
+mod ping;
+mod sender_rules;
+mod settings;
+mod stats;
 
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) {
@@ -21,17 +32,28 @@ pub fn init(ctx: &ReducerContext) {
         .find(sender_identity)
         .is_none()
     {
-        ctx.db.admin_identities().insert(AdminIdentity {
-            identity: sender_identity,
-        });
+        ctx.db.admin_identities().insert(AdminIdentity::new(
+            ctx,
+            sender_identity,
+            "Publisher".to_string(),
+        ));
         log::info!("Seeded sender identity as admin: {:?}", sender_identity);
     }
+
+    digest::ensure_digest_flush_schedule(ctx);
+    mailing::ensure_subscription_expiry_schedule(ctx);
+    stats::ensure_table_size_schedule(ctx);
+    mailing::ensure_category_addresses(ctx);
+    mailing::seed_default_categories(ctx);
 }
 
 #[spacetimedb::reducer(client_connected)]
 pub fn identity_connected(ctx: &ReducerContext) {
     // Called everytime a new client connects
     log::info!("Client connected with identity: {:?}", ctx.sender());
+    account::touch_admin_identity(ctx);
+    // An update of the module does not run `init`; the first connection after it starts the timer.
+    stats::ensure_table_size_schedule(ctx);
 }
 
 #[spacetimedb::reducer(client_disconnected)]
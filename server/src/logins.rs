@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{account, admin_identities__view, is_admin_user};
+
+/// Calls of `record_login` by the same identity within this interval add no new event, so a
+/// reconnect loop doesn't fill the table. Set at build time via `LOGIN_EVENT_MIN_INTERVAL_SECS`.
+fn login_event_min_interval() -> Duration {
+    let secs = option_env!("LOGIN_EVENT_MIN_INTERVAL_SECS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(10 * 60);
+    Duration::from_secs(secs)
+}
+
+/// How long login events are kept, set at build time via `LOGIN_EVENT_RETENTION_DAYS`.
+fn login_event_retention() -> Duration {
+    let days: u64 = option_env!("LOGIN_EVENT_RETENTION_DAYS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(90);
+    Duration::from_secs(days * 24 * 60 * 60)
+}
+
+/// Longest user agent `record_login` stores; longer ones are cut.
+const USER_AGENT_MAX_CHARS: usize = 256;
+
+/// One login to the admin panel, reported by the app itself after the OIDC login succeeded.
+/// Rows older than the retention are dropped on the next login of the same identity.
+#[spacetimedb::table(accessor = login_event)]
+pub struct LoginEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub identity: Identity,
+    /// FK → Account.id; None for an admin identity without account
+    pub account_id: Option<u64>,
+    /// As reported by the browser; not verified
+    pub user_agent: String,
+    pub logged_in_at: Timestamp,
+}
+
+/// Returns all login events for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_login_events, public)]
+pub fn visible_login_events(ctx: &ViewContext) -> impl Query<LoginEvent> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.login_event().r#filter(move |_| is_admin)
+}
+
+/// Whether a login at `now` adds an event, given the times of the caller's earlier events: not
+/// if one of them is younger than `min_interval`.
+fn adds_login_event(
+    earlier: impl IntoIterator<Item = Timestamp>,
+    now: Timestamp,
+    min_interval: Duration,
+) -> bool {
+    earlier
+        .into_iter()
+        .all(|at| now.duration_since(at).unwrap_or_default() >= min_interval)
+}
+
+/// Record that the caller logged into the admin panel. Only identities with an account, and
+/// admins. A call within `LOGIN_EVENT_MIN_INTERVAL_SECS` of the caller's previous event is
+/// ignored.
+#[spacetimedb::reducer]
+pub fn record_login(ctx: &ReducerContext, user_agent: String) -> Result<(), String> {
+    let identity = ctx.sender();
+    let account_id = ctx.db.account().identity().find(&identity).map(|a| a.id);
+    if account_id.is_none() && !is_admin_user(ctx) {
+        return Err("Unauthorized: record_login requires an account".to_string());
+    }
+    let retention = login_event_retention();
+    let min_interval = login_event_min_interval();
+
+    let mut kept = Vec::new();
+    let previous: Vec<LoginEvent> = ctx.db.login_event().identity().filter(&identity).collect();
+    for event in previous {
+        let age = ctx
+            .timestamp
+            .duration_since(event.logged_in_at)
+            .unwrap_or_default();
+        if age > retention {
+            ctx.db.login_event().id().delete(&event.id);
+        } else {
+            kept.push(event.logged_in_at);
+        }
+    }
+    if !adds_login_event(kept, ctx.timestamp, min_interval) {
+        return Ok(());
+    }
+
+    ctx.db.login_event().insert(LoginEvent {
+        id: 0,
+        identity,
+        account_id,
+        user_agent: user_agent
+            .trim()
+            .chars()
+            .take(USER_AGENT_MAX_CHARS)
+            .collect(),
+        logged_in_at: ctx.timestamp,
+    });
+    log::info!(
+        "Admin panel login of account {:?} (identity: {:?})",
+        account_id,
+        identity
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logins_within_the_interval_collapse_to_one_event() {
+        let minutes = |m: i64| Timestamp::from_micros_since_unix_epoch(m * 60 * 1_000_000);
+        let interval = Duration::from_secs(10 * 60);
+        let mut events = Vec::new();
+        for at in [0, 1, 5, 9, 10, 15, 21] {
+            if adds_login_event(events.iter().copied(), minutes(at), interval) {
+                events.push(minutes(at));
+            }
+        }
+        assert_eq!(events, vec![minutes(0), minutes(10), minutes(21)]);
+    }
+
+    #[test]
+    fn first_login_adds_an_event() {
+        let interval = Duration::from_secs(10 * 60);
+        assert!(adds_login_event([], Timestamp::UNIX_EPOCH, interval));
+    }
+}
@@ -1,8 +1,17 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use log::{error, info};
-use spacetimedb::{Query, ReducerContext, Table, Timestamp, ViewContext};
+use spacetimedb::{
+    Identity, Query, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, ViewContext,
+};
 
-use crate::account::{account, account__view, admin_identities__view, is_admin_user, Account};
+use crate::account::{
+    account, account__view, account_for_sender, admin_identities__view, check_request_owner,
+    is_admin_user, Account,
+};
+use crate::digest;
 
+#[derive(Clone)]
 #[spacetimedb::table(accessor = message_categories, public)]
 pub struct MessageCategory {
     #[primary_key]
@@ -13,10 +22,508 @@ pub struct MessageCategory {
     pub email_address: String,
     pub description: String,
     pub active: bool,
+    /// When set, accepted messages are collected into `pending_digest` and relayed in batches
+    /// every `digest_interval_secs` seconds instead of one by one.
+    #[default(None)]
+    pub digest_interval_secs: Option<u64>,
+    /// Set by `remove_message_category`; a soft-deleted category is treated as inactive
+    /// until `restore_category` clears it again.
+    #[default(None)]
+    pub deleted_at: Option<Timestamp>,
+    /// When false the category is open: DATA accepts any sender the sender rules allow,
+    /// without a subscription.
+    #[default(true)]
+    pub subscription_required: bool,
+    /// How much of this category's mail `mta_message_log` keeps; one of the `LOG_LEVEL_*` constants.
+    #[default(LOG_LEVEL_FULL.to_string())]
+    pub log_level: String,
+    /// Most messages DATA accepts for this category per clock hour; further ones are deferred
+    /// with 451 until the next hour. `None` means unlimited.
+    #[default(None)]
+    pub rate_limit_per_hour: Option<u32>,
+    /// SMTP code DATA answers with when it accepts mail for this category (2xx); `None` is 250.
+    #[default(None)]
+    pub accept_code: Option<u16>,
+    /// Text of that accept reply, e.g. a routing hint; `None` is the MTA's default text.
+    #[default(None)]
+    pub accept_message: Option<String>,
+}
+
+/// `MessageCategory.log_level` values.
+/// `none` writes no `mta_message_log` row, `summary` writes one without subject and sender
+/// local part, `full` writes the complete row.
+pub const LOG_LEVEL_NONE: &str = "none";
+pub const LOG_LEVEL_SUMMARY: &str = "summary";
+pub const LOG_LEVEL_FULL: &str = "full";
+/// All levels, least verbose first.
+pub const LOG_LEVELS: [&str; 3] = [LOG_LEVEL_NONE, LOG_LEVEL_SUMMARY, LOG_LEVEL_FULL];
+
+impl MessageCategory {
+    /// Active and not soft-deleted, i.e. RCPT and DATA accept mail for it.
+    pub(crate) fn accepts_mail(&self) -> bool {
+        self.active && self.deleted_at.is_none()
+    }
+
+    /// The custom accept reply of this category, if it has one.
+    pub(crate) fn accept_reply(&self) -> Option<(u16, String)> {
+        if self.accept_code.is_none() && self.accept_message.is_none() {
+            return None;
+        }
+        Some((
+            self.accept_code.unwrap_or(DEFAULT_ACCEPT_CODE),
+            self.accept_message
+                .clone()
+                .unwrap_or_else(|| "Accepted".to_string()),
+        ))
+    }
+}
+
+/// SMTP code of an accept reply without a custom `accept_code`.
+pub const DEFAULT_ACCEPT_CODE: u16 = 250;
+/// Longest `accept_message` `set_category_accept_response` accepts.
+const MAX_ACCEPT_MESSAGE_LEN: usize = 200;
+
+/// Additional receiving address of a category (e.g. `board@` next to `vorstand@`).
+/// The primary `MessageCategory.email_address` has a row here as well.
+#[spacetimedb::table(accessor = category_address, public)]
+pub struct CategoryAddress {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub category_id: u64, // FK → MessageCategory.id
+    #[unique]
+    pub email_address: String,
+    pub active: bool,
+}
+
+/// Catch-all routing for a recipient domain: mail to any address on `domain` that no category
+/// address matches goes to `category_id`. Its forward rules apply as usual.
+#[spacetimedb::table(accessor = domain_route, public)]
+pub struct DomainRoute {
+    /// Lowercase, without `@`
+    #[primary_key]
+    pub domain: String,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    pub created_at: Timestamp,
+}
+
+/// Which rule `route_recipient_match` routed an address by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RouteMatch {
+    /// A category address, primary or additional
+    Address,
+    /// A `domain_route` of the recipient's domain
+    Domain,
+}
+
+/// Where the exact-address lookups of `route_recipient_match` lead.
+#[derive(Debug, PartialEq, Eq)]
+enum AddressLookup {
+    /// An active category address, primary or additional, of this category
+    Category(u64),
+    /// A deactivated category address; neither the category nor a domain route gets the mail
+    Deactivated,
+    /// No category address; the recipient's `domain_route` decides
+    Domain,
+}
+
+/// Resolve `entry`, the `category_address` row of an address. Without one, `primary_of` looks
+/// the address up as the primary address of a category that predates `category_address`.
+fn address_lookup(
+    entry: Option<&CategoryAddress>,
+    primary_of: impl FnOnce() -> Option<u64>,
+) -> AddressLookup {
+    match entry {
+        Some(entry) if entry.active => AddressLookup::Category(entry.category_id),
+        Some(_) => AddressLookup::Deactivated,
+        None => primary_of().map_or(AddressLookup::Domain, AddressLookup::Category),
+    }
+}
+
+/// The category mail to `address` is routed to, if any. This is the matching used by the RCPT
+/// and DATA stages and by `resolve_recipient`, so support answers agree with live routing.
+pub(crate) fn route_recipient(ctx: &ReducerContext, address: &str) -> Option<MessageCategory> {
+    route_recipient_match(ctx, address).map(|(category, _)| category)
+}
+
+/// `route_recipient`, also telling how the address matched. Exact addresses win; the
+/// recipient's `domain_route` only applies when no category address matches at all, so a
+/// deactivated address is not picked up by its domain either. At most three unique-index
+/// lookups and one primary-key lookup, independent of the number of categories; there is no
+/// wildcard matching that would need a scan.
+pub(crate) fn route_recipient_match(
+    ctx: &ReducerContext,
+    address: &str,
+) -> Option<(MessageCategory, RouteMatch)> {
+    let address_key = address.to_string();
+    let entry = ctx.db.category_address().email_address().find(&address_key);
+    let primary_of = || {
+        ctx.db
+            .message_categories()
+            .email_address()
+            .find(&address_key)
+            .map(|c| c.id)
+    };
+    routed_category(
+        address_lookup(entry.as_ref(), primary_of),
+        |id| ctx.db.message_categories().id().find(&id),
+        || domain_route_category(ctx, address),
+    )
+}
+
+/// The category `lookup` leads to: `category_of` finds it by id, `domain_category` is the
+/// category of the recipient's `domain_route`. A category that does not accept mail takes none,
+/// whoever is subscribed to it.
+fn routed_category(
+    lookup: AddressLookup,
+    category_of: impl FnOnce(u64) -> Option<MessageCategory>,
+    domain_category: impl FnOnce() -> Option<MessageCategory>,
+) -> Option<(MessageCategory, RouteMatch)> {
+    let (category, matched) = match lookup {
+        AddressLookup::Category(id) => (category_of(id), RouteMatch::Address),
+        AddressLookup::Deactivated => return None,
+        AddressLookup::Domain => (domain_category(), RouteMatch::Domain),
+    };
+    category
+        .filter(|c| c.accepts_mail())
+        .map(|category| (category, matched))
+}
+
+/// The category of the `domain_route` for the domain of `address`, if any.
+fn domain_route_category(ctx: &ReducerContext, address: &str) -> Option<MessageCategory> {
+    let (_, domain) = address.rsplit_once('@')?;
+    let route = ctx
+        .db
+        .domain_route()
+        .domain()
+        .find(&domain.to_lowercase())?;
+    ctx.db.message_categories().id().find(&route.category_id)
+}
+
+/// Domains categories may receive on, set at build time as a comma-separated list via
+/// `CATEGORY_ALLOWED_DOMAINS`. Empty or unset means any domain is allowed.
+fn category_allowed_domains() -> Vec<String> {
+    option_env!("CATEGORY_ALLOWED_DOMAINS")
+        .unwrap_or("")
+        .split(',')
+        .map(|d| d.trim().to_lowercase())
+        .filter(|d| !d.is_empty())
+        .collect()
+}
+
+/// Reject a category address whose domain is not in `category_allowed_domains`, so a
+/// mistyped domain is caught when the category is created rather than when mail goes missing.
+fn check_category_domain(address: &str) -> Result<(), String> {
+    check_domain_allowed(address, &category_allowed_domains())
+}
+
+/// `check_category_domain` against the lowercased domains `allowed`; empty allows any.
+fn check_domain_allowed(address: &str, allowed: &[String]) -> Result<(), String> {
+    if allowed.is_empty() {
+        return Ok(());
+    }
+    let domain = address
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.trim().to_lowercase())
+        .unwrap_or_default();
+    if allowed.contains(&domain) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Address {} is not on an allowed category domain ({})",
+            address,
+            allowed.join(", ")
+        ))
+    }
+}
+
+/// Whether `address` is already used by any category, as primary or additional address.
+fn address_in_use(ctx: &ReducerContext, address: &str) -> bool {
+    let address = address.to_string();
+    ctx.db
+        .category_address()
+        .email_address()
+        .find(&address)
+        .is_some()
+        || ctx
+            .db
+            .message_categories()
+            .email_address()
+            .find(&address)
+            .is_some()
+}
+
+/// Insert the `category_address` row for a category's primary address.
+fn insert_primary_address(ctx: &ReducerContext, category: &MessageCategory) {
+    ctx.db.category_address().insert(CategoryAddress {
+        id: 0,
+        category_id: category.id,
+        email_address: category.email_address.clone(),
+        active: true,
+    });
+}
+
+/// Whether `upsert_category` created a category or updated an existing one, with its id.
+#[derive(Debug, PartialEq)]
+pub(crate) enum CategoryUpsert {
+    Created(u64),
+    Updated(u64),
+}
+
+/// Check that `upsert_category` may update `existing`, the category with the address as
+/// primary address. Only an active category is updated; a soft-deleted one has to be restored
+/// and an inactive one activated first, so a repeated create never rewrites a category that is
+/// not receiving mail.
+fn check_category_updatable(existing: &MessageCategory) -> Result<(), String> {
+    if existing.deleted_at.is_some() {
+        return Err(format!(
+            "Category {} with address {} is deleted; restore it instead",
+            existing.id, existing.email_address
+        ));
+    }
+    if !existing.active {
+        return Err(format!(
+            "Category {} with address {} is inactive; activate it instead",
+            existing.id, existing.email_address
+        ));
+    }
+    Ok(())
+}
+
+/// Create the category for `email_address`, or, if an active category with that primary
+/// address already exists, update its name and description instead. Repeating a create is
+/// therefore harmless. Inactive and soft-deleted categories are refused
+/// (`check_category_updatable`), and so is an address that another category receives on.
+pub(crate) fn upsert_category(
+    ctx: &ReducerContext,
+    name: String,
+    email_address: String,
+    description: String,
+) -> Result<CategoryUpsert, String> {
+    upsert_category_row(
+        name,
+        email_address,
+        description,
+        |address| ctx.db.message_categories().email_address().find(address),
+        |address| {
+            ctx.db
+                .category_address()
+                .email_address()
+                .find(address)
+                .map(|a| a.category_id)
+        },
+        |write| match write {
+            CategoryWrite::Update(category) => ctx.db.message_categories().id().update(category).id,
+            CategoryWrite::Insert(category) => {
+                let category = ctx.db.message_categories().insert(category);
+                insert_primary_address(ctx, &category);
+                category.id
+            }
+        },
+    )
+}
+
+/// A row `upsert_category` writes: the existing category with the new name and description,
+/// or a new one.
+enum CategoryWrite {
+    Update(MessageCategory),
+    Insert(MessageCategory),
+}
+
+/// `upsert_category` on plain rows: `by_primary` finds the category with a primary address,
+/// `address_owner` the category receiving on an additional one, and `write` stores the row and
+/// returns its id.
+fn upsert_category_row(
+    name: String,
+    email_address: String,
+    description: String,
+    by_primary: impl FnOnce(&String) -> Option<MessageCategory>,
+    address_owner: impl FnOnce(&String) -> Option<u64>,
+    write: impl FnOnce(CategoryWrite) -> u64,
+) -> Result<CategoryUpsert, String> {
+    if let Some(existing) = by_primary(&email_address) {
+        check_category_updatable(&existing)?;
+        let id = write(CategoryWrite::Update(MessageCategory {
+            name,
+            description,
+            ..existing
+        }));
+        return Ok(CategoryUpsert::Updated(id));
+    }
+    if let Some(category_id) = address_owner(&email_address) {
+        return Err(format!(
+            "Address {} already belongs to category {}",
+            email_address, category_id
+        ));
+    }
+
+    let id = write(CategoryWrite::Insert(MessageCategory {
+        id: 0,
+        name,
+        email_address,
+        description,
+        active: true,
+        digest_interval_secs: None,
+        deleted_at: None,
+        subscription_required: true,
+        log_level: LOG_LEVEL_FULL.to_string(),
+        rate_limit_per_hour: None,
+        accept_code: None,
+        accept_message: None,
+    }));
+    Ok(CategoryUpsert::Created(id))
+}
+
+/// Migration: give every category without one a `category_address` row for its primary address.
+/// Runs on init and, since an update of the module does not run init, again when a category
+/// gets its first additional address (`add_category_address`).
+pub(crate) fn ensure_category_addresses(ctx: &ReducerContext) {
+    let missing: Vec<MessageCategory> = ctx
+        .db
+        .message_categories()
+        .iter()
+        .filter(|c| {
+            ctx.db
+                .category_address()
+                .email_address()
+                .find(&c.email_address)
+                .is_none()
+        })
+        .collect();
+    for category in &missing {
+        insert_primary_address(ctx, category);
+    }
+    if !missing.is_empty() {
+        log::info!(
+            "Migrated {} primary category addresses into category_address",
+            missing.len()
+        );
+    }
+}
+
+/// One entry of `DEFAULT_CATEGORIES_JSON`.
+#[derive(serde::Deserialize)]
+struct DefaultCategory {
+    name: String,
+    email_address: String,
+    #[serde(default)]
+    description: String,
+}
+
+/// Categories a fresh deployment starts with, set at build time via `DEFAULT_CATEGORIES_JSON`
+/// as `[{"name": ..., "email_address": ..., "description": ...}]`. Unset, none are created.
+fn default_categories_json() -> Option<&'static str> {
+    option_env!("DEFAULT_CATEGORIES_JSON").filter(|json| !json.trim().is_empty())
+}
+
+/// The defaults to create: none once `message_categories` has rows, otherwise the first entry
+/// per address that is not `in_use`.
+fn defaults_to_seed(
+    defaults: Vec<DefaultCategory>,
+    table_empty: bool,
+    in_use: impl Fn(&str) -> bool,
+) -> Vec<DefaultCategory> {
+    if !table_empty {
+        return Vec::new();
+    }
+    let mut seen = BTreeSet::new();
+    defaults
+        .into_iter()
+        .filter_map(|default| {
+            let email_address = default.email_address.trim().to_string();
+            if email_address.is_empty()
+                || in_use(&email_address)
+                || !seen.insert(email_address.clone())
+            {
+                return None;
+            }
+            Some(DefaultCategory {
+                email_address,
+                ..default
+            })
+        })
+        .collect()
+}
+
+/// Create the default categories, but only while `message_categories` is completely empty, so a
+/// republish never adds them again next to (or after deletion of) the admins' own categories.
+pub(crate) fn seed_default_categories(ctx: &ReducerContext) {
+    let Some(json) = default_categories_json() else {
+        return;
+    };
+    let defaults: Vec<DefaultCategory> = match serde_json::from_str(json) {
+        Ok(defaults) => defaults,
+        Err(e) => {
+            error!("Failed to parse default categories, seeding none: {}", e);
+            return;
+        }
+    };
+    let table_empty = ctx.db.message_categories().iter().next().is_none();
+    let mut seeded = 0usize;
+    for default in defaults_to_seed(defaults, table_empty, |address| {
+        address_in_use(ctx, address)
+    }) {
+        if let Err(e) = check_category_domain(&default.email_address) {
+            error!("Skipping default category: {}", e);
+            continue;
+        }
+        let category = ctx.db.message_categories().insert(MessageCategory {
+            id: 0,
+            name: default.name,
+            email_address: default.email_address,
+            description: default.description,
+            active: true,
+            digest_interval_secs: None,
+            deleted_at: None,
+            subscription_required: true,
+            log_level: LOG_LEVEL_FULL.to_string(),
+            rate_limit_per_hour: None,
+            accept_code: None,
+            accept_message: None,
+        });
+        insert_primary_address(ctx, &category);
+        seeded += 1;
+    }
+    if seeded > 0 {
+        info!("Seeded {} default message categories", seeded);
+    }
+}
+
+/// Answer of `resolve_recipient`, keyed by the caller-chosen `request_id`.
+#[spacetimedb::table(accessor = resolve_result)]
+pub struct ResolveResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub address: String,
+    /// FK → MessageCategory.id; None when the address is not routed anywhere
+    pub category_id: Option<u64>,
+    /// Whether `category_id` comes from a `domain_route` rather than a category address
+    #[default(false)]
+    pub via_domain_route: bool,
+    pub resolved_at: Timestamp,
+}
+
+/// The caller's own `resolve_recipient` answers.
+#[spacetimedb::view(accessor = my_resolve_results, public)]
+pub fn my_resolve_results(ctx: &ViewContext) -> Vec<ResolveResult> {
+    ctx.db
+        .resolve_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
 }
 
 #[derive(Clone)]
-#[spacetimedb::table(accessor = subscriptions, public)]
+#[spacetimedb::table(
+    accessor = subscriptions,
+    public,
+    index(accessor = account_category, btree(columns = [subscriber_account_id, category_id]))
+)]
 pub struct Subscription {
     #[primary_key]
     #[auto_inc]
@@ -29,8 +536,80 @@ pub struct Subscription {
     pub category_id: u64,
     pub subscribed_at: Timestamp,
     pub active: bool,
+    /// The subscription lapses after this time; `expire_subscriptions` then sets `active = false`.
+    #[default(None)]
+    pub expires_at: Option<Timestamp>,
+    /// Created by the group sync (`apply_group_subscriptions`), which only ever removes these.
+    /// A subscription made by hand or by import is `false`, also once its group is mapped.
+    #[default(false)]
+    pub from_group_sync: bool,
+    /// Inactive because the group sync removed it, so the sync may reactivate it. Unset for
+    /// rows deactivated any other way, e.g. an unsubscribe the sync must not undo.
+    #[default(false)]
+    pub deactivated_by_group_sync: bool,
+}
+
+/// How often `expire_subscriptions` runs.
+fn subscription_expiry_period() -> TimeDuration {
+    TimeDuration::from_micros(60 * 60 * 1_000_000)
+}
+
+/// Repeating timer row that drives `expire_subscriptions`.
+#[spacetimedb::table(accessor = subscription_expiry_schedule, scheduled(expire_subscriptions))]
+pub struct SubscriptionExpirySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// The subscription of `account_id` to `category_id`, active or not. A single lookup on the
+/// `account_category` index instead of scanning all subscriptions of the account. Imports can
+/// leave duplicates (see `dedupe_subscriptions`); an active row is preferred over inactive ones.
+pub(crate) fn find_subscription(
+    ctx: &ReducerContext,
+    account_id: u64,
+    category_id: u64,
+) -> Option<Subscription> {
+    preferred_subscription(
+        ctx.db
+            .subscriptions()
+            .account_category()
+            .filter((account_id, category_id)),
+    )
+}
+
+/// The first active row of `rows`, otherwise the first row.
+fn preferred_subscription(rows: impl Iterator<Item = Subscription>) -> Option<Subscription> {
+    let mut first = None;
+    for row in rows {
+        if row.active {
+            return Some(row);
+        }
+        first.get_or_insert(row);
+    }
+    first
+}
+
+/// Whether `account_id` has a current subscription (see `Subscription::is_current`) to
+/// `category_id`, considering every duplicate row.
+pub(crate) fn has_current_subscription(
+    ctx: &ReducerContext,
+    account_id: u64,
+    category_id: u64,
+    now: Timestamp,
+) -> bool {
+    ctx.db
+        .subscriptions()
+        .account_category()
+        .filter((account_id, category_id))
+        .any(|sub| sub.is_current(now))
 }
 
+/// `Subscription.subscriber_account_id` for imported subscriptions whose email did not match
+/// any account yet. `link_unresolved_subscriptions` fills in the id once the account syncs.
+pub const UNRESOLVED_ACCOUNT_ID: u64 = 0;
+
 #[derive(Clone)]
 #[spacetimedb::table(accessor = subscription_unsubscribe_tokens, public)]
 pub struct SubscriptionUnsubscribeToken {
@@ -81,6 +660,8 @@ pub fn active_unsubscribe_tokens(ctx: &ViewContext) -> impl Query<SubscriptionUn
         .r#filter(|token| token.active)
 }
 
+/// Create a category, or update name and description of the one that already has
+/// `email_address` (see `upsert_category`). Only admins.
 #[spacetimedb::reducer]
 pub fn add_message_category(
     ctx: &ReducerContext,
@@ -91,26 +672,121 @@ pub fn add_message_category(
     if !is_admin_user(ctx) {
         return Err("Unauthorized: Admin access required".to_string());
     }
+    check_category_domain(&email_address)?;
 
-    ctx.db.message_categories().insert(MessageCategory {
+    match upsert_category(ctx, name, email_address, description)? {
+        CategoryUpsert::Created(id) => log::info!(
+            "Added new message category {} (by identity: {:?})",
+            id,
+            ctx.sender()
+        ),
+        CategoryUpsert::Updated(id) => log::info!(
+            "Updated existing message category {} (by identity: {:?})",
+            id,
+            ctx.sender()
+        ),
+    }
+    Ok(())
+}
+
+/// Let a category also receive mail on `email_address`. Only admins.
+#[spacetimedb::reducer]
+pub fn add_category_address(
+    ctx: &ReducerContext,
+    category_id: u64,
+    email_address: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let email_address = email_address.trim().to_string();
+    if email_address.is_empty() {
+        return Err("Email address must not be empty".to_string());
+    }
+    check_category_domain(&email_address)?;
+    if ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .is_none()
+    {
+        return Err(format!("Message category {} not found", category_id));
+    }
+    if address_in_use(ctx, &email_address) {
+        return Err(format!(
+            "Address {} is already used by a category",
+            email_address
+        ));
+    }
+    // Categories from before `category_address` get their primary row next to the new one.
+    ensure_category_addresses(ctx);
+    ctx.db.category_address().insert(CategoryAddress {
         id: 0,
-        name,
-        email_address,
-        description,
+        category_id,
+        email_address: email_address.clone(),
         active: true,
     });
     log::info!(
-        "Added new message category (by identity: {:?})",
+        "Added address {} to category {} (by identity: {:?})",
+        email_address,
+        category_id,
         ctx.sender()
     );
     Ok(())
 }
 
+/// Remove an additional address of a category. The primary address cannot be removed.
+/// Only admins.
 #[spacetimedb::reducer]
-pub fn remove_message_category(ctx: &ReducerContext, category_id: u64) -> Result<(), String> {
+pub fn remove_category_address(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let entry = ctx
+        .db
+        .category_address()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Category address {} not found", id))?;
+    let is_primary = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&entry.category_id)
+        .is_some_and(|c| c.email_address == entry.email_address);
+    if is_primary {
+        return Err(format!(
+            "{} is the primary address of category {}",
+            entry.email_address, entry.category_id
+        ));
+    }
+    ctx.db.category_address().id().delete(&id);
+    log::info!(
+        "Removed address {} from category {} (by identity: {:?})",
+        entry.email_address,
+        entry.category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Route all mail to `domain` that no category address matches to `category_id`, replacing an
+/// earlier route of the domain. Only admins.
+#[spacetimedb::reducer]
+pub fn set_domain_route(
+    ctx: &ReducerContext,
+    domain: String,
+    category_id: u64,
+) -> Result<(), String> {
     if !is_admin_user(ctx) {
         return Err("Unauthorized: Admin access required".to_string());
     }
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+    if domain.is_empty() || domain.contains('@') {
+        return Err(format!("Invalid domain '{}'", domain));
+    }
+    check_category_domain(&format!("@{}", domain))?;
     if ctx
         .db
         .message_categories()
@@ -120,49 +796,599 @@ pub fn remove_message_category(ctx: &ReducerContext, category_id: u64) -> Result
     {
         return Err(format!("Message category {} not found", category_id));
     }
-    ctx.db.message_categories().id().delete(&category_id);
+    let route = DomainRoute {
+        domain: domain.clone(),
+        category_id,
+        created_at: ctx.timestamp,
+    };
+    if ctx.db.domain_route().domain().find(&domain).is_some() {
+        ctx.db.domain_route().domain().update(route);
+    } else {
+        ctx.db.domain_route().insert(route);
+    }
     log::info!(
-        "Removed message category {} (by identity: {:?})",
+        "Routed domain {} to category {} (by identity: {:?})",
+        domain,
         category_id,
         ctx.sender()
     );
     Ok(())
 }
 
+/// Stop routing `domain` by domain; exact category addresses on it keep working. Only admins.
 #[spacetimedb::reducer]
-pub fn add_subscription(
+pub fn remove_domain_route(ctx: &ReducerContext, domain: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+    if !ctx.db.domain_route().domain().delete(&domain) {
+        return Err(format!("No domain route for {}", domain));
+    }
+    log::info!(
+        "Removed domain route of {} (by identity: {:?})",
+        domain,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Support lookup: "where does mail to `address` go?". Writes the category RCPT/DATA would
+/// route it to (or none) into `resolve_result` under `request_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn resolve_recipient(
     ctx: &ReducerContext,
-    subscriber_account_id: u64,
-    subscriber_email: String,
-    category_id: u64,
+    request_id: String,
+    address: String,
 ) -> Result<(), String> {
-    let is_admin = is_admin_user(ctx);
-    let is_self = ctx
-        .db
-        .account()
-        .id()
-        .find(&subscriber_account_id)
-        .map(|a: Account| a.identity == ctx.sender())
-        .unwrap_or(false);
-
-    if !is_admin && !is_self {
-        return Err("Unauthorized: can only subscribe yourself or requires admin".to_string());
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
     }
+    let address = address.trim().to_string();
+    let route = route_recipient_match(ctx, &address);
+    let category_id = route.as_ref().map(|(c, _)| c.id);
+    let result = ResolveResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        address,
+        category_id,
+        via_domain_route: route.is_some_and(|(_, matched)| matched == RouteMatch::Domain),
+        resolved_at: ctx.timestamp,
+    };
+    let owner = ctx
+        .db
+        .resolve_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db.resolve_result().request_id().update(result);
+    } else {
+        ctx.db.resolve_result().insert(result);
+    }
+    log::info!(
+        "Resolved recipient for request {} to category {:?} (by identity: {:?})",
+        request_id,
+        category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// `category` soft-deleted at `now`; an error if it already is.
+fn soft_deleted(category: MessageCategory, now: Timestamp) -> Result<MessageCategory, String> {
+    if category.deleted_at.is_some() {
+        return Err(format!(
+            "Message category {} is already deleted",
+            category.id
+        ));
+    }
+    Ok(MessageCategory {
+        deleted_at: Some(now),
+        ..category
+    })
+}
+
+/// `category` restored from a soft delete, `active` left as it was; an error if it is not
+/// deleted.
+fn restored(category: MessageCategory) -> Result<MessageCategory, String> {
+    if category.deleted_at.is_none() {
+        return Err(format!("Message category {} is not deleted", category.id));
+    }
+    Ok(MessageCategory {
+        deleted_at: None,
+        ..category
+    })
+}
+
+/// Soft-delete a category: it keeps its row, subscriptions and history but no longer
+/// receives mail. Undo with `restore_category`, remove for good with `hard_delete_category`.
+#[spacetimedb::reducer]
+pub fn remove_message_category(ctx: &ReducerContext, category_id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .ok_or_else(|| format!("Message category {} not found", category_id))?;
+    ctx.db
+        .message_categories()
+        .id()
+        .update(soft_deleted(category, ctx.timestamp)?);
+    log::info!(
+        "Soft-deleted message category {} (by identity: {:?})",
+        category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Undo `remove_message_category`. The category's `active` flag is left as it was.
+#[spacetimedb::reducer]
+pub fn restore_category(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    ctx.db.message_categories().id().update(restored(category)?);
+    log::info!(
+        "Restored message category {} (by identity: {:?})",
+        id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Permanently remove a category row and its addresses. Subscriptions pointing at it are left
+/// behind and show up as orphaned in `validate_data`.
+#[spacetimedb::reducer]
+pub fn hard_delete_category(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if !ctx.db.message_categories().id().delete(&id) {
+        return Err(format!("Message category {} not found", id));
+    }
+    let addresses: Vec<u64> = ctx
+        .db
+        .category_address()
+        .category_id()
+        .filter(&id)
+        .map(|a| a.id)
+        .collect();
+    for address_id in addresses {
+        ctx.db.category_address().id().delete(&address_id);
+    }
+    let domains: Vec<String> = ctx
+        .db
+        .domain_route()
+        .category_id()
+        .filter(&id)
+        .map(|r| r.domain)
+        .collect();
+    for domain in domains {
+        ctx.db.domain_route().domain().delete(&domain);
+    }
+    log::info!(
+        "Permanently deleted message category {} (by identity: {:?})",
+        id,
+        ctx.sender()
+    );
+    Ok(())
+}
 
-    let timestamp = ctx.timestamp;
-
-    let existing = ctx
+/// Switch a category between immediate relay (`None`) and digest mode with the given interval.
+#[spacetimedb::reducer]
+pub fn set_category_digest(
+    ctx: &ReducerContext,
+    category_id: u64,
+    interval_secs: Option<u64>,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let category = ctx
         .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .ok_or_else(|| format!("Message category {} not found", category_id))?;
+
+    // An interval of 0 means "no batching"; store it as None so there is only one off-state.
+    let interval_secs = interval_secs.filter(|secs| *secs > 0);
+    ctx.db.message_categories().id().update(MessageCategory {
+        digest_interval_secs: interval_secs,
+        ..category
+    });
+    if interval_secs.is_some() {
+        digest::ensure_digest_flush_schedule(ctx);
+    }
+    log::info!(
+        "Set digest interval of category {} to {:?} (by identity: {:?})",
+        category_id,
+        interval_secs,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// How many subscriber addresses `preview_category_impact` lists.
+const IMPACT_SAMPLE_SIZE: usize = 10;
+
+/// Subscriptions of a category that are active and not expired at `now`.
+pub(crate) fn current_category_subscriptions(
+    ctx: &ReducerContext,
+    category_id: u64,
+    now: Timestamp,
+) -> Vec<Subscription> {
+    ctx.db
         .subscriptions()
-        .subscriber_account_id()
-        .filter(&subscriber_account_id)
-        .find(|sub| sub.category_id == category_id);
+        .category_id()
+        .filter(&category_id)
+        .filter(|sub| sub.is_current(now))
+        .collect()
+}
+
+/// Answer of `preview_category_impact`, keyed by the caller-chosen `request_id`.
+#[spacetimedb::table(accessor = category_impact_result)]
+pub struct CategoryImpactResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub category_id: u64,
+    pub active_subscribers: u64,
+    /// Up to `IMPACT_SAMPLE_SIZE` subscriber addresses
+    pub sample_emails: Vec<String>,
+    pub computed_at: Timestamp,
+}
+
+/// The caller's own `preview_category_impact` answers.
+#[spacetimedb::view(accessor = my_category_impact_results, public)]
+pub fn my_category_impact_results(ctx: &ViewContext) -> Vec<CategoryImpactResult> {
+    ctx.db
+        .category_impact_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Who would be cut off by pausing a category: writes the number of current subscribers and a
+/// sample of their addresses into `category_impact_result` under `request_id`. Only admins.
+#[spacetimedb::reducer]
+pub fn preview_category_impact(
+    ctx: &ReducerContext,
+    request_id: String,
+    category_id: u64,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .is_none()
+    {
+        return Err(format!("Message category {} not found", category_id));
+    }
+    let subscriptions = current_category_subscriptions(ctx, category_id, ctx.timestamp);
+    let result = CategoryImpactResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        category_id,
+        active_subscribers: subscriptions.len() as u64,
+        sample_emails: subscriptions
+            .iter()
+            .take(IMPACT_SAMPLE_SIZE)
+            .map(|sub| sub.subscriber_email.clone())
+            .collect(),
+        computed_at: ctx.timestamp,
+    };
+    let owner = ctx
+        .db
+        .category_impact_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db.category_impact_result().request_id().update(result);
+    } else {
+        ctx.db.category_impact_result().insert(result);
+    }
+    Ok(())
+}
+
+/// Pause or resume a category. Inactive categories are rejected at RCPT and ignored at DATA;
+/// the category and its subscriptions stay untouched.
+#[spacetimedb::reducer]
+pub fn set_category_active(ctx: &ReducerContext, id: u64, active: bool) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    ctx.db
+        .message_categories()
+        .id()
+        .update(MessageCategory { active, ..category });
+    log::info!(
+        "Set category {} active = {} (by identity: {:?})",
+        id,
+        active,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Open a category to non-subscribers (`false`) or require a subscription again (`true`).
+/// Only admins.
+#[spacetimedb::reducer]
+pub fn set_category_subscription_required(
+    ctx: &ReducerContext,
+    id: u64,
+    subscription_required: bool,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    ctx.db.message_categories().id().update(MessageCategory {
+        subscription_required,
+        ..category
+    });
+    log::info!(
+        "Set category {} subscription_required = {} (by identity: {:?})",
+        id,
+        subscription_required,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Set how much of a category's mail is kept in `mta_message_log`: `none`, `summary` or
+/// `full`. Only admins.
+#[spacetimedb::reducer]
+pub fn set_category_log_level(
+    ctx: &ReducerContext,
+    id: u64,
+    log_level: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let log_level = log_level.trim().to_lowercase();
+    if !LOG_LEVELS.contains(&log_level.as_str()) {
+        return Err(format!(
+            "Invalid log level '{}', expected one of: {}",
+            log_level,
+            LOG_LEVELS.join(", ")
+        ));
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    log::info!(
+        "Set category {} log_level = {} (by identity: {:?})",
+        id,
+        log_level,
+        ctx.sender()
+    );
+    ctx.db.message_categories().id().update(MessageCategory {
+        log_level,
+        ..category
+    });
+    Ok(())
+}
+
+/// Limit how many messages DATA accepts for a category per hour; `None` or 0 removes the
+/// limit. Only admins.
+#[spacetimedb::reducer]
+pub fn set_category_rate_limit(
+    ctx: &ReducerContext,
+    id: u64,
+    rate_limit_per_hour: Option<u32>,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let rate_limit_per_hour = rate_limit_per_hour.filter(|limit| *limit > 0);
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    log::info!(
+        "Set category {} rate_limit_per_hour = {:?} (by identity: {:?})",
+        id,
+        rate_limit_per_hour,
+        ctx.sender()
+    );
+    ctx.db.message_categories().id().update(MessageCategory {
+        rate_limit_per_hour,
+        ..category
+    });
+    Ok(())
+}
+
+/// Give a category its own DATA accept reply, e.g. `250 Queued for the board, reply within a
+/// week`. `None` for both restores the default. The code must be 2xx; the message is a single
+/// line. Only admins.
+#[spacetimedb::reducer]
+pub fn set_category_accept_response(
+    ctx: &ReducerContext,
+    id: u64,
+    accept_code: Option<u16>,
+    accept_message: Option<String>,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if let Some(code) = accept_code {
+        if !(200..300).contains(&code) {
+            return Err(format!("Accept code {} is not a 2xx SMTP code", code));
+        }
+    }
+    let accept_message = accept_message
+        .map(|message| message.trim().to_string())
+        .filter(|message| !message.is_empty());
+    if let Some(message) = &accept_message {
+        if message.contains(['\r', '\n']) {
+            return Err("Accept message must be a single line".to_string());
+        }
+        if message.chars().count() > MAX_ACCEPT_MESSAGE_LEN {
+            return Err(format!(
+                "Accept message is longer than {} characters",
+                MAX_ACCEPT_MESSAGE_LEN
+            ));
+        }
+    }
+    let category = ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Message category {} not found", id))?;
+    log::info!(
+        "Set category {} accept response = {:?} {:?} (by identity: {:?})",
+        id,
+        accept_code,
+        accept_message,
+        ctx.sender()
+    );
+    ctx.db.message_categories().id().update(MessageCategory {
+        accept_code,
+        accept_message,
+        ..category
+    });
+    Ok(())
+}
+
+impl Subscription {
+    /// Active and not past `expires_at`. Use this instead of `active` where a lapsed
+    /// subscription must not count before `expire_subscriptions` has run.
+    pub(crate) fn is_current(&self, now: Timestamp) -> bool {
+        self.active && self.expires_at.map_or(true, |expires_at| now <= expires_at)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_subscription(
+    ctx: &ReducerContext,
+    subscriber_account_id: u64,
+    subscriber_email: String,
+    category_id: u64,
+) -> Result<(), String> {
+    upsert_subscription(
+        ctx,
+        subscriber_account_id,
+        subscriber_email,
+        category_id,
+        None,
+    )
+}
+
+/// Like `add_subscription`, but the subscription lapses at `expires_at` (e.g. trial or
+/// seasonal members).
+#[spacetimedb::reducer]
+pub fn add_subscription_with_expiry(
+    ctx: &ReducerContext,
+    subscriber_account_id: u64,
+    subscriber_email: String,
+    category_id: u64,
+    expires_at: Timestamp,
+) -> Result<(), String> {
+    if expires_at <= ctx.timestamp {
+        return Err("expires_at must be in the future".to_string());
+    }
+    upsert_subscription(
+        ctx,
+        subscriber_account_id,
+        subscriber_email,
+        category_id,
+        Some(expires_at),
+    )?;
+    ensure_subscription_expiry_schedule(ctx);
+    Ok(())
+}
+
+fn upsert_subscription(
+    ctx: &ReducerContext,
+    subscriber_account_id: u64,
+    subscriber_email: String,
+    category_id: u64,
+    expires_at: Option<Timestamp>,
+) -> Result<(), String> {
+    let is_admin = is_admin_user(ctx);
+    let is_self = ctx
+        .db
+        .account()
+        .id()
+        .find(&subscriber_account_id)
+        .map(|a: Account| a.identity == ctx.sender())
+        .unwrap_or(false);
+
+    if !is_admin && !is_self {
+        return Err("Unauthorized: can only subscribe yourself or requires admin".to_string());
+    }
+
+    write_subscription(
+        ctx,
+        subscriber_account_id,
+        subscriber_email,
+        category_id,
+        expires_at,
+        false,
+    )
+}
+
+/// Create or reactivate the subscription of `subscriber_account_id` to `category_id` and make
+/// sure it has an unsubscribe token. `from_group_sync` marks who made it (see
+/// `Subscription::from_group_sync`). Callers check authorization.
+fn write_subscription(
+    ctx: &ReducerContext,
+    subscriber_account_id: u64,
+    subscriber_email: String,
+    category_id: u64,
+    expires_at: Option<Timestamp>,
+    from_group_sync: bool,
+) -> Result<(), String> {
+    let timestamp = ctx.timestamp;
+
+    let existing = find_subscription(ctx, subscriber_account_id, category_id);
 
     let subscription = if let Some(existing) = existing {
         let updated = Subscription {
             subscriber_email: subscriber_email.clone(),
             subscribed_at: timestamp,
             active: true,
+            expires_at,
+            from_group_sync,
+            deactivated_by_group_sync: false,
             ..existing
         };
         ctx.db.subscriptions().id().update(updated.clone());
@@ -175,21 +1401,516 @@ pub fn add_subscription(
             category_id,
             subscribed_at: timestamp,
             active: true,
+            expires_at,
+            from_group_sync,
+            deactivated_by_group_sync: false,
         };
         ctx.db.subscriptions().insert(candidate);
-        ctx.db
-            .subscriptions()
-            .subscriber_account_id()
-            .filter(&subscriber_account_id)
-            .find(|sub| sub.category_id == category_id)
+        find_subscription(ctx, subscriber_account_id, category_id)
             .ok_or_else(|| "Subscription insert failed".to_string())?
     };
 
-    let token = upsert_subscription_unsubscribe_token(ctx, subscription.id)?;
+    let token = upsert_subscription_unsubscribe_token(ctx, subscription.id)?;
+    log::info!(
+        "Added subscription for account {} (token: {}, by identity: {:?})",
+        subscriber_account_id,
+        token,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Django group whose members are subscribed to a category. `sync_user` keeps the
+/// subscriptions of every mapped category in line with the groups Django sends.
+#[spacetimedb::table(accessor = group_category_map)]
+pub struct GroupCategoryMap {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Django group name, matched exactly
+    #[index(btree)]
+    pub group_name: String,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    pub created_at: Timestamp,
+}
+
+/// Returns all group mappings for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_group_category_map, public)]
+pub fn visible_group_category_map(ctx: &ViewContext) -> impl Query<GroupCategoryMap> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.group_category_map().r#filter(move |_| is_admin)
+}
+
+/// Subscribe members of `group_name` to `category_id` from their next sync on. Only admins.
+#[spacetimedb::reducer]
+pub fn add_group_category_mapping(
+    ctx: &ReducerContext,
+    group_name: String,
+    category_id: u64,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let group_name = group_name.trim().to_string();
+    if group_name.is_empty() {
+        return Err("group_name must not be empty".to_string());
+    }
+    if ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .is_none()
+    {
+        return Err(format!("Category {} not found", category_id));
+    }
+    let exists = ctx
+        .db
+        .group_category_map()
+        .group_name()
+        .filter(&group_name)
+        .any(|m| m.category_id == category_id);
+    if exists {
+        return Err(format!(
+            "Group {} is already mapped to category {}",
+            group_name, category_id
+        ));
+    }
+    ctx.db.group_category_map().insert(GroupCategoryMap {
+        id: 0,
+        group_name: group_name.clone(),
+        category_id,
+        created_at: ctx.timestamp,
+    });
+    log::info!(
+        "Mapped group {} to category {} (by identity: {:?})",
+        group_name,
+        category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Remove a group mapping. Existing subscriptions stay; once no mapping names the category,
+/// syncs no longer touch its subscriptions. Only admins.
+#[spacetimedb::reducer]
+pub fn remove_group_category_mapping(ctx: &ReducerContext, mapping_id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if ctx.db.group_category_map().id().find(&mapping_id).is_none() {
+        return Err(format!("Group mapping {} not found", mapping_id));
+    }
+    ctx.db.group_category_map().id().delete(&mapping_id);
+    log::info!(
+        "Removed group mapping {} (by identity: {:?})",
+        mapping_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Split the mapped categories by `groups`: the categories to subscribe to (mapped from one of
+/// `groups` but neither in `subscribed` nor in `declined`, the subscriptions deactivated other
+/// than by the group sync) and the ones to unsubscribe from (in `synced`, the subscriptions the
+/// group sync made, but mapped from none of `groups`). A manual subscription is never removed,
+/// and an unsubscribe never undone. `mappings` are `(group_name, category_id)` pairs.
+fn group_subscription_changes<'a>(
+    mappings: impl IntoIterator<Item = (&'a str, u64)>,
+    groups: &[String],
+    subscribed: &BTreeSet<u64>,
+    synced: &BTreeSet<u64>,
+    declined: &BTreeSet<u64>,
+) -> (BTreeSet<u64>, BTreeSet<u64>) {
+    let mut mapped = BTreeSet::new();
+    let mut wanted = BTreeSet::new();
+    for (group_name, category_id) in mappings {
+        if groups.iter().any(|g| g.trim() == group_name) {
+            wanted.insert(category_id);
+        }
+        mapped.insert(category_id);
+    }
+    let add = wanted
+        .difference(subscribed)
+        .filter(|id| !declined.contains(id))
+        .copied()
+        .collect();
+    let remove = mapped
+        .intersection(synced)
+        .filter(|id| !wanted.contains(id))
+        .copied()
+        .collect();
+    (add, remove)
+}
+
+/// Align the subscriptions of `account` with its Django `groups`: it is subscribed to every
+/// category one of its groups maps to, and the subscriptions the sync made are removed from
+/// mapped categories none of its groups maps to. Subscriptions made by hand or by import stay.
+/// A subscription the member (or anything but the sync) deactivated is not reactivated; one the
+/// sync removed is. Categories without a mapping are left alone, as are deleted ones.
+pub(crate) fn apply_group_subscriptions(
+    ctx: &ReducerContext,
+    account: &Account,
+    groups: &[String],
+) -> Result<(), String> {
+    let mappings: Vec<GroupCategoryMap> = ctx
+        .db
+        .group_category_map()
+        .iter()
+        .filter(|mapping| {
+            ctx.db
+                .message_categories()
+                .id()
+                .find(&mapping.category_id)
+                .is_some_and(|c| c.deleted_at.is_none())
+        })
+        .collect();
+    let mut subscribed = BTreeSet::new();
+    let mut synced = BTreeSet::new();
+    let mut declined = BTreeSet::new();
+    for mapping in &mappings {
+        let category_id = mapping.category_id;
+        match find_subscription(ctx, account.id, category_id) {
+            Some(sub) if sub.active && sub.from_group_sync => {
+                subscribed.insert(category_id);
+                synced.insert(category_id);
+            }
+            Some(sub) if sub.active => {
+                subscribed.insert(category_id);
+            }
+            Some(sub) if !sub.deactivated_by_group_sync => {
+                declined.insert(category_id);
+            }
+            _ => {}
+        }
+    }
+    let (add, remove) = group_subscription_changes(
+        mappings
+            .iter()
+            .map(|m| (m.group_name.as_str(), m.category_id)),
+        groups,
+        &subscribed,
+        &synced,
+        &declined,
+    );
+
+    for category_id in &add {
+        write_subscription(
+            ctx,
+            account.id,
+            account.email.clone(),
+            *category_id,
+            None,
+            true,
+        )?;
+    }
+    for category_id in &remove {
+        let active: Vec<Subscription> = ctx
+            .db
+            .subscriptions()
+            .account_category()
+            .filter((account.id, *category_id))
+            .filter(|sub| sub.active && sub.from_group_sync)
+            .collect();
+        for sub in active {
+            deactivate_group_subscription(ctx, sub);
+        }
+    }
+    if !add.is_empty() || !remove.is_empty() {
+        log::info!(
+            "Group sync for account {}: {} subscriptions added, {} removed",
+            account.id,
+            add.len(),
+            remove.len()
+        );
+    }
+    Ok(())
+}
+
+/// One entry of the `import_subscriptions` payload.
+#[derive(serde::Deserialize)]
+struct SubscriptionImportEntry {
+    subscriber_email: String,
+    category_id: u64,
+}
+
+/// What `import_subscriptions` does with one entry.
+#[derive(Debug, PartialEq, Eq)]
+enum ImportAction {
+    /// Not an address, unknown category, or already actively subscribed.
+    Skip,
+    /// An inactive row exists for the subscriber and category and is reactivated.
+    Reactivate,
+    /// No row yet: a new one is inserted.
+    Create,
+}
+
+/// The `ImportAction` for an entry with `email`, whose category exists if `category_known`,
+/// and whose subscriber's row on that category is `existing`.
+fn import_action(
+    email: &str,
+    category_known: bool,
+    existing: Option<&Subscription>,
+) -> ImportAction {
+    match existing {
+        _ if !email.contains('@') || !category_known => ImportAction::Skip,
+        Some(existing) if existing.active => ImportAction::Skip,
+        Some(_) => ImportAction::Reactivate,
+        None => ImportAction::Create,
+    }
+}
+
+/// What `import_entries` needs to know about one entry.
+struct ImportLookup {
+    /// The account owning the email, `UNRESOLVED_ACCOUNT_ID` if none does
+    account_id: u64,
+    category_known: bool,
+    /// The subscriber's row on the entry's category, if any
+    existing: Option<Subscription>,
+}
+
+/// Import `entries` at `now`. `lookup` resolves an entry's trimmed email and category, `save`
+/// stores a new row (`id` 0) or a reactivated one right away, so a repeated entry sees it.
+/// Returns how many subscriptions were created, how many of those are unresolved, and how many
+/// entries were skipped.
+fn import_entries(
+    entries: Vec<SubscriptionImportEntry>,
+    now: Timestamp,
+    mut lookup: impl FnMut(&str, u64) -> ImportLookup,
+    mut save: impl FnMut(Subscription) -> Result<(), String>,
+) -> Result<(usize, usize, usize), String> {
+    let (mut created, mut unresolved, mut skipped) = (0usize, 0usize, 0usize);
+    for entry in entries {
+        let email = entry.subscriber_email.trim().to_string();
+        let ImportLookup {
+            account_id,
+            category_known,
+            existing,
+        } = lookup(&email, entry.category_id);
+
+        if import_action(&email, category_known, existing.as_ref()) == ImportAction::Skip {
+            skipped += 1;
+            continue;
+        }
+        save(match existing {
+            Some(existing) => Subscription {
+                subscriber_email: email,
+                subscribed_at: now,
+                active: true,
+                expires_at: None,
+                from_group_sync: false,
+                deactivated_by_group_sync: false,
+                ..existing
+            },
+            None => Subscription {
+                id: 0,
+                subscriber_account_id: account_id,
+                subscriber_email: email,
+                category_id: entry.category_id,
+                subscribed_at: now,
+                active: true,
+                expires_at: None,
+                from_group_sync: false,
+                deactivated_by_group_sync: false,
+            },
+        })?;
+        created += 1;
+        if account_id == UNRESOLVED_ACCOUNT_ID {
+            unresolved += 1;
+        }
+    }
+    Ok((created, unresolved, skipped))
+}
+
+/// Bulk-create subscriptions from `[{"subscriber_email": ..., "category_id": ...}]`, e.g. when
+/// migrating from another list manager. Only admins.
+/// Emails are resolved to accounts where possible; unresolved ones are stored with
+/// `UNRESOLVED_ACCOUNT_ID` and linked when the account syncs. Entries that already have an
+/// active subscription or name an unknown category are skipped.
+#[spacetimedb::reducer]
+pub fn import_subscriptions(ctx: &ReducerContext, json: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let entries: Vec<SubscriptionImportEntry> = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse subscription import: {}", e))?;
+
+    let lookup = |email: &str, category_id: u64| {
+        let account_id = ctx
+            .db
+            .account()
+            .email()
+            .filter(&email.to_string())
+            .next()
+            .map_or(UNRESOLVED_ACCOUNT_ID, |a| a.id);
+        let existing = if account_id == UNRESOLVED_ACCOUNT_ID {
+            ctx.db
+                .subscriptions()
+                .subscriber_email()
+                .filter(&email.to_string())
+                .find(|sub| sub.category_id == category_id)
+        } else {
+            find_subscription(ctx, account_id, category_id)
+        };
+        ImportLookup {
+            account_id,
+            category_known: ctx
+                .db
+                .message_categories()
+                .id()
+                .find(&category_id)
+                .is_some(),
+            existing,
+        }
+    };
+    let (created, unresolved, skipped) =
+        import_entries(entries, ctx.timestamp, lookup, |subscription| {
+            let stored = if subscription.id == 0 {
+                ctx.db.subscriptions().insert(subscription)
+            } else {
+                ctx.db.subscriptions().id().update(subscription)
+            };
+            upsert_subscription_unsubscribe_token(ctx, stored.id).map(|_| ())
+        })?;
+
     log::info!(
-        "Added subscription for account {} (token: {}, by identity: {:?})",
-        subscriber_account_id,
-        token,
+        "Imported subscriptions: {} created ({} unresolved), {} skipped (by identity: {:?})",
+        created,
+        unresolved,
+        skipped,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Attach imported subscriptions that were stored by email only to the now-known account.
+pub(crate) fn link_unresolved_subscriptions(ctx: &ReducerContext, account_id: u64, email: &str) {
+    let unresolved: Vec<Subscription> = ctx
+        .db
+        .subscriptions()
+        .subscriber_email()
+        .filter(&email.to_string())
+        .filter(|sub| sub.subscriber_account_id == UNRESOLVED_ACCOUNT_ID)
+        .collect();
+    for sub in unresolved {
+        log::info!(
+            "Linked imported subscription {} to account {}",
+            sub.id,
+            account_id
+        );
+        ctx.db.subscriptions().id().update(Subscription {
+            subscriber_account_id: account_id,
+            ..sub
+        });
+    }
+}
+
+/// What `relink_subscriptions` does with one subscription.
+#[derive(Debug, PartialEq, Eq)]
+enum Relink {
+    /// Linked correctly and the email is current.
+    Keep,
+    /// The account exists but its email changed: the row gets the new one.
+    RefreshEmail(String),
+    /// The account is gone; the account owning the email takes over.
+    Relink(u64),
+    /// No account owns the email. `reset` when the row still points at a dangling id.
+    Unresolvable { reset: bool },
+}
+
+/// The `Relink` for `sub`, given the current email of its account (`None` if the account no
+/// longer exists) and a lookup of the account that owns the subscription's email.
+fn relink(
+    sub: &Subscription,
+    account_email: Option<&str>,
+    owner_of_email: impl FnOnce() -> Option<u64>,
+) -> Relink {
+    match account_email {
+        Some(email) if !email.is_empty() && !email.eq_ignore_ascii_case(&sub.subscriber_email) => {
+            Relink::RefreshEmail(email.to_string())
+        }
+        Some(_) => Relink::Keep,
+        None => match owner_of_email() {
+            Some(account_id) => Relink::Relink(account_id),
+            // Imports waiting for their account are expected; anything else is a dangling id.
+            None => Relink::Unresolvable {
+                reset: sub.subscriber_account_id != UNRESOLVED_ACCOUNT_ID,
+            },
+        },
+    }
+}
+
+/// Repair the account linkage of every subscription, e.g. after Django changed member emails.
+/// A subscription whose account exists keeps it and gets the account's current email; one
+/// without a (still existing) account is linked to the account that owns its email, as primary
+/// address or verified alias. Logs how many were relinked, refreshed and left unresolvable.
+/// Duplicates this may create are left to `dedupe_subscriptions`. Only admins.
+#[spacetimedb::reducer]
+pub fn relink_subscriptions(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let mut relinked = 0usize;
+    let mut refreshed = 0usize;
+    let mut unresolvable = 0usize;
+    let subs: Vec<Subscription> = ctx.db.subscriptions().iter().collect();
+    for sub in subs {
+        let account = ctx.db.account().id().find(&sub.subscriber_account_id);
+        let action = relink(&sub, account.as_ref().map(|a| a.email.as_str()), || {
+            account_for_sender(ctx, &sub.subscriber_email).map(|a| a.id)
+        });
+        match action {
+            Relink::Keep => {}
+            Relink::RefreshEmail(email) => {
+                log::info!(
+                    "Subscription {}: email {} -> {} of account {}",
+                    sub.id,
+                    sub.subscriber_email,
+                    email,
+                    sub.subscriber_account_id
+                );
+                ctx.db.subscriptions().id().update(Subscription {
+                    subscriber_email: email,
+                    ..sub
+                });
+                refreshed += 1;
+            }
+            Relink::Relink(account_id) => {
+                log::info!(
+                    "Subscription {}: account {} -> {}",
+                    sub.id,
+                    sub.subscriber_account_id,
+                    account_id
+                );
+                ctx.db.subscriptions().id().update(Subscription {
+                    subscriber_account_id: account_id,
+                    ..sub
+                });
+                relinked += 1;
+            }
+            Relink::Unresolvable { reset } => {
+                if reset {
+                    ctx.db.subscriptions().id().update(Subscription {
+                        subscriber_account_id: UNRESOLVED_ACCOUNT_ID,
+                        ..sub
+                    });
+                }
+                unresolvable += 1;
+            }
+        }
+    }
+    log::info!(
+        "Relinked subscriptions: {} relinked, {} emails refreshed, {} unresolvable (by identity: {:?})",
+        relinked,
+        refreshed,
+        unresolvable,
         ctx.sender()
     );
     Ok(())
@@ -219,10 +1940,7 @@ pub fn remove_subscription(ctx: &ReducerContext, subscription_id: u64) -> Result
         );
     }
 
-    let mut updated = sub.clone();
-    updated.active = false;
-    ctx.db.subscriptions().id().update(updated);
-    deactivate_subscription_unsubscribe_token(ctx, subscription_id);
+    deactivate_subscription(ctx, sub);
     log::info!(
         "Deactivated subscription {} (by identity: {:?})",
         subscription_id,
@@ -231,6 +1949,284 @@ pub fn remove_subscription(ctx: &ReducerContext, subscription_id: u64) -> Result
     Ok(())
 }
 
+/// Mark a subscription inactive and retire its unsubscribe token. The group sync leaves it
+/// inactive from then on.
+pub(crate) fn deactivate_subscription(ctx: &ReducerContext, sub: Subscription) {
+    store_inactive(
+        ctx,
+        Subscription {
+            deactivated_by_group_sync: false,
+            ..sub
+        },
+    );
+}
+
+/// `deactivate_subscription` for the group sync, which reactivates the row once one of the
+/// member's groups maps to its category again.
+fn deactivate_group_subscription(ctx: &ReducerContext, sub: Subscription) {
+    store_inactive(
+        ctx,
+        Subscription {
+            deactivated_by_group_sync: true,
+            ..sub
+        },
+    );
+}
+
+fn store_inactive(ctx: &ReducerContext, sub: Subscription) {
+    let subscription_id = sub.id;
+    ctx.db.subscriptions().id().update(Subscription {
+        active: false,
+        ..sub
+    });
+    deactivate_subscription_unsubscribe_token(ctx, subscription_id);
+}
+
+/// The active rows of `subs` that duplicate an older active row of the same category and
+/// subscriber (the account, or the email while unresolved).
+fn duplicate_subscriptions(subs: impl Iterator<Item = Subscription>) -> Vec<Subscription> {
+    let mut groups: BTreeMap<(u64, String, u64), Vec<Subscription>> = BTreeMap::new();
+    for sub in subs.filter(|sub| sub.active) {
+        let email_key = if sub.subscriber_account_id == UNRESOLVED_ACCOUNT_ID {
+            sub.subscriber_email.to_lowercase()
+        } else {
+            String::new()
+        };
+        groups
+            .entry((sub.subscriber_account_id, email_key, sub.category_id))
+            .or_default()
+            .push(sub);
+    }
+    let mut duplicates = Vec::new();
+    for (_, mut subs) in groups {
+        subs.sort_by_key(|sub| (sub.subscribed_at.to_micros_since_unix_epoch(), sub.id));
+        duplicates.extend(subs.into_iter().skip(1));
+    }
+    duplicates
+}
+
+/// Deactivate duplicate active subscriptions left behind by earlier imports. Per category and
+/// subscriber (the account, or the email while unresolved) the oldest active row is kept.
+/// Running it again finds nothing to collapse. Only admins.
+#[spacetimedb::reducer]
+pub fn dedupe_subscriptions(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let mut collapsed = 0usize;
+    for duplicate in duplicate_subscriptions(ctx.db.subscriptions().iter()) {
+        log::info!(
+            "Deactivating duplicate subscription {} (category {}, {})",
+            duplicate.id,
+            duplicate.category_id,
+            duplicate.subscriber_email
+        );
+        deactivate_subscription(ctx, duplicate);
+        collapsed += 1;
+    }
+    log::info!(
+        "Collapsed {} duplicate subscriptions (by identity: {:?})",
+        collapsed,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// What `reassign_subscribers` does with an active source subscription.
+#[derive(Debug, PartialEq, Eq)]
+enum Reassignment {
+    /// No row on the target: the source row itself moves over.
+    Move,
+    /// An inactive row on the target is reactivated; the source row is deactivated.
+    Reactivate,
+    /// Already active on the target: only the source row is deactivated.
+    AlreadyThere,
+}
+
+/// The `Reassignment` for a subscriber whose row on the target category is `on_target`.
+fn reassignment(on_target: Option<&Subscription>) -> Reassignment {
+    match on_target {
+        None => Reassignment::Move,
+        Some(target) if target.active => Reassignment::AlreadyThere,
+        Some(_) => Reassignment::Reactivate,
+    }
+}
+
+/// A row `reassign_rows` writes, in its new state.
+enum ReassignWrite {
+    /// Moved to the target category; keeps its unsubscribe token.
+    Moved(Subscription),
+    /// Active again on the target category; needs an unsubscribe token.
+    Reactivated(Subscription),
+    /// The source row of a subscriber already on the target; its token is revoked.
+    Deactivated(Subscription),
+}
+
+/// Reassign the active `sources` to `to_category_id`. `on_target` finds a subscriber's row on
+/// the target category, `write` stores each changed row right away, so a later source of the
+/// same subscriber sees it. Returns how many subscribers moved and how many were already there.
+fn reassign_rows(
+    sources: Vec<Subscription>,
+    to_category_id: u64,
+    mut on_target: impl FnMut(&Subscription) -> Option<Subscription>,
+    mut write: impl FnMut(ReassignWrite) -> Result<(), String>,
+) -> Result<(usize, usize), String> {
+    let (mut moved, mut skipped) = (0usize, 0usize);
+    for sub in sources {
+        let target = on_target(&sub);
+        match (reassignment(target.as_ref()), target) {
+            (Reassignment::AlreadyThere, _) => {
+                write(ReassignWrite::Deactivated(Subscription {
+                    active: false,
+                    ..sub
+                }))?;
+                skipped += 1;
+            }
+            (Reassignment::Reactivate, Some(target)) => {
+                write(ReassignWrite::Reactivated(Subscription {
+                    active: true,
+                    expires_at: sub.expires_at,
+                    deactivated_by_group_sync: false,
+                    ..target
+                }))?;
+                write(ReassignWrite::Deactivated(Subscription {
+                    active: false,
+                    ..sub
+                }))?;
+                moved += 1;
+            }
+            _ => {
+                write(ReassignWrite::Moved(Subscription {
+                    category_id: to_category_id,
+                    ..sub
+                }))?;
+                moved += 1;
+            }
+        }
+    }
+    Ok((moved, skipped))
+}
+
+/// Move every active subscription of `from_category_id` to `to_category_id`, e.g. when merging
+/// two categories. Runs as one transaction, so no subscriber is ever on neither category.
+/// Subscribers already active on the target keep that subscription and their source row is
+/// deactivated; an inactive one on the target is reactivated instead of adding a second row.
+/// Only admins.
+#[spacetimedb::reducer]
+pub fn reassign_subscribers(
+    ctx: &ReducerContext,
+    from_category_id: u64,
+    to_category_id: u64,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if from_category_id == to_category_id {
+        return Err("Source and target category are the same".to_string());
+    }
+    for id in [from_category_id, to_category_id] {
+        if ctx.db.message_categories().id().find(&id).is_none() {
+            return Err(format!("Message category {} not found", id));
+        }
+    }
+
+    let sources: Vec<Subscription> = ctx
+        .db
+        .subscriptions()
+        .category_id()
+        .filter(&from_category_id)
+        .filter(|sub| sub.active)
+        .collect();
+    let find_on_target = |sub: &Subscription| {
+        if sub.subscriber_account_id == UNRESOLVED_ACCOUNT_ID {
+            preferred_subscription(
+                ctx.db
+                    .subscriptions()
+                    .subscriber_email()
+                    .filter(&sub.subscriber_email)
+                    .filter(|s| {
+                        s.subscriber_account_id == UNRESOLVED_ACCOUNT_ID
+                            && s.category_id == to_category_id
+                    }),
+            )
+        } else {
+            find_subscription(ctx, sub.subscriber_account_id, to_category_id)
+        }
+    };
+    let (moved, skipped) = reassign_rows(sources, to_category_id, find_on_target, |write| {
+        match write {
+            // Same row, so the subscriber's unsubscribe token keeps working.
+            ReassignWrite::Moved(sub) => {
+                ctx.db.subscriptions().id().update(sub);
+            }
+            ReassignWrite::Reactivated(target) => {
+                let target_id = target.id;
+                ctx.db.subscriptions().id().update(target);
+                upsert_subscription_unsubscribe_token(ctx, target_id)?;
+            }
+            ReassignWrite::Deactivated(sub) => deactivate_subscription(ctx, sub),
+        }
+        Ok(())
+    })?;
+    log::info!(
+        "Moved {} subscribers from category {} to {} ({} already subscribed there) (by identity: {:?})",
+        moved,
+        from_category_id,
+        to_category_id,
+        skipped,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Insert the repeating expiry timer unless one already exists.
+/// Called from `init` and whenever a subscription with expiry is added.
+pub(crate) fn ensure_subscription_expiry_schedule(ctx: &ReducerContext) {
+    if ctx
+        .db
+        .subscription_expiry_schedule()
+        .iter()
+        .next()
+        .is_some()
+    {
+        return;
+    }
+    ctx.db
+        .subscription_expiry_schedule()
+        .insert(SubscriptionExpirySchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(subscription_expiry_period()),
+        });
+    log::info!(
+        "Scheduled subscription expiry every {:?}",
+        subscription_expiry_period()
+    );
+}
+
+/// Scheduled reducer: deactivate every active subscription whose `expires_at` has passed,
+/// so views, the sender fan-out, and stats no longer count it.
+#[spacetimedb::reducer]
+pub fn expire_subscriptions(
+    ctx: &ReducerContext,
+    _schedule: SubscriptionExpirySchedule,
+) -> Result<(), String> {
+    if ctx.sender() != ctx.database_identity() {
+        return Err("Unauthorized: expire_subscriptions is only run by the scheduler".into());
+    }
+    let lapsed: Vec<Subscription> = ctx
+        .db
+        .subscriptions()
+        .iter()
+        .filter(|sub| sub.active && !sub.is_current(ctx.timestamp))
+        .collect();
+    for sub in lapsed {
+        let id = sub.id;
+        deactivate_subscription(ctx, sub);
+        log::info!("Expired subscription {}", id);
+    }
+    Ok(())
+}
+
 fn upsert_subscription_unsubscribe_token(
     ctx: &ReducerContext,
     subscription_id: u64,
@@ -348,15 +2344,30 @@ pub fn provision_message_category(
     }
 
     info!("User has required permissions!");
+    check_category_domain(&email_address)?;
 
-    // 2) Ensure category doesn't already exist
-    let exists: bool = ctx.with_tx(|tx| {
+    // 2) A category that already has this address keeps its mailbox; only update it
+    let is_known: bool = ctx.with_tx(|tx| {
         tx.db
             .message_categories()
             .email_address()
             .find(&email_address)
             .is_some()
     });
+    if is_known {
+        let outcome = ctx.with_tx(|tx| {
+            upsert_category(tx, name.clone(), email_address.clone(), description.clone())
+        })?;
+        let (CategoryUpsert::Created(id) | CategoryUpsert::Updated(id)) = outcome;
+        info!(
+            "Category {} already exists, updated name and description",
+            id
+        );
+        return Ok(());
+    }
+
+    // Any other use of the address (e.g. as an additional address) is a conflict
+    let exists: bool = ctx.with_tx(|tx| address_in_use(tx, &email_address));
 
     if exists {
         error!("The category with that mailadress already exists");
@@ -472,16 +2483,23 @@ pub fn provision_message_category(
                                 return Err(format!("JMAP reported notCreated: {}", not_created));
                             }
                         }
-                        // Success path: insert the category inside a transaction
-                        ctx.with_tx(|tx| {
-                            tx.db.message_categories().insert(MessageCategory {
-                                id: 0,
-                                name: name.clone(),
-                                email_address: email_address.clone(),
-                                description: description.clone(),
-                                active: true,
-                            });
-                        });
+                        // Success path: create (or update) the category inside a transaction
+                        let outcome = ctx.with_tx(|tx| {
+                            upsert_category(
+                                tx,
+                                name.clone(),
+                                email_address.clone(),
+                                description.clone(),
+                            )
+                        })?;
+                        match outcome {
+                            CategoryUpsert::Created(id) => {
+                                info!("Provisioned new category {}", id)
+                            }
+                            CategoryUpsert::Updated(id) => {
+                                info!("Provisioned existing category {}, updated it", id)
+                            }
+                        }
 
                         return Ok(());
                     }
@@ -492,3 +2510,643 @@ pub fn provision_message_category(
 
     Err(format!("Unexpected JMAP response: {}", res_body))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    fn set(ids: &[u64]) -> BTreeSet<u64> {
+        ids.iter().copied().collect()
+    }
+
+    fn groups(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    const MAPPINGS: [(&str, u64); 3] = [("vorstand", 1), ("gaertner", 2), ("vorstand", 3)];
+
+    /// `group_subscription_changes` for subscriptions that all came from the group sync.
+    fn synced_changes(groups: &[String], synced: &[u64]) -> (BTreeSet<u64>, BTreeSet<u64>) {
+        group_subscription_changes(MAPPINGS, groups, &set(synced), &set(synced), &set(&[]))
+    }
+
+    #[test]
+    fn group_change_adds_and_removes_subscriptions() {
+        // Joining "vorstand" subscribes to both of its categories.
+        let (add, remove) = synced_changes(&groups(&["vorstand"]), &[]);
+        assert_eq!((add, remove), (set(&[1, 3]), set(&[])));
+
+        // Moving from "vorstand" to "gaertner" swaps the subscriptions.
+        let (add, remove) = synced_changes(&groups(&["gaertner"]), &[1, 3]);
+        assert_eq!((add, remove), (set(&[2]), set(&[1, 3])));
+
+        // Leaving every group removes all mapped subscriptions.
+        let (add, remove) = synced_changes(&groups(&[]), &[2]);
+        assert_eq!((add, remove), (set(&[]), set(&[2])));
+    }
+
+    #[test]
+    fn group_sync_is_idempotent_and_leaves_unmapped_categories_alone() {
+        let (add, remove) = synced_changes(&groups(&[" gaertner "]), &[2, 7]);
+        assert_eq!((add, remove), (set(&[]), set(&[])));
+    }
+
+    #[test]
+    fn manual_subscription_survives_the_group_sync() {
+        // Subscribed to 1 by hand, to 2 by the sync of "gaertner".
+        let (subscribed, synced) = (set(&[1, 2]), set(&[2]));
+        let (add, remove) =
+            group_subscription_changes(MAPPINGS, &groups(&[]), &subscribed, &synced, &set(&[]));
+        assert_eq!((add, remove), (set(&[]), set(&[2])));
+
+        // Joining "vorstand" adds only 3; the manual subscription to 1 is kept as it is.
+        let (add, remove) = group_subscription_changes(
+            MAPPINGS,
+            &groups(&["vorstand"]),
+            &set(&[1]),
+            &set(&[]),
+            &set(&[]),
+        );
+        assert_eq!((add, remove), (set(&[3]), set(&[])));
+
+        // Leaving "vorstand" again removes 3 and still keeps 1.
+        let (add, remove) = group_subscription_changes(
+            MAPPINGS,
+            &groups(&[]),
+            &set(&[1, 3]),
+            &set(&[3]),
+            &set(&[]),
+        );
+        assert_eq!((add, remove), (set(&[]), set(&[3])));
+    }
+
+    #[test]
+    fn group_sync_does_not_undo_an_unsubscribe() {
+        // Unsubscribed from 1 while still in "vorstand": only 3 is (re)subscribed.
+        let (add, remove) = group_subscription_changes(
+            MAPPINGS,
+            &groups(&["vorstand"]),
+            &set(&[]),
+            &set(&[]),
+            &set(&[1]),
+        );
+        assert_eq!((add, remove), (set(&[3]), set(&[])));
+    }
+
+    fn subscription(id: u64, active: bool) -> Subscription {
+        Subscription {
+            id,
+            subscriber_account_id: 42,
+            subscriber_email: "alice@example.org".to_string(),
+            category_id: 1,
+            subscribed_at: Timestamp::UNIX_EPOCH,
+            active,
+            expires_at: None,
+            from_group_sync: false,
+            deactivated_by_group_sync: false,
+        }
+    }
+
+    #[test]
+    fn preferred_subscription_picks_an_active_duplicate() {
+        let rows = vec![
+            subscription(1, false),
+            subscription(2, true),
+            subscription(3, true),
+        ];
+        assert_eq!(
+            preferred_subscription(rows.into_iter()).map(|s| s.id),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn preferred_subscription_falls_back_to_the_first_row() {
+        let rows = vec![subscription(4, false), subscription(5, false)];
+        assert_eq!(
+            preferred_subscription(rows.into_iter()).map(|s| s.id),
+            Some(4)
+        );
+        assert!(preferred_subscription(std::iter::empty()).is_none());
+    }
+
+    /// Run `reassign_rows` on plain rows, with every write replacing the row of the same id.
+    fn reassign(rows: &mut Vec<Subscription>, from: u64, to: u64) -> (usize, usize) {
+        let store = RefCell::new(std::mem::take(rows));
+        let sources: Vec<Subscription> = store
+            .borrow()
+            .iter()
+            .filter(|s| s.category_id == from && s.active)
+            .cloned()
+            .collect();
+        let counts = reassign_rows(
+            sources,
+            to,
+            |sub| {
+                preferred_subscription(
+                    store
+                        .borrow()
+                        .iter()
+                        .filter(|s| {
+                            s.subscriber_account_id == sub.subscriber_account_id
+                                && s.category_id == to
+                        })
+                        .cloned(),
+                )
+            },
+            |write| {
+                let (ReassignWrite::Moved(row)
+                | ReassignWrite::Reactivated(row)
+                | ReassignWrite::Deactivated(row)) = write;
+                let mut rows = store.borrow_mut();
+                let slot = rows.iter_mut().find(|s| s.id == row.id).unwrap();
+                *slot = row;
+                Ok(())
+            },
+        )
+        .unwrap();
+        *rows = store.into_inner();
+        counts
+    }
+
+    #[test]
+    fn reassign_leaves_no_duplicates_and_loses_nobody() {
+        let on = |id, account, category, active| Subscription {
+            subscriber_account_id: account,
+            category_id: category,
+            ..subscription(id, active)
+        };
+        let mut rows = vec![
+            on(1, 10, 1, true), // only on the source
+            on(2, 11, 1, true), // already active on the target
+            on(3, 11, 2, true),
+            on(4, 12, 1, true), // inactive on the target
+            on(5, 12, 2, false),
+            on(6, 13, 2, true),  // only on the target
+            on(7, 14, 1, false), // inactive on the source stays put
+            on(8, 15, 1, true),  // two active source rows of one subscriber
+            on(9, 15, 1, true),
+        ];
+        // Moved: 10, 12 (reactivated) and 15 once; already there: 11 and 15's second row.
+        assert_eq!(reassign(&mut rows, 1, 2), (3, 2));
+
+        assert!(!rows.iter().any(|s| s.category_id == 1 && s.active));
+        for account in [10, 11, 12, 13, 15] {
+            let active_on_target = rows
+                .iter()
+                .filter(|s| s.subscriber_account_id == account && s.category_id == 2 && s.active)
+                .count();
+            assert_eq!(active_on_target, 1, "account {account}");
+        }
+        assert!(!rows
+            .iter()
+            .any(|s| s.subscriber_account_id == 14 && s.active));
+
+        // Running it again changes nothing.
+        let before: Vec<(u64, u64, bool)> = rows
+            .iter()
+            .map(|s| (s.id, s.category_id, s.active))
+            .collect();
+        assert_eq!(reassign(&mut rows, 1, 2), (0, 0));
+        let after: Vec<(u64, u64, bool)> = rows
+            .iter()
+            .map(|s| (s.id, s.category_id, s.active))
+            .collect();
+        assert_eq!(before, after);
+    }
+
+    fn parse_defaults(json: &str) -> Vec<DefaultCategory> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const DEFAULTS: &str = r#"[
+        {"name": "Info", "email_address": " info@example.org ", "description": "Allgemein"},
+        {"name": "Vorstand", "email_address": "vorstand@example.org"},
+        {"name": "Info doppelt", "email_address": "info@example.org"}
+    ]"#;
+
+    #[test]
+    fn rerunning_init_does_not_duplicate_default_categories() {
+        let mut table: Vec<String> = Vec::new();
+        for _ in 0..2 {
+            let in_use = |address: &str| table.iter().any(|a| a == address);
+            let seeded = defaults_to_seed(parse_defaults(DEFAULTS), table.is_empty(), in_use);
+            table.extend(seeded.into_iter().map(|d| d.email_address));
+        }
+        assert_eq!(table, ["info@example.org", "vorstand@example.org"]);
+    }
+
+    #[test]
+    fn defaults_skip_addresses_in_use() {
+        let seeded = defaults_to_seed(parse_defaults(DEFAULTS), true, |address| {
+            address == "vorstand@example.org"
+        });
+        let addresses: Vec<&str> = seeded.iter().map(|d| d.email_address.as_str()).collect();
+        assert_eq!(addresses, ["info@example.org"]);
+        assert_eq!(seeded[0].description, "Allgemein");
+    }
+
+    fn address(category_id: u64, active: bool) -> CategoryAddress {
+        CategoryAddress {
+            id: 9,
+            category_id,
+            email_address: "board@example.org".to_string(),
+            active,
+        }
+    }
+
+    #[test]
+    fn secondary_address_routes_to_its_category() {
+        let entry = address(4, true);
+        assert_eq!(
+            address_lookup(Some(&entry), || panic!("no primary lookup needed")),
+            AddressLookup::Category(4)
+        );
+    }
+
+    #[test]
+    fn deactivated_address_is_not_routed_by_primary_or_domain() {
+        let entry = address(4, false);
+        assert_eq!(
+            address_lookup(Some(&entry), || Some(7)),
+            AddressLookup::Deactivated
+        );
+    }
+
+    #[test]
+    fn address_without_entry_falls_back_to_primary_then_domain() {
+        assert_eq!(address_lookup(None, || Some(7)), AddressLookup::Category(7));
+        assert_eq!(address_lookup(None, || None), AddressLookup::Domain);
+    }
+
+    fn category(id: u64, active: bool) -> MessageCategory {
+        MessageCategory {
+            id,
+            name: "Gemüse".to_string(),
+            email_address: "gemuese@example.org".to_string(),
+            description: String::new(),
+            active,
+            digest_interval_secs: None,
+            deleted_at: None,
+            subscription_required: true,
+            log_level: LOG_LEVEL_FULL.to_string(),
+            rate_limit_per_hour: None,
+            accept_code: None,
+            accept_message: None,
+        }
+    }
+
+    #[test]
+    fn inactive_category_takes_no_rcpt() {
+        // Subscriptions are not consulted; the subscriber of `subscription` gets no mail either.
+        assert!(subscription(1, true).is_current(Timestamp::UNIX_EPOCH));
+        let routed = |active| {
+            routed_category(
+                AddressLookup::Category(1),
+                |id| Some(category(id, active)),
+                || panic!("no domain lookup for a category address"),
+            )
+        };
+        assert!(routed(false).is_none());
+        let (category, matched) = routed(true).unwrap();
+        assert_eq!((category.id, matched), (1, RouteMatch::Address));
+    }
+
+    #[test]
+    fn soft_deleted_category_takes_no_mail_until_restored() {
+        let delete = |category| soft_deleted(category, Timestamp::UNIX_EPOCH);
+        let deleted = delete(category(1, true)).unwrap();
+        assert!(!deleted.accepts_mail());
+        let restored_category = restored(deleted).unwrap();
+        assert!(restored_category.accepts_mail());
+        assert!(restored(restored_category).is_err());
+        assert!(delete(delete(category(1, true)).unwrap()).is_err());
+
+        // Restoring leaves a paused category paused.
+        let paused = delete(category(2, false)).unwrap();
+        assert!(!restored(paused).unwrap().accepts_mail());
+    }
+
+    #[test]
+    fn category_domain_must_be_allowed() {
+        let allowed = ["example.org".to_string(), "solawi.example".to_string()];
+        let check = |address| check_domain_allowed(address, &allowed);
+        assert!(check("gemuese@example.org").is_ok());
+        assert!(check("Gemuese@Solawi.Example").is_ok());
+        let err = check("gemuese@exmaple.org").unwrap_err();
+        assert!(err.contains("not on an allowed category domain"), "{err}");
+        // No list, no restriction
+        assert!(check_domain_allowed("gemuese@exmaple.org", &[]).is_ok());
+    }
+
+    /// Run `upsert_category_row` on `rows` (new rows get the next free id) for the address of
+    /// `category`.
+    fn upsert(
+        rows: &RefCell<Vec<MessageCategory>>,
+        name: &str,
+        description: &str,
+    ) -> Result<CategoryUpsert, String> {
+        upsert_category_row(
+            name.to_string(),
+            "gemuese@example.org".to_string(),
+            description.to_string(),
+            |address| {
+                rows.borrow()
+                    .iter()
+                    .find(|c| &c.email_address == address)
+                    .cloned()
+            },
+            |_| None,
+            |write| {
+                let mut rows = rows.borrow_mut();
+                match write {
+                    CategoryWrite::Update(category) => {
+                        let id = category.id;
+                        *rows.iter_mut().find(|c| c.id == id).unwrap() = category;
+                        id
+                    }
+                    CategoryWrite::Insert(category) => {
+                        let id = rows.len() as u64 + 1;
+                        rows.push(MessageCategory { id, ..category });
+                        id
+                    }
+                }
+            },
+        )
+    }
+
+    #[test]
+    fn repeated_create_updates_only_an_active_category() {
+        // The second create finds the row of the first and updates it instead of inserting.
+        let rows = RefCell::new(Vec::new());
+        assert_eq!(upsert(&rows, "Gemüse", ""), Ok(CategoryUpsert::Created(1)));
+        assert_eq!(
+            upsert(&rows, "Gemüse & Obst", "Alles vom Feld"),
+            Ok(CategoryUpsert::Updated(1))
+        );
+        let rows = rows.into_inner();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            (rows[0].name.as_str(), rows[0].description.as_str()),
+            ("Gemüse & Obst", "Alles vom Feld")
+        );
+        assert!(rows[0].active);
+
+        // A paused category is left as it is.
+        let paused = RefCell::new(vec![category(1, false)]);
+        let err = upsert(&paused, "Gemüse & Obst", "").unwrap_err();
+        assert!(err.contains("inactive"), "{err}");
+        assert_eq!(paused.borrow()[0].name, "Gemüse");
+
+        assert_eq!(check_category_updatable(&category(1, true)), Ok(()));
+        let paused = check_category_updatable(&category(1, false)).unwrap_err();
+        assert!(paused.contains("inactive"), "{paused}");
+        let deleted = MessageCategory {
+            deleted_at: Some(Timestamp::UNIX_EPOCH),
+            ..category(1, true)
+        };
+        let err = check_category_updatable(&deleted).unwrap_err();
+        assert!(err.contains("restore it instead"), "{err}");
+    }
+
+    #[test]
+    fn recipient_without_category_address_matches_by_domain() {
+        let lookup = address_lookup(None, || None);
+        let routed = routed_category(
+            lookup,
+            |_| panic!("no category address to look up"),
+            || Some(category(5, true)),
+        );
+        let (category, matched) = routed.unwrap();
+        assert_eq!((category.id, matched), (5, RouteMatch::Domain));
+        // Without a domain route nothing matches.
+        assert!(routed_category(AddressLookup::Domain, |_| None, || None).is_none());
+    }
+
+    /// Run `import_entries` on plain rows, with `accounts` resolving emails to account ids and
+    /// categories 1 and 2 existing. New rows get the next free id.
+    fn import(
+        rows: &mut Vec<Subscription>,
+        accounts: &[(&str, u64)],
+        entries: &[(&str, u64)],
+    ) -> (usize, usize, usize) {
+        let store = RefCell::new(std::mem::take(rows));
+        let entries = entries
+            .iter()
+            .map(|(email, category_id)| SubscriptionImportEntry {
+                subscriber_email: email.to_string(),
+                category_id: *category_id,
+            })
+            .collect();
+        let counts = import_entries(
+            entries,
+            Timestamp::UNIX_EPOCH,
+            |email, category_id| {
+                let account_id = accounts
+                    .iter()
+                    .find(|(address, _)| *address == email)
+                    .map_or(UNRESOLVED_ACCOUNT_ID, |(_, id)| *id);
+                let existing = store
+                    .borrow()
+                    .iter()
+                    .find(|s| {
+                        s.category_id == category_id
+                            && if account_id == UNRESOLVED_ACCOUNT_ID {
+                                s.subscriber_email == email
+                            } else {
+                                s.subscriber_account_id == account_id
+                            }
+                    })
+                    .cloned();
+                ImportLookup {
+                    account_id,
+                    category_known: [1, 2].contains(&category_id),
+                    existing,
+                }
+            },
+            |subscription| {
+                let mut rows = store.borrow_mut();
+                match rows.iter_mut().find(|s| s.id == subscription.id) {
+                    Some(slot) => *slot = subscription,
+                    None => {
+                        let id = rows.len() as u64 + 1;
+                        rows.push(Subscription { id, ..subscription });
+                    }
+                }
+                Ok(())
+            },
+        )
+        .unwrap();
+        *rows = store.into_inner();
+        counts
+    }
+
+    #[test]
+    fn import_links_known_emails_and_keeps_the_rest_unresolved() {
+        let accounts = [("alice@example.org", 42)];
+        let mut rows = Vec::new();
+        let counts = import(
+            &mut rows,
+            &accounts,
+            &[
+                (" alice@example.org ", 1),
+                ("bob@example.org", 1),
+                ("bob@example.org", 2),
+                ("bob@example.org", 2),
+                ("kein-absender", 1),
+                ("carol@example.org", 99),
+            ],
+        );
+        // Created, of those unresolved, skipped (the repeated entry, no address, no category)
+        assert_eq!(counts, (3, 2, 3));
+        let imported: Vec<(&str, u64, u64)> = rows
+            .iter()
+            .map(|s| {
+                (
+                    s.subscriber_email.as_str(),
+                    s.subscriber_account_id,
+                    s.category_id,
+                )
+            })
+            .collect();
+        assert_eq!(
+            imported,
+            [
+                ("alice@example.org", 42, 1),
+                ("bob@example.org", UNRESOLVED_ACCOUNT_ID, 1),
+                ("bob@example.org", UNRESOLVED_ACCOUNT_ID, 2),
+            ]
+        );
+
+        // A second import adds nothing; an inactive row is reactivated instead of duplicated.
+        rows[1].active = false;
+        let counts = import(
+            &mut rows,
+            &accounts,
+            &[("alice@example.org", 1), ("bob@example.org", 1)],
+        );
+        assert_eq!(counts, (1, 1, 1));
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|s| s.active));
+    }
+
+    #[test]
+    fn import_action_skips_bad_entries_and_active_rows() {
+        assert_eq!(
+            import_action("kein-absender", true, None),
+            ImportAction::Skip
+        );
+        assert_eq!(
+            import_action("a@example.org", false, None),
+            ImportAction::Skip
+        );
+        assert_eq!(
+            import_action("a@example.org", true, Some(&subscription(1, true))),
+            ImportAction::Skip
+        );
+        assert_eq!(
+            import_action("a@example.org", true, Some(&subscription(1, false))),
+            ImportAction::Reactivate
+        );
+        assert_eq!(
+            import_action("a@example.org", true, None),
+            ImportAction::Create
+        );
+    }
+
+    #[test]
+    fn dedupe_keeps_the_oldest_row_and_is_idempotent() {
+        let at = |secs: i64| Timestamp::from_micros_since_unix_epoch(secs * 1_000_000);
+        let mut rows = vec![
+            Subscription {
+                subscribed_at: at(20),
+                ..subscription(1, true)
+            },
+            Subscription {
+                subscribed_at: at(10),
+                ..subscription(2, true)
+            },
+            subscription(3, false),
+            // Unresolved: grouped by email, case-insensitively
+            Subscription {
+                subscriber_account_id: UNRESOLVED_ACCOUNT_ID,
+                subscriber_email: "Bob@example.org".to_string(),
+                ..subscription(4, true)
+            },
+            Subscription {
+                subscriber_account_id: UNRESOLVED_ACCOUNT_ID,
+                subscriber_email: "bob@example.org".to_string(),
+                ..subscription(5, true)
+            },
+            // Another category is no duplicate
+            Subscription {
+                category_id: 2,
+                ..subscription(6, true)
+            },
+        ];
+        let duplicates: Vec<u64> = duplicate_subscriptions(rows.clone().into_iter())
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(duplicates, [5, 1]);
+
+        for row in rows.iter_mut().filter(|s| duplicates.contains(&s.id)) {
+            row.active = false;
+        }
+        assert!(duplicate_subscriptions(rows.into_iter()).is_empty());
+    }
+
+    #[test]
+    fn relink_follows_a_changed_account_email() {
+        let sub = subscription(1, true);
+        let no_owner = || None;
+        assert_eq!(
+            relink(&sub, Some("alice.neu@example.org"), no_owner),
+            Relink::RefreshEmail("alice.neu@example.org".to_string())
+        );
+        assert_eq!(
+            relink(&sub, Some("Alice@Example.org"), no_owner),
+            Relink::Keep
+        );
+        assert_eq!(relink(&sub, Some(""), no_owner), Relink::Keep);
+    }
+
+    #[test]
+    fn relink_moves_orphans_to_the_owner_of_their_email() {
+        let orphan = subscription(1, true);
+        assert_eq!(relink(&orphan, None, || Some(7)), Relink::Relink(7));
+        assert_eq!(
+            relink(&orphan, None, || None),
+            Relink::Unresolvable { reset: true }
+        );
+        let unresolved = Subscription {
+            subscriber_account_id: UNRESOLVED_ACCOUNT_ID,
+            ..subscription(2, true)
+        };
+        assert_eq!(
+            relink(&unresolved, None, || None),
+            Relink::Unresolvable { reset: false }
+        );
+    }
+
+    #[test]
+    fn expired_subscriber_counts_as_unsubscribed_at_data() {
+        let at = |secs: i64| Timestamp::from_micros_since_unix_epoch(secs * 1_000_000);
+        let expiring = Subscription {
+            expires_at: Some(at(100)),
+            ..subscription(1, true)
+        };
+        // Still active: `expire_subscriptions` has not run yet.
+        assert!(expiring.is_current(at(99)));
+        assert!(expiring.is_current(at(100)));
+        assert!(!expiring.is_current(at(101)));
+
+        // The DATA check (`has_current_subscription`) looks at every duplicate row.
+        let rows = [expiring.clone(), subscription(2, false)];
+        assert!(!rows.iter().any(|sub| sub.is_current(at(101))));
+        let rows = [expiring, subscription(3, true)];
+        assert!(rows.iter().any(|sub| sub.is_current(at(101))));
+    }
+}
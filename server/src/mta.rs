@@ -1,11 +1,24 @@
-use spacetimedb::{ReducerContext, Table, Timestamp, ViewContext};
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use spacetimedb::log_stopwatch::LogStopwatch;
+use spacetimedb::{Identity, Query, ReducerContext, Table, Timestamp, ViewContext};
 use stalwart_mta_hook_types::{Request as MtaHookRequest, Stage};
 
 use crate::account::{
-    account, account__view, admin_identities, admin_identities__view, is_admin_identity,
+    account, account__view, account_for_sender, admin_identities, admin_identities__view,
+    check_request_owner, is_admin_identity, is_admin_user, store_export_parts,
+    EXPORT_KIND_MESSAGE_LOGS,
 };
 use crate::delivery;
-use crate::mailing::{message_categories, subscriptions, subscriptions__view};
+use crate::digest;
+use crate::forwarding;
+use crate::mailing::{self, message_categories, subscriptions, subscriptions__view};
+use crate::notifications;
+use crate::sender_rules::{self, SenderRuleDecision};
+use crate::settings;
+use crate::stats;
 
 #[spacetimedb::table(accessor = mta_connection_log)]
 pub struct MtaConnectionLog {
@@ -17,6 +30,54 @@ pub struct MtaConnectionLog {
     pub action: String,
     pub timestamp: Timestamp,
     pub details: String,
+    /// Id of the hook request that wrote this row, shared with its `mta_message_log` row
+    #[default(String::new())]
+    #[index(btree)]
+    pub correlation_id: String,
+    /// Wall-clock milliseconds of the whole hook request, set once its stage finished; see
+    /// `record_processing_ms`
+    #[default(0)]
+    pub processing_ms: u64,
+    /// `RejectReason::key` of the rejection `reject_reason_stats` counted for this row, empty
+    /// for every other row; `recompute_reject_stats` counts these again
+    #[default(String::new())]
+    pub reject_reason: String,
+}
+
+/// MTA decisions for admins, e.g. for the live decision stream; empty for everyone else.
+#[spacetimedb::view(accessor = visible_mta_connection_log, public)]
+pub fn visible_mta_connection_log(ctx: &ViewContext) -> impl Query<MtaConnectionLog> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.mta_connection_log().r#filter(move |_| is_admin)
+}
+
+impl MtaConnectionLog {
+    /// The row for a decision on `request` under its `correlation_id`, with the client IP
+    /// redacted.
+    pub(crate) fn entry(
+        request: &MtaHookRequest,
+        correlation_id: &str,
+        action: &str,
+        details: String,
+        timestamp: Timestamp,
+    ) -> Self {
+        Self {
+            id: 0,
+            correlation_id: correlation_id.to_string(),
+            client_ip: redact_ip(&request.context.client.ip),
+            stage: stage_name(&request.context.stage).to_string(),
+            action: action.to_string(),
+            timestamp,
+            details,
+            processing_ms: 0,
+            reject_reason: String::new(),
+        }
+    }
 }
 
 #[spacetimedb::table(accessor = mta_message_log)]
@@ -32,8 +93,106 @@ pub struct MtaMessageLog {
     pub action: String,
     pub timestamp: Timestamp,
     pub queue_id: Option<String>,
+    /// Number of recipients of the transaction (envelope, or `To` header after the fallback).
+    #[default(0)]
+    pub recipient_count: u32,
+    /// JSON array of the ids of the categories the message was accepted for, after sender
+    /// rules and the subscription check; `[]` when none.
+    #[default("[]".to_string())]
+    pub matched_categories: String,
+    /// Id of the hook request that wrote this row, see `MtaConnectionLog::correlation_id`
+    #[default(String::new())]
+    #[index(btree)]
+    pub correlation_id: String,
+    /// See `MtaConnectionLog::processing_ms`
+    #[default(0)]
+    pub processing_ms: u64,
+    /// See `MtaConnectionLog::reject_reason`; for DATA the rejection, or the first one dry-run
+    /// mode let through
+    #[default(String::new())]
+    pub reject_reason: String,
+}
+
+impl MtaMessageLog {
+    /// The DATA row for `request` under its `correlation_id`, sent to `to_addresses`.
+    pub(crate) fn entry(
+        request: &MtaHookRequest,
+        correlation_id: &str,
+        to_addresses: &[String],
+        action: &str,
+        matched_categories: String,
+        timestamp: Timestamp,
+    ) -> Self {
+        Self {
+            id: 0,
+            correlation_id: correlation_id.to_string(),
+            from_address: envelope_from(request).to_string(),
+            to_addresses: serde_json::to_string(to_addresses).unwrap_or_default(),
+            subject: subject_for_log(&extract_subject_from_request(request)),
+            message_size: request_message_size(request),
+            stage: "data".to_string(),
+            action: action.to_string(),
+            timestamp,
+            queue_id: request.context.queue.as_ref().map(|q| q.id.clone()),
+            recipient_count: u32::try_from(to_addresses.len()).unwrap_or(u32::MAX),
+            matched_categories,
+            processing_ms: 0,
+            reject_reason: String::new(),
+        }
+    }
+
+    /// The row counted under `reason` in `reject_reason_stats`, if there is one.
+    fn counted_as(self, reason: Option<RejectReason>) -> Self {
+        Self {
+            reject_reason: reason.map_or_else(String::new, |r| r.key().to_string()),
+            ..self
+        }
+    }
+}
+
+/// Full, unredacted envelope and headers of a sample of DATA messages, for spam-model
+/// training; `module_settings.envelope_sample_permille` sets the share. Private; admins read it
+/// through `visible_envelope_samples`.
+#[spacetimedb::table(accessor = envelope_sample)]
+pub struct EnvelopeSample {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[unique]
+    pub queue_id: String,
+    pub client_ip: String,
+    pub helo: Option<String>,
+    pub from_address: String,
+    pub to_addresses: String, // JSON array as string
+    /// JSON array of `[name, value]` pairs, original and server-added headers
+    pub headers_raw: String,
+    pub sampled_at: Timestamp,
 }
 
+/// Returns all envelope samples for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_envelope_samples, public)]
+pub fn visible_envelope_samples(ctx: &ViewContext) -> impl Query<EnvelopeSample> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.envelope_sample().r#filter(move |_| is_admin)
+}
+
+/// `BlockedIp.source` values: who or what created the block.
+pub const BLOCK_SOURCE_MANUAL: &str = "Manual";
+pub const BLOCK_SOURCE_RATE_LIMIT: &str = "RateLimit";
+pub const BLOCK_SOURCE_GREYLIST_ABUSE: &str = "GreylistAbuse";
+pub const BLOCK_SOURCE_IMPORTED: &str = "Imported";
+const BLOCK_SOURCES: [&str; 4] = [
+    BLOCK_SOURCE_MANUAL,
+    BLOCK_SOURCE_RATE_LIMIT,
+    BLOCK_SOURCE_GREYLIST_ABUSE,
+    BLOCK_SOURCE_IMPORTED,
+];
+
 #[spacetimedb::table(accessor = blocked_ips)]
 pub struct BlockedIp {
     #[primary_key]
@@ -41,6 +200,308 @@ pub struct BlockedIp {
     pub reason: String,
     pub blocked_at: Timestamp,
     pub active: bool,
+    /// One of the `BLOCK_SOURCE_*` constants
+    #[default(BLOCK_SOURCE_MANUAL.to_string())]
+    pub source: String,
+}
+
+/// Create or refresh the block for `ip`. Every code path that blocks an IP goes through here
+/// so the source is always recorded.
+pub(crate) fn insert_block(ctx: &ReducerContext, ip: &str, reason: &str, source: &str) {
+    let row = BlockedIp {
+        ip: ip.to_string(),
+        reason: reason.to_string(),
+        blocked_at: ctx.timestamp,
+        active: true,
+        source: source.to_string(),
+    };
+    if ctx.db.blocked_ips().ip().find(&row.ip).is_some() {
+        ctx.db.blocked_ips().ip().update(row);
+    } else {
+        ctx.db.blocked_ips().insert(row);
+    }
+}
+
+/// Network prefix of `ip`: `a.b.c.0/24` for IPv4, the `/48` for IPv6. This is the granularity
+/// of prefix blocks and what reject rows keep of the client address. `None` if `ip` is not an
+/// address.
+pub(crate) fn ip_prefix(ip: &str) -> Option<String> {
+    match ip.trim().parse::<IpAddr>().ok()? {
+        IpAddr::V4(v4) => {
+            let [a, b, c, _] = v4.octets();
+            Some(format!("{}/24", Ipv4Addr::new(a, b, c, 0)))
+        }
+        IpAddr::V6(v6) => {
+            let [a, b, c, ..] = v6.segments();
+            Some(format!("{}/48", Ipv6Addr::new(a, b, c, 0, 0, 0, 0, 0)))
+        }
+    }
+}
+
+/// `client_ip` of every `mta_connection_log` row, accepted or not: the network prefix, so rejects
+/// can still be grouped by netblock without keeping the address, or `[REDACTED]` if it does not
+/// parse.
+pub(crate) fn redact_ip(ip: &str) -> String {
+    ip_prefix(ip).unwrap_or_else(|| "[REDACTED]".to_string())
+}
+
+/// The active block covering `ip`, either of the address itself or of its prefix.
+pub(crate) fn active_block(ctx: &ReducerContext, ip: &str) -> Option<BlockedIp> {
+    let active = |key: &String| ctx.db.blocked_ips().ip().find(key).filter(|b| b.active);
+    active(&ip.to_string()).or_else(|| active(&ip_prefix(ip)?))
+}
+
+/// Block an IP, or a whole prefix in the form `ip_prefix` writes (`192.0.2.0/24`,
+/// `2001:db8:1::/48`), by hand; recorded with source `Manual`.
+#[spacetimedb::reducer]
+pub fn block_ip(ctx: &ReducerContext, ip: String, reason: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let ip = ip.trim();
+    let is_prefix = ip
+        .split_once('/')
+        .and_then(|(network, _)| ip_prefix(network))
+        .is_some_and(|prefix| prefix == ip);
+    if ip.parse::<IpAddr>().is_err() && !is_prefix {
+        return Err(format!("Invalid IP address or prefix: {}", ip));
+    }
+    insert_block(ctx, ip, reason.trim(), BLOCK_SOURCE_MANUAL);
+    log::info!("Manually blocked IP");
+    Ok(())
+}
+
+/// Longest list `top_offending_prefixes` writes.
+const OFFENDING_PREFIXES_MAX: u32 = 50;
+
+/// Answer of `top_offending_prefixes`, keyed by the caller-chosen `request_id`.
+#[spacetimedb::table(accessor = offending_prefix_result)]
+pub struct OffendingPrefixResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub window_secs: u64,
+    /// Network prefixes, most rejects first
+    pub prefixes: Vec<String>,
+    /// Reject count of each entry of `prefixes`, in the same order
+    pub rejects: Vec<u64>,
+    pub computed_at: Timestamp,
+}
+
+/// The caller's own `top_offending_prefixes` answers.
+#[spacetimedb::view(accessor = my_offending_prefix_results, public)]
+pub fn my_offending_prefix_results(ctx: &ViewContext) -> Vec<OffendingPrefixResult> {
+    ctx.db
+        .offending_prefix_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Abuse triage: count the `reject` rows of `mta_connection_log` from the last `window_secs` by
+/// network prefix and write the `limit` prefixes (at most `OFFENDING_PREFIXES_MAX`) with the
+/// most rejects into `offending_prefix_result` under `request_id`. Prefixes that are already
+/// blocked are left out, as are rows without an address or prefix. Only admins.
+#[spacetimedb::reducer]
+pub fn top_offending_prefixes(
+    ctx: &ReducerContext,
+    request_id: String,
+    window_secs: u64,
+    limit: u32,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if window_secs == 0 {
+        return Err("window_secs must be positive".to_string());
+    }
+    let window = Duration::from_secs(window_secs);
+
+    let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+    for row in ctx.db.mta_connection_log().iter() {
+        if row.action != "reject"
+            || ctx
+                .timestamp
+                .duration_since(row.timestamp)
+                .unwrap_or_default()
+                > window
+        {
+            continue;
+        }
+        // Rows hold the prefix; rows written before that may hold the full address.
+        let prefix = if row.client_ip.contains('/') {
+            Some(row.client_ip)
+        } else {
+            ip_prefix(&row.client_ip)
+        };
+        if let Some(prefix) = prefix {
+            *counts.entry(prefix).or_default() += 1;
+        }
+    }
+    let mut ranked: Vec<(String, u64)> = counts
+        .into_iter()
+        .filter(|(prefix, _)| {
+            !ctx.db
+                .blocked_ips()
+                .ip()
+                .find(prefix)
+                .is_some_and(|b| b.active)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.truncate(limit.min(OFFENDING_PREFIXES_MAX) as usize);
+
+    let (prefixes, rejects): (Vec<String>, Vec<u64>) = ranked.into_iter().unzip();
+    let result = OffendingPrefixResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        window_secs,
+        prefixes,
+        rejects,
+        computed_at: ctx.timestamp,
+    };
+    let owner = ctx
+        .db
+        .offending_prefix_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db.offending_prefix_result().request_id().update(result);
+    } else {
+        ctx.db.offending_prefix_result().insert(result);
+    }
+    Ok(())
+}
+
+/// One entry of the `import_blocked_ips` payload.
+#[derive(serde::Deserialize)]
+struct BlockImportEntry {
+    ip: String,
+    #[serde(default)]
+    reason: String,
+}
+
+/// What `import_blocked_ips` does with one feed entry.
+#[derive(Debug, PartialEq, Eq)]
+enum BlockImport {
+    Insert,
+    /// An active block exists; it is kept as it is
+    AlreadyBlocked,
+    /// Not a single IP address, e.g. a CIDR range
+    Invalid,
+}
+
+/// Decide on the trimmed `ip` of a feed entry; `is_blocked` tells whether it has an active block.
+fn block_import_action(ip: &str, is_blocked: impl FnOnce(&str) -> bool) -> BlockImport {
+    if ip.parse::<IpAddr>().is_err() {
+        BlockImport::Invalid
+    } else if is_blocked(ip) {
+        BlockImport::AlreadyBlocked
+    } else {
+        BlockImport::Insert
+    }
+}
+
+/// Bulk-block IPs from `[{"ip": ..., "reason": ...}]`, e.g. an IP reputation feed, tagged with
+/// `source` (usually `Imported`). Only admins. IPs that are already actively blocked keep their
+/// block; invalid addresses (including CIDR ranges) are skipped. To refresh a feed, prune its
+/// `source` with `prune_blocks_by_source(source, 0)` first.
+#[spacetimedb::reducer]
+pub fn import_blocked_ips(
+    ctx: &ReducerContext,
+    json: String,
+    source: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if !BLOCK_SOURCES.contains(&source.as_str()) {
+        return Err(format!(
+            "Unknown block source '{}', expected one of {}",
+            source,
+            BLOCK_SOURCES.join(", ")
+        ));
+    }
+    let entries: Vec<BlockImportEntry> = serde_json::from_str(&json)
+        .map_err(|e| format!("Failed to parse blocked IP import: {}", e))?;
+
+    let mut inserted = 0usize;
+    let mut skipped = 0usize;
+    let mut invalid = 0usize;
+    for entry in entries {
+        let ip = entry.ip.trim();
+        let is_blocked = |ip: &str| {
+            ctx.db
+                .blocked_ips()
+                .ip()
+                .find(&ip.to_string())
+                .is_some_and(|b| b.active)
+        };
+        match block_import_action(ip, is_blocked) {
+            BlockImport::Invalid => invalid += 1,
+            BlockImport::AlreadyBlocked => skipped += 1,
+            BlockImport::Insert => {
+                insert_block(ctx, ip, entry.reason.trim(), &source);
+                inserted += 1;
+            }
+        }
+    }
+    log::info!(
+        "Imported {} blocked IPs from {} ({} already blocked, {} invalid)",
+        inserted,
+        source,
+        skipped,
+        invalid
+    );
+    Ok(())
+}
+
+/// Delete all blocks of one `source` that are older than `older_than_secs`,
+/// e.g. to expire automatic rate-limit blocks nightly while keeping manual ones.
+#[spacetimedb::reducer]
+pub fn prune_blocks_by_source(
+    ctx: &ReducerContext,
+    source: String,
+    older_than_secs: u64,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if !BLOCK_SOURCES.contains(&source.as_str()) {
+        return Err(format!(
+            "Unknown block source '{}', expected one of {}",
+            source,
+            BLOCK_SOURCES.join(", ")
+        ));
+    }
+
+    let max_age = std::time::Duration::from_secs(older_than_secs);
+    let expired: Vec<String> = ctx
+        .db
+        .blocked_ips()
+        .iter()
+        .filter(|b| b.source == source)
+        .filter(|b| {
+            ctx.timestamp
+                .duration_since(b.blocked_at)
+                .unwrap_or_default()
+                >= max_age
+        })
+        .map(|b| b.ip)
+        .collect();
+    for ip in &expired {
+        ctx.db.blocked_ips().ip().delete(ip);
+    }
+    log::info!(
+        "Pruned {} {} blocks older than {}s",
+        expired.len(),
+        source,
+        older_than_secs
+    );
+    Ok(())
 }
 
 /// One row per accepted email delivery, linked to its sender and the target mailing list category.
@@ -79,8 +540,146 @@ pub struct ReceivedMessage {
     pub message_size: u64,
 }
 
+/// Decision of one `handle_mta_hook` call, keyed by the caller-chosen `request_id`, so a caller
+/// over the reducer interface can read back what the module answered.
+#[spacetimedb::table(accessor = mta_decision)]
+pub struct MtaDecision {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub stage: String,
+    /// "accept", "reject" or "defer"
+    pub action: String,
+    /// SMTP code the caller should answer with (250 for accepts)
+    pub code: u16,
+    pub reason: String,
+    pub queue_id: Option<String>,
+    pub decided_at: Timestamp,
+    /// Set when dry-run mode overrode a rejection: `action`/`code`/`reason` are what the module
+    /// would have answered, but the caller should accept.
+    #[default(false)]
+    pub dry_run: bool,
+}
+
+/// The caller's own `handle_mta_hook` decisions.
+#[spacetimedb::view(accessor = my_mta_decisions, public)]
+pub fn my_mta_decisions(ctx: &ViewContext) -> Vec<MtaDecision> {
+    ctx.db
+        .mta_decision()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// What a stage decided, as stored in `mta_decision`.
+struct StageDecision {
+    action: &'static str,
+    code: u16,
+    reason: String,
+    /// The rejection `reason` describes, counted in `reject_reason_stats`; None on accept
+    reject_reason: Option<RejectReason>,
+    /// The stage already let a rejection through in dry-run mode; `action`, `code` and `reason`
+    /// are that rejection.
+    dry_run: bool,
+}
+
+impl StageDecision {
+    fn accept() -> Self {
+        Self {
+            action: "accept",
+            code: mailing::DEFAULT_ACCEPT_CODE,
+            reason: "Accepted".to_string(),
+            reject_reason: None,
+            dry_run: false,
+        }
+    }
+
+    fn from_outcome<T>(outcome: Result<T, StageRejection>) -> Self {
+        match outcome {
+            Ok(_) => Self::accept(),
+            Err(rejection) => Self {
+                action: rejection_action(rejection.code),
+                code: rejection.code,
+                reason: rejection.message,
+                reject_reason: Some(rejection.reason),
+                dry_run: false,
+            },
+        }
+    }
+}
+
+/// Action recorded for a rejection with SMTP `code`: 4xx replies make the client retry, so they
+/// are deferrals like those of maintenance mode; everything else is a rejection.
+fn rejection_action(code: u16) -> &'static str {
+    if (400..500).contains(&code) {
+        "defer"
+    } else {
+        "reject"
+    }
+}
+
+/// The `mta_decision` row of `decision` on `request`, asked for by `requested_by`.
+fn decision_row(
+    request_id: String,
+    requested_by: Identity,
+    request: &MtaHookRequest,
+    decision: StageDecision,
+    decided_at: Timestamp,
+    dry_run: bool,
+) -> MtaDecision {
+    MtaDecision {
+        request_id,
+        requested_by,
+        stage: stage_name(&request.context.stage).to_string(),
+        action: decision.action.to_string(),
+        code: decision.code,
+        reason: decision.reason,
+        queue_id: request.context.queue.as_ref().map(|q| q.id.clone()),
+        decided_at,
+        dry_run,
+    }
+}
+
+/// Store `decision` under `request_id`, replacing an earlier decision of the caller with the
+/// same id; `handle_mta_hook` has made sure it is not another caller's.
+fn record_decision(
+    ctx: &ReducerContext,
+    request_id: String,
+    request: &MtaHookRequest,
+    decision: StageDecision,
+    dry_run: bool,
+) {
+    let row = decision_row(
+        request_id.clone(),
+        ctx.sender(),
+        request,
+        decision,
+        ctx.timestamp,
+        dry_run,
+    );
+    if ctx
+        .db
+        .mta_decision()
+        .request_id()
+        .find(&request_id)
+        .is_some()
+    {
+        ctx.db.mta_decision().request_id().update(row);
+    } else {
+        ctx.db.mta_decision().insert(row);
+    }
+}
+
+/// Run one MTA hook through the stage handlers and write the decision into `mta_decision`
+/// under `request_id`. Only admin identities. A payload that does not parse is an error, and
+/// so is a `request_id` that holds another caller's decision.
 #[spacetimedb::reducer]
-pub fn handle_mta_hook(ctx: &ReducerContext, hook_data: String) -> Result<(), String> {
+pub fn handle_mta_hook(
+    ctx: &ReducerContext,
+    request_id: String,
+    hook_data: String,
+) -> Result<(), String> {
     if !is_admin_identity(ctx, ctx.sender()) {
         return Err(format!(
             "Unauthorized: MTA hook called by non-admin identity {:?}",
@@ -88,95 +687,703 @@ pub fn handle_mta_hook(ctx: &ReducerContext, hook_data: String) -> Result<(), St
         ));
     }
 
-    match serde_json::from_str::<MtaHookRequest>(&hook_data) {
-        Ok(request) => {
-            let timestamp = ctx.timestamp;
+    let owner = ctx
+        .db
+        .mta_decision()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
 
-            match request.context.stage {
-                Stage::Connect => handle_connect_stage(ctx, &request, timestamp),
-                Stage::Ehlo => handle_ehlo_stage(ctx, &request, timestamp),
-                Stage::Mail => handle_mail_stage(ctx, &request, timestamp),
-                Stage::Rcpt => handle_rcpt_stage(ctx, &request, timestamp),
-                Stage::Data => handle_data_stage(ctx, &request, timestamp),
-                Stage::Auth => handle_auth_stage(ctx, &request, timestamp),
-            }
+    let mut request = serde_json::from_str::<MtaHookRequest>(&hook_data).map_err(|e| {
+        log::error!("Failed to parse MTA hook data: {}", e);
+        format!("Failed to parse MTA hook data: {}", e)
+    })?;
+    cap_message_headers(&mut request, &request_id);
+    // Maintenance deferrals are not rejections; dry-run mode leaves them alone.
+    let maintenance = settings::current(ctx).maintenance_mode;
+    let decision = dispatch_stage(ctx, &request, &request_id, ctx.timestamp);
+    let dry_run = decision.dry_run
+        || (decision.action != "accept"
+            && !maintenance
+            && dry_run_override(
+                ctx,
+                &request,
+                &request_id,
+                decision.code,
+                &decision.reason,
+                ctx.timestamp,
+            ));
+    record_decision(ctx, request_id, &request, decision, dry_run);
+    Ok(())
+}
+
+/// In dry-run mode (`module_settings.dry_run`) a rejection is only logged, as a `dry-run` row in
+/// `mta_connection_log`, and the MTA is told to accept. Returns whether the rejection is
+/// overridden. Maintenance deferrals are not rejections and still apply. The DATA stage calls
+/// it at each of its rejections (`overridden_in_dry_run`) and carries on, so the message is
+/// still stored.
+pub(crate) fn dry_run_override(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    code: u16,
+    reason: &str,
+    timestamp: Timestamp,
+) -> bool {
+    if !settings::current(ctx).dry_run {
+        return false;
+    }
+    let stage = stage_name(&request.context.stage);
+    log::info!(
+        "[dry-run] {} stage would reject with {} {}, accepting",
+        stage.to_uppercase(),
+        code,
+        reason
+    );
+    ctx.db.mta_connection_log().insert(MtaConnectionLog::entry(
+        request,
+        correlation_id,
+        "dry-run",
+        format!("Would reject: {} {}", code, reason),
+        timestamp,
+    ));
+    true
+}
+
+/// Name of a stage as used in `mta_connection_log.stage`.
+pub(crate) fn stage_name(stage: &Stage) -> &'static str {
+    match stage {
+        Stage::Connect => "connect",
+        Stage::Ehlo => "ehlo",
+        Stage::Mail => "mail",
+        Stage::Rcpt => "rcpt",
+        Stage::Data => "data",
+        Stage::Auth => "auth",
+    }
+}
+
+/// Wall-clock timer for one stage, named `mta_<stage>_stage`. The host logs the elapsed time
+/// when it is dropped. Inside a transaction the module has no clock of its own
+/// (`ctx.timestamp` is fixed for the whole transaction), so in `handle_mta_hook` this log line
+/// is the only measurement; the HTTP route also stores its time in `stage_timing` and in the
+/// `processing_ms` of the request's log rows.
+pub(crate) fn stage_stopwatch(stage: &Stage) -> LogStopwatch {
+    LogStopwatch::new(&format!("mta_{}_stage", stage_name(stage)))
+}
+
+/// In maintenance mode every stage is deferred before its normal checks run: the deferral is
+/// logged and a 451 is returned so the sending MTA retries later. `None` outside maintenance mode.
+pub(crate) fn maintenance_deferral(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    timestamp: Timestamp,
+) -> Option<StageRejection> {
+    if !settings::current(ctx).maintenance_mode {
+        return None;
+    }
+    ctx.db.mta_connection_log().insert(MtaConnectionLog::entry(
+        request,
+        correlation_id,
+        "defer",
+        "Maintenance mode".to_string(),
+        timestamp,
+    ));
+    Some(StageRejection::new(RejectReason::Maintenance))
+}
+
+/// The maintenance deferral if there is one, without running `run`; otherwise the decision of
+/// `run`, the stage's normal checks.
+fn stage_decision(
+    maintenance: Option<StageRejection>,
+    run: impl FnOnce() -> StageDecision,
+) -> StageDecision {
+    match maintenance {
+        Some(deferral) => StageDecision {
+            action: "defer",
+            code: deferral.code,
+            reason: deferral.message,
+            reject_reason: Some(deferral.reason),
+            dry_run: false,
+        },
+        None => run(),
+    }
+}
+
+/// Run the stage handler matching `request.context.stage` unless maintenance mode defers it.
+fn dispatch_stage(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    timestamp: Timestamp,
+) -> StageDecision {
+    let _timing = stage_stopwatch(&request.context.stage);
+    let maintenance = maintenance_deferral(ctx, request, correlation_id, timestamp);
+    if let Some(deferral) = &maintenance {
+        let stage = stage_name(&request.context.stage);
+        log::info!("{} stage deferred: {}", stage, deferral.message);
+    }
+    stage_decision(maintenance, || {
+        run_stage_handler(ctx, request, correlation_id, timestamp)
+    })
+}
+
+/// Run the stage handler matching `request.context.stage`, logging rejections.
+fn run_stage_handler(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    timestamp: Timestamp,
+) -> StageDecision {
+    let stage = stage_name(&request.context.stage);
+    let decision = match request.context.stage {
+        Stage::Connect => StageDecision::from_outcome(handle_connect_stage(
+            ctx,
+            request,
+            correlation_id,
+            timestamp,
+        )),
+        Stage::Ehlo => {
+            StageDecision::from_outcome(handle_ehlo_stage(ctx, request, correlation_id, timestamp))
         }
-        Err(e) => {
-            log::error!("Failed to parse MTA hook data: {}", e);
+        Stage::Mail => {
+            StageDecision::from_outcome(handle_mail_stage(ctx, request, correlation_id, timestamp))
         }
+        Stage::Rcpt => {
+            StageDecision::from_outcome(handle_rcpt_stage(ctx, request, correlation_id, timestamp))
+        }
+        Stage::Data => data_decision(handle_data_stage(ctx, request, correlation_id, timestamp)),
+        Stage::Auth => {
+            handle_auth_stage(ctx, request, correlation_id, timestamp);
+            StageDecision::accept()
+        }
+    };
+    if let Some(reason) = decision.reject_reason {
+        log::info!(
+            "{} stage {}: {}",
+            stage.to_uppercase(),
+            decision.action,
+            decision.reason
+        );
+        stats::record_reject_reason(ctx, timestamp, reason);
+    }
+    decision
+}
+
+/// The decision recorded for the DATA `outcome`: a rejection let through in dry-run mode is
+/// recorded as that rejection with `dry_run` set, a custom accept reply with its code and text.
+fn data_decision(outcome: Result<DataAcceptance, StageRejection>) -> StageDecision {
+    match outcome {
+        Ok(DataAcceptance {
+            dry_run_rejection: Some(rejection),
+            ..
+        }) => StageDecision {
+            action: rejection_action(rejection.code),
+            code: rejection.code,
+            reason: rejection.message,
+            reject_reason: Some(rejection.reason),
+            dry_run: true,
+        },
+        Ok(DataAcceptance {
+            accept_reply: Some((code, reason)),
+            ..
+        }) => StageDecision {
+            action: "accept",
+            code,
+            reason,
+            reject_reason: None,
+            dry_run: false,
+        },
+        outcome => StageDecision::from_outcome(outcome),
+    }
+}
+
+/// Whether `inject_test_mta_event` is available; set at build time via `ENABLE_TEST_MTA_EVENTS=true`.
+fn test_mta_events_enabled() -> bool {
+    option_env!("ENABLE_TEST_MTA_EVENTS") == Some("true")
+}
+
+/// Build a hook request like Stalwart would send for `stage`, from a fixed test client
+/// (`192.0.2.1`, HELO `mta-test.example.org`). `to` is a comma-separated list of recipients.
+pub(crate) fn test_mta_request(
+    stage: &str,
+    from: &str,
+    to: &str,
+    subject: &str,
+    queue_id: String,
+) -> serde_json::Value {
+    let recipients: Vec<&str> = to
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .collect();
+    let envelope = match stage {
+        "connect" | "ehlo" | "auth" => serde_json::Value::Null,
+        _ => serde_json::json!({
+            "from": { "address": from },
+            "to": recipients.iter().map(|r| serde_json::json!({ "address": r })).collect::<Vec<_>>(),
+        }),
+    };
+    let message = if stage == "data" {
+        let contents = "Test event injected via inject_test_mta_event.\r\n".to_string();
+        serde_json::json!({
+            "headers": [
+                ["From", format!(" {}\r\n", from)],
+                ["To", format!(" {}\r\n", recipients.join(", "))],
+                ["Subject", format!(" {}\r\n", subject)],
+                ["Message-Id", format!(" <{}@mta-test.example.org>\r\n", queue_id)],
+            ],
+            "contents": contents,
+            "size": contents.len(),
+        })
+    } else {
+        serde_json::Value::Null
+    };
+    serde_json::json!({
+        "context": {
+            "stage": stage,
+            "client": {
+                "ip": "192.0.2.1",
+                "port": 0,
+                "ptr": null,
+                "helo": "mta-test.example.org",
+                "activeConnections": 1
+            },
+            "server": { "name": "inject_test_mta_event", "port": 25, "ip": "127.0.0.1" },
+            "protocol": { "version": 1 },
+            "queue": { "id": queue_id }
+        },
+        "envelope": envelope,
+        "message": message,
+    })
+}
+
+/// Feed a synthetic hook request for `stage` (`connect`, `ehlo`, `mail`, `rcpt`, `data`,
+/// `auth`) through the same stage handlers as `handle_mta_hook`, e.g. to check category
+/// matching and subscriptions without a real Stalwart. Only admins, and only in builds
+/// with `ENABLE_TEST_MTA_EVENTS=true`.
+#[spacetimedb::reducer]
+pub fn inject_test_mta_event(
+    ctx: &ReducerContext,
+    stage: String,
+    from: String,
+    to: String,
+    subject: String,
+) -> Result<(), String> {
+    if !test_mta_events_enabled() {
+        return Err("inject_test_mta_event is disabled in this build".to_string());
+    }
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
     }
+
+    let stage = stage.trim().to_lowercase();
+    let queue_id = format!("TEST{}", ctx.timestamp.to_micros_since_unix_epoch());
+    let request: MtaHookRequest = serde_json::from_value(test_mta_request(
+        &stage,
+        &from,
+        &to,
+        &subject,
+        queue_id.clone(),
+    ))
+    .map_err(|e| format!("Invalid test MTA event for stage {:?}: {}", stage, e))?;
+    log::info!(
+        "Injecting test MTA event for stage {} (by identity: {:?})",
+        stage,
+        ctx.sender()
+    );
+    let decision = dispatch_stage(ctx, &request, &queue_id, ctx.timestamp);
+    log::info!(
+        "Test MTA event for stage {}: {} {} {}",
+        stage,
+        decision.action,
+        decision.code,
+        decision.reason
+    );
     Ok(())
 }
 
 pub(crate) fn handle_connect_stage(
     ctx: &ReducerContext,
     request: &MtaHookRequest,
+    correlation_id: &str,
     timestamp: Timestamp,
-) {
-    let client_ip = &request.context.client.ip;
-
+) -> Result<(), StageRejection> {
     log::info!("Connect stage - IP: [REDACTED]");
 
-    // Check if IP is blocked
-    if let Some(blocked) = ctx.db.blocked_ips().ip().find(client_ip) {
-        if blocked.active {
-            log::warn!("Blocked connection from IP");
-            ctx.db.mta_connection_log().insert(MtaConnectionLog {
-                id: 0,
-                client_ip: "[REDACTED]".to_string(),
-                stage: "connect".to_string(),
-                action: "reject".to_string(),
+    let check = connect_check(active_block(ctx, &request.context.client.ip).is_some());
+    stats::record_mta_action(ctx, timestamp, check.action());
+    log_stage_check(ctx, request, correlation_id, check, timestamp)
+}
+
+/// What a stage's checks decided, and the details `mta_connection_log` records with it.
+#[derive(Debug, PartialEq)]
+struct StageCheck {
+    outcome: Result<(), StageRejection>,
+    details: String,
+}
+
+impl StageCheck {
+    fn new(rejection: Option<RejectReason>, details: String) -> Self {
+        Self {
+            outcome: rejection.map_or(Ok(()), |reason| Err(StageRejection::new(reason))),
+            details,
+        }
+    }
+
+    /// Action logged in `mta_connection_log`.
+    fn action(&self) -> &'static str {
+        if self.outcome.is_ok() {
+            "accept"
+        } else {
+            "reject"
+        }
+    }
+
+    /// The `mta_connection_log` row of the check, carrying the key of its rejection.
+    fn log_entry(
+        &self,
+        request: &MtaHookRequest,
+        correlation_id: &str,
+        timestamp: Timestamp,
+    ) -> MtaConnectionLog {
+        MtaConnectionLog {
+            reject_reason: match &self.outcome {
+                Ok(()) => String::new(),
+                Err(rejection) => rejection.reason.key().to_string(),
+            },
+            ..MtaConnectionLog::entry(
+                request,
+                correlation_id,
+                self.action(),
+                self.details.clone(),
                 timestamp,
-                details: "IP blocked".to_string(),
-            });
-            return;
+            )
         }
     }
+}
+
+/// Log `check` in `mta_connection_log` and return its outcome.
+fn log_stage_check(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    check: StageCheck,
+    timestamp: Timestamp,
+) -> Result<(), StageRejection> {
+    ctx.db
+        .mta_connection_log()
+        .insert(check.log_entry(request, correlation_id, timestamp));
+    check.outcome
+}
+
+/// CONNECT: refuse a client whose IP is `blocked`.
+fn connect_check(blocked: bool) -> StageCheck {
+    if blocked {
+        log::warn!("Blocked connection from IP");
+        return StageCheck::new(
+            Some(RejectReason::IpBlocked),
+            RejectReason::IpBlocked.message().to_string(),
+        );
+    }
+    StageCheck::new(None, "Connection accepted".to_string())
+}
+
+/// Subjects in `mta_message_log` are cut to this many characters, set at build time via
+/// `SUBJECT_LOG_MAX_CHARS`. Cut subjects end in `…` so the log shows they are incomplete.
+fn subject_log_max_chars() -> usize {
+    option_env!("SUBJECT_LOG_MAX_CHARS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(100)
+}
+
+/// With `LOG_FULL_SUBJECT=true` at build time, `mta_message_log` keeps every subject in full.
+/// The table is private; only admins read it.
+fn log_full_subject() -> bool {
+    option_env!("LOG_FULL_SUBJECT") == Some("true")
+}
+
+/// The subject as stored in `mta_message_log`, according to the two settings above.
+fn subject_for_log(subject: &str) -> String {
+    let max_chars = subject_log_max_chars();
+    if log_full_subject() || subject.chars().count() <= max_chars {
+        return subject.to_string();
+    }
+    let mut cut: String = subject.chars().take(max_chars).collect();
+    cut.push('…');
+    cut
+}
+
+/// The most verbose `log_level` among the matched categories, so a fully logged category is
+/// never cut short by a quieter one in the same message. Without a matched category the
+/// message is logged in full.
+fn message_log_level(ctx: &ReducerContext, categories: &[(u64, String)]) -> &'static str {
+    let levels: Vec<String> = categories
+        .iter()
+        .filter_map(|(id, _)| ctx.db.message_categories().id().find(id))
+        .map(|category| category.log_level)
+        .collect();
+    most_verbose_log_level(&levels)
+}
+
+/// The most verbose of `levels`; `full` for none or only unknown ones.
+fn most_verbose_log_level(levels: &[String]) -> &'static str {
+    mailing::LOG_LEVELS
+        .iter()
+        .rev()
+        .find(|level| levels.iter().any(|l| l == *level))
+        .copied()
+        .unwrap_or(mailing::LOG_LEVEL_FULL)
+}
 
-    ctx.db.mta_connection_log().insert(MtaConnectionLog {
+/// `row` as `mta_message_log` keeps it at `log_level`: nothing at `none`, without subject and
+/// sender local part at `summary`, unchanged otherwise.
+pub(crate) fn message_log_row(log_level: &str, mut row: MtaMessageLog) -> Option<MtaMessageLog> {
+    match log_level {
+        mailing::LOG_LEVEL_NONE => return None,
+        mailing::LOG_LEVEL_SUMMARY => {
+            row.subject = String::new();
+            row.from_address = match row.from_address.rsplit_once('@') {
+                Some((_, domain)) => format!("…@{domain}"),
+                None => "…".to_string(),
+            };
+        }
+        _ => {}
+    }
+    Some(row)
+}
+
+/// Insert `row` into `mta_message_log` as far as `log_level` allows; returns the row id.
+fn insert_message_log(ctx: &ReducerContext, log_level: &str, row: MtaMessageLog) -> Option<u64> {
+    message_log_row(log_level, row).map(|row| ctx.db.mta_message_log().insert(row).id)
+}
+
+/// Store `processing_ms` on the `mta_connection_log` and `mta_message_log` rows of the hook
+/// request `correlation_id`. Rows are written while the stage runs, so the time is added after.
+pub(crate) fn record_processing_ms(ctx: &ReducerContext, correlation_id: &str, processing_ms: u64) {
+    let correlation_id = correlation_id.to_string();
+    let connection_rows: Vec<MtaConnectionLog> = ctx
+        .db
+        .mta_connection_log()
+        .correlation_id()
+        .filter(&correlation_id)
+        .collect();
+    for row in connection_rows {
+        ctx.db.mta_connection_log().id().update(MtaConnectionLog {
+            processing_ms,
+            ..row
+        });
+    }
+    let message_rows: Vec<MtaMessageLog> = ctx
+        .db
+        .mta_message_log()
+        .correlation_id()
+        .filter(&correlation_id)
+        .collect();
+    for row in message_rows {
+        ctx.db.mta_message_log().id().update(MtaMessageLog {
+            processing_ms,
+            ..row
+        });
+    }
+}
+
+/// Sample bucket (0–999) of a queue id: FNV-1a, so the same message always lands in the same
+/// bucket, also across retries and module versions.
+fn sample_bucket(queue_id: &str) -> u32 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in queue_id.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    (hash % 1000) as u32
+}
+
+/// Store the full envelope and headers in `envelope_sample` if the message's queue id falls
+/// into the sampled share. Messages without queue id are never sampled; a retried message is
+/// stored once.
+fn record_envelope_sample(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    to_addresses: &[String],
+    timestamp: Timestamp,
+) {
+    let permille = settings::current(ctx).envelope_sample_permille;
+    let Some(queue) = request.context.queue.as_ref() else {
+        return;
+    };
+    let already_sampled = ctx
+        .db
+        .envelope_sample()
+        .queue_id()
+        .find(&queue.id)
+        .is_some();
+    if sample_bucket(&queue.id) >= permille || already_sampled {
+        return;
+    }
+    let from_address = request
+        .envelope
+        .as_ref()
+        .map(|env| env.from.address.clone())
+        .unwrap_or_default();
+    let headers: Vec<(&str, &str)> = request
+        .message
+        .iter()
+        .flat_map(|message| message.headers.iter().chain(message.server_headers.iter()))
+        .map(|(n, v)| (n.as_str(), v.as_str()))
+        .collect();
+    ctx.db.envelope_sample().insert(EnvelopeSample {
         id: 0,
-        client_ip: client_ip.to_string(),
-        stage: "connect".to_string(),
-        action: "accept".to_string(),
-        timestamp,
-        details: "Connection accepted".to_string(),
+        queue_id: queue.id.clone(),
+        client_ip: request.context.client.ip.clone(),
+        helo: request.context.client.helo.clone(),
+        from_address,
+        to_addresses: serde_json::to_string(to_addresses).unwrap_or_default(),
+        headers_raw: serde_json::to_string(&headers).unwrap_or_default(),
+        sampled_at: timestamp,
     });
 }
 
+/// `MtaMessageLog.matched_categories` for these `(category_id, address)` pairs.
+fn matched_categories_json(categories: &[(u64, String)]) -> String {
+    let ids: Vec<u64> = categories.iter().map(|(id, _)| *id).collect();
+    serde_json::to_string(&ids).unwrap_or_default()
+}
+
+/// Apply `change` to an already written log row; nothing happens if none was written.
+fn update_message_log(
+    ctx: &ReducerContext,
+    log_id: Option<u64>,
+    change: impl FnOnce(&mut MtaMessageLog),
+) {
+    let Some(mut row) = log_id.and_then(|id| ctx.db.mta_message_log().id().find(&id)) else {
+        return;
+    };
+    change(&mut row);
+    ctx.db.mta_message_log().id().update(row);
+}
+
+/// Most recipients one transaction may have, set at build time via `MAX_RECIPIENTS`.
+/// Further `RCPT TO`s get a 452 so the client sends them in another transaction.
+fn max_recipients() -> usize {
+    option_env!("MAX_RECIPIENTS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(100)
+}
+
+/// Whether `count` recipients are more than `limit` allows. Exactly `limit` is still accepted.
+fn over_recipient_limit(count: usize, limit: usize) -> bool {
+    count > limit
+}
+
+/// HELO policy, set at build time via `HELO_POLICY`:
+/// `permissive` (default) only rejects an empty HELO, `strict` additionally requires a FQDN
+/// or a bracketed address literal.
+const HELO_POLICY: &str = match option_env!("HELO_POLICY") {
+    Some(policy) => policy,
+    None => "permissive",
+};
+
+/// Result of checking a HELO/EHLO name; recorded in the connection log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeloCheck {
+    Missing,
+    Empty,
+    /// An IP address without the `[...]` brackets RFC 5321 requires.
+    BareIp,
+    /// A name without a dot, e.g. `localhost` or a bare hostname.
+    NotFqdn,
+    Fqdn,
+    /// `[192.0.2.1]` or `[IPv6:...]`
+    AddressLiteral,
+}
+
+impl HeloCheck {
+    fn as_str(self) -> &'static str {
+        match self {
+            HeloCheck::Missing => "missing",
+            HeloCheck::Empty => "empty",
+            HeloCheck::BareIp => "bare_ip",
+            HeloCheck::NotFqdn => "not_fqdn",
+            HeloCheck::Fqdn => "fqdn",
+            HeloCheck::AddressLiteral => "address_literal",
+        }
+    }
+}
+
+pub(crate) fn check_helo(helo: Option<&str>) -> HeloCheck {
+    let Some(helo) = helo.map(str::trim) else {
+        return HeloCheck::Missing;
+    };
+    if helo.is_empty() {
+        return HeloCheck::Empty;
+    }
+    if let Some(literal) = helo.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        let addr = literal.strip_prefix("IPv6:").unwrap_or(literal);
+        return if addr.parse::<std::net::IpAddr>().is_ok() {
+            HeloCheck::AddressLiteral
+        } else {
+            HeloCheck::NotFqdn
+        };
+    }
+    if helo.parse::<std::net::IpAddr>().is_ok() {
+        return HeloCheck::BareIp;
+    }
+    let name = helo.strip_suffix('.').unwrap_or(helo);
+    if name.contains('.') && !name.split('.').any(str::is_empty) {
+        HeloCheck::Fqdn
+    } else {
+        HeloCheck::NotFqdn
+    }
+}
+
+/// The rejection for a HELO that checked as `check`, under the strict policy if `strict`.
+/// An empty HELO is refused under either policy; a missing one only when strict.
+fn helo_rejection(check: HeloCheck, strict: bool) -> Option<RejectReason> {
+    match check {
+        HeloCheck::Empty => Some(RejectReason::EmptyHelo),
+        HeloCheck::Fqdn | HeloCheck::AddressLiteral => None,
+        HeloCheck::Missing | HeloCheck::BareIp | HeloCheck::NotFqdn if strict => {
+            Some(RejectReason::UnqualifiedHelo)
+        }
+        HeloCheck::Missing | HeloCheck::BareIp | HeloCheck::NotFqdn => None,
+    }
+}
+
 pub(crate) fn handle_ehlo_stage(
     ctx: &ReducerContext,
     request: &MtaHookRequest,
+    correlation_id: &str,
     timestamp: Timestamp,
-) {
-    let client_ip = request.context.client.ip.as_str();
-    let helo = request.context.client.helo.as_deref().unwrap_or("unknown");
-
+) -> Result<(), StageRejection> {
     log::info!("EHLO stage - HELO: [REDACTED]");
 
-    // Basic EHLO validation
-    let is_valid = !helo.is_empty() && helo != "unknown";
-    let action = if is_valid { "accept" } else { "reject" };
-    let details = if is_valid {
-        "Valid EHLO/HELO".to_string()
-    } else {
-        "Invalid EHLO/HELO".to_string()
-    };
+    let check = ehlo_check(request.context.client.helo.as_deref(), HELO_POLICY);
+    log_stage_check(ctx, request, correlation_id, check, timestamp)
+}
 
-    ctx.db.mta_connection_log().insert(MtaConnectionLog {
-        id: 0,
-        client_ip: client_ip.to_string(),
-        stage: "ehlo".to_string(),
-        action: action.to_string(),
-        timestamp,
-        details,
-    });
+/// EHLO: check `helo` under the HELO policy `policy`.
+fn ehlo_check(helo: Option<&str>, policy: &str) -> StageCheck {
+    let check = check_helo(helo);
+    StageCheck::new(
+        helo_rejection(check, policy == "strict"),
+        format!("HELO policy {}: {}", policy, check.as_str()),
+    )
 }
 
 pub(crate) fn handle_mail_stage(
     ctx: &ReducerContext,
     request: &MtaHookRequest,
+    correlation_id: &str,
     timestamp: Timestamp,
-) {
+) -> Result<(), StageRejection> {
+    let check = mail_check(request);
+    log_stage_check(ctx, request, correlation_id, check, timestamp)
+}
+
+/// MAIL: the envelope sender must look like an email address.
+fn mail_check(request: &MtaHookRequest) -> StageCheck {
     let from_address = request
         .envelope
         .as_ref()
@@ -186,74 +1393,356 @@ pub(crate) fn handle_mail_stage(
     log::trace!("MAIL stage - From: {}", from_address);
 
     // Basic sender validation
-    let is_valid = from_address.contains('@') && !from_address.is_empty();
-    let action = if is_valid { "accept" } else { "reject" };
-    let details = format!(
-        "Sender validation: {}",
-        if is_valid { "passed" } else { "failed" }
-    );
+    let is_valid = from_address.contains('@') && !from_address.trim().is_empty();
+    StageCheck::new(
+        (!is_valid).then_some(RejectReason::InvalidSender),
+        format!(
+            "Sender validation: {}",
+            if is_valid { "passed" } else { "failed" }
+        ),
+    )
+}
 
-    ctx.db.mta_connection_log().insert(MtaConnectionLog {
-        id: 0,
-        client_ip: "[REDACTED]".to_string(),
-        stage: "mail".to_string(),
-        action: action.to_string(),
-        timestamp,
-        details,
-    });
+/// The recipient of the current `RCPT TO`: the last envelope entry. `None` without envelope or
+/// recipients.
+fn current_recipient(request: &MtaHookRequest) -> Option<&str> {
+    request
+        .envelope
+        .as_ref()
+        .and_then(|envelope| envelope.to.last())
+        .map(|recipient| recipient.address.as_str())
+}
+
+/// Whether RCPT accepts a recipient `route_recipient_match` routed by `route` (`None`: to no
+/// category that takes mail), and the validation result logged for it. Strict mode: only
+/// category addresses count, catch-all domain routes are ignored.
+fn rcpt_route_decision(route: Option<mailing::RouteMatch>, strict: bool) -> (bool, &'static str) {
+    match route {
+        Some(mailing::RouteMatch::Address) => (true, "found"),
+        Some(mailing::RouteMatch::Domain) if strict => (false, "domain route ignored (strict)"),
+        Some(mailing::RouteMatch::Domain) => (true, "found by domain route"),
+        None => (false, "not found"),
+    }
 }
 
+/// Decide the recipient of the current `RCPT TO`. Stalwart runs the hook once per `RCPT TO` and
+/// lists the recipients accepted so far before the new one, so only the last entry is decided
+/// here; a rejection refuses just that recipient and the transaction continues with the others.
+/// A request without envelope or recipients is malformed and rejected.
 pub(crate) fn handle_rcpt_stage(
     ctx: &ReducerContext,
     request: &MtaHookRequest,
+    correlation_id: &str,
     timestamp: Timestamp,
-) {
-    if let Some(envelope) = &request.envelope {
-        for recipient in &envelope.to {
-            let to_address = recipient.address.as_str();
-            log::trace!("RCPT stage - To: {}", to_address);
-
-            // O(1) unique-index lookup instead of full table scan
-            let category_found = ctx
-                .db
-                .message_categories()
-                .email_address()
-                .find(&to_address.to_string())
-                .map_or(false, |c| c.active);
-
-            let action = if category_found { "accept" } else { "reject" };
-            let details = format!(
-                "Category validation: {}",
-                if category_found { "found" } else { "not found" }
-            );
+) -> Result<(), StageRejection> {
+    let strict = settings::current(ctx).reject_unknown_recipients_strict;
+    let check = rcpt_check(request, max_recipients(), strict, |to_address| {
+        mailing::route_recipient_match(ctx, to_address).map(|(_, matched)| matched)
+    });
+    log_stage_check(ctx, request, correlation_id, check, timestamp)
+}
 
-            ctx.db.mta_connection_log().insert(MtaConnectionLog {
-                id: 0,
-                client_ip: "[REDACTED]".to_string(),
-                stage: "rcpt".to_string(),
-                action: action.to_string(),
-                timestamp,
-                details,
-            });
+/// RCPT: the current recipient, as long as the transaction has at most `limit` recipients, must
+/// be routed by `route` to a category (see `rcpt_route_decision`).
+fn rcpt_check(
+    request: &MtaHookRequest,
+    limit: usize,
+    strict: bool,
+    route: impl FnOnce(&str) -> Option<mailing::RouteMatch>,
+) -> StageCheck {
+    let Some(to_address) = current_recipient(request) else {
+        log::warn!("RCPT stage without envelope recipients, rejecting");
+        return StageCheck::new(
+            Some(RejectReason::MissingRecipient),
+            "Missing envelope recipient".to_string(),
+        );
+    };
+
+    log::trace!("RCPT stage - To: {}", to_address);
+
+    let recipient_count = request.envelope.as_ref().map_or(0, |e| e.to.len());
+    if over_recipient_limit(recipient_count, limit) {
+        log::warn!(
+            "RCPT stage: {} recipients exceed the limit of {}, deferring {}",
+            recipient_count,
+            limit,
+            to_address
+        );
+        return StageCheck::new(
+            Some(RejectReason::RecipientLimit),
+            format!("Too many recipients: {} > {}", recipient_count, limit),
+        );
+    }
+
+    let (category_found, validation) = rcpt_route_decision(route(to_address), strict);
+    StageCheck::new(
+        (!category_found).then_some(RejectReason::UnknownRecipient),
+        format!("Category validation: {}", validation),
+    )
+}
+
+/// Temporary failure; the sending MTA retries later.
+pub const SMTP_TEMPORARY_FAILURE: u16 = 451;
+/// Too many recipients in this transaction; the client sends the rest in another one.
+pub const SMTP_TOO_MANY_RECIPIENTS: u16 = 452;
+/// Syntax error in a command argument.
+pub const SMTP_SYNTAX_ERROR: u16 = 501;
+/// Permanent rejection by policy.
+pub const SMTP_POLICY_REJECTION: u16 = 550;
+
+/// Why a stage refuses a client. Every rejection goes through this enum, so the SMTP code and
+/// text of a reason are the same wherever it is decided, and `reject_reason_stats` counts it
+/// under its `key`, however specific the text the client was told.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RejectReason {
+    /// Maintenance mode is on
+    Maintenance,
+    /// The client IP has an active entry in `blocked_ips`
+    IpBlocked,
+    /// EHLO/HELO without argument
+    EmptyHelo,
+    /// EHLO/HELO is neither an FQDN nor an address literal (`HELO_POLICY=strict`)
+    UnqualifiedHelo,
+    /// MAIL FROM is not an email address
+    InvalidSender,
+    /// RCPT without envelope recipient
+    MissingRecipient,
+    /// More `RCPT TO`s than `max_recipients()` in one transaction
+    RecipientLimit,
+    /// The recipient matches no category
+    UnknownRecipient,
+    /// DATA for more recipients than `max_recipients()`
+    MessageRecipientLimit,
+    /// Category sender rules refused the sender
+    SenderNotPermitted,
+    /// A category's `rate_limit_per_hour` is reached
+    RateLimited,
+}
+
+impl RejectReason {
+    /// Stable name of the reason, the bucket `reject_reason_stats` counts it in.
+    pub fn key(self) -> &'static str {
+        match self {
+            RejectReason::Maintenance => "Maintenance",
+            RejectReason::IpBlocked => "IpBlocked",
+            RejectReason::EmptyHelo => "EmptyHelo",
+            RejectReason::UnqualifiedHelo => "UnqualifiedHelo",
+            RejectReason::InvalidSender => "InvalidSender",
+            RejectReason::MissingRecipient => "MissingRecipient",
+            RejectReason::RecipientLimit => "RecipientLimit",
+            RejectReason::UnknownRecipient => "UnknownRecipient",
+            RejectReason::MessageRecipientLimit => "MessageRecipientLimit",
+            RejectReason::SenderNotPermitted => "SenderNotPermitted",
+            RejectReason::RateLimited => "RateLimited",
+        }
+    }
+
+    pub fn code(self) -> u16 {
+        match self {
+            RejectReason::Maintenance | RejectReason::RateLimited => SMTP_TEMPORARY_FAILURE,
+            RejectReason::RecipientLimit => SMTP_TOO_MANY_RECIPIENTS,
+            RejectReason::EmptyHelo | RejectReason::MissingRecipient => SMTP_SYNTAX_ERROR,
+            RejectReason::IpBlocked
+            | RejectReason::UnqualifiedHelo
+            | RejectReason::InvalidSender
+            | RejectReason::UnknownRecipient
+            | RejectReason::MessageRecipientLimit
+            | RejectReason::SenderNotPermitted => SMTP_POLICY_REJECTION,
+        }
+    }
+
+    pub fn message(self) -> &'static str {
+        match self {
+            RejectReason::Maintenance => "Service temporarily unavailable, try again later",
+            RejectReason::IpBlocked => "IP blocked",
+            RejectReason::EmptyHelo => "Invalid EHLO/HELO argument",
+            RejectReason::UnqualifiedHelo => {
+                "HELO must be a fully qualified domain name or address literal"
+            }
+            RejectReason::InvalidSender => "Invalid sender address",
+            RejectReason::MissingRecipient => "Missing recipient",
+            RejectReason::RecipientLimit | RejectReason::MessageRecipientLimit => {
+                "Too many recipients"
+            }
+            RejectReason::UnknownRecipient => "No such mailing list",
+            RejectReason::SenderNotPermitted => "Sender not permitted for this list",
+            RejectReason::RateLimited => "Rate limit reached, try again later",
         }
     }
 }
 
-pub(crate) fn handle_data_stage(
+/// Why a stage refused the client; the HTTP hook replies with this SMTP code and text.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct StageRejection {
+    pub reason: RejectReason,
+    pub code: u16,
+    pub message: String,
+}
+
+impl StageRejection {
+    pub fn new(reason: RejectReason) -> Self {
+        Self::with_message(reason, reason.message().to_string())
+    }
+
+    /// The code of `reason` with a more specific text, e.g. naming the rate-limited category.
+    pub fn with_message(reason: RejectReason, message: String) -> Self {
+        Self {
+            reason,
+            code: reason.code(),
+            message,
+        }
+    }
+}
+
+/// What the DATA hook response should change on an accepted message.
+/// Empty by default, in which case the message is accepted unmodified.
+#[derive(Debug, Default)]
+pub(crate) struct DataAcceptance {
+    /// Envelope recipients to add, from envelope-mode forward rules
+    pub add_recipients: Vec<String>,
+    /// Custom SMTP accept reply (code, text) of the first matched category that has one
+    pub accept_reply: Option<(u16, String)>,
+    /// In dry-run mode, the first rejection that was only logged; the message was accepted and
+    /// stored all the same
+    pub dry_run_rejection: Option<StageRejection>,
+}
+
+/// Whether dry-run mode overrides `rejection` of the DATA stage (see `dry_run_override`). The
+/// message then goes on down the accept path, so it is still stored, and `acceptance` keeps the
+/// rejection it would have got.
+fn overridden_in_dry_run(
     ctx: &ReducerContext,
     request: &MtaHookRequest,
+    correlation_id: &str,
+    rejection: StageRejection,
+    acceptance: &mut DataAcceptance,
     timestamp: Timestamp,
+) -> bool {
+    if !dry_run_override(
+        ctx,
+        request,
+        correlation_id,
+        rejection.code,
+        &rejection.message,
+        timestamp,
+    ) {
+        return false;
+    }
+    acceptance.dry_run_rejection.get_or_insert(rejection);
+    true
+}
+
+/// The sender of a DATA message, as far as the subscription check needs it.
+struct Poster<'a> {
+    /// Account of the sender address or of a verified alias; `None` for external senders
+    account_id: Option<u64>,
+    whitelisted: bool,
+    admin: bool,
+    /// Categories whose sender rules explicitly allow the sender
+    explicitly_allowed: &'a [u64],
+}
+
+/// `posting_permission` of a sender for one category.
+#[derive(Debug, PartialEq, Eq)]
+enum Posting {
+    /// No subscription needed: whitelisted sender, admin, allow rule or open category
+    Exempt,
+    /// The sender's account has a current subscription
+    Subscriber,
+    /// The sender's account, which is not subscribed
+    NotSubscribed(u64),
+    /// No account behind the sender address
+    External,
+}
+
+/// Whether `poster` may post to category `category_id`. Whitelisted senders, admins, allow
+/// rules and `open_categories` skip the subscription check; otherwise `has_subscription` tells
+/// whether the sender's account is subscribed.
+fn posting_permission(
+    poster: &Poster,
+    category_id: u64,
+    open_categories: &[u64],
+    has_subscription: impl FnOnce(u64) -> bool,
+) -> Posting {
+    if poster.whitelisted
+        || poster.admin
+        || poster.explicitly_allowed.contains(&category_id)
+        || open_categories.contains(&category_id)
+    {
+        return Posting::Exempt;
+    }
+    match poster.account_id {
+        Some(account_id) if has_subscription(account_id) => Posting::Subscriber,
+        Some(account_id) => Posting::NotSubscribed(account_id),
+        None => Posting::External,
+    }
+}
+
+/// Keep the `(category_id, address)` pairs of `categories` that `poster`, sending from
+/// `from_address`, may post to, and log the others. `has_subscription(account, category)` is
+/// the subscription check.
+fn retain_permitted(
+    categories: &mut Vec<(u64, String)>,
+    poster: &Poster,
+    from_address: &str,
+    open_categories: &[u64],
+    has_subscription: impl Fn(u64, u64) -> bool,
 ) {
-    let from_address = request
+    categories.retain(|(cat_id, cat_email)| {
+        let permission = posting_permission(poster, *cat_id, open_categories, |acc_id| {
+            has_subscription(acc_id, *cat_id)
+        });
+        match permission {
+            Posting::Exempt | Posting::Subscriber => true,
+            Posting::NotSubscribed(acc_id) => {
+                log::warn!(
+                    "Sender {} (acc {}) is NOT subscribed to category {} ({})",
+                    from_address,
+                    acc_id,
+                    cat_id,
+                    cat_email
+                );
+                false
+            }
+            Posting::External => {
+                log::warn!(
+                    "External sender {} attempted to post to category {} ({})",
+                    from_address,
+                    cat_id,
+                    cat_email
+                );
+                false
+            }
+        }
+    });
+}
+
+/// Envelope sender of `request`, `unknown` without envelope.
+fn envelope_from(request: &MtaHookRequest) -> &str {
+    request
         .envelope
         .as_ref()
         .map(|env| env.from.address.as_str())
-        .unwrap_or("unknown");
-    let message_size = request
+        .unwrap_or("unknown")
+}
+
+/// Size of the message of `request` in bytes, 0 without message.
+fn request_message_size(request: &MtaHookRequest) -> u64 {
+    request
         .message
         .as_ref()
         .map(|msg| msg.size as u64)
-        .unwrap_or(0);
+        .unwrap_or(0)
+}
+
+pub(crate) fn handle_data_stage(
+    ctx: &ReducerContext,
+    request: &MtaHookRequest,
+    correlation_id: &str,
+    timestamp: Timestamp,
+) -> Result<DataAcceptance, StageRejection> {
+    let from_address = envelope_from(request);
+    let message_size = request_message_size(request);
     let subject = extract_subject_from_request(request);
 
     log::trace!(
@@ -265,6 +1754,9 @@ pub(crate) fn handle_data_stage(
 
     let mut to_addresses = Vec::new();
     let mut valid_categories: Vec<(u64, String)> = Vec::new();
+    // Categories with `subscription_required = false`; any permitted sender may post there.
+    let mut open_categories: Vec<u64> = Vec::new();
+    let mut acceptance = DataAcceptance::default();
 
     log::trace!(
         "envelope: {}",
@@ -277,13 +1769,10 @@ pub(crate) fn handle_data_stage(
             let to_address = recipient.address.as_str();
             to_addresses.push(to_address.to_string());
 
-            if let Some(category) = ctx
-                .db
-                .message_categories()
-                .email_address()
-                .find(&to_address.to_string())
-                .filter(|c| c.active)
-            {
+            if let Some(category) = mailing::route_recipient(ctx, to_address) {
+                if !category.subscription_required {
+                    open_categories.push(category.id);
+                }
                 valid_categories.push((category.id, category.email_address.clone()));
             }
         }
@@ -298,13 +1787,10 @@ pub(crate) fn handle_data_stage(
                     to_addresses = header_addrs.clone();
 
                     for to_address in header_addrs {
-                        if let Some(category) = ctx
-                            .db
-                            .message_categories()
-                            .email_address()
-                            .find(&to_address)
-                            .filter(|c| c.active)
-                        {
+                        if let Some(category) = mailing::route_recipient(ctx, &to_address) {
+                            if !category.subscription_required {
+                                open_categories.push(category.id);
+                            }
                             valid_categories.push((category.id, category.email_address.clone()));
                         }
                     }
@@ -313,7 +1799,100 @@ pub(crate) fn handle_data_stage(
         }
     }
 
-    let action = if !valid_categories.is_empty() {
+    let log_level = message_log_level(ctx, &valid_categories);
+    // Follows the log level: nothing is sampled for a message logged less than in full.
+    if log_level == mailing::LOG_LEVEL_FULL {
+        record_envelope_sample(ctx, request, &to_addresses, timestamp);
+    }
+
+    // Normally RCPT already deferred the surplus; this catches envelopes that skipped it.
+    if over_recipient_limit(to_addresses.len(), max_recipients())
+        && !overridden_in_dry_run(
+            ctx,
+            request,
+            correlation_id,
+            StageRejection::new(RejectReason::MessageRecipientLimit),
+            &mut acceptance,
+            timestamp,
+        )
+    {
+        log::warn!(
+            "DATA stage: {} recipients exceed the limit of {}, rejecting message",
+            to_addresses.len(),
+            max_recipients()
+        );
+        insert_message_log(
+            ctx,
+            log_level,
+            MtaMessageLog::entry(
+                request,
+                correlation_id,
+                &to_addresses,
+                "reject",
+                "[]".to_string(),
+                timestamp,
+            )
+            .counted_as(Some(RejectReason::MessageRecipientLimit)),
+        );
+        stats::record_mta_action(ctx, timestamp, "reject");
+        return Err(StageRejection::new(RejectReason::MessageRecipientLimit));
+    }
+
+    // Whitelisted senders skip the sender rules, the subscription check and the rate limits.
+    let whitelisted = sender_rules::is_sender_whitelisted(ctx, from_address, timestamp);
+    if whitelisted && !valid_categories.is_empty() {
+        log::warn!(
+            "Sender {} is whitelisted, bypassing sender rules, subscription check and rate limits for {} categories",
+            from_address,
+            valid_categories.len()
+        );
+    }
+
+    // Per-category sender allow/deny rules run before the subscription check.
+    let mut explicitly_allowed: Vec<u64> = Vec::new();
+    let mut denied_categories: Vec<(u64, String)> = Vec::new();
+    valid_categories.retain(|(cat_id, cat_email)| {
+        if whitelisted {
+            return true;
+        }
+        match sender_rules::evaluate_sender_rules(ctx, *cat_id, from_address) {
+            SenderRuleDecision::Denied => {
+                log::warn!(
+                    "Sender {} is not permitted by the sender rules of category {} ({})",
+                    from_address,
+                    cat_id,
+                    cat_email
+                );
+                denied_categories.push((*cat_id, cat_email.clone()));
+                false
+            }
+            SenderRuleDecision::Allowed => {
+                explicitly_allowed.push(*cat_id);
+                true
+            }
+            SenderRuleDecision::Unlisted => true,
+        }
+    });
+    let mut rejected_by_rule = valid_categories.is_empty() && !denied_categories.is_empty();
+    if rejected_by_rule
+        && overridden_in_dry_run(
+            ctx,
+            request,
+            correlation_id,
+            StageRejection::new(RejectReason::SenderNotPermitted),
+            &mut acceptance,
+            timestamp,
+        )
+    {
+        // Let the message through to the categories whose rules deny the sender.
+        valid_categories = std::mem::take(&mut denied_categories);
+        rejected_by_rule = false;
+    }
+
+    let action = if rejected_by_rule {
+        log::warn!("All matched categories deny this sender, rejecting message");
+        "reject"
+    } else if !valid_categories.is_empty() {
         log::info!(
             "Accepting message for {} valid category deliveries",
             valid_categories.len()
@@ -324,31 +1903,61 @@ pub(crate) fn handle_data_stage(
         "quarantine"
     };
 
-    ctx.db.mta_message_log().insert(MtaMessageLog {
-        id: 0,
-        from_address: from_address.to_string(),
-        to_addresses: serde_json::to_string(&to_addresses).unwrap_or_default(),
-        subject: subject.chars().take(100).collect(),
-        message_size,
-        stage: "data".to_string(),
-        action: action.to_string(),
-        timestamp,
-        queue_id: request.context.queue.as_ref().map(|q| q.id.clone()),
-    });
+    let counted_reason = if rejected_by_rule {
+        Some(RejectReason::SenderNotPermitted)
+    } else {
+        acceptance.dry_run_rejection.as_ref().map(|r| r.reason)
+    };
+    let log_id = insert_message_log(
+        ctx,
+        log_level,
+        MtaMessageLog::entry(
+            request,
+            correlation_id,
+            &to_addresses,
+            action,
+            matched_categories_json(&valid_categories),
+            timestamp,
+        )
+        .counted_as(counted_reason),
+    );
+    // An accepted message can still be quarantined by the subscription check or deferred by the
+    // rate limit below; it is counted once that is decided, so it is not counted as accepted.
+    if action != "accept" {
+        stats::record_mta_action(ctx, timestamp, action);
+    }
+
+    if action == "quarantine" {
+        notifications::notify_message_event(
+            ctx,
+            "quarantine",
+            "No active category matches the recipients",
+            &[],
+            from_address,
+            &to_addresses,
+        );
+    }
+    if rejected_by_rule {
+        let denied_emails: Vec<String> = denied_categories.into_iter().map(|(_, e)| e).collect();
+        notifications::notify_message_event(
+            ctx,
+            "reject",
+            "Sender not permitted by category sender rules",
+            &denied_emails,
+            from_address,
+            &to_addresses,
+        );
+        return Err(StageRejection::new(RejectReason::SenderNotPermitted));
+    }
 
     // Persist the full message for each accepted category delivery
     if !valid_categories.is_empty() {
         if let Some(message) = &request.message {
-            // Look up sender's SoLaWi account by email (None for external senders)
-            let sender_account_id = ctx
-                .db
-                .account()
-                .email()
-                .filter(&from_address.to_string())
-                .next()
-                .map(|a| a.id);
+            // Look up sender's SoLaWi account by email or verified alias (None for external senders)
+            let sender_account_id = account_for_sender(ctx, from_address).map(|a| a.id);
 
             // Filter valid_categories: only allow if sender is an admin OR has an active subscription to that category
+            // (open categories, explicit allow rules and whitelisted senders skip the subscription check)
             let sender_is_admin = sender_account_id
                 .and_then(|id| ctx.db.account().id().find(&id))
                 .map_or(false, |acc| {
@@ -359,41 +1968,95 @@ pub(crate) fn handle_data_stage(
                         .is_some()
                 });
 
-            valid_categories.retain(|(cat_id, cat_email)| {
-                if sender_is_admin {
-                    return true;
+            let requested_categories: Vec<String> = valid_categories
+                .iter()
+                .map(|(_, email)| email.clone())
+                .collect();
+            let poster = Poster {
+                account_id: sender_account_id,
+                whitelisted,
+                admin: sender_is_admin,
+                explicitly_allowed: &explicitly_allowed,
+            };
+            retain_permitted(
+                &mut valid_categories,
+                &poster,
+                from_address,
+                &open_categories,
+                |acc_id, cat_id| mailing::has_current_subscription(ctx, acc_id, cat_id, timestamp),
+            );
+            if valid_categories.len() < requested_categories.len() {
+                update_message_log(ctx, log_id, |row| {
+                    row.matched_categories = matched_categories_json(&valid_categories)
+                });
+            }
+
+            if valid_categories.is_empty() {
+                log::warn!("No authorized categories left after subscription check");
+                update_message_log(ctx, log_id, |row| row.action = "quarantine".to_string());
+                notifications::notify_message_event(
+                    ctx,
+                    "quarantine",
+                    "Sender is not subscribed to any matched category",
+                    &requested_categories,
+                    from_address,
+                    &to_addresses,
+                );
+                stats::record_mta_action(ctx, timestamp, "quarantine");
+                return Ok(acceptance);
+            }
+
+            // Defer the whole message while one of its categories is over its hourly limit;
+            // storing it for the others now would deliver it to them twice after the retry.
+            let over_limit = valid_categories.iter().find_map(|(cat_id, cat_email)| {
+                if whitelisted {
+                    return None;
                 }
-                if let Some(acc_id) = sender_account_id {
-                    let has_sub = ctx
-                        .db
-                        .subscriptions()
-                        .subscriber_account_id()
-                        .filter(&acc_id)
-                        .any(|s| s.category_id == *cat_id && s.active);
-                    if !has_sub {
-                        log::warn!(
-                            "Sender {} (acc {}) is NOT subscribed to category {} ({})",
-                            from_address,
-                            acc_id,
-                            cat_id,
-                            cat_email
-                        );
+                let limit = ctx
+                    .db
+                    .message_categories()
+                    .id()
+                    .find(cat_id)?
+                    .rate_limit_per_hour?;
+                let count = stats::category_count_this_hour(ctx, *cat_id, timestamp);
+                stats::rate_limit_reached(count, limit).then(|| (cat_email.clone(), limit))
+            });
+            if let Some((cat_email, limit)) = over_limit {
+                let rejection = StageRejection::with_message(
+                    RejectReason::RateLimited,
+                    format!("Rate limit of {cat_email} reached, try again later"),
+                );
+                let first_rejection = acceptance.dry_run_rejection.is_none();
+                if overridden_in_dry_run(
+                    ctx,
+                    request,
+                    correlation_id,
+                    rejection.clone(),
+                    &mut acceptance,
+                    timestamp,
+                ) {
+                    if first_rejection {
+                        update_message_log(ctx, log_id, |row| {
+                            row.reject_reason = RejectReason::RateLimited.key().to_string()
+                        });
                     }
-                    has_sub
                 } else {
                     log::warn!(
-                        "External sender {} attempted to post to category {} ({})",
-                        from_address,
-                        cat_id,
-                        cat_email
+                        "Category {} reached its limit of {} messages per hour, deferring message",
+                        cat_email,
+                        limit
                     );
-                    false
+                    update_message_log(ctx, log_id, |row| {
+                        row.action = "defer".to_string();
+                        row.matched_categories = "[]".to_string();
+                        row.reject_reason = RejectReason::RateLimited.key().to_string();
+                    });
+                    return Err(rejection);
                 }
-            });
-
-            if valid_categories.is_empty() {
-                log::warn!("No authorized categories left after subscription check");
-                return;
+            }
+            stats::record_mta_action(ctx, timestamp, action);
+            for (category_id, _) in &valid_categories {
+                stats::record_category_message(ctx, *category_id, timestamp);
             }
 
             // Extract parsed header fields
@@ -428,7 +2091,7 @@ pub(crate) fn handle_data_stage(
             let queue_id = request.context.queue.as_ref().map(|q| q.id.clone());
 
             for (category_id, category_email) in &valid_categories {
-                ctx.db.received_message().insert(ReceivedMessage {
+                let stored = ctx.db.received_message().insert(ReceivedMessage {
                     id: 0,
                     queue_id: queue_id.clone(),
                     received_at: timestamp,
@@ -447,6 +2110,45 @@ pub(crate) fn handle_data_stage(
                     message_size,
                 });
 
+                if let Some(relay_id) =
+                    forwarding::emit_relay_out(ctx, *category_id, stored.id, queue_id.clone())
+                {
+                    log::info!(
+                        "Queued relay_out {} for message {} in category {}",
+                        relay_id,
+                        stored.id,
+                        category_id
+                    );
+                }
+                for recipient in forwarding::envelope_forward_recipients(ctx, *category_id) {
+                    if !to_addresses.contains(&recipient)
+                        && !acceptance.add_recipients.contains(&recipient)
+                    {
+                        acceptance.add_recipients.push(recipient);
+                    }
+                }
+
+                let category = ctx.db.message_categories().id().find(category_id);
+                if acceptance.accept_reply.is_none() {
+                    acceptance.accept_reply = category.as_ref().and_then(|c| c.accept_reply());
+                }
+
+                // Digest categories batch messages; `flush_digests` relays them later.
+                let in_digest_mode = category
+                    .as_ref()
+                    .map_or(false, |c| c.digest_interval_secs.is_some());
+                if in_digest_mode {
+                    let pending_id = digest::queue_pending_digest(ctx, *category_id, stored.id);
+                    log::info!(
+                        "Queued message {} as pending digest entry {} for category {} ({})",
+                        stored.id,
+                        pending_id,
+                        category_id,
+                        category_email
+                    );
+                    continue;
+                }
+
                 let ingress_id = delivery::upsert_mail_ingress(
                     ctx,
                     queue_id.clone(),
@@ -471,31 +2173,98 @@ pub(crate) fn handle_data_stage(
                     category_email
                 );
             }
+        } else {
+            stats::record_mta_action(ctx, timestamp, action);
         }
     }
+    Ok(acceptance)
 }
 
 pub(crate) fn handle_auth_stage(
     ctx: &ReducerContext,
-    _request: &MtaHookRequest,
+    request: &MtaHookRequest,
+    correlation_id: &str,
     timestamp: Timestamp,
 ) {
     log::info!("AUTH stage - accepting");
 
-    ctx.db.mta_connection_log().insert(MtaConnectionLog {
-        id: 0,
-        client_ip: "[REDACTED]".to_string(),
-        stage: "auth".to_string(),
-        action: "accept".to_string(),
-        timestamp,
-        details: "Authentication stage - accept".to_string(),
+    // Always accepts.
+    let _ = log_stage_check(ctx, request, correlation_id, auth_check(), timestamp);
+}
+
+/// AUTH: accepted; authentication itself is Stalwart's.
+fn auth_check() -> StageCheck {
+    StageCheck::new(None, "Authentication stage - accept".to_string())
+}
+
+/// Most headers of a message (and, separately, most server-added headers) a hook request keeps,
+/// set at build time via `MAX_MESSAGE_HEADERS`. Forwarding loops can pile up thousands of
+/// `Received` lines; the rest is dropped by `cap_message_headers`.
+fn max_message_headers() -> usize {
+    option_env!("MAX_MESSAGE_HEADERS")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(500)
+}
+
+/// Trace headers that every relay adds, and that a forwarding loop piles up.
+fn is_trace_header(name: &str) -> bool {
+    ["received", "x-received", "return-path"]
+        .iter()
+        .any(|trace| name.eq_ignore_ascii_case(trace))
+}
+
+/// Cut `headers` to at most `max` entries. Trace headers are dropped first, the last (oldest)
+/// hops before the newer ones, so a pile of `Received` lines can't push out From, Subject, Date
+/// or Message-ID. Only if that is not enough are the remaining headers cut at the end.
+fn cap_headers(headers: &mut Vec<(String, String)>, max: usize) {
+    if headers.len() <= max {
+        return;
+    }
+    let trace = headers.iter().filter(|(n, _)| is_trace_header(n)).count();
+    let mut keep_trace = trace.saturating_sub(headers.len() - max);
+    headers.retain(|(n, _)| {
+        if !is_trace_header(n) {
+            return true;
+        }
+        if keep_trace == 0 {
+            return false;
+        }
+        keep_trace -= 1;
+        true
     });
+    headers.truncate(max);
+}
+
+/// Drop the headers beyond `MAX_MESSAGE_HEADERS` from `request` (see `cap_headers`), right
+/// after it is parsed, so they are neither searched nor stored. Logs a warning when it cuts
+/// anything.
+pub(crate) fn cap_message_headers(request: &mut MtaHookRequest, correlation_id: &str) {
+    let Some(message) = request.message.as_mut() else {
+        return;
+    };
+    let max = max_message_headers();
+    let (headers, server_headers) = (message.headers.len(), message.server_headers.len());
+    if headers <= max && server_headers <= max {
+        return;
+    }
+    cap_headers(&mut message.headers, max);
+    cap_headers(&mut message.server_headers, max);
+    log::warn!(
+        "[{}] Message has {} headers and {} server headers, keeping {} of each",
+        correlation_id,
+        headers,
+        server_headers,
+        max
+    );
 }
 
 /// Find the first header whose name (case-insensitive) matches `name` and return its trimmed value.
+/// `name` is lowercase. Searches at most `MAX_MESSAGE_HEADERS` entries, in
+/// case a request reaches it without `cap_message_headers`.
 fn extract_header(headers: &[(String, String)], name: &str) -> Option<String> {
     headers
         .iter()
+        .take(max_message_headers())
         .find(|(n, _)| n.to_lowercase() == name)
         .map(|(_, v)| v.trim().to_string())
 }
@@ -592,6 +2361,169 @@ pub fn visible_messages(ctx: &ViewContext) -> Vec<ReceivedMessage> {
     }
 }
 
+/// `DeliveryOutcome.outcome` values, as reported by Stalwart after the hook accepted a message.
+pub const DELIVERY_OUTCOME_DELIVERED: &str = "delivered";
+pub const DELIVERY_OUTCOME_DEFERRED: &str = "deferred";
+pub const DELIVERY_OUTCOME_BOUNCED: &str = "bounced";
+const DELIVERY_OUTCOMES: [&str; 3] = [
+    DELIVERY_OUTCOME_DELIVERED,
+    DELIVERY_OUTCOME_DEFERRED,
+    DELIVERY_OUTCOME_BOUNCED,
+];
+
+/// What finally happened to an accepted message, joined to `mta_message_log` and
+/// `received_message` via the Stalwart `queue_id`. A message can have several rows,
+/// e.g. `deferred` followed by `delivered`.
+#[spacetimedb::table(accessor = delivery_outcome)]
+pub struct DeliveryOutcome {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub queue_id: String,
+    /// One of the `DELIVERY_OUTCOME_*` constants
+    pub outcome: String,
+    pub detail: String,
+    pub recorded_at: Timestamp,
+}
+
+/// All delivery outcomes for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_delivery_outcomes, public)]
+pub fn visible_delivery_outcomes(ctx: &ViewContext) -> impl Query<DeliveryOutcome> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.delivery_outcome().r#filter(move |_| is_admin)
+}
+
+/// Store a Stalwart delivery report for `queue_id`, e.g. from its delivery event hook.
+/// Only admin identities, like `handle_mta_hook`.
+#[spacetimedb::reducer]
+pub fn record_delivery_outcome(
+    ctx: &ReducerContext,
+    queue_id: String,
+    outcome: String,
+    detail: String,
+) -> Result<(), String> {
+    if !is_admin_identity(ctx, ctx.sender()) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let queue_id = queue_id.trim().to_string();
+    if queue_id.is_empty() {
+        return Err("queue_id must not be empty".to_string());
+    }
+    let outcome = outcome.trim().to_lowercase();
+    if !DELIVERY_OUTCOMES.contains(&outcome.as_str()) {
+        return Err(format!(
+            "Invalid delivery outcome '{}': expected one of {}",
+            outcome,
+            DELIVERY_OUTCOMES.join(", ")
+        ));
+    }
+    log::info!("Delivery outcome for queue {}: {}", queue_id, outcome);
+    ctx.db.delivery_outcome().insert(DeliveryOutcome {
+        id: 0,
+        queue_id,
+        outcome,
+        detail,
+        recorded_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+/// `export_message_logs` starts a new `export_blob` part before one would grow past this size.
+const MESSAGE_LOG_EXPORT_PART_BYTES: usize = 256 * 1024;
+
+/// One `mta_message_log` row as an NDJSON line. The JSON string columns are embedded as JSON.
+fn message_log_ndjson_line(row: &MtaMessageLog) -> String {
+    let embedded = |raw: &str| {
+        serde_json::from_str::<serde_json::Value>(raw)
+            .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()))
+    };
+    serde_json::json!({
+        "id": row.id,
+        "correlation_id": row.correlation_id,
+        "timestamp_micros": row.timestamp.to_micros_since_unix_epoch(),
+        "stage": row.stage,
+        "action": row.action,
+        "from_address": row.from_address,
+        "to_addresses": embedded(&row.to_addresses),
+        "subject": row.subject,
+        "message_size": row.message_size,
+        "queue_id": row.queue_id,
+        "recipient_count": row.recipient_count,
+        "matched_categories": embedded(&row.matched_categories),
+    })
+    .to_string()
+}
+
+/// The rows of `rows` with `from_ts <= timestamp < to_ts` as NDJSON in id order, split into
+/// parts of at most `part_bytes` (a longer single line gets a part of its own), and how many
+/// rows that are.
+fn message_log_export(
+    rows: impl Iterator<Item = MtaMessageLog>,
+    from_ts: Timestamp,
+    to_ts: Timestamp,
+    part_bytes: usize,
+) -> (usize, Vec<String>) {
+    let mut rows: Vec<MtaMessageLog> = rows
+        .filter(|row| from_ts <= row.timestamp && row.timestamp < to_ts)
+        .collect();
+    rows.sort_by_key(|row| row.id);
+
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for row in &rows {
+        let line = message_log_ndjson_line(row);
+        if !current.is_empty() && current.len() + line.len() + 1 > part_bytes {
+            parts.push(std::mem::take(&mut current));
+        }
+        current.push_str(&line);
+        current.push('\n');
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    (rows.len(), parts)
+}
+
+/// Write the `mta_message_log` rows with `from_ts <= timestamp < to_ts` as NDJSON (one JSON
+/// object per line, in log order) into the caller's `export_blob` rows of kind
+/// `message_logs`, split into parts of at most `MESSAGE_LOG_EXPORT_PART_BYTES`. Replaces the
+/// caller's previous message log export; an empty range leaves no part. Only admins.
+#[spacetimedb::reducer]
+pub fn export_message_logs(
+    ctx: &ReducerContext,
+    from_ts: Timestamp,
+    to_ts: Timestamp,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if from_ts >= to_ts {
+        return Err("from_ts must be before to_ts".to_string());
+    }
+    let (count, parts) = message_log_export(
+        ctx.db.mta_message_log().iter(),
+        from_ts,
+        to_ts,
+        MESSAGE_LOG_EXPORT_PART_BYTES,
+    );
+
+    let part_count = parts.len();
+    store_export_parts(ctx, EXPORT_KIND_MESSAGE_LOGS, parts);
+    log::info!(
+        "Exported {} message log entries in {} parts (by identity: {:?})",
+        count,
+        part_count,
+        ctx.sender()
+    );
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn dump_mta_logs_to_server_logs(ctx: &ReducerContext) {
     log::info!("=== MTA Connection Logs ===");
@@ -616,3 +2548,606 @@ pub fn dump_mta_logs_to_server_logs(ctx: &ReducerContext) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn check_helo_classifies_names() {
+        assert_eq!(check_helo(None), HeloCheck::Missing);
+        assert_eq!(check_helo(Some("  ")), HeloCheck::Empty);
+        assert_eq!(check_helo(Some("mail.example.org")), HeloCheck::Fqdn);
+        assert_eq!(check_helo(Some("mail.example.org.")), HeloCheck::Fqdn);
+        assert_eq!(check_helo(Some("localhost")), HeloCheck::NotFqdn);
+        assert_eq!(check_helo(Some("mail..example.org")), HeloCheck::NotFqdn);
+        assert_eq!(check_helo(Some("192.0.2.1")), HeloCheck::BareIp);
+        assert_eq!(check_helo(Some("2001:db8::1")), HeloCheck::BareIp);
+        assert_eq!(check_helo(Some("[192.0.2.1]")), HeloCheck::AddressLiteral);
+        assert_eq!(
+            check_helo(Some("[IPv6:2001:db8::1]")),
+            HeloCheck::AddressLiteral
+        );
+        assert_eq!(check_helo(Some("[not-an-ip]")), HeloCheck::NotFqdn);
+    }
+
+    #[test]
+    fn empty_helo_is_rejected_under_both_policies() {
+        for strict in [false, true] {
+            assert_eq!(
+                helo_rejection(HeloCheck::Empty, strict),
+                Some(RejectReason::EmptyHelo)
+            );
+            assert_eq!(helo_rejection(HeloCheck::Fqdn, strict), None);
+            assert_eq!(helo_rejection(HeloCheck::AddressLiteral, strict), None);
+        }
+    }
+
+    #[test]
+    fn missing_or_unqualified_helo_is_only_rejected_when_strict() {
+        for check in [HeloCheck::Missing, HeloCheck::BareIp, HeloCheck::NotFqdn] {
+            assert_eq!(helo_rejection(check, false), None);
+            assert_eq!(
+                helo_rejection(check, true),
+                Some(RejectReason::UnqualifiedHelo)
+            );
+        }
+    }
+
+    #[test]
+    fn ip_prefix_keeps_the_network_part() {
+        assert_eq!(ip_prefix("192.0.2.77").as_deref(), Some("192.0.2.0/24"));
+        assert_eq!(ip_prefix(" 192.0.2.77 ").as_deref(), Some("192.0.2.0/24"));
+        assert_eq!(
+            ip_prefix("2001:db8:1:2:3:4:5:6").as_deref(),
+            Some("2001:db8:1::/48")
+        );
+        assert_eq!(ip_prefix("::1").as_deref(), Some("::/48"));
+        assert_eq!(ip_prefix("not-an-ip"), None);
+        assert_eq!(ip_prefix("192.0.2.0/24"), None);
+    }
+
+    #[test]
+    fn redact_ip_never_returns_the_address() {
+        assert_eq!(redact_ip("198.51.100.23"), "198.51.100.0/24");
+        assert_eq!(redact_ip("2001:db8:abcd:12::1"), "2001:db8:abcd::/48");
+        assert_eq!(redact_ip(""), "[REDACTED]");
+        assert_eq!(redact_ip("unknown"), "[REDACTED]");
+    }
+
+    fn header(name: &str, value: &str) -> (String, String) {
+        (name.to_string(), format!(" {value}\r\n"))
+    }
+
+    #[test]
+    fn oversized_headers_drop_trace_lines_first() {
+        let mut headers: Vec<(String, String)> = (0..1_000)
+            .map(|hop| header("Received", &format!("from relay{hop}.example.org")))
+            .collect();
+        headers.push(header("From", "alice@example.org"));
+        headers.push(header("Subject", "Loop"));
+        headers.push(header("Date", "Wed, 14 Oct 2026 10:00:00 +0000"));
+        headers.push(header("Message-ID", "<loop@example.org>"));
+
+        cap_headers(&mut headers, 10);
+
+        assert_eq!(headers.len(), 10);
+        assert_eq!(
+            extract_header(&headers, "from").as_deref(),
+            Some("alice@example.org")
+        );
+        assert_eq!(extract_header(&headers, "subject").as_deref(), Some("Loop"));
+        assert!(extract_header(&headers, "date").is_some());
+        assert_eq!(
+            extract_header(&headers, "message-id").as_deref(),
+            Some("<loop@example.org>")
+        );
+        // The newest hops, at the top, are the ones kept.
+        assert_eq!(
+            headers[0].1,
+            header("Received", "from relay0.example.org").1
+        );
+        assert_eq!(
+            headers[5].1,
+            header("Received", "from relay5.example.org").1
+        );
+    }
+
+    #[test]
+    fn headers_within_the_limit_are_untouched() {
+        let mut headers = vec![
+            header("Received", "from a"),
+            header("From", "b@example.org"),
+        ];
+        let before = headers.clone();
+        cap_headers(&mut headers, 2);
+        assert_eq!(headers, before);
+    }
+
+    #[test]
+    fn without_trace_headers_the_end_is_cut() {
+        let mut headers: Vec<(String, String)> = (0..5)
+            .map(|i| header(&format!("X-Custom-{i}"), "v"))
+            .collect();
+        headers.insert(0, header("Received", "from a"));
+        cap_headers(&mut headers, 3);
+        let names: Vec<&str> = headers.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["X-Custom-0", "X-Custom-1", "X-Custom-2"]);
+    }
+
+    #[test]
+    fn every_reject_reason_has_its_smtp_code() {
+        let expected = [
+            (RejectReason::Maintenance, 451),
+            (RejectReason::RateLimited, 451),
+            (RejectReason::RecipientLimit, 452),
+            (RejectReason::EmptyHelo, 501),
+            (RejectReason::MissingRecipient, 501),
+            (RejectReason::IpBlocked, 550),
+            (RejectReason::UnqualifiedHelo, 550),
+            (RejectReason::InvalidSender, 550),
+            (RejectReason::UnknownRecipient, 550),
+            (RejectReason::MessageRecipientLimit, 550),
+            (RejectReason::SenderNotPermitted, 550),
+        ];
+        let keys: HashSet<&str> = expected.iter().map(|(reason, _)| reason.key()).collect();
+        assert_eq!(keys.len(), expected.len());
+        for (reason, code) in expected {
+            assert_eq!(reason.code(), code, "{reason:?}");
+            assert!(!reason.message().is_empty(), "{reason:?}");
+            let rejection = StageRejection::new(reason);
+            assert_eq!(
+                (rejection.code, rejection.message.as_str()),
+                (code, reason.message())
+            );
+        }
+    }
+
+    #[test]
+    fn specific_message_keeps_the_reason_code() {
+        let rejection =
+            StageRejection::with_message(RejectReason::RateLimited, "Liste voll".to_string());
+        assert_eq!(rejection.code, SMTP_TEMPORARY_FAILURE);
+        assert_eq!(rejection.message, "Liste voll");
+        // Counted with every other rate limit, whatever category the text names
+        assert_eq!(rejection.reason.key(), RejectReason::RateLimited.key());
+    }
+
+    #[test]
+    fn sample_bucket_is_deterministic_per_queue_id() {
+        assert_eq!(sample_bucket("A1B2C3D4E5"), sample_bucket("A1B2C3D4E5"));
+        // Fixed FNV-1a values: a changed hash would resample every stored message.
+        assert_eq!(sample_bucket(""), 37);
+        assert_eq!(sample_bucket("a"), 996);
+        assert_eq!(sample_bucket("A1B2C3D4E5"), 575);
+    }
+
+    #[test]
+    fn sample_bucket_spreads_queue_ids() {
+        let sampled = (0..10_000)
+            .map(|i| sample_bucket(&format!("Q{i:05}")))
+            .inspect(|bucket| assert!(*bucket < 1000))
+            .filter(|bucket| *bucket < 10)
+            .count();
+        // 1 % of 10 000
+        assert!((50..=150).contains(&sampled), "{sampled}");
+    }
+
+    #[test]
+    fn temporary_failures_are_recorded_as_deferrals() {
+        let action = |reason| rejection_action(StageRejection::new(reason).code);
+        assert_eq!(action(RejectReason::RateLimited), "defer");
+        assert_eq!(action(RejectReason::RecipientLimit), "defer");
+        assert_eq!(action(RejectReason::UnknownRecipient), "reject");
+        assert_eq!(action(RejectReason::EmptyHelo), "reject");
+        assert_eq!(StageDecision::from_outcome(Ok(())).action, "accept");
+    }
+
+    fn message_log() -> MtaMessageLog {
+        MtaMessageLog {
+            id: 0,
+            from_address: "alice@example.org".to_string(),
+            to_addresses: r#"["gemuese@example.org"]"#.to_string(),
+            subject: "Ernte am Samstag".to_string(),
+            message_size: 1024,
+            stage: "data".to_string(),
+            action: "accept".to_string(),
+            timestamp: Timestamp::UNIX_EPOCH,
+            queue_id: Some("Q1".to_string()),
+            recipient_count: 1,
+            matched_categories: "[1]".to_string(),
+            correlation_id: "req-1".to_string(),
+            processing_ms: 0,
+            reject_reason: String::new(),
+        }
+    }
+
+    #[test]
+    fn log_level_none_writes_no_row() {
+        assert!(message_log_row(mailing::LOG_LEVEL_NONE, message_log()).is_none());
+    }
+
+    #[test]
+    fn log_level_summary_drops_subject_and_local_part() {
+        let row = message_log_row(mailing::LOG_LEVEL_SUMMARY, message_log()).unwrap();
+        assert_eq!(row.subject, "");
+        assert_eq!(row.from_address, "…@example.org");
+        assert_eq!(row.to_addresses, message_log().to_addresses);
+        assert_eq!(row.message_size, 1024);
+    }
+
+    #[test]
+    fn log_level_full_keeps_the_row() {
+        let row = message_log_row(mailing::LOG_LEVEL_FULL, message_log()).unwrap();
+        assert_eq!(row.subject, "Ernte am Samstag");
+        assert_eq!(row.from_address, "alice@example.org");
+        assert_eq!(row.to_addresses, message_log().to_addresses);
+        assert_eq!(row.queue_id.as_deref(), Some("Q1"));
+    }
+
+    #[test]
+    fn most_verbose_matched_log_level_wins() {
+        let levels = |levels: &[&str]| {
+            most_verbose_log_level(&levels.iter().map(|l| l.to_string()).collect::<Vec<_>>())
+        };
+        assert_eq!(levels(&["none", "summary"]), "summary");
+        assert_eq!(levels(&["summary", "full", "none"]), "full");
+        assert_eq!(levels(&["none"]), "none");
+        assert_eq!(levels(&[]), "full");
+    }
+
+    fn request(stage: &str, to: &str) -> MtaHookRequest {
+        let value = test_mta_request(stage, "alice@example.org", to, "Test", "Q1".to_string());
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn rcpt_without_envelope_is_rejected_as_malformed() {
+        let mut no_envelope = request("rcpt", "gemuese@example.org");
+        no_envelope.envelope = None;
+        for rcpt in [no_envelope, request("rcpt", "")] {
+            let check = rcpt_check(&rcpt, 100, false, |_| {
+                panic!("a malformed RCPT is not routed")
+            });
+            assert_eq!(
+                (check.action(), check.details.as_str()),
+                ("reject", "Missing envelope recipient")
+            );
+            let rejection = check.outcome.unwrap_err();
+            assert_eq!(
+                rejection,
+                StageRejection::new(RejectReason::MissingRecipient)
+            );
+            // What the HTTP hook answers
+            let response =
+                stalwart_mta_hook_types::Response::reject(rejection.code, rejection.message);
+            let response = serde_json::to_value(response).unwrap();
+            assert_eq!(response["action"], "reject");
+            assert_eq!(response["response"]["status"], 501);
+            assert_eq!(response["response"]["message"], "Missing recipient");
+        }
+    }
+
+    #[test]
+    fn each_rcpt_decides_only_its_own_recipient() {
+        // Stalwart lists the recipients accepted so far before the new one.
+        let first = request("rcpt", "gemuese@example.org");
+        let second = request("rcpt", "gemuese@example.org, unbekannt@example.org");
+        assert_eq!(current_recipient(&first), Some("gemuese@example.org"));
+        assert_eq!(current_recipient(&second), Some("unbekannt@example.org"));
+        // The known address was accepted, the unknown one is refused on its own.
+        let known = Some(mailing::RouteMatch::Address);
+        assert!(rcpt_route_decision(known, false).0);
+        assert!(!rcpt_route_decision(None, false).0);
+    }
+
+    #[test]
+    fn strict_mode_ignores_a_catch_all_domain_route() {
+        use mailing::RouteMatch::{Address, Domain};
+        assert_eq!(
+            rcpt_route_decision(Some(Domain), true),
+            (false, "domain route ignored (strict)")
+        );
+        assert_eq!(
+            rcpt_route_decision(Some(Domain), false),
+            (true, "found by domain route")
+        );
+        assert_eq!(rcpt_route_decision(Some(Address), true), (true, "found"));
+        assert_eq!(rcpt_route_decision(None, true), (false, "not found"));
+    }
+
+    fn poster(account_id: Option<u64>) -> Poster<'static> {
+        Poster {
+            account_id,
+            whitelisted: false,
+            admin: false,
+            explicitly_allowed: &[],
+        }
+    }
+
+    #[test]
+    fn open_category_accepts_a_non_subscriber() {
+        let not_subscribed = |_| false;
+        for sender in [poster(Some(42)), poster(None)] {
+            assert_eq!(
+                posting_permission(&sender, 1, &[1], not_subscribed),
+                Posting::Exempt
+            );
+        }
+        assert_eq!(
+            posting_permission(&poster(Some(42)), 2, &[1], not_subscribed),
+            Posting::NotSubscribed(42)
+        );
+        assert_eq!(
+            posting_permission(&poster(None), 2, &[1], not_subscribed),
+            Posting::External
+        );
+        assert_eq!(
+            posting_permission(&poster(Some(42)), 2, &[1], |id| id == 42),
+            Posting::Subscriber
+        );
+    }
+
+    #[test]
+    fn whitelisted_sender_needs_no_subscription() {
+        let whitelisted = |account_id| Poster {
+            whitelisted: true,
+            ..poster(account_id)
+        };
+        for sender in [whitelisted(Some(42)), whitelisted(None)] {
+            assert_eq!(
+                posting_permission(&sender, 1, &[], |_| false),
+                Posting::Exempt
+            );
+        }
+    }
+
+    #[test]
+    fn recipient_limit_accepts_exactly_the_limit() {
+        let limit = max_recipients();
+        // The `count`th RCPT TO of one transaction, to a category address.
+        let rcpt = |count: usize| {
+            let to: Vec<String> = (1..=count)
+                .map(|n| format!("liste{n}@example.org"))
+                .collect();
+            let check = rcpt_check(&request("rcpt", &to.join(",")), limit, false, |_| {
+                Some(mailing::RouteMatch::Address)
+            });
+            (check.action(), check.details, check.outcome)
+        };
+
+        assert_eq!(
+            rcpt(limit),
+            ("accept", "Category validation: found".to_string(), Ok(()))
+        );
+
+        let (action, details, outcome) = rcpt(limit + 1);
+        assert_eq!(
+            (action, details),
+            (
+                "reject",
+                format!("Too many recipients: {} > {limit}", limit + 1)
+            )
+        );
+        let rejection = outcome.unwrap_err();
+        assert_eq!(rejection, StageRejection::new(RejectReason::RecipientLimit));
+        assert_eq!(rejection_action(rejection.code), "defer");
+        // What the HTTP hook answers
+        let response = stalwart_mta_hook_types::Response::reject(rejection.code, rejection.message);
+        let response = serde_json::to_value(response).unwrap();
+        assert_eq!(response["action"], "reject");
+        assert_eq!(response["response"]["status"], 452);
+    }
+
+    #[test]
+    fn rejected_check_is_logged_under_the_key_it_is_counted_under() {
+        let request = request("connect", "gemuese@example.org");
+        let rejected = connect_check(true);
+        let counted = StageDecision::from_outcome(rejected.outcome.clone()).reject_reason;
+        let row = rejected.log_entry(&request, "req-1", Timestamp::UNIX_EPOCH);
+        assert_eq!(
+            Some(row.reject_reason.as_str()),
+            counted.map(RejectReason::key)
+        );
+        assert_eq!(row.reject_reason, "IpBlocked");
+
+        let accepted = connect_check(false).log_entry(&request, "req-1", Timestamp::UNIX_EPOCH);
+        assert!(accepted.reject_reason.is_empty());
+    }
+
+    #[test]
+    fn counted_message_log_row_carries_the_reason_key() {
+        let row = message_log().counted_as(Some(RejectReason::RateLimited));
+        assert_eq!(row.reject_reason, "RateLimited");
+        assert!(message_log().counted_as(None).reject_reason.is_empty());
+    }
+
+    #[test]
+    fn every_stage_records_its_decision() {
+        let rcpt = request("rcpt", "gemuese@example.org");
+        let two_rcpt = request("rcpt", "gemuese@example.org, brot@example.org");
+        let mut no_sender = request("mail", "gemuese@example.org");
+        if let Some(envelope) = no_sender.envelope.as_mut() {
+            envelope.from.address = "alice".to_string();
+        }
+        let address = |_: &str| Some(mailing::RouteMatch::Address);
+        let nowhere = |_: &str| None;
+        let rate_limited = StageRejection::new(RejectReason::RateLimited);
+        let checks = |check: StageCheck| StageDecision::from_outcome(check.outcome);
+        let cases = [
+            ("connect", checks(connect_check(false)), ("accept", 250)),
+            ("connect", checks(connect_check(true)), ("reject", 550)),
+            (
+                "ehlo",
+                checks(ehlo_check(Some("mta-test.example.org"), "strict")),
+                ("accept", 250),
+            ),
+            (
+                "ehlo",
+                checks(ehlo_check(Some("localhost"), "strict")),
+                ("reject", 550),
+            ),
+            (
+                "ehlo",
+                checks(ehlo_check(None, "permissive")),
+                ("accept", 250),
+            ),
+            ("ehlo", checks(ehlo_check(None, "strict")), ("reject", 550)),
+            (
+                "ehlo",
+                checks(ehlo_check(Some(" "), "permissive")),
+                ("reject", 501),
+            ),
+            (
+                "mail",
+                checks(mail_check(&request("mail", "gemuese@example.org"))),
+                ("accept", 250),
+            ),
+            ("mail", checks(mail_check(&no_sender)), ("reject", 550)),
+            (
+                "rcpt",
+                checks(rcpt_check(&rcpt, 100, false, address)),
+                ("accept", 250),
+            ),
+            (
+                "rcpt",
+                checks(rcpt_check(&rcpt, 100, false, nowhere)),
+                ("reject", 550),
+            ),
+            (
+                "rcpt",
+                checks(rcpt_check(&two_rcpt, 1, false, address)),
+                ("defer", 452),
+            ),
+            (
+                "data",
+                data_decision(Ok(DataAcceptance::default())),
+                ("accept", 250),
+            ),
+            ("data", data_decision(Err(rate_limited)), ("defer", 451)),
+            (
+                "data",
+                data_decision(Err(StageRejection::new(RejectReason::SenderNotPermitted))),
+                ("reject", 550),
+            ),
+            ("auth", checks(auth_check()), ("accept", 250)),
+        ];
+        let maintenance = ["connect", "ehlo", "mail", "rcpt", "data", "auth"].map(|stage| {
+            let deferral = Some(StageRejection::new(RejectReason::Maintenance));
+            (
+                stage,
+                stage_decision(deferral, StageDecision::accept),
+                ("defer", 451),
+            )
+        });
+        for (stage, decision, expected) in cases.into_iter().chain(maintenance) {
+            let dry_run = decision.dry_run;
+            let row = decision_row(
+                "req-1".to_string(),
+                Identity::ZERO,
+                &request(stage, "gemuese@example.org"),
+                decision,
+                Timestamp::UNIX_EPOCH,
+                dry_run,
+            );
+            assert_eq!(row.stage, stage);
+            assert_eq!((row.action.as_str(), row.code), expected, "{stage}");
+            assert_eq!(row.request_id, "req-1");
+            assert_eq!(row.queue_id.as_deref(), Some("Q1"));
+        }
+    }
+
+    #[test]
+    fn matched_categories_are_those_of_the_subscribed_sender() {
+        let mut categories = vec![
+            (1, "gemuese@example.org".to_string()),
+            (2, "vorstand@example.org".to_string()),
+            (3, "brot@example.org".to_string()),
+        ];
+        let subscribed = |account_id: u64, category_id: u64| account_id == 42 && category_id != 2;
+        retain_permitted(
+            &mut categories,
+            &poster(Some(42)),
+            "alice@example.org",
+            &[],
+            subscribed,
+        );
+        assert_eq!(matched_categories_json(&categories), "[1,3]");
+    }
+
+    #[test]
+    fn feed_import_skips_blocked_and_invalid_entries() {
+        let feed = [
+            "198.51.100.7",
+            "2001:db8::1",
+            "203.0.113.9",
+            "203.0.113.0/24",
+            "kein-ip",
+        ];
+        let blocked = ["203.0.113.9"];
+        let actions: Vec<BlockImport> = feed
+            .iter()
+            .map(|ip| block_import_action(ip, |ip| blocked.contains(&ip)))
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                BlockImport::Insert,
+                BlockImport::Insert,
+                BlockImport::AlreadyBlocked,
+                BlockImport::Invalid,
+                BlockImport::Invalid,
+            ]
+        );
+    }
+
+    #[test]
+    fn message_log_export_honors_the_range_strictly() {
+        let at = Timestamp::from_micros_since_unix_epoch;
+        // Out of id order, as the table iterates
+        let rows = || {
+            [3, 1, 4, 2, 5].into_iter().map(|id| MtaMessageLog {
+                id,
+                timestamp: at(id as i64 * 1_000),
+                ..message_log()
+            })
+        };
+        let (count, parts) = message_log_export(rows(), at(2_000), at(4_000), 1 << 20);
+        assert_eq!((count, parts.len()), (2, 1));
+        let ids: Vec<u64> = parts[0]
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .map(|row| row["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, [2, 3]);
+
+        // Small parts hold one line each; an empty range leaves none.
+        let (count, parts) = message_log_export(rows(), at(0), at(10_000), 1);
+        assert_eq!((count, parts.len()), (5, 5));
+        assert_eq!(
+            message_log_export(rows(), at(6_000), at(7_000), 1 << 20),
+            (0, vec![])
+        );
+    }
+
+    #[test]
+    fn maintenance_mode_overrides_accept_and_reject() {
+        let maintenance = || Some(StageRejection::new(RejectReason::Maintenance));
+        let rejected =
+            || StageDecision::from_outcome::<()>(Err(StageRejection::new(RejectReason::IpBlocked)));
+        let runs: [fn() -> StageDecision; 2] = [StageDecision::accept, rejected];
+        for run in runs {
+            let decision = stage_decision(maintenance(), run);
+            assert_eq!((decision.action, decision.code), ("defer", 451));
+        }
+        let mut ran = false;
+        stage_decision(maintenance(), || {
+            ran = true;
+            StageDecision::accept()
+        });
+        assert!(!ran, "the stage's checks run in maintenance mode");
+        assert_eq!(stage_decision(None, rejected).action, "reject");
+    }
+}
@@ -0,0 +1,90 @@
+use spacetimedb::{Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{admin_identities__view, is_admin_user};
+
+/// Whether notification payloads carry full email addresses. Off by default: addresses are
+/// reduced to their domain. Set at build time via `NOTIFY_INCLUDE_ADDRESSES=true`.
+fn include_addresses() -> bool {
+    option_env!("NOTIFY_INCLUDE_ADDRESSES") == Some("true")
+}
+
+/// An outbound alert for admins, e.g. a quarantined or rejected message. The sender service
+/// POSTs undelivered rows to `NOTIFICATION_WEBHOOK_URL` and marks them delivered.
+#[spacetimedb::table(accessor = notification_out)]
+pub struct NotificationOut {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// `quarantine` or `reject`
+    pub kind: String,
+    /// JSON object with `reason`, `categories`, `from`, `to`
+    pub payload: String,
+    #[index(btree)]
+    pub created_at: Timestamp,
+    pub delivered_at: Option<Timestamp>,
+}
+
+/// Returns all notifications for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_notifications, public)]
+pub fn visible_notifications(ctx: &ViewContext) -> impl Query<NotificationOut> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.notification_out().r#filter(move |_| is_admin)
+}
+
+/// `user@example.org` → `***@example.org`, unless full addresses are enabled.
+fn redact(address: &str) -> String {
+    if include_addresses() {
+        return address.to_string();
+    }
+    match address.rsplit_once('@') {
+        Some((_, domain)) => format!("***@{domain}"),
+        None => "***".to_string(),
+    }
+}
+
+/// Queue a notification about a message the DATA stage did not deliver.
+pub(crate) fn notify_message_event(
+    ctx: &ReducerContext,
+    kind: &str,
+    reason: &str,
+    categories: &[String],
+    from_address: &str,
+    to_addresses: &[String],
+) {
+    let payload = serde_json::json!({
+        "reason": reason,
+        "categories": categories,
+        "from": redact(from_address),
+        "to": to_addresses.iter().map(|a| redact(a)).collect::<Vec<_>>(),
+    });
+    ctx.db.notification_out().insert(NotificationOut {
+        id: 0,
+        kind: kind.to_string(),
+        payload: payload.to_string(),
+        created_at: ctx.timestamp,
+        delivered_at: None,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn mark_notification_delivered(ctx: &ReducerContext, id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let notification = ctx
+        .db
+        .notification_out()
+        .id()
+        .find(&id)
+        .ok_or_else(|| format!("Notification {} not found", id))?;
+    ctx.db.notification_out().id().update(NotificationOut {
+        delivered_at: Some(ctx.timestamp),
+        ..notification
+    });
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::check_request_owner;
+
+/// `ping_result` rows older than this are deleted on the next ping. Longer than any sensible
+/// client timeout, so a slow answer is still there when the client looks for it.
+const PING_RESULT_TTL: Duration = Duration::from_secs(300);
+
+/// Answer of `ping`, keyed by the caller-chosen `request_id`. A client that sees its row appear
+/// knows the whole path works: its connection, reducer execution and subscription updates.
+#[spacetimedb::table(accessor = ping_result)]
+pub struct PingResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    #[index(btree)]
+    pub pinged_at: Timestamp,
+}
+
+/// The caller's own ping answers.
+#[spacetimedb::view(accessor = my_ping_results, public)]
+pub fn my_ping_results(ctx: &ViewContext) -> Vec<PingResult> {
+    ctx.db
+        .ping_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Round-trip check: write a `ping_result` row for `request_id`. Drops answers older than
+/// `PING_RESULT_TTL`, so the table stays small. Open to every caller; each only sees its own rows
+/// and may not reuse a `request_id` another caller's answer still holds.
+#[spacetimedb::reducer]
+pub fn ping(ctx: &ReducerContext, request_id: String) -> Result<(), String> {
+    let request_id = request_id.trim().to_string();
+    if request_id.is_empty() {
+        return Err("request_id must not be empty".to_string());
+    }
+    let expired: Vec<String> = ctx
+        .db
+        .ping_result()
+        .pinged_at()
+        .filter(..ping_cutoff(ctx.timestamp))
+        .map(|row| row.request_id)
+        .collect();
+    for id in expired {
+        ctx.db.ping_result().request_id().delete(&id);
+    }
+
+    let existing = ctx.db.ping_result().request_id().find(&request_id);
+    check_request_owner(
+        existing.as_ref().map(|row| row.requested_by),
+        ctx.sender(),
+        &request_id,
+    )?;
+    let row = PingResult {
+        request_id,
+        requested_by: ctx.sender(),
+        pinged_at: ctx.timestamp,
+    };
+    if existing.is_some() {
+        ctx.db.ping_result().request_id().update(row);
+    } else {
+        ctx.db.ping_result().insert(row);
+    }
+    Ok(())
+}
+
+/// Answers from before this are older than `PING_RESULT_TTL` at `now`.
+fn ping_cutoff(now: Timestamp) -> Timestamp {
+    let ttl = i64::try_from(PING_RESULT_TTL.as_micros()).unwrap_or(i64::MAX);
+    Timestamp::from_micros_since_unix_epoch(now.to_micros_since_unix_epoch().saturating_sub(ttl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_ping_keeps_its_request_id_until_it_expires() {
+        let (alice, bob) = (Identity::ZERO, Identity::from_byte_array([1; 32]));
+        assert_eq!(check_request_owner(None, alice, "p-1"), Ok(()));
+        assert_eq!(check_request_owner(Some(alice), alice, "p-1"), Ok(()));
+        assert_eq!(
+            check_request_owner(Some(alice), bob, "p-1"),
+            Err("request_id p-1 belongs to another caller".to_string())
+        );
+
+        let now = Timestamp::from_micros_since_unix_epoch(1_000_000_000);
+        let cutoff = ping_cutoff(now);
+        let pinged = |secs_ago: i64| {
+            Timestamp::from_micros_since_unix_epoch(1_000_000_000 - secs_ago * 1_000_000)
+        };
+        // `..cutoff` is what is pruned
+        assert!(pinged(301) < cutoff);
+        assert!(pinged(300) >= cutoff);
+        assert!(pinged(0) >= cutoff);
+    }
+}
@@ -0,0 +1,337 @@
+use spacetimedb::{Query, ReducerContext, Table, Timestamp, ViewContext};
+
+use crate::account::{admin_identities__view, is_admin_user};
+use crate::mailing::message_categories;
+
+pub const SENDER_RULE_ALLOW: &str = "allow";
+pub const SENDER_RULE_DENY: &str = "deny";
+
+/// Per-category sender filter. `pattern` is an exact address or `*@domain`.
+/// As soon as a category has one `allow` rule it only accepts explicitly allowed senders.
+#[spacetimedb::table(accessor = category_sender_rule)]
+pub struct CategorySenderRule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// FK → MessageCategory.id
+    #[index(btree)]
+    pub category_id: u64,
+    /// Lowercased address or `*@domain`
+    pub pattern: String,
+    /// `allow` or `deny`
+    pub kind: String,
+}
+
+/// Senders that bypass the sender rules, the subscription check and the rate limits at DATA,
+/// e.g. for an urgent announcement from an address that normally can't post. Exact addresses
+/// only; an entry past `expires_at` no longer applies.
+#[spacetimedb::table(accessor = sender_whitelist)]
+pub struct SenderWhitelist {
+    /// Lowercased address
+    #[primary_key]
+    pub email: String,
+    pub expires_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
+impl SenderWhitelist {
+    /// The entry has not expired at `now`.
+    pub(crate) fn applies_at(&self, now: Timestamp) -> bool {
+        self.expires_at.map_or(true, |at| now < at)
+    }
+}
+
+/// Outcome of checking a sender against a category's rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SenderRuleDecision {
+    /// No rule applies; the usual subscription check decides.
+    Unlisted,
+    /// Matched an allow rule; accepted regardless of subscription.
+    Allowed,
+    /// Matched a deny rule, or the category is allow-only and the sender isn't listed.
+    Denied,
+}
+
+/// Returns all sender rules for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_category_sender_rules, public)]
+pub fn visible_category_sender_rules(ctx: &ViewContext) -> impl Query<CategorySenderRule> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.category_sender_rule().r#filter(move |_| is_admin)
+}
+
+/// Returns the sender whitelist for admins; regular users get an empty list.
+#[spacetimedb::view(accessor = visible_sender_whitelist, public)]
+pub fn visible_sender_whitelist(ctx: &ViewContext) -> impl Query<SenderWhitelist> {
+    let is_admin = ctx
+        .db
+        .admin_identities()
+        .identity()
+        .find(&ctx.sender())
+        .is_some();
+    ctx.from.sender_whitelist().r#filter(move |_| is_admin)
+}
+
+#[spacetimedb::reducer]
+pub fn add_category_sender_rule(
+    ctx: &ReducerContext,
+    category_id: u64,
+    pattern: String,
+    kind: String,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if ctx
+        .db
+        .message_categories()
+        .id()
+        .find(&category_id)
+        .is_none()
+    {
+        return Err(format!("Message category {} not found", category_id));
+    }
+    let kind = kind.trim().to_lowercase();
+    if kind != SENDER_RULE_ALLOW && kind != SENDER_RULE_DENY {
+        return Err(format!(
+            "Invalid rule kind '{}': expected '{}' or '{}'",
+            kind, SENDER_RULE_ALLOW, SENDER_RULE_DENY
+        ));
+    }
+    let pattern = pattern.trim().to_lowercase();
+    let valid_pattern = match pattern.strip_prefix("*@") {
+        Some(domain) => !domain.is_empty() && !domain.contains('@'),
+        None => pattern.split_once('@').map_or(false, |(local, domain)| {
+            !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+        }),
+    };
+    if !valid_pattern {
+        return Err(format!(
+            "Invalid sender pattern '{}': expected an address or '*@domain'",
+            pattern
+        ));
+    }
+    if ctx
+        .db
+        .category_sender_rule()
+        .category_id()
+        .filter(&category_id)
+        .any(|r| r.pattern == pattern && r.kind == kind)
+    {
+        return Ok(()); // idempotent
+    }
+    let rule = ctx.db.category_sender_rule().insert(CategorySenderRule {
+        id: 0,
+        category_id,
+        pattern,
+        kind,
+    });
+    log::info!(
+        "Added {} sender rule {} for category {} (by identity: {:?})",
+        rule.kind,
+        rule.id,
+        category_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_category_sender_rule(ctx: &ReducerContext, rule_id: u64) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if ctx.db.category_sender_rule().id().find(&rule_id).is_none() {
+        return Err(format!("Sender rule {} not found", rule_id));
+    }
+    ctx.db.category_sender_rule().id().delete(&rule_id);
+    log::info!(
+        "Removed sender rule {} (by identity: {:?})",
+        rule_id,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Whitelist `email` until `expires_at` (`None` = until removed). Adding an address again
+/// replaces its expiry.
+#[spacetimedb::reducer]
+pub fn add_sender_whitelist(
+    ctx: &ReducerContext,
+    email: String,
+    expires_at: Option<Timestamp>,
+) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let email = email.trim().to_lowercase();
+    let valid = email.split_once('@').map_or(false, |(local, domain)| {
+        !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+    });
+    if !valid {
+        return Err(format!("Invalid sender address '{}'", email));
+    }
+    if expires_at.is_some_and(|at| at <= ctx.timestamp) {
+        return Err("expires_at must be in the future".to_string());
+    }
+    let entry = SenderWhitelist {
+        email: email.clone(),
+        expires_at,
+        created_at: ctx.timestamp,
+    };
+    if ctx.db.sender_whitelist().email().find(&email).is_some() {
+        ctx.db.sender_whitelist().email().update(entry);
+    } else {
+        ctx.db.sender_whitelist().insert(entry);
+    }
+    log::warn!(
+        "Whitelisted sender {} until {:?} (by identity: {:?})",
+        email,
+        expires_at,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_sender_whitelist(ctx: &ReducerContext, email: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let email = email.trim().to_lowercase();
+    if !ctx.db.sender_whitelist().email().delete(&email) {
+        return Err(format!("Sender {} is not whitelisted", email));
+    }
+    log::info!(
+        "Removed sender {} from the whitelist (by identity: {:?})",
+        email,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Whether `sender` has a whitelist entry that hasn't expired at `now`.
+pub(crate) fn is_sender_whitelisted(ctx: &ReducerContext, sender: &str, now: Timestamp) -> bool {
+    let sender = sender.trim().to_lowercase();
+    ctx.db
+        .sender_whitelist()
+        .email()
+        .find(&sender)
+        .is_some_and(|entry| entry.applies_at(now))
+}
+
+fn pattern_matches(pattern: &str, sender: &str) -> bool {
+    match pattern.strip_prefix("*@") {
+        Some(domain) => sender
+            .rsplit_once('@')
+            .map_or(false, |(_, sender_domain)| sender_domain == domain),
+        None => pattern == sender,
+    }
+}
+
+/// Decide on the lowercased `sender` from a category's `(pattern, kind)` rules. Deny rules
+/// win over allow rules.
+fn sender_rule_decision<'a>(
+    rules: impl Iterator<Item = (&'a str, &'a str)>,
+    sender: &str,
+) -> SenderRuleDecision {
+    let mut allow_only = false;
+    let mut allowed = false;
+    for (pattern, kind) in rules {
+        let matches = pattern_matches(pattern, sender);
+        if kind == SENDER_RULE_DENY {
+            if matches {
+                return SenderRuleDecision::Denied;
+            }
+        } else {
+            allow_only = true;
+            allowed |= matches;
+        }
+    }
+    match (allow_only, allowed) {
+        (_, true) => SenderRuleDecision::Allowed,
+        (true, false) => SenderRuleDecision::Denied,
+        (false, false) => SenderRuleDecision::Unlisted,
+    }
+}
+
+/// Check `sender` against the rules of `category_id`. Deny rules win over allow rules.
+pub(crate) fn evaluate_sender_rules(
+    ctx: &ReducerContext,
+    category_id: u64,
+    sender: &str,
+) -> SenderRuleDecision {
+    let sender = sender.trim().to_lowercase();
+    let rules: Vec<CategorySenderRule> = ctx
+        .db
+        .category_sender_rule()
+        .category_id()
+        .filter(&category_id)
+        .collect();
+    sender_rule_decision(
+        rules.iter().map(|r| (r.pattern.as_str(), r.kind.as_str())),
+        &sender,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitelist_entry_applies_until_it_expires() {
+        let at = Timestamp::from_micros_since_unix_epoch;
+        let entry = |expires_at| SenderWhitelist {
+            email: "vorstand@example.org".to_string(),
+            expires_at,
+            created_at: at(0),
+        };
+        assert!(entry(None).applies_at(at(i64::MAX)));
+        assert!(entry(Some(at(1_000))).applies_at(at(999)));
+        assert!(!entry(Some(at(1_000))).applies_at(at(1_000)));
+    }
+
+    #[test]
+    fn patterns_match_exact_addresses_and_whole_domains() {
+        assert!(pattern_matches("chef@example.org", "chef@example.org"));
+        assert!(!pattern_matches("chef@example.org", "kasse@example.org"));
+        assert!(pattern_matches("*@example.org", "kasse@example.org"));
+        assert!(!pattern_matches("*@example.org", "kasse@sub.example.org"));
+        assert!(!pattern_matches("*@example.org", "example.org"));
+    }
+
+    #[test]
+    fn allow_only_category_rejects_unlisted_senders() {
+        let rules = [("vorstand@example.org", SENDER_RULE_ALLOW)];
+        let decide = |sender| sender_rule_decision(rules.into_iter(), sender);
+        assert_eq!(decide("vorstand@example.org"), SenderRuleDecision::Allowed);
+        assert_eq!(decide("mitglied@example.org"), SenderRuleDecision::Denied);
+    }
+
+    #[test]
+    fn deny_list_category_rejects_only_listed_senders() {
+        let rules = [
+            ("*@spam.example", SENDER_RULE_DENY),
+            ("troll@example.org", SENDER_RULE_DENY),
+        ];
+        let decide = |sender| sender_rule_decision(rules.into_iter(), sender);
+        assert_eq!(decide("x@spam.example"), SenderRuleDecision::Denied);
+        assert_eq!(decide("troll@example.org"), SenderRuleDecision::Denied);
+        assert_eq!(decide("mitglied@example.org"), SenderRuleDecision::Unlisted);
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let rules = [
+            ("*@example.org", SENDER_RULE_ALLOW),
+            ("troll@example.org", SENDER_RULE_DENY),
+        ];
+        let decide = |sender| sender_rule_decision(rules.into_iter(), sender);
+        assert_eq!(decide("troll@example.org"), SenderRuleDecision::Denied);
+        assert_eq!(decide("kasse@example.org"), SenderRuleDecision::Allowed);
+    }
+}
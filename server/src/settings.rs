@@ -0,0 +1,155 @@
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::account::is_admin_user;
+
+/// Primary key of the only `module_settings` row.
+const SETTINGS_ID: u32 = 0;
+
+/// Runtime switches of the module, stored in a single row with `id = 0`.
+/// Missing until an admin changes a setting for the first time; `current` then returns the defaults.
+#[derive(Clone)]
+#[spacetimedb::table(accessor = module_settings, public)]
+pub struct ModuleSettings {
+    #[primary_key]
+    pub id: u32,
+    /// When on, every MTA stage defers with 451 so senders retry later (e.g. during deploys).
+    pub maintenance_mode: bool,
+    /// When on, rejections are only logged (as `dry-run`) and the MTA is told to accept, so
+    /// changed routing rules can be watched against live traffic before they are enforced.
+    #[default(false)]
+    pub dry_run: bool,
+    /// When on, Django syncs are queued in `pending_sync` instead of applied (e.g. during a
+    /// Django migration); `flush_pending_syncs` applies them after resuming.
+    #[default(false)]
+    pub sync_paused: bool,
+    /// Share of DATA messages, in per mille, whose full envelope and headers are kept in
+    /// `envelope_sample` (e.g. for spam-model training). 0 = off.
+    #[default(0)]
+    pub envelope_sample_permille: u32,
+    /// When on, RCPT rejects every recipient that no category address matches, ignoring
+    /// `domain_route` catch-alls; a lockdown for incidents that, unlike maintenance mode, keeps
+    /// mail to known addresses flowing. Rejections still count as dry-run while `dry_run` is on.
+    #[default(false)]
+    pub reject_unknown_recipients_strict: bool,
+    pub updated_at: Timestamp,
+}
+
+impl ModuleSettings {
+    fn defaults(now: Timestamp) -> Self {
+        Self {
+            id: SETTINGS_ID,
+            maintenance_mode: false,
+            dry_run: false,
+            sync_paused: false,
+            envelope_sample_permille: 0,
+            reject_unknown_recipients_strict: false,
+            updated_at: now,
+        }
+    }
+}
+
+/// The settings row, or the defaults if none has been written yet.
+pub(crate) fn current(ctx: &ReducerContext) -> ModuleSettings {
+    ctx.db
+        .module_settings()
+        .id()
+        .find(&SETTINGS_ID)
+        .unwrap_or_else(|| ModuleSettings::defaults(ctx.timestamp))
+}
+
+/// Apply `change` to the settings row and store it, creating the row on first use.
+fn update(ctx: &ReducerContext, change: impl FnOnce(&mut ModuleSettings)) {
+    let mut settings = current(ctx);
+    change(&mut settings);
+    settings.updated_at = ctx.timestamp;
+    if ctx.db.module_settings().id().find(&SETTINGS_ID).is_some() {
+        ctx.db.module_settings().id().update(settings);
+    } else {
+        ctx.db.module_settings().insert(settings);
+    }
+}
+
+/// Switch maintenance mode on or off. Only admins.
+#[spacetimedb::reducer]
+pub fn set_maintenance_mode(ctx: &ReducerContext, on: bool) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    update(ctx, |settings| settings.maintenance_mode = on);
+    log::warn!(
+        "Maintenance mode {} (by identity: {:?})",
+        if on { "enabled" } else { "disabled" },
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Switch dry-run mode on or off. Only admins.
+#[spacetimedb::reducer]
+pub fn set_dry_run(ctx: &ReducerContext, on: bool) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    update(ctx, |settings| settings.dry_run = on);
+    log::warn!(
+        "Dry-run mode {} (by identity: {:?})",
+        if on { "enabled" } else { "disabled" },
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Pause or resume the Django sync intake. Resuming does not apply the queued syncs; call
+/// `flush_pending_syncs` for that. Only admins.
+#[spacetimedb::reducer]
+pub fn set_sync_paused(ctx: &ReducerContext, paused: bool) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    update(ctx, |settings| settings.sync_paused = paused);
+    log::warn!(
+        "Django sync {} (by identity: {:?})",
+        if paused { "paused" } else { "resumed" },
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Switch strict recipient checking on or off. Only admins.
+#[spacetimedb::reducer]
+pub fn set_reject_unknown_recipients_strict(ctx: &ReducerContext, on: bool) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    update(ctx, |settings| {
+        settings.reject_unknown_recipients_strict = on
+    });
+    log::warn!(
+        "Strict recipient checking {} (by identity: {:?})",
+        if on { "enabled" } else { "disabled" },
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Set the share of DATA messages, in per mille (0–1000), that `envelope_sample` records.
+/// 0 switches sampling off. Only admins.
+#[spacetimedb::reducer]
+pub fn set_envelope_sample_rate(ctx: &ReducerContext, permille: u32) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    if permille > 1000 {
+        return Err(format!(
+            "Invalid sample rate {}: expected 0 to 1000 per mille",
+            permille
+        ));
+    }
+    update(ctx, |settings| settings.envelope_sample_permille = permille);
+    log::info!(
+        "Envelope sample rate set to {}‰ (by identity: {:?})",
+        permille,
+        ctx.sender()
+    );
+    Ok(())
+}
@@ -0,0 +1,638 @@
+use std::collections::BTreeMap;
+
+use spacetimedb::{
+    Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp, ViewContext,
+};
+
+use crate::account::{account, account_alias, check_request_owner, is_admin_user};
+use crate::auth_failures::auth_failure;
+use crate::complaints::complaint;
+use crate::delivery::{mail_deliveries, mail_delivery_events, mail_ingress};
+use crate::digest::digest_out;
+use crate::forwarding::relay_out;
+use crate::logins::login_event;
+use crate::mailing::{message_categories, subscriptions};
+use crate::mta::{
+    blocked_ips, mta_connection_log, mta_message_log, received_message, RejectReason,
+};
+use crate::notifications::notification_out;
+
+const SECS_PER_DAY: i64 = 86_400;
+const SECS_PER_HOUR: i64 = 3_600;
+
+/// `reject_reason_stats` keeps this many hourly buckets; older ones are pruned.
+const REJECT_STATS_WINDOW_HOURS: u64 = 24;
+
+/// Per-day counters of MTA decisions, so the dashboard doesn't have to scan the log tables.
+/// Counts connect-stage and data-stage decisions.
+#[spacetimedb::table(accessor = mta_daily_stats, public)]
+pub struct MtaDailyStats {
+    /// Days since the Unix epoch (`timestamp / 86400`)
+    #[primary_key]
+    pub day: u64,
+    pub accept_count: u64,
+    pub reject_count: u64,
+    pub quarantine_count: u64,
+}
+
+/// Hourly counters of MTA rejections per reason, for the last `REJECT_STATS_WINDOW_HOURS` hours.
+#[spacetimedb::table(accessor = reject_reason_stats, public)]
+pub struct RejectReasonStat {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Hours since the Unix epoch (`timestamp / 3600`)
+    #[index(btree)]
+    pub hour: u64,
+    /// `RejectReason::key` of the rejection, not the SMTP text, which may name a category
+    pub reason: String,
+    pub count: u64,
+}
+
+/// How many hours of `stage_timing` rows are kept, set at build time via
+/// `STAGE_TIMING_WINDOW_HOURS`.
+fn stage_timing_window_hours() -> u64 {
+    option_env!("STAGE_TIMING_WINDOW_HOURS")
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|h| *h > 0)
+        .unwrap_or(24)
+}
+
+/// Processing time of one MTA hook request on the HTTP route, from the start of the handler
+/// to its last transaction. Rows older than `STAGE_TIMING_WINDOW_HOURS` are pruned on insert.
+#[spacetimedb::table(accessor = stage_timing)]
+pub struct StageTiming {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    /// Hours since the Unix epoch (`timestamp / 3600`)
+    #[index(btree)]
+    pub hour: u64,
+    /// `connect`, `ehlo`, `mail`, `rcpt`, `data` or `auth`
+    pub stage: String,
+    pub elapsed_micros: u64,
+    pub recorded_at: Timestamp,
+}
+
+/// Answer of `stage_timing_percentiles`, keyed by the caller-chosen `request_id`. One entry
+/// per stage in the parallel vectors, stages in alphabetical order.
+#[spacetimedb::table(accessor = stage_timing_result)]
+pub struct StageTimingResult {
+    #[primary_key]
+    pub request_id: String,
+    #[index(btree)]
+    pub requested_by: Identity,
+    pub stages: Vec<String>,
+    /// Number of measurements of each stage
+    pub samples: Vec<u64>,
+    pub p50_micros: Vec<u64>,
+    pub p95_micros: Vec<u64>,
+    pub computed_at: Timestamp,
+}
+
+/// The caller's own `stage_timing_percentiles` answers.
+#[spacetimedb::view(accessor = my_stage_timing_results, public)]
+pub fn my_stage_timing_results(ctx: &ViewContext) -> Vec<StageTimingResult> {
+    ctx.db
+        .stage_timing_result()
+        .requested_by()
+        .filter(&ctx.sender())
+        .collect()
+}
+
+/// Messages accepted per category in the current clock hour, for
+/// `MessageCategory.rate_limit_per_hour`. One row per category; it starts over at 0 when
+/// `hour` has passed.
+#[spacetimedb::table(accessor = category_hourly_count)]
+pub struct CategoryHourlyCount {
+    #[primary_key]
+    pub category_id: u64,
+    /// Hours since the Unix epoch (`timestamp / 3600`)
+    pub hour: u64,
+    pub count: u32,
+}
+
+/// How often `record_table_sizes` takes a snapshot.
+fn table_size_period() -> TimeDuration {
+    TimeDuration::from_micros(SECS_PER_DAY * 1_000_000)
+}
+
+/// Row counts of the larger tables over time, one row per table and snapshot, for capacity
+/// planning and tuning the pruning intervals.
+#[spacetimedb::table(accessor = table_size_history, public)]
+pub struct TableSizeHistory {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub table_name: String,
+    pub count: u64,
+    pub timestamp: Timestamp,
+}
+
+/// Repeating timer row that drives `record_table_sizes`.
+#[spacetimedb::table(accessor = table_size_schedule, scheduled(record_table_sizes))]
+pub struct TableSizeSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+fn hour_bucket(timestamp: Timestamp) -> u64 {
+    let secs = timestamp.to_micros_since_unix_epoch() / 1_000_000;
+    (secs / SECS_PER_HOUR).max(0) as u64
+}
+
+fn day_bucket(timestamp: Timestamp) -> u64 {
+    let secs = timestamp.to_micros_since_unix_epoch() / 1_000_000;
+    (secs / SECS_PER_DAY).max(0) as u64
+}
+
+fn bump(row: &mut MtaDailyStats, action: &str) {
+    match action {
+        "accept" => row.accept_count += 1,
+        "reject" => row.reject_count += 1,
+        "quarantine" => row.quarantine_count += 1,
+        _ => {}
+    }
+}
+
+/// Count one MTA decision (`accept`, `reject` or `quarantine`) in its day bucket.
+pub(crate) fn record_mta_action(ctx: &ReducerContext, timestamp: Timestamp, action: &str) {
+    let day = day_bucket(timestamp);
+    match ctx.db.mta_daily_stats().day().find(&day) {
+        Some(mut row) => {
+            bump(&mut row, action);
+            ctx.db.mta_daily_stats().day().update(row);
+        }
+        None => {
+            let mut row = MtaDailyStats {
+                day,
+                accept_count: 0,
+                reject_count: 0,
+                quarantine_count: 0,
+            };
+            bump(&mut row, action);
+            ctx.db.mta_daily_stats().insert(row);
+        }
+    }
+}
+
+/// Count one rejection under `reason` in its hour bucket, and drop buckets that have left the
+/// window relative to `now`.
+fn count_reject_reason(ctx: &ReducerContext, now: Timestamp, timestamp: Timestamp, reason: &str) {
+    let current = hour_bucket(now);
+    let cutoff = current.saturating_sub(REJECT_STATS_WINDOW_HOURS - 1);
+    let expired: Vec<u64> = ctx
+        .db
+        .reject_reason_stats()
+        .hour()
+        .filter(..cutoff)
+        .map(|r| r.id)
+        .collect();
+    for id in expired {
+        ctx.db.reject_reason_stats().id().delete(&id);
+    }
+
+    let hour = hour_bucket(timestamp);
+    if hour < cutoff {
+        return;
+    }
+    let existing = ctx
+        .db
+        .reject_reason_stats()
+        .hour()
+        .filter(&hour)
+        .find(|r| r.reason == reason);
+    match existing {
+        Some(mut row) => {
+            row.count += 1;
+            ctx.db.reject_reason_stats().id().update(row);
+        }
+        None => {
+            ctx.db.reject_reason_stats().insert(RejectReasonStat {
+                id: 0,
+                hour,
+                reason: reason.to_string(),
+                count: 1,
+            });
+        }
+    }
+}
+
+/// Messages accepted for `category_id` so far in the hour of `now`.
+pub(crate) fn category_count_this_hour(
+    ctx: &ReducerContext,
+    category_id: u64,
+    now: Timestamp,
+) -> u32 {
+    let row = ctx
+        .db
+        .category_hourly_count()
+        .category_id()
+        .find(&category_id);
+    count_in_hour(row.as_ref(), now)
+}
+
+/// The count of `row` if it is of the hour of `now`, else 0.
+fn count_in_hour(row: Option<&CategoryHourlyCount>, now: Timestamp) -> u32 {
+    row.filter(|row| row.hour == hour_bucket(now))
+        .map_or(0, |row| row.count)
+}
+
+/// Whether a category that may take `limit` messages per hour is full after `count`.
+pub(crate) fn rate_limit_reached(count: u32, limit: u32) -> bool {
+    count >= limit
+}
+
+/// Count one accepted message for `category_id` in the hour of `now`.
+pub(crate) fn record_category_message(ctx: &ReducerContext, category_id: u64, now: Timestamp) {
+    let existing = ctx
+        .db
+        .category_hourly_count()
+        .category_id()
+        .find(&category_id);
+    let exists = existing.is_some();
+    let row = counted(existing, category_id, now);
+    if exists {
+        ctx.db.category_hourly_count().category_id().update(row);
+    } else {
+        ctx.db.category_hourly_count().insert(row);
+    }
+}
+
+/// `row` after counting one more message at `now`; a row of an earlier hour starts over.
+fn counted(
+    row: Option<CategoryHourlyCount>,
+    category_id: u64,
+    now: Timestamp,
+) -> CategoryHourlyCount {
+    let hour = hour_bucket(now);
+    let count = count_in_hour(row.as_ref(), now) + 1;
+    CategoryHourlyCount {
+        category_id,
+        hour,
+        count,
+    }
+}
+
+/// Count one MTA rejection under the key of `reason` in `reject_reason_stats`.
+pub(crate) fn record_reject_reason(
+    ctx: &ReducerContext,
+    timestamp: Timestamp,
+    reason: RejectReason,
+) {
+    count_reject_reason(ctx, timestamp, timestamp, reason.key());
+}
+
+/// Rebuild `reject_reason_stats` from the rows of the last `REJECT_STATS_WINDOW_HOURS` hours in
+/// `mta_connection_log` and `mta_message_log` that carry a `reject_reason`, the key the
+/// rejection was counted under when it happened. Only admins may call this.
+#[spacetimedb::reducer]
+pub fn recompute_reject_stats(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let ids: Vec<u64> = ctx.db.reject_reason_stats().iter().map(|r| r.id).collect();
+    for id in ids {
+        ctx.db.reject_reason_stats().id().delete(&id);
+    }
+
+    let connect_rejects: Vec<(Timestamp, String)> = ctx
+        .db
+        .mta_connection_log()
+        .iter()
+        .filter(|l| !l.reject_reason.is_empty())
+        .map(|l| (l.timestamp, l.reject_reason))
+        .collect();
+    let message_rejects: Vec<(Timestamp, String)> = ctx
+        .db
+        .mta_message_log()
+        .iter()
+        .filter(|l| !l.reject_reason.is_empty())
+        .map(|l| (l.timestamp, l.reject_reason))
+        .collect();
+    let total = connect_rejects.len() + message_rejects.len();
+    for (timestamp, reason) in connect_rejects.into_iter().chain(message_rejects) {
+        count_reject_reason(ctx, ctx.timestamp, timestamp, &reason);
+    }
+    log::info!(
+        "Recomputed reject reason stats from {} log entries (by identity: {:?})",
+        total,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Rebuild `mta_daily_stats` from the connect entries in `mta_connection_log` and all of
+/// `mta_message_log`. Only admins may call this.
+#[spacetimedb::reducer]
+pub fn recompute_mta_stats(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let days: Vec<u64> = ctx.db.mta_daily_stats().iter().map(|r| r.day).collect();
+    for day in days {
+        ctx.db.mta_daily_stats().day().delete(&day);
+    }
+
+    let connect_entries: Vec<(Timestamp, String)> = ctx
+        .db
+        .mta_connection_log()
+        .iter()
+        .filter(|l| l.stage == "connect")
+        .map(|l| (l.timestamp, l.action))
+        .collect();
+    let message_entries: Vec<(Timestamp, String)> = ctx
+        .db
+        .mta_message_log()
+        .iter()
+        .map(|l| (l.timestamp, l.action))
+        .collect();
+    let total = connect_entries.len() + message_entries.len();
+    for (timestamp, action) in connect_entries.into_iter().chain(message_entries) {
+        record_mta_action(ctx, timestamp, &action);
+    }
+    log::info!(
+        "Recomputed MTA daily stats from {} log entries (by identity: {:?})",
+        total,
+        ctx.sender()
+    );
+    Ok(())
+}
+
+/// Insert the repeating table size timer unless one already exists. Called from `init` and on
+/// every client connection, so a module that was updated rather than published fresh gets it
+/// too; with the timer in place this is a single-row check.
+pub(crate) fn ensure_table_size_schedule(ctx: &ReducerContext) {
+    if ctx.db.table_size_schedule().iter().next().is_some() {
+        return;
+    }
+    ctx.db.table_size_schedule().insert(TableSizeSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(table_size_period()),
+    });
+    log::info!(
+        "Scheduled table size snapshot every {:?}",
+        table_size_period()
+    );
+}
+
+/// Tables whose row count `record_table_sizes` appends to `table_size_history`.
+const TRACKED_TABLES: [&str; 19] = [
+    "account",
+    "account_alias",
+    "subscriptions",
+    "message_categories",
+    "blocked_ips",
+    "auth_failure",
+    "complaint",
+    "login_event",
+    "mta_connection_log",
+    "mta_message_log",
+    "received_message",
+    "mail_ingress",
+    "mail_deliveries",
+    "mail_delivery_events",
+    "relay_out",
+    "digest_out",
+    "notification_out",
+    "table_size_history",
+    "stage_timing",
+];
+
+/// Row count of the tracked table `table_name`, `None` for a name without a table here.
+/// Uses `count()`, so no table is scanned.
+fn tracked_table_count(ctx: &ReducerContext, table_name: &str) -> Option<u64> {
+    Some(match table_name {
+        "account" => ctx.db.account().count(),
+        "account_alias" => ctx.db.account_alias().count(),
+        "subscriptions" => ctx.db.subscriptions().count(),
+        "message_categories" => ctx.db.message_categories().count(),
+        "blocked_ips" => ctx.db.blocked_ips().count(),
+        "auth_failure" => ctx.db.auth_failure().count(),
+        "complaint" => ctx.db.complaint().count(),
+        "login_event" => ctx.db.login_event().count(),
+        "mta_connection_log" => ctx.db.mta_connection_log().count(),
+        "mta_message_log" => ctx.db.mta_message_log().count(),
+        "received_message" => ctx.db.received_message().count(),
+        "mail_ingress" => ctx.db.mail_ingress().count(),
+        "mail_deliveries" => ctx.db.mail_deliveries().count(),
+        "mail_delivery_events" => ctx.db.mail_delivery_events().count(),
+        "relay_out" => ctx.db.relay_out().count(),
+        "digest_out" => ctx.db.digest_out().count(),
+        "notification_out" => ctx.db.notification_out().count(),
+        "table_size_history" => ctx.db.table_size_history().count(),
+        "stage_timing" => ctx.db.stage_timing().count(),
+        _ => return None,
+    })
+}
+
+/// One `table_size_history` row per `(table, count)` of `counts`, taken at `timestamp`.
+fn table_size_rows(counts: &[(&str, u64)], timestamp: Timestamp) -> Vec<TableSizeHistory> {
+    counts
+        .iter()
+        .map(|(table_name, count)| TableSizeHistory {
+            id: 0,
+            table_name: table_name.to_string(),
+            count: *count,
+            timestamp,
+        })
+        .collect()
+}
+
+/// Scheduled reducer: append the current row count of every table in `TRACKED_TABLES` to
+/// `table_size_history`.
+#[spacetimedb::reducer]
+pub fn record_table_sizes(
+    ctx: &ReducerContext,
+    _schedule: TableSizeSchedule,
+) -> Result<(), String> {
+    if ctx.sender() != ctx.database_identity() {
+        return Err("Unauthorized: record_table_sizes is only run by the scheduler".into());
+    }
+    let counts: Vec<(&str, u64)> = TRACKED_TABLES
+        .iter()
+        .filter_map(|table_name| match tracked_table_count(ctx, table_name) {
+            Some(count) => Some((*table_name, count)),
+            None => {
+                log::warn!("No row count for tracked table {}", table_name);
+                None
+            }
+        })
+        .collect();
+    for row in table_size_rows(&counts, ctx.timestamp) {
+        ctx.db.table_size_history().insert(row);
+    }
+    log::info!("Recorded sizes of {} tables", counts.len());
+    Ok(())
+}
+
+/// Whole milliseconds from `started` to `finished`; 0 if the clock went backwards.
+fn processing_ms(started: Timestamp, finished: Timestamp) -> u64 {
+    let elapsed = finished.duration_since(started).unwrap_or_default();
+    elapsed.as_millis().min(u64::MAX as u128) as u64
+}
+
+/// Store how long the hook request `correlation_id` of `stage` took from `started` to the
+/// current transaction, in `stage_timing` and in the `processing_ms` of its log rows. Drops
+/// `stage_timing` rows that have left the `STAGE_TIMING_WINDOW_HOURS` window.
+pub(crate) fn record_stage_timing(
+    ctx: &ReducerContext,
+    stage: &str,
+    correlation_id: &str,
+    started: Timestamp,
+) {
+    let cutoff = hour_bucket(ctx.timestamp).saturating_sub(stage_timing_window_hours() - 1);
+    let expired: Vec<u64> = ctx
+        .db
+        .stage_timing()
+        .hour()
+        .filter(..cutoff)
+        .map(|r| r.id)
+        .collect();
+    for id in expired {
+        ctx.db.stage_timing().id().delete(&id);
+    }
+
+    let elapsed = ctx.timestamp.duration_since(started).unwrap_or_default();
+    ctx.db.stage_timing().insert(StageTiming {
+        id: 0,
+        hour: hour_bucket(ctx.timestamp),
+        stage: stage.to_string(),
+        elapsed_micros: elapsed.as_micros().min(u64::MAX as u128) as u64,
+        recorded_at: ctx.timestamp,
+    });
+    crate::mta::record_processing_ms(ctx, correlation_id, processing_ms(started, ctx.timestamp));
+}
+
+/// Nearest-rank percentile `p` (0–100) of the ascending `sorted`; `None` when it is empty.
+fn percentile(sorted: &[u64], p: u32) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (sorted.len() * p.min(100) as usize).div_ceil(100);
+    Some(sorted[rank.saturating_sub(1)])
+}
+
+/// Write p50 and p95 of the stored `stage_timing` rows per stage into `stage_timing_result`
+/// under `request_id`. Covers the last `STAGE_TIMING_WINDOW_HOURS` hours. Only admins.
+#[spacetimedb::reducer]
+pub fn stage_timing_percentiles(ctx: &ReducerContext, request_id: String) -> Result<(), String> {
+    if !is_admin_user(ctx) {
+        return Err("Unauthorized: Admin access required".to_string());
+    }
+    let mut by_stage: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for row in ctx.db.stage_timing().iter() {
+        by_stage
+            .entry(row.stage)
+            .or_default()
+            .push(row.elapsed_micros);
+    }
+
+    let mut result = StageTimingResult {
+        request_id: request_id.clone(),
+        requested_by: ctx.sender(),
+        stages: Vec::new(),
+        samples: Vec::new(),
+        p50_micros: Vec::new(),
+        p95_micros: Vec::new(),
+        computed_at: ctx.timestamp,
+    };
+    for (stage, mut elapsed) in by_stage {
+        elapsed.sort_unstable();
+        result.samples.push(elapsed.len() as u64);
+        result
+            .p50_micros
+            .push(percentile(&elapsed, 50).unwrap_or_default());
+        result
+            .p95_micros
+            .push(percentile(&elapsed, 95).unwrap_or_default());
+        result.stages.push(stage);
+    }
+    let owner = ctx
+        .db
+        .stage_timing_result()
+        .request_id()
+        .find(&request_id)
+        .map(|row| row.requested_by);
+    check_request_owner(owner, ctx.sender(), &request_id)?;
+    if owner.is_some() {
+        ctx.db.stage_timing_result().request_id().update(result);
+    } else {
+        ctx.db.stage_timing_result().insert(result);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentile_uses_the_nearest_rank() {
+        let sorted: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&sorted, 50), Some(10));
+        assert_eq!(percentile(&sorted, 95), Some(19));
+        assert_eq!(percentile(&sorted, 100), Some(20));
+    }
+
+    #[test]
+    fn percentile_of_a_single_sample_is_that_sample() {
+        assert_eq!(percentile(&[7], 50), Some(7));
+        assert_eq!(percentile(&[7], 95), Some(7));
+        assert_eq!(percentile(&[7], 0), Some(7));
+    }
+
+    #[test]
+    fn processing_ms_is_filled_and_never_negative() {
+        let at = Timestamp::from_micros_since_unix_epoch;
+        let started = at(1_700_000_000_000_000);
+        assert_eq!(processing_ms(started, at(1_700_000_001_500_400)), 1_500);
+        assert_eq!(processing_ms(started, started), 0);
+        // A clock that went backwards counts as no time, not as a wrapped huge value.
+        assert_eq!(processing_ms(started, at(1_699_999_999_000_000)), 0);
+    }
+
+    #[test]
+    fn percentile_of_no_samples_is_none() {
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn table_sizes_record_one_row_per_tracked_table() {
+        let at = Timestamp::from_micros_since_unix_epoch(1_700_000_000_000_000);
+        let counts: Vec<(&str, u64)> = TRACKED_TABLES.iter().map(|name| (*name, 1)).collect();
+        let rows = table_size_rows(&counts, at);
+        let mut recorded: Vec<&str> = rows.iter().map(|row| row.table_name.as_str()).collect();
+        assert_eq!(recorded, TRACKED_TABLES);
+        recorded.sort_unstable();
+        recorded.dedup();
+        assert_eq!(
+            recorded.len(),
+            TRACKED_TABLES.len(),
+            "duplicate tracked table"
+        );
+        assert!(rows.iter().all(|row| row.timestamp == at));
+    }
+
+    #[test]
+    fn messages_over_the_hourly_limit_are_deferred_until_the_next_hour() {
+        let at = |secs: i64| Timestamp::from_micros_since_unix_epoch(secs * 1_000_000);
+        let hour = 3_600 * 480_000;
+        let full = |row: &Option<CategoryHourlyCount>, secs| {
+            rate_limit_reached(count_in_hour(row.as_ref(), at(hour + secs)), 2)
+        };
+        let mut row = None;
+        for second in 0..2 {
+            assert!(!full(&row, second));
+            row = Some(counted(row, 1, at(hour + second)));
+        }
+        // The third message of the hour is over the limit of 2.
+        assert!(full(&row, 3_599));
+        // The next hour starts over.
+        assert_eq!(count_in_hour(row.as_ref(), at(hour + 3_600)), 0);
+        assert_eq!(counted(row, 1, at(hour + 3_600)).count, 1);
+    }
+}